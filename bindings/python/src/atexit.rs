@@ -5,7 +5,9 @@ macro_rules! create_register_atexit {
         fn atexit_register_hook(_py: pyo3::prelude::Python<'_>) {
             _py.allow_threads(move || {
                 log::info!("shutting down DEFAULT_POOL_HANDLER");
-                links_nonblocking::prelude::DEFAULT_POLL_HANDLER.shutdown(None);
+                if let Err(e) = links_nonblocking::prelude::DEFAULT_POLL_HANDLER.shutdown(None) {
+                    log::warn!("failed to shutdown DEFAULT_POOL_HANDLER, error: {}", e);
+                }
                 log::info!("shutting down DEFAULT_HBEAT_HANDLER");
                 links_nonblocking::prelude::DEFAULT_HBEAT_HANDLER.clear();
                 let sleep = std::time::Duration::from_millis(100);
@@ -75,6 +75,18 @@ impl From<&ConIdRs> for ConId {
                     None => "pending".to_owned(),
                 },
             },
+            UdsInitiator { name, path } => Self {
+                con_type: ConType::Initiator,
+                name: name.to_owned(),
+                local: "".to_owned(),
+                peer: path.display().to_string(),
+            },
+            UdsAcceptor { name, path } => Self {
+                con_type: ConType::Acceptor,
+                name: name.to_owned(),
+                local: path.display().to_string(),
+                peer: "".to_owned(),
+            },
         }
     }
 }
@@ -235,7 +247,7 @@ mod test {
 
         create_callback_for_messenger!(CltTestProtocolManual, CltTestProtocolManualCallback);
         let callback = CltTestProtocolManualCallback::new_ref(valid_python_callback);
-        let con_id = ConIdRs::clt(Some("clt"), None, "127.0.0.1:8080");
+        let con_id = ConIdRs::clt(Some("clt"), None, "127.0.0.1:8080").unwrap();
         let msg = CltTestMsgDebug::default().into();
         callback.on_sent(&con_id, &msg);
         let msg = SvcTestMsgDebug::default().into();
@@ -29,7 +29,7 @@ fn send_random_frame(c: &mut Criterion) {
             move || {
                 let listener = TcpListener::bind(addr).unwrap();
                 let (stream, _) = listener.accept().unwrap();
-                let (mut svc_reader, _svc_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::svc(Some("bench"), addr, None), stream);
+                let (mut svc_reader, _svc_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::svc(Some("bench"), addr, None).unwrap(), stream);
                 // info!("svc: reader: {}", reader);
                 let mut frame_recv_count = 0_u32;
                 loop {
@@ -57,7 +57,7 @@ fn send_random_frame(c: &mut Criterion) {
     sleep(Duration::from_millis(100)); // allow the spawned to bind
 
     // CONFIGURE clt
-    let (_clt_reader, mut clt_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::clt(Some("bench"), None, addr), TcpStream::connect(addr).unwrap());
+    let (_clt_reader, mut clt_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::clt(Some("bench"), None, addr).unwrap(), TcpStream::connect(addr).unwrap());
     // info!("clt: writer: {}", writer);
 
     let id = format!("framer_blocking_send_random_frame size: {} bytes", fmt_num!(BENCH_MAX_FRAME_SIZE));
@@ -90,7 +90,7 @@ fn recv_random_frame(c: &mut Criterion) {
             move || {
                 let listener = TcpListener::bind(addr).unwrap();
                 let (stream, _) = listener.accept().unwrap();
-                let (_svc_reader, mut svc_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::svc(Some("bench"), addr, None), stream);
+                let (_svc_reader, mut svc_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::svc(Some("bench"), addr, None).unwrap(), stream);
                 // info!("svc: writer: {}", writer);
                 let mut frame_send_count = 0_u32;
                 loop {
@@ -113,7 +113,7 @@ fn recv_random_frame(c: &mut Criterion) {
     sleep(Duration::from_millis(100)); // allow the spawned to bind
 
     // CONFIGURE clt
-    let (mut clt_reader, _clt_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::clt(Some("bench"), None, addr), TcpStream::connect(addr).unwrap());
+    let (mut clt_reader, _clt_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::clt(Some("bench"), None, addr).unwrap(), TcpStream::connect(addr).unwrap());
     // info!("clt: reader: {}", reader);
 
     let id = format!("framer_blocking_recv_random_frame size: {} bytes", fmt_num!(BENCH_MAX_FRAME_SIZE));
@@ -153,7 +153,7 @@ fn round_trip_random_frame(c: &mut Criterion) {
             move || {
                 let listener = TcpListener::bind(addr).unwrap();
                 let (stream, _) = listener.accept().unwrap();
-                let (mut svc_reader, mut svc_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::svc(Some("bench"), addr, None), stream);
+                let (mut svc_reader, mut svc_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::svc(Some("bench"), addr, None).unwrap(), stream);
                 // info!("svc: reader: {}", reader);
                 loop {
                     let res = svc_reader.read_frame();
@@ -178,7 +178,7 @@ fn round_trip_random_frame(c: &mut Criterion) {
     sleep(Duration::from_millis(100)); // allow the spawned to bind
 
     // CONFIGURE clt
-    let (mut clt_reader, mut clt_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::clt(Some("bench"), None, addr), TcpStream::connect(addr).unwrap());
+    let (mut clt_reader, mut clt_writer) = into_split_framer::<BenchMsgFramer, BENCH_MAX_FRAME_SIZE>(ConId::clt(Some("bench"), None, addr).unwrap(), TcpStream::connect(addr).unwrap());
     // info!("clt: writer: {}", writer);
 
     let id = format!("framer_blocking_round_trip_random_frame size: {} bytes", fmt_num!(BENCH_MAX_FRAME_SIZE));
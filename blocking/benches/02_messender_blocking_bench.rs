@@ -28,7 +28,7 @@ fn send_msg(c: &mut Criterion) {
         .spawn(move || {
             let listener = TcpListener::bind(addr).unwrap();
             let (stream, _) = listener.accept().unwrap();
-            let (mut svc_reader, _svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None), stream);
+            let (mut svc_reader, _svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), stream).unwrap();
             // info!("svc: reader: {}", reader);
             let mut frame_recv_count = 0_u32;
             while let Some(_) = svc_reader.recv().unwrap() {
@@ -42,7 +42,7 @@ fn send_msg(c: &mut Criterion) {
     sleep(Duration::from_millis(100)); // allow the spawned to bind
 
     // CONFIGURE clt
-    let (_clt_reader, mut clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), TcpStream::connect(addr).unwrap());
+    let (_clt_reader, mut clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), TcpStream::connect(addr).unwrap()).unwrap();
     // info!("clt: writer: {}", writer);
 
     let id = format!("messenger_blocking_send_msg TestCltMsg");
@@ -76,7 +76,7 @@ fn recv_msg(c: &mut Criterion) {
             let msg = SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"Hello Frm Server Msg"));
             let listener = TcpListener::bind(addr).unwrap();
             let (stream, _) = listener.accept().unwrap();
-            let (_svc_reader, mut svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(None, addr, None), stream);
+            let (_svc_reader, mut svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(None, addr, None).unwrap(), stream).unwrap();
             // info!("svc: writer: {}", writer);
             let mut frame_send_count = 0_u32;
             while let Ok(_) = svc_writer.send(&msg) {
@@ -89,7 +89,7 @@ fn recv_msg(c: &mut Criterion) {
     sleep(Duration::from_millis(100)); // allow the spawned to bind
 
     // CONFIGURE clt
-    let (mut clt_reader, _clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), TcpStream::connect(addr).unwrap());
+    let (mut clt_reader, _clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), TcpStream::connect(addr).unwrap()).unwrap();
     // info!("clt: reader: {}", reader);
 
     let id = format!("messenger_blocking_recv_msg TestSvcMsg");
@@ -124,7 +124,7 @@ fn round_trip_msg(c: &mut Criterion) {
                 let msg = SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"Hello Frm Server Msg"));
                 let listener = TcpListener::bind(addr).unwrap();
                 let (stream, _) = listener.accept().unwrap();
-                let (mut svc_reader, mut svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None), stream);
+                let (mut svc_reader, mut svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), stream).unwrap();
                 // info!("svc: reader: {}", reader);
                 loop {
                     let res = svc_reader.recv();
@@ -150,7 +150,7 @@ fn round_trip_msg(c: &mut Criterion) {
 
     // CONFIGURE clt
     let stream = TcpStream::connect(addr).unwrap();
-    let (mut clt_reader, mut clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), stream);
+    let (mut clt_reader, mut clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), stream).unwrap();
     // info!("clt: writer: {}", writer);
 
     let id = format!("messenger_blocking_round_trip_msg",);
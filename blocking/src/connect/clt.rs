@@ -91,7 +91,7 @@ impl<M: Messenger, C: CallbackRecvSend<M>, const MAX_MSG_SIZE: usize> Clt<M, C,
     pub fn connect(addr: &str, timeout: Duration, retry_after: Duration, callback: Arc<C>, name: Option<&str>) -> Result<Self, Error> {
         assert!(timeout > retry_after, "timeout: {:?}, retry_after: {:?}", timeout, retry_after);
         let now = Instant::now();
-        let con_id = ConId::clt(name, None, addr);
+        let con_id = ConId::clt(name, None, addr)?;
         while now.elapsed() < timeout {
             match TcpStream::connect(addr) {
                 Err(e) => {
@@ -100,7 +100,7 @@ impl<M: Messenger, C: CallbackRecvSend<M>, const MAX_MSG_SIZE: usize> Clt<M, C,
                     continue;
                 }
                 Ok(stream) => {
-                    return Ok(Self::from_stream(stream, con_id, callback));
+                    return Self::from_stream(stream, con_id, callback);
                 }
             }
         }
@@ -109,12 +109,12 @@ impl<M: Messenger, C: CallbackRecvSend<M>, const MAX_MSG_SIZE: usize> Clt<M, C,
         Err(Error::new(std::io::ErrorKind::TimedOut, msg))
     }
 
-    pub(crate) fn from_stream(stream: TcpStream, con_id: ConId, callback: Arc<C>) -> Self {
-        let (msg_recver, msg_sender) = into_split_messenger::<M, MAX_MSG_SIZE>(con_id, stream);
-        Self {
+    pub(crate) fn from_stream(stream: TcpStream, con_id: ConId, callback: Arc<C>) -> Result<Self, Error> {
+        let (msg_recver, msg_sender) = into_split_messenger::<M, MAX_MSG_SIZE>(con_id, stream)?;
+        Ok(Self {
             clt_recver: CltRecver::new(msg_recver, callback.clone()),
             clt_sender: CltSender::new(msg_sender, callback.clone()),
-        }
+        })
     }
     pub fn into_split(self) -> (CltRecver<M, C, MAX_MSG_SIZE>, CltSender<M, C, MAX_MSG_SIZE>) {
         (self.clt_recver, self.clt_sender)
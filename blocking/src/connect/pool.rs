@@ -378,7 +378,7 @@ impl<M: Messenger, C: CallbackSend<M>, const MAX_MSG_SIZE: usize> Display for Cl
 ///
 /// let addr = "127.0.0.1:8080";
 /// let acceptor = SvcAcceptor::<_,_, TEST_MSG_FRAME_SIZE>::new(
-///     ConId::svc(Some("doctest"), addr, None),
+///     ConId::svc(Some("doctest"), addr, None).unwrap(),
 ///     std::net::TcpListener::bind(addr).unwrap(),
 ///     DevNullCallback::<SvcTestMessenger>::default().into(),
 /// );
@@ -31,8 +31,7 @@ impl<M: Messenger, C: CallbackRecvSend<M>, const MAX_MSG_SIZE: usize> AcceptClt<
                 if log_enabled!(log::Level::Debug) {
                     debug!("{} Accepted", con_id);
                 }
-                let clt = Clt::<_, _, MAX_MSG_SIZE>::from_stream(stream, con_id.clone(), self.callback.clone());
-                Ok(clt)
+                Clt::<_, _, MAX_MSG_SIZE>::from_stream(stream, con_id.clone(), self.callback.clone())
             }
             Err(e) => Err(e),
         }
@@ -58,7 +57,7 @@ impl<M: Messenger, C: CallbackRecvSend<M>, const MAX_MSG_SIZE: usize> Svc<M, C,
         let listener = std::net::TcpListener::bind(addr)?;
 
         let acceptor = SvcAcceptor {
-            con_id: ConId::svc(name, addr, None),
+            con_id: ConId::svc(name, addr, None)?,
             callback,
             listener,
             phantom: std::marker::PhantomData,
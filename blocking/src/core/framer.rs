@@ -16,13 +16,13 @@
 //!
 //! let clt_stream = std::net::TcpStream::connect(addr).unwrap();
 //! let (clt_reader, clt_writer) = into_split_framer::<FixedSizeFramer<FRAME_SIZE>, FRAME_SIZE>(
-//!         ConId::clt(Some("unittest"), None, addr),
+//!         ConId::clt(Some("unittest"), None, addr).unwrap(),
 //!         clt_stream,
 //!     );
 //!
 //! let svc_stream = svc_listener.accept().unwrap().0;
 //! let (svc_reader, svc_writer) = into_split_framer::<FixedSizeFramer<FRAME_SIZE>, FRAME_SIZE>(
-//!         ConId::svc(Some("unittest"), addr, None),
+//!         ConId::svc(Some("unittest"), addr, None).unwrap(),
 //!         svc_stream,
 //!     );
 //!
@@ -307,7 +307,7 @@ mod test {
                 move || {
                     let listener = TcpListener::bind(addr).unwrap();
                     let (stream, _) = listener.accept().unwrap();
-                    let (mut svc_reader, _svc_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None), stream);
+                    let (mut svc_reader, _svc_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), stream);
                     info!("svc: reader: {}", svc_reader);
                     let mut frame_recv_count = 0_usize;
                     loop {
@@ -334,7 +334,7 @@ mod test {
 
         sleep(Duration::from_millis(100)); // allow the spawned to bind
                                            // CONFIGURE clt
-        let (mut clt_reader, mut clt_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), TcpStream::connect(addr).unwrap());
+        let (mut clt_reader, mut clt_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), TcpStream::connect(addr).unwrap());
 
         info!("clt: {}", clt_writer);
 
@@ -15,15 +15,15 @@
 //!
 //! let clt_stream = std::net::TcpStream::connect(addr).unwrap();
 //! let (clt_recv, clt_send) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(
-//!         ConId::clt(Some("unittest"), None, addr),
+//!         ConId::clt(Some("unittest"), None, addr).unwrap(),
 //!         clt_stream,
-//!     );
+//!     ).unwrap();
 //!
 //! let svc_stream = svc_listener.accept().unwrap().0;
 //! let (svc_recv, svc_send) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(
-//!         ConId::svc(Some("unittest"), addr, None),
+//!         ConId::svc(Some("unittest"), addr, None).unwrap(),
 //!         svc_stream,
-//!     );
+//!     ).unwrap();
 //!
 //! drop(clt_recv);
 //! drop(clt_send);
@@ -119,11 +119,18 @@ pub type MessageProcessor<M, const MAX_MSG_SIZE: usize> = (MessageRecver<M, MAX_
 ///
 /// # Important
 /// if either [MessageRecver] or [MessageSender] is dropped, the underlying stream will be shutdown and all actions on the remaining `pair` will fail
-pub fn into_split_messenger<M: Messenger, const MAX_MSG_SIZE: usize>(mut con_id: ConId, stream: TcpStream) -> MessageProcessor<M, MAX_MSG_SIZE> {
-    con_id.set_local(stream.local_addr().unwrap());
-    con_id.set_peer(stream.peer_addr().unwrap());
-    let (reader, writer) = (stream.try_clone().expect("Failed to try_clone TcpStream for MessageRecver"), stream);
-    (MessageRecver::<M, MAX_MSG_SIZE>::new(con_id.clone(), reader), MessageSender::<M, MAX_MSG_SIZE>::new(con_id, writer))
+///
+/// # Errors
+/// [links_core::Error::Disconnected] if the `stream` is no longer connected, for example when the peer resets it right after it was accepted
+pub fn into_split_messenger<M: Messenger, const MAX_MSG_SIZE: usize>(mut con_id: ConId, stream: TcpStream) -> Result<MessageProcessor<M, MAX_MSG_SIZE>, Error> {
+    let disconnected = |con_id: &ConId, addr: &str, e: Error| links_core::Error::Disconnected {
+        con_id: Box::new(con_id.clone()),
+        reason: format!("failed to get {} from stream: {:?}, error: {}", addr, stream, e),
+    };
+    con_id.set_local(stream.local_addr().map_err(|e| disconnected(&con_id, "local_addr", e))?);
+    con_id.set_peer(stream.peer_addr().map_err(|e| disconnected(&con_id, "peer_addr", e))?);
+    let (reader, writer) = (stream.try_clone()?, stream);
+    Ok((MessageRecver::<M, MAX_MSG_SIZE>::new(con_id.clone(), reader), MessageSender::<M, MAX_MSG_SIZE>::new(con_id, writer)))
 }
 
 #[cfg(test)]
@@ -162,7 +169,7 @@ mod test {
                 let (mut svc_msg_sent_count, mut svc_msg_recv_count) = (0_usize, 0_usize);
                 let listener = TcpListener::bind(addr).unwrap();
                 let (stream, _) = listener.accept().unwrap();
-                let (mut svc_recver, mut svc_sender) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None), stream);
+                let (mut svc_recver, mut svc_sender) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), stream).unwrap();
                 info!("{} connected", svc_sender);
 
                 while let Some(_) = svc_recver.recv().unwrap() {
@@ -181,7 +188,7 @@ mod test {
         let inp_clt_msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Client Msg"));
         let (mut clt_msg_sent_count, mut clt_msg_recv_count) = (0, 0);
         let stream = TcpStream::connect(addr).unwrap();
-        let (mut clt_recver, mut clt_sender) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), stream);
+        let (mut clt_recver, mut clt_sender) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), stream).unwrap();
         info!("{} connected", clt_sender);
         let start = Instant::now();
         for _ in 0..WRITE_N_TIMES {
@@ -1,6 +1,8 @@
+use crate::core::error::Error;
 use std::{
     fmt::Display,
     net::SocketAddr,
+    path::{Path, PathBuf},
     time::{Duration, Instant},
 };
 
@@ -9,68 +11,117 @@ use std::{
 /// #Variants
 /// * Initiator: Indicates a Clt connection or the side of the link which initiated the connection
 /// * Acceptor: Indicates a Svc connection or the side of the link which accepted the connection
+/// * UdsInitiator: Same as Initiator but for a unix domain socket link, where `path` is the filesystem path the Svc is bound to
+/// * UdsAcceptor: Same as Acceptor but for a unix domain socket link, where `path` is the filesystem path the Svc is bound to
 #[derive(Debug, Clone, PartialEq)]
 pub enum ConId {
     Initiator { name: String, local: Option<SocketAddr>, peer: SocketAddr },
     Acceptor { name: String, local: SocketAddr, peer: Option<SocketAddr> },
+    UdsInitiator { name: String, path: PathBuf },
+    UdsAcceptor { name: String, path: PathBuf },
 }
 impl ConId {
-    pub fn clt(name: Option<&str>, local: Option<&str>, peer: &str) -> Self {
-        ConId::Initiator {
+    /// # Errors
+    /// [Error::InvalidAddress] if either `local` or `peer` can't be parsed
+    pub fn clt(name: Option<&str>, local: Option<&str>, peer: &str) -> Result<Self, Error> {
+        Ok(ConId::Initiator {
+            name: name.unwrap_or("unknown").to_owned(),
+            local: local.map(parse_addr).transpose()?,
+            peer: parse_addr(peer)?,
+        })
+    }
+    /// Unix domain socket variant of [ConId::clt], where `path` is the filesystem path the Svc is bound to
+    pub fn clt_uds(name: Option<&str>, path: impl AsRef<Path>) -> Self {
+        ConId::UdsInitiator {
             name: name.unwrap_or("unknown").to_owned(),
-            local: local.map(|addr| addr.parse().unwrap_or_else(|_| panic!("unable to parse addr: {:?}", addr))),
-            peer: peer.parse().unwrap_or_else(|_| panic!("unable to parse addr: {:?}", peer)),
+            path: path.as_ref().to_path_buf(),
         }
     }
+    /// Has no effect on unix domain socket variants
     pub fn set_local(&mut self, local: SocketAddr) {
         match self {
             ConId::Initiator { local: l, .. } => *l = Some(local),
             ConId::Acceptor { local: l, .. } => *l = local,
+            ConId::UdsInitiator { .. } | ConId::UdsAcceptor { .. } => {}
         }
     }
+    /// Has no effect on unix domain socket variants
     pub fn set_peer(&mut self, peer: SocketAddr) {
         match self {
             ConId::Initiator { peer: p, .. } => *p = peer,
             ConId::Acceptor { peer: p, .. } => *p = Some(peer),
+            ConId::UdsInitiator { .. } | ConId::UdsAcceptor { .. } => {}
         }
     }
 
-    pub fn svc(name: Option<&str>, local: &str, peer: Option<&str>) -> Self {
-        ConId::Acceptor {
+    /// # Errors
+    /// [Error::InvalidAddress] if either `local` or `peer` can't be parsed
+    pub fn svc(name: Option<&str>, local: &str, peer: Option<&str>) -> Result<Self, Error> {
+        Ok(ConId::Acceptor {
             name: name.unwrap_or("unknown").to_owned(),
-            local: local.parse().unwrap_or_else(|_| panic!("unable to parse addr: {:?}", local)),
-            peer: peer.map(|addr| addr.parse().unwrap_or_else(|_| panic!("unable to parse addr: {:?}", addr))),
+            local: parse_addr(local)?,
+            peer: peer.map(parse_addr).transpose()?,
+        })
+    }
+    /// Unix domain socket variant of [ConId::svc], where `path` is the filesystem path the Svc is bound to
+    pub fn svc_uds(name: Option<&str>, path: impl AsRef<Path>) -> Self {
+        ConId::UdsAcceptor {
+            name: name.unwrap_or("unknown").to_owned(),
+            path: path.as_ref().to_path_buf(),
         }
     }
     pub fn name(&self) -> &str {
         match self {
             ConId::Initiator { name, .. } => name,
             ConId::Acceptor { name, .. } => name,
+            ConId::UdsInitiator { name, .. } => name,
+            ConId::UdsAcceptor { name, .. } => name,
         }
     }
+    /// Always [None] for unix domain socket variants, see [ConId::get_path]
     pub fn get_peer(&self) -> Option<SocketAddr> {
         match self {
             ConId::Initiator { peer, .. } => Some(*peer),
             ConId::Acceptor { peer, .. } => *peer,
+            ConId::UdsInitiator { .. } | ConId::UdsAcceptor { .. } => None,
         }
     }
+    /// Always [None] for unix domain socket variants, see [ConId::get_path]
     pub fn get_local(&self) -> Option<SocketAddr> {
         match self {
             ConId::Initiator { local, .. } => *local,
             ConId::Acceptor { local, .. } => Some(*local),
+            ConId::UdsInitiator { .. } | ConId::UdsAcceptor { .. } => None,
+        }
+    }
+    /// Filesystem path of the unix domain socket, always [None] for tcp variants
+    pub fn get_path(&self) -> Option<&Path> {
+        match self {
+            ConId::UdsInitiator { path, .. } => Some(path),
+            ConId::UdsAcceptor { path, .. } => Some(path),
+            ConId::Initiator { .. } | ConId::Acceptor { .. } => None,
         }
     }
     pub fn from_same_lineage(&self, other: &Self) -> bool {
         match (self, other) {
             // listening ports are unique hence must be ( self IS other | other is a Clt that was started by self )
             (ConId::Acceptor { local: l1, .. }, ConId::Acceptor { local: l2, .. }) => l1 == l2, 
+            // same applies to listening paths
+            (ConId::UdsAcceptor { path: p1, .. }, ConId::UdsAcceptor { path: p2, .. }) => p1 == p2,
             _ => false,
         }
     }
 }
+fn parse_addr(addr: &str) -> Result<SocketAddr, Error> {
+    addr.parse().map_err(|e: std::net::AddrParseError| Error::InvalidAddress { addr: addr.to_owned(), reason: e.to_string() })
+}
 impl Default for ConId {
     fn default() -> Self {
-        ConId::clt(None, None, "0.0.0.0:0")
+        ConId::Initiator {
+            name: "unknown".to_owned(),
+            local: None,
+            peer: SocketAddr::from(([0, 0, 0, 0], 0)),
+        }
     }
 }
 impl Display for ConId {
@@ -96,6 +147,8 @@ impl Display for ConId {
                     }
                 )
             }
+            ConId::UdsInitiator { name, path } => write!(f, "UdsInitiator({name}@->{})", path.display()),
+            ConId::UdsAcceptor { name, path } => write!(f, "UdsAcceptor({name}@{}<-)", path.display()),
         }
     }
 }
@@ -159,14 +212,39 @@ mod test {
     #[test]
     fn test_con_id() {
         setup::log::configure();
-        let con_id = ConId::clt(Some("unittest"), None, "0.0.0.0:1");
+        let con_id = ConId::clt(Some("unittest"), None, "0.0.0.0:1").unwrap();
         info!("con_id: {:?}", con_id);
         info!("con_id: {}", con_id);
         assert_eq!(con_id.to_string(), "Initiator(unittest@pending->0.0.0.0:1)");
 
-        let con_id = ConId::svc(Some("unittest"), "0.0.0.0:1", None);
+        let con_id = ConId::svc(Some("unittest"), "0.0.0.0:1", None).unwrap();
         info!("con_id: {:?}", con_id);
         info!("con_id: {}", con_id);
         assert_eq!(con_id.to_string(), "Acceptor(unittest@0.0.0.0:1<-pending)");
+
+        let con_id = ConId::clt_uds(Some("unittest"), "/tmp/links.sock");
+        info!("con_id: {}", con_id);
+        assert_eq!(con_id.to_string(), "UdsInitiator(unittest@->/tmp/links.sock)");
+        assert_eq!(con_id.get_path(), Some(std::path::Path::new("/tmp/links.sock")));
+        assert_eq!(con_id.get_peer(), None);
+
+        let con_id = ConId::svc_uds(Some("unittest"), "/tmp/links.sock");
+        info!("con_id: {}", con_id);
+        assert_eq!(con_id.to_string(), "UdsAcceptor(unittest@/tmp/links.sock<-)");
+        assert!(con_id.from_same_lineage(&ConId::svc_uds(None, "/tmp/links.sock")));
+    }
+
+    #[test]
+    fn test_con_id_invalid_addr() {
+        setup::log::configure();
+        let res = ConId::clt(Some("unittest"), None, "not an addr");
+        info!("res: {:?}", res);
+        assert!(matches!(res, Err(crate::Error::InvalidAddress { .. })));
+
+        let res = ConId::svc(Some("unittest"), "0.0.0.0:1", Some("0.0.0.0"));
+        info!("res: {:?}", res);
+        assert!(matches!(res, Err(crate::Error::InvalidAddress { .. })));
+
+        assert_eq!(ConId::default(), ConId::clt(None, None, "0.0.0.0:0").unwrap());
     }
 }
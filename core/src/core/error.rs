@@ -0,0 +1,142 @@
+use crate::core::conid::ConId;
+use std::{
+    fmt::Display,
+    io::{self, ErrorKind},
+};
+
+/// Typed error of a links connection, every variant other than [Error::InvalidAddress] & [Error::Io] carries the [ConId] of the connection it relates to,
+/// the [ConId] is boxed to keep [Error] small enough to be returned by value on hot paths.
+///
+/// # Important
+/// [ConId] construction and the poll handler, poll handler group and runtime management apis return [Error], while the connection level traits, such as sending
+/// and receiving messages, return [std::io::Error]. Use [From] conversions to move between the two, an [Error] converted into [std::io::Error] keeps the
+/// respective [ErrorKind] and is recovered intact when converted back
+///
+/// # Example
+/// ```
+/// use links_core::{prelude::*, Error};
+///
+/// let error = Error::Disconnected { con_id: Box::new(ConId::default()), reason: "peer closed".to_owned() };
+/// let io_error = std::io::Error::from(error);
+/// assert_eq!(io_error.kind(), std::io::ErrorKind::NotConnected);
+///
+/// let error = Error::from(io_error);
+/// assert!(matches!(error, Error::Disconnected { .. }));
+/// assert_eq!(error.con_id(), Some(&ConId::default()));
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// Connection is closed or was never established
+    Disconnected { con_id: Box<ConId>, reason: String },
+    /// Operation did not complete within the allotted time
+    Timeout { con_id: Box<ConId>, reason: String },
+    /// Frame received could not be deserialized into a message
+    Serialization { con_id: Box<ConId>, reason: String },
+    /// Connection was refused, for example because the max number of connections is reached or the handler it was given to is shut down
+    Rejected { con_id: Box<ConId>, reason: String },
+    /// Address could not be parsed
+    InvalidAddress { addr: String, reason: String },
+    /// Any other [std::io::Error], with the [ConId] when it is known
+    Io { con_id: Option<Box<ConId>>, source: io::Error },
+}
+impl Error {
+    pub fn con_id(&self) -> Option<&ConId> {
+        use Error::*;
+        match self {
+            Disconnected { con_id, .. } | Timeout { con_id, .. } | Serialization { con_id, .. } | Rejected { con_id, .. } => Some(con_id.as_ref()),
+            InvalidAddress { .. } => None,
+            Io { con_id, .. } => con_id.as_deref(),
+        }
+    }
+    /// [ErrorKind] used when converting into [std::io::Error]
+    pub fn kind(&self) -> ErrorKind {
+        use Error::*;
+        match self {
+            Disconnected { .. } => ErrorKind::NotConnected,
+            Timeout { .. } => ErrorKind::TimedOut,
+            Serialization { .. } => ErrorKind::InvalidData,
+            Rejected { .. } => ErrorKind::ConnectionRefused,
+            InvalidAddress { .. } => ErrorKind::InvalidInput,
+            Io { source, .. } => source.kind(),
+        }
+    }
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Error::*;
+        match self {
+            Disconnected { con_id, reason } => write!(f, "{} disconnected: {}", con_id, reason),
+            Timeout { con_id, reason } => write!(f, "{} timeout: {}", con_id, reason),
+            Serialization { con_id, reason } => write!(f, "{} serialization error: {}", con_id, reason),
+            Rejected { con_id, reason } => write!(f, "{} rejected: {}", con_id, reason),
+            InvalidAddress { addr, reason } => write!(f, "unable to parse addr: {:?}, reason: {}", addr, reason),
+            Io { con_id: Some(con_id), source } => write!(f, "{} io error: {}", con_id, source),
+            Io { con_id: None, source } => write!(f, "io error: {}", source),
+        }
+    }
+}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+impl From<io::Error> for Error {
+    /// Recovers the [Error] if `error` was created from one, otherwise wraps it into [Error::Io] without a [ConId]
+    fn from(error: io::Error) -> Self {
+        if let Some(true) = error.get_ref().map(|inner| inner.is::<Self>()) {
+            if let Some(Ok(error)) = error.into_inner().map(|inner| inner.downcast::<Self>()) {
+                return *error;
+            }
+            unreachable!("io::Error inner was checked to be links_core::Error");
+        }
+        Error::Io { con_id: None, source: error }
+    }
+}
+impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+        match error {
+            Error::Io { con_id: None, source } => source,
+            error => io::Error::new(error.kind(), error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::unittest::setup;
+    use log::info;
+
+    #[test]
+    fn test_error_io_round_trip() {
+        setup::log::configure();
+        let con_id = ConId::clt(Some("unittest"), None, "0.0.0.0:1").unwrap();
+
+        let error = Error::Timeout {
+            con_id: Box::new(con_id.clone()),
+            reason: "no heart beat".to_owned(),
+        };
+        info!("error: {}", error);
+        let io_error = io::Error::from(error);
+        info!("io_error: {}", io_error);
+        assert_eq!(io_error.kind(), ErrorKind::TimedOut);
+
+        let error = Error::from(io_error);
+        assert!(matches!(error, Error::Timeout { .. }));
+        assert_eq!(error.con_id(), Some(&con_id));
+
+        // plain io errors are wrapped and unwrapped as is
+        let error = Error::from(io::Error::new(ErrorKind::BrokenPipe, "broken"));
+        assert!(matches!(error, Error::Io { con_id: None, .. }));
+        assert_eq!(error.con_id(), None);
+        let io_error = io::Error::from(error);
+        assert_eq!(io_error.kind(), ErrorKind::BrokenPipe);
+        assert!(matches!(io_error.get_ref(), Some(inner) if !inner.is::<Error>()));
+
+        // boxed ConId keeps the error cheap to return by value
+        assert!(std::mem::size_of::<Error>() <= 64, "size_of::<Error>(): {}", std::mem::size_of::<Error>());
+    }
+}
@@ -1,5 +1,6 @@
 pub mod conid;
 pub mod counters;
+pub mod error;
 pub mod framer;
 pub mod macros;
 pub mod messenger;
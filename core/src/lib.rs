@@ -6,5 +6,7 @@ pub mod prelude;
 pub mod scheduler;
 pub mod stores;
 
+pub use crate::core::error::Error;
+
 #[cfg(feature = "unittest")]
 pub mod unittest;
@@ -9,7 +9,10 @@ pub use crate::core::pool::RoundRobinPool;
 pub use crate::core::counters::max_connection::{AcceptorConnectionGate, RemoveConnectionBarrierOnDrop};
 
 // timer
-pub use crate::scheduler::{task::TimerTaskStatus, timer::Timer};
+pub use crate::scheduler::{
+    task::TimerTaskStatus,
+    timer::{Timer, TimerHandle},
+};
 
 // callbacks
 pub use crate::callbacks::CallbackRecv;
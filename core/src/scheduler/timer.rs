@@ -4,7 +4,7 @@ use log::{debug, info, log_enabled, warn};
 use std::{
     collections::BinaryHeap,
     sync::mpsc::{channel, Receiver, Sender, TryRecvError},
-    thread::{park, park_timeout, Builder, JoinHandle},
+    thread::{park, park_timeout, Builder, JoinHandle, Thread},
     time::Duration,
 };
 
@@ -35,6 +35,14 @@ impl Timer {
         self.jh_executor.thread().unpark();
     }
 
+    /// Returns a cloneable [TimerHandle] which can schedule tasks on this [Timer] without borrowing it
+    pub fn handle(&self) -> TimerHandle {
+        TimerHandle {
+            tx_task: self.tx_task.clone(),
+            executor: self.jh_executor.thread().clone(),
+        }
+    }
+
     /// Drops all scheduled tasks and stops the executor thread
     pub fn terminate(self) {
         self.tx_task.send(Operation::Terminate).unwrap();
@@ -49,6 +57,26 @@ impl Timer {
     }
 }
 
+/// A cloneable handle issued by [Timer::handle] that can only schedule tasks. Tasks scheduled after the [Timer] was terminated are dropped.
+#[derive(Debug, Clone)]
+pub struct TimerHandle {
+    tx_task: Sender<Operation>,
+    executor: Thread,
+}
+impl TimerHandle {
+    /// Same as [Timer::schedule]
+    pub fn schedule<T: FnMut() -> TimerTaskStatus + Send + 'static>(&self, name: &str, repeat: Duration, task: T) {
+        let task = Box::new(task);
+        let task_schedule = Task::new(name, repeat, task);
+
+        if let Err(e) = self.tx_task.send(Operation::Execute(task_schedule)) {
+            warn!("{} executor thread is terminated, dropping task: {:?}", asserted_short_name!("TimerHandle", Self), e.0);
+            return;
+        }
+        self.executor.unpark();
+    }
+}
+
 /// This class runs in a separate thread and is responsible for executing tasks passed to it via rx_task channel.
 struct Executor {
     name: String,
@@ -255,4 +283,32 @@ mod test {
 
         sleep(REPEAT_INTERVAL * 2);
     }
+
+    #[test]
+    fn test_timer_handle() {
+        setup::log::configure_level(log::LevelFilter::Debug);
+        let timer = Timer::new("unittest");
+        let handle = timer.handle();
+
+        static TASK_REMAINING_ITERATIONS: AtomicU32 = AtomicU32::new(3);
+        static REPEAT_INTERVAL: Duration = Duration::from_millis(10);
+
+        handle.clone().schedule("task", REPEAT_INTERVAL, || {
+            let iteration_remaining = TASK_REMAINING_ITERATIONS.fetch_sub(1, Ordering::Relaxed) - 1;
+            info!("task, iterations_remaining {}", iteration_remaining);
+            if iteration_remaining == 0 {
+                TimerTaskStatus::Terminate
+            } else {
+                TimerTaskStatus::Completed
+            }
+        });
+
+        let now = Instant::now();
+        while TASK_REMAINING_ITERATIONS.load(Ordering::Relaxed) > 0 && now.elapsed() < REPEAT_INTERVAL * 100 {}
+        assert_eq!(TASK_REMAINING_ITERATIONS.load(Ordering::Relaxed), 0);
+
+        // once terminated the handle drops new tasks instead of panicking
+        timer.terminate();
+        handle.schedule("dropped", REPEAT_INTERVAL, || TimerTaskStatus::Terminate);
+    }
 }
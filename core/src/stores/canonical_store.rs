@@ -205,13 +205,13 @@ mod test {
 
         let svc_on_recv_msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"SVC: on_recv Message"));
         let svc_on_sent_msg = SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"SVC: on_send Message"));
-        svc_clb.on_recv(&ConId::svc(Some("svc"), "0.0.0.0:0", None), &svc_on_recv_msg);
-        svc_clb.on_sent(&ConId::svc(Some("svc"), "0.0.0.0:0", None), &svc_on_sent_msg);
+        svc_clb.on_recv(&ConId::svc(Some("svc"), "0.0.0.0:0", None).unwrap(), &svc_on_recv_msg);
+        svc_clb.on_sent(&ConId::svc(Some("svc"), "0.0.0.0:0", None).unwrap(), &svc_on_sent_msg);
 
         let clt_on_recv_msg = SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"CLT: on_recv Message"));
         let clt_on_send_msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"CLT: on_send Message"));
-        clt_clb.on_recv(&ConId::clt(Some("clt"), None, "0.0.0.0:0"), &clt_on_recv_msg);
-        clt_clb.on_sent(&ConId::clt(Some("clt"), None, "0.0.0.0:0"), &clt_on_send_msg);
+        clt_clb.on_recv(&ConId::clt(Some("clt"), None, "0.0.0.0:0").unwrap(), &clt_on_recv_msg);
+        clt_clb.on_sent(&ConId::clt(Some("clt"), None, "0.0.0.0:0").unwrap(), &clt_on_send_msg);
 
         info!("store: {}", store);
 
@@ -99,6 +99,13 @@ pub mod setup {
             Box::leak(addr)
         }
 
+        /// Returns a unique unix domain socket path in the temp directory, e.g. `/tmp/links_1234_5678.sock`
+        pub fn rand_avail_uds_path() -> std::path::PathBuf {
+            use rand::Rng;
+            let id = rand::thread_rng().gen::<u32>();
+            std::env::temp_dir().join(format!("links_{}_{}.sock", std::process::id(), id))
+        }
+
         pub fn default_connect_timeout() -> Duration {
             Duration::from_millis(1000) // 1000 mil | 1 sec
         }
@@ -29,7 +29,7 @@ fn send_msg(c: &mut Criterion) {
         .spawn(move || {
             let listener = TcpListener::bind(addr).unwrap();
             let (stream, _) = listener.accept().unwrap();
-            let (mut svc_reader, _svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None), stream);
+            let (mut svc_reader, _svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), stream).unwrap();
             // info!("svc: reader: {}", reader);
             let mut frame_recv_count = 0_u32;
             loop {
@@ -52,7 +52,7 @@ fn send_msg(c: &mut Criterion) {
     sleep(Duration::from_millis(100)); // allow the spawned to bind
 
     // CONFIGURE clt
-    let (_clt_reader, mut clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), TcpStream::connect(addr).unwrap());
+    let (_clt_reader, mut clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), TcpStream::connect(addr).unwrap()).unwrap();
     // info!("clt: writer: {}", writer);
 
     let id = format!("messenger_nonblocking_send_msg TestCltMsg");
@@ -86,7 +86,7 @@ fn recv_msg(c: &mut Criterion) {
             let msg = SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"Hello Frm Server Msg"));
             let listener = TcpListener::bind(addr).unwrap();
             let (stream, _) = listener.accept().unwrap();
-            let (_clt_reader, mut svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(None, addr, None), stream);
+            let (_clt_reader, mut svc_writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(None, addr, None).unwrap(), stream).unwrap();
             // info!("svc: writer: {}", writer);
             let mut msg_send_count = 0_u32;
             while let Ok(status) = svc_writer.send(&msg) {
@@ -104,7 +104,7 @@ fn recv_msg(c: &mut Criterion) {
     sleep(Duration::from_millis(100)); // allow the spawned to bind
 
     // CONFIGURE clt
-    let (mut clt_reader, _clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), TcpStream::connect(addr).unwrap());
+    let (mut clt_reader, _clt_writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), TcpStream::connect(addr).unwrap()).unwrap();
     // info!("clt: reader: {}", reader);
 
     let id = format!("messenger_nonblocking_recv_msg TestSvcMsg");
@@ -145,7 +145,7 @@ fn round_trip_msg(c: &mut Criterion) {
                 let msg = SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"Hello Frm Server Msg"));
                 let listener = TcpListener::bind(addr).unwrap();
                 let (stream, _) = listener.accept().unwrap();
-                let (mut reader, mut writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None), stream);
+                let (mut reader, mut writer) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), stream).unwrap();
                 // info!("svc: reader: {}", reader);
                 while let Ok(status) = reader.recv() {
                     match status {
@@ -165,7 +165,7 @@ fn round_trip_msg(c: &mut Criterion) {
 
     // CONFIGURE clt
     let stream = TcpStream::connect(addr).unwrap();
-    let (mut reader, mut writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), stream);
+    let (mut reader, mut writer) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), stream).unwrap();
     // info!("clt: writer: {}", writer);
 
     let id = format!("messenger_nonblocking_round_trip_msg",);
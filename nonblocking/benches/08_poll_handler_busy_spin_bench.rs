@@ -0,0 +1,98 @@
+use std::{num::NonZeroUsize, thread::Builder};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use links_core::{
+    fmt_num,
+    prelude::{CounterCallback, DevNullCallback},
+    unittest::setup::{
+        self,
+        framer::TEST_MSG_FRAME_SIZE,
+        model::{CltTestMsg, CltTestMsgDebug, SvcTestMsg, SvcTestMsgDebug},
+    },
+};
+use links_nonblocking::{
+    prelude::*,
+    unittest::setup::protocol::{CltTestProtocolManual, SvcTestProtocolManual},
+};
+use log::{info, LevelFilter};
+static LOG_LEVEL: LevelFilter = LevelFilter::Error;
+
+/// Measures the round trip where the echo is received by a [CltRecverRef] serviced by a [PollHandler] thread configured with `options`,
+/// hence the difference between configurations is the latency of the poll thread waking up to the reply
+fn round_trip_msg(c: &mut Criterion, id: &str, options: PollHandlerOptions) {
+    setup::log::configure_level(LOG_LEVEL);
+    let addr = setup::net::rand_avail_addr_port();
+    let name = Some("bench");
+    let timeout = setup::net::default_connect_timeout();
+    let retry_after = timeout / 10;
+
+    let clt_acceptor_jh = Builder::new()
+        .name("Acceptor-Thread".to_owned())
+        .spawn(move || {
+            let svc = Svc::<_, _, TEST_MSG_FRAME_SIZE>::bind(addr, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, name).unwrap();
+
+            let (mut clt_acceptor_recv, mut clt_acceptor_send) = svc.accept_busywait_timeout(timeout).unwrap().unwrap_accepted().into_split_ref();
+            info!("clt_acceptor_recv: {}", clt_acceptor_recv);
+
+            let mut clt_acceptor_msg_recv_count = 0_usize;
+            let mut clt_acceptor_msg_send = SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"Hello Frm Server Msg"));
+            loop {
+                match clt_acceptor_recv.recv_busywait() {
+                    Ok(Some(_recv_msg)) => {
+                        clt_acceptor_send.send_busywait(&mut clt_acceptor_msg_send).unwrap();
+                        clt_acceptor_msg_recv_count += 1;
+                    }
+                    Ok(None) => {
+                        info!("Connection Closed by clt_initiator clt_acceptor_recv: {}", clt_acceptor_recv);
+                        break;
+                    }
+                    Err(err) => {
+                        info!("Connection Closed by clt_initiator, clt_acceptor_recv: {}, err: {}", clt_acceptor_recv, err);
+                        break;
+                    }
+                }
+            }
+            clt_acceptor_msg_recv_count
+        })
+        .unwrap();
+
+    let counter = CounterCallback::new_ref();
+    let (clt_initiator_recv, mut clt_initiator_send) = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect(addr, timeout, retry_after, counter.clone(), CltTestProtocolManual, name).unwrap().into_split_ref();
+    let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler_with(format!("{}-Poll-Thread", id).as_str(), options).unwrap();
+    spawned_poll_handler.add_recver(clt_initiator_recv.into()).unwrap();
+    info!("clt_initiator_send: {}", clt_initiator_send);
+
+    let mut clt_initiator_msg_send_count = 0_usize;
+    let mut clt_initiator_send_msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Client Msg"));
+    c.bench_function(id, |b| {
+        b.iter(|| {
+            clt_initiator_send.send_busywait(&mut clt_initiator_send_msg).unwrap();
+            clt_initiator_msg_send_count += 1;
+            while counter.recv_count() < clt_initiator_msg_send_count {}
+            black_box(clt_initiator_msg_send_count)
+        })
+    });
+
+    drop(spawned_poll_handler); // drops clt_initiator_recv
+    drop(clt_initiator_send); // this will allow svc.join to complete
+    let clt_acceptor_msg_recv_count = clt_acceptor_jh.join().unwrap();
+    info!(
+        "clt_acceptor_msg_recv_count: {:?} > clt_initiator_msg_send_count: {:?}",
+        fmt_num!(clt_acceptor_msg_recv_count),
+        fmt_num!(clt_initiator_msg_send_count)
+    );
+
+    assert_eq!(clt_initiator_msg_send_count, clt_acceptor_msg_recv_count);
+}
+
+fn round_trip_msg_parked(c: &mut Criterion) {
+    round_trip_msg(c, "poll_handler_parked_round_trip_msg SvcTestMsg", PollHandlerOptions::default());
+}
+
+fn round_trip_msg_busy_spin(c: &mut Criterion) {
+    round_trip_msg(c, "poll_handler_busy_spin_round_trip_msg SvcTestMsg", PollHandlerOptions::default().with_busy_spin(true));
+}
+
+criterion_group!(benches, round_trip_msg_parked, round_trip_msg_busy_spin);
+
+criterion_main!(benches);
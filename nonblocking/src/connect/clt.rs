@@ -1,6 +1,8 @@
+use crate::core::framer::SharedOutboundQueue;
 use crate::prelude::{
-    asserted_short_name, into_split_messenger, CallbackRecv, CallbackRecvSend, CallbackSend, ConId, ConnectionId, ConnectionStatus, MessageRecver, MessageSender, Messenger, PollAble, PollEventStatus, PollRead, Protocol, ReSendNonBlocking,
-    RecvNonBlocking, RecvStatus, RemoveConnectionBarrierOnDrop, SendNonBlocking, SendNonBlockingNonMut, SendStatus, TimerTaskStatus,
+    asserted_short_name, into_split_messenger, into_split_messenger_transport, CallbackRecv, CallbackRecvSend, CallbackSend, ConId, ConnectTransport, ConnectionId, ConnectionStatus, MessageRecver, MessageSender, Messenger, PollAble, PollConnect,
+    PollEventStatus, PollRead, PollWrite, Protocol, ReSendNonBlocking, RecvNonBlocking, RecvStatus, RemoveConnectionBarrierOnDrop, SendBatchError, SendNonBlocking, SendNonBlockingNonMut, SendStatus, SocketOptions, SpawnedPollHandlerDynamic, Timer,
+    TimerHandle, TimerTaskStatus, Transport,
 };
 use log::{debug, info, log_enabled, warn};
 use std::{
@@ -8,7 +10,10 @@ use std::{
     io::Error,
     net::TcpStream,
     ops::DerefMut,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
     thread::sleep,
     time::{Duration, Instant},
 };
@@ -22,52 +27,93 @@ use std::{
 /// # Warning
 /// Dropping [CltRecver] will also result in termination of the connection in the `paired` [CltSender] instance
 #[derive(Debug)]
-pub struct CltRecver<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> {
-    msg_recver: MessageRecver<P, MAX_MSG_SIZE>,
+pub struct CltRecver<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
+    msg_recver: MessageRecver<P, MAX_MSG_SIZE, T>,
     callback: Arc<C>,
     protocol: Arc<P>,
     #[allow(dead_code)] // exists to indicate to Svc::accept that this connection no longer active when Self is dropped and is only set by Svc and not Clt
     acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>,
+    last_recv: Instant,                    // only updated when [crate::prelude::ProtocolCore::conf_recv_timeout] is configured
+    is_recv_withheld: bool,                // set when [crate::prelude::ProtocolCore::is_recv_ready] withholds a message and cleared by [CltRecver::take_recv_withheld]
+    outbound: Option<SharedOutboundQueue>, // outbound queue of the `paired` [CltSender], only set by [Clt] when [crate::prelude::ProtocolCore::conf_outbound_queue_high_water_mark] is enabled
+    sender_due: Option<Arc<SenderDue>>,    // shared with the `paired` [CltSender], only set by [Clt::into_split]
 }
-impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> CltRecver<P, C, MAX_MSG_SIZE> {
-    pub fn new(recver: MessageRecver<P, MAX_MSG_SIZE>, callback: Arc<C>, protocol: Arc<P>, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Self {
+impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport> CltRecver<P, C, MAX_MSG_SIZE, T> {
+    pub fn new(recver: MessageRecver<P, MAX_MSG_SIZE, T>, callback: Arc<C>, protocol: Arc<P>, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Self {
         Self {
             msg_recver: recver,
             callback,
             protocol,
             acceptor_connection_gate,
+            last_recv: Instant::now(),
+            is_recv_withheld: false,
+            outbound: None,
+            sender_due: None,
+        }
+    }
+    /// Effective [SocketOptions] of the underlying connection, see [Transport::socket_options]
+    pub fn socket_options(&self) -> Result<SocketOptions, Error> {
+        self.msg_recver.frm_reader.stream_reader.socket_options()
+    }
+    /// `true` if [crate::prelude::ProtocolCore::is_recv_ready] withheld a message since the last call, used to issue [Protocol::re_send_reply]
+    #[inline(always)]
+    pub(crate) fn take_recv_withheld(&mut self) -> bool {
+        std::mem::take(&mut self.is_recv_withheld)
+    }
+    /// Flags [Protocol::re_send_reply] as due for the `paired` [CltSender] of [Clt::into_split], see [CltSender::send_due]
+    #[inline(always)]
+    fn flag_re_send_reply_due(&self) {
+        if let Some(ref sender_due) = self.sender_due {
+            sender_due.re_send_reply.store(true, Ordering::Release);
         }
     }
 }
-impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> RecvNonBlocking<P::RecvT> for CltRecver<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport> RecvNonBlocking<P::RecvT> for CltRecver<P, C, MAX_MSG_SIZE, T> {
     // NOTE: that the [RecvNonBlocking::recv_busywait] & [RecvNonBlocking::recv_busywait_timeout] default implementation
     // is not overridden because the callback is only issues when [RecvStatus::Completed] is returned, hence default implementation is sufficient
+    /// Messages withheld by [crate::prelude::ProtocolCore::is_recv_ready] are neither delivered to [CallbackRecv::on_recv] nor returned, instead the next
+    /// message is read, while messages yielded by [crate::prelude::ProtocolCore::pop_recv_ready] are delivered before reading from the connection.
     #[inline(always)]
     fn recv(&mut self) -> Result<RecvStatus<P::RecvT>, Error> {
-        match self.msg_recver.recv()? {
-            RecvStatus::Completed(Some(msg)) => {
-                self.protocol.on_recv(self, &msg);
-                self.callback.on_recv(self.con_id(), &msg);
-                Ok(RecvStatus::Completed(Some(msg)))
+        if let Some(msg) = self.protocol.pop_recv_ready() {
+            self.callback.on_recv(self.con_id(), &msg);
+            return Ok(RecvStatus::Completed(Some(msg)));
+        }
+        loop {
+            match self.msg_recver.recv()? {
+                RecvStatus::Completed(Some(msg)) => {
+                    if self.protocol.conf_recv_timeout().is_some() {
+                        self.last_recv = Instant::now();
+                    }
+                    self.protocol.on_recv(self, &msg);
+                    if !self.protocol.is_recv_ready(self, &msg) {
+                        self.is_recv_withheld = true;
+                        self.flag_re_send_reply_due();
+                        continue;
+                    }
+                    self.callback.on_recv(self.con_id(), &msg);
+                    self.flag_re_send_reply_due();
+                    return Ok(RecvStatus::Completed(Some(msg)));
+                }
+                RecvStatus::Completed(None) => return Ok(RecvStatus::Completed(None)),
+                RecvStatus::WouldBlock => return Ok(RecvStatus::WouldBlock),
             }
-            RecvStatus::Completed(None) => Ok(RecvStatus::Completed(None)),
-            RecvStatus::WouldBlock => Ok(RecvStatus::WouldBlock),
         }
     }
 }
-impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> ConnectionId for CltRecver<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionId for CltRecver<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn con_id(&self) -> &ConId {
         &self.msg_recver.frm_reader.con_id
     }
 }
-impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> ConnectionStatus for CltRecver<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionStatus for CltRecver<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn is_connected(&self) -> bool {
         self.protocol.is_connected()
     }
 }
-impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> PollRead for CltRecver<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport> PollRead for CltRecver<P, C, MAX_MSG_SIZE, T> {
     fn on_readable_event(&mut self) -> Result<PollEventStatus, Error> {
         use RecvStatus::*;
         match self.recv()? {
@@ -76,13 +122,73 @@ impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> PollRead for Cl
             Completed(None) => Ok(PollEventStatus::Terminate),
         }
     }
+    #[inline(always)]
+    fn recv_deadline(&self) -> Option<Instant> {
+        self.protocol.conf_recv_timeout().map(|timeout| self.last_recv + timeout)
+    }
+    /// Calls [crate::prelude::ProtocolCore::on_recv_timeout] and shuts down the connection if no message was received within [crate::prelude::ProtocolCore::conf_recv_timeout]
+    fn on_recv_deadline(&mut self) -> Result<PollEventStatus, Error> {
+        let elapsed = self.last_recv.elapsed();
+        match self.protocol.conf_recv_timeout() {
+            Some(timeout) if elapsed >= timeout => {
+                if log_enabled!(log::Level::Warn) {
+                    warn!("{} no message received for: {:?}, exceeds recv_timeout: {:?}, terminating connection", self.con_id(), elapsed, timeout);
+                }
+                self.protocol.on_recv_timeout(self, elapsed);
+                self.msg_recver.frm_reader.shutdown(std::net::Shutdown::Both, "CltRecver::on_recv_deadline");
+                Ok(PollEventStatus::Terminate)
+            }
+            _ => Ok(PollEventStatus::WouldBlock),
+        }
+    }
+    /// Includes [mio::Interest::WRITABLE] when [crate::prelude::ProtocolCore::conf_outbound_queue_high_water_mark] is enabled so that the outbound queue
+    /// of the `paired` [CltSender] is drained as soon as the socket becomes writable
+    #[inline(always)]
+    fn interests(&self) -> mio::Interest {
+        match self.outbound {
+            Some(_) => mio::Interest::READABLE | mio::Interest::WRITABLE,
+            None => mio::Interest::READABLE,
+        }
+    }
+    #[inline(always)]
+    fn as_poll_write(&mut self) -> Option<&mut dyn PollWrite> {
+        match self.outbound {
+            Some(_) => Some(self),
+            None => None,
+        }
+    }
+    /// Same as [Protocol::conf_heart_beat_interval] but only if [CltRecver] was created using [Clt::into_split]
+    #[inline(always)]
+    fn timer_interval(&self) -> Option<Duration> {
+        self.sender_due.as_ref().and(self.protocol.conf_heart_beat_interval())
+    }
+    /// Flags [Protocol::send_heart_beat] as due, it is then sent by the `paired` [CltSender], which is the only writer of the connection, see [CltSender::send_due]
+    fn on_timer(&mut self) -> Result<TimerTaskStatus, Error> {
+        match self.sender_due {
+            Some(ref sender_due) => {
+                sender_due.hbeat.store(true, Ordering::Release);
+                Ok(TimerTaskStatus::Completed)
+            }
+            None => Ok(TimerTaskStatus::Terminate),
+        }
+    }
+}
+impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport> PollWrite for CltRecver<P, C, MAX_MSG_SIZE, T> {
+    /// Drains the outbound queue of the `paired` [CltSender] which allows the owned [Clt::into_split] to flush frames queued by [CltSender::send]
+    fn on_writable_event(&mut self) -> Result<PollEventStatus, Error> {
+        if let Some(outbound) = &self.outbound {
+            // nothing else can be written until the next writable event whether the queue is fully drained or not
+            self.msg_recver.frm_reader.drain_outbound_queue(outbound)?;
+        }
+        Ok(PollEventStatus::WouldBlock)
+    }
 }
-impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> PollAble for CltRecver<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport> PollAble for CltRecver<P, C, MAX_MSG_SIZE, T> {
     fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
         Box::new(&mut self.msg_recver.frm_reader.stream_reader)
     }
 }
-impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> Display for CltRecver<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport> Display for CltRecver<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let recv_t = std::any::type_name::<P::RecvT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         let send_t = std::any::type_name::<P::SendT>().split("::").last().unwrap_or("Unknown").replace('>', "");
@@ -90,6 +196,35 @@ impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> Display for Clt
     }
 }
 
+/// Delay before [Protocol::send_heart_beat] is retried when the socket would block, it is not retried immediately to avoid spinning the thread servicing the timer
+const HBEAT_RETRY_AFTER: Duration = Duration::from_millis(1);
+
+/// Maps the outcome of [Protocol::send_heart_beat] to the [TimerTaskStatus] which reschedules it
+fn heart_beat_task_status(con_id: &ConId, res: Result<SendStatus, Error>) -> TimerTaskStatus {
+    match res {
+        Ok(SendStatus::Completed) => TimerTaskStatus::Completed,
+        Ok(SendStatus::WouldBlock) => TimerTaskStatus::RetryAfter(HBEAT_RETRY_AFTER),
+        Err(err) => {
+            warn!("{} Failed to send heart beat. Will no longer attempt to send. err:\n{}", con_id, err);
+            TimerTaskStatus::Terminate
+        }
+    }
+}
+
+/// Work flagged by the owned [CltRecver] and carried out by the `paired` [CltSender], so that the connection is only ever written by the thread owning [CltSender], see [Clt::into_split]
+#[derive(Debug, Default)]
+struct SenderDue {
+    hbeat: AtomicBool,
+    re_send_reply: AtomicBool,
+}
+impl SenderDue {
+    /// Clears the flag and yields `true` if it was set, the flag is only read unless set to avoid contending with the [CltRecver] thread
+    #[inline(always)]
+    fn take(flag: &AtomicBool) -> bool {
+        flag.load(Ordering::Relaxed) && flag.swap(false, Ordering::Acquire)
+    }
+}
+
 /// An abstraction over a [MessageSender] that executes [crate::prelude::ProtocolCore::on_send], [crate::prelude::ProtocolCore::on_sent]/[crate::prelude::ProtocolCore::on_wouldblock]/[crate::prelude::ProtocolCore::on_error] and [CallbackSend::on_sent] on every message processed by [CltSender].
 /// It is designed to work in a single thread that is different from [CltRecver] thread
 ///
@@ -99,25 +234,69 @@ impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> Display for Clt
 /// # Warning
 /// Dropping [CltSender] will also result in termination of the connection in the `paired` [CltRecver] instance
 #[derive(Debug)]
-pub struct CltSender<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> {
-    msg_sender: MessageSender<P, MAX_MSG_SIZE>,
+pub struct CltSender<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
+    msg_sender: MessageSender<P, MAX_MSG_SIZE, T>,
     callback: Arc<C>,
     protocol: Arc<P>,
     #[allow(dead_code)] // exists to indicate to Svc::accept that this connection no longer active when Self is dropped
     // Options because only Svc sets up the barrier but Clt does not
     acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>,
-    is_on_disconnect: bool, // to ensure that on_drop is called only once this is due to the fact that CltSenderRef calls it on its drop and then CltSender also calls it on its drop
+    is_on_disconnect: bool,             // to ensure that on_drop is called only once this is due to the fact that CltSenderRef calls it on its drop and then CltSender also calls it on its drop
+    sender_due: Option<Arc<SenderDue>>, // shared with the `paired` [CltRecver], only set by [Clt::into_split]
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> CltSender<P, C, MAX_MSG_SIZE> {
-    pub fn new(sender: MessageSender<P, MAX_MSG_SIZE>, callback: Arc<C>, protocol: Arc<P>, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Self {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> CltSender<P, C, MAX_MSG_SIZE, T> {
+    pub fn new(sender: MessageSender<P, MAX_MSG_SIZE, T>, callback: Arc<C>, protocol: Arc<P>, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Self {
         Self {
             msg_sender: sender,
             callback,
             protocol,
             acceptor_connection_gate,
             is_on_disconnect: false,
+            sender_due: None,
         }
     }
+    /// Effective [SocketOptions] of the underlying connection, see [Transport::socket_options]
+    pub fn socket_options(&self) -> Result<SocketOptions, Error> {
+        self.msg_sender.frm_writer.stream_writer.socket_options()
+    }
+    /// Sends [Protocol::re_send_reply] and [Protocol::send_heart_beat] once they were flagged as due by the `paired` [CltRecver], see [Clt::into_split].
+    /// This is issued ahead of every message sent by this instance, hence it only has to be called by a [CltSender] which stays idle for longer than [Protocol::conf_heart_beat_interval]
+    /// or needs to reply to the peer without sending messages of its own
+    ///
+    /// # Errors
+    /// If either of [Protocol::re_send_reply] or [Protocol::send_heart_beat] fails, a heart beat which would block remains due
+    #[inline(always)]
+    pub fn send_due(&mut self) -> Result<(), Error> {
+        let (is_re_send_reply_due, is_hbeat_due) = match self.sender_due {
+            Some(ref sender_due) => (SenderDue::take(&sender_due.re_send_reply), SenderDue::take(&sender_due.hbeat)),
+            None => return Ok(()),
+        };
+        if !is_re_send_reply_due && !is_hbeat_due {
+            return Ok(());
+        }
+        let protocol = self.protocol.clone();
+        if is_re_send_reply_due {
+            protocol.re_send_reply(self)?;
+        }
+        if is_hbeat_due {
+            if let SendStatus::WouldBlock = protocol.send_heart_beat(self)? {
+                if let Some(ref sender_due) = self.sender_due {
+                    sender_due.hbeat.store(true, Ordering::Release);
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Writes messages waiting in the outbound queue without `busy waiting`, see [crate::prelude::ProtocolCore::conf_outbound_queue_high_water_mark]
+    #[inline(always)]
+    pub fn drain_outbound_queue(&mut self) -> Result<SendStatus, Error> {
+        self.msg_sender.frm_writer.drain_outbound_queue()
+    }
+    /// Number of bytes waiting in the outbound queue, see [crate::prelude::ProtocolCore::conf_outbound_queue_high_water_mark]
+    #[inline(always)]
+    pub fn outbound_queue_len(&self) -> usize {
+        self.msg_sender.frm_writer.outbound_queue_len()
+    }
     fn on_disconnect(&mut self) {
         if self.is_on_disconnect {
             return;
@@ -143,9 +322,10 @@ impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> CltSender<P, C,
         self.msg_sender.frm_writer.shutdown(std::net::Shutdown::Both, "CltSender::on_disconnect");
     }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> SendNonBlocking<P::SendT> for CltSender<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> SendNonBlocking<P::SendT> for CltSender<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn send(&mut self, msg: &mut <P as Messenger>::SendT) -> Result<SendStatus, Error> {
+        self.send_due()?;
         self.protocol.on_send(self, msg);
         let res = self.msg_sender.send(msg);
         match res {
@@ -170,6 +350,7 @@ impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> SendNonBlocking
         // NOTE: that the [SendNonBlocking::send_busywait_timeout] default implementation is overridden to ensure correct callback sequence
         use SendStatus::{Completed, WouldBlock};
         let start = Instant::now();
+        self.send_due()?;
         self.protocol.on_send(self, msg);
         loop {
             let res = self.msg_sender.send(msg);
@@ -198,6 +379,7 @@ impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> SendNonBlocking
     fn send_busywait(&mut self, msg: &mut <P as Messenger>::SendT) -> Result<(), Error> {
         // NOTE: that the [SendNonBlocking::send_busywait] default implementation is overridden to ensure correct callback sequence
         use SendStatus::{Completed, WouldBlock};
+        self.send_due()?;
         self.protocol.on_send(self, msg);
         loop {
             let res = self.msg_sender.send(msg);
@@ -215,32 +397,65 @@ impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> SendNonBlocking
             }
         }
     }
+    /// Overrides the default implementation by serializing all of the `msgs` into a single buffer which is then written using as few
+    /// system calls as the underlying stream permits, see [crate::prelude::FrameWriter::write_frames]
+    ///
+    /// # Important
+    /// [crate::prelude::ProtocolCore::on_send] is called for every message prior to serialization, after which every message gets exactly one of
+    /// [crate::prelude::ProtocolCore::on_sent] along with [CallbackSend::on_sent], [crate::prelude::ProtocolCore::on_wouldblock] or [crate::prelude::ProtocolCore::on_error]
+    #[inline(always)]
+    fn send_batch(&mut self, msgs: &mut [<P as Messenger>::SendT]) -> Result<usize, SendBatchError> {
+        self.send_due()?;
+        for msg in msgs.iter_mut() {
+            self.protocol.on_send(self, msg);
+        }
+        let mut completed = 0;
+        let res = self.msg_sender.send_batch(msgs, &mut completed);
+        for msg in msgs[..completed].iter() {
+            self.protocol.on_sent(self, msg);
+            self.callback.on_sent(self.con_id(), msg);
+        }
+        match res {
+            Ok(_) => {
+                for msg in msgs[completed..].iter() {
+                    self.protocol.on_wouldblock(self, msg);
+                }
+                Ok(completed)
+            }
+            Err(e) => {
+                for msg in msgs[completed..].iter() {
+                    self.protocol.on_error(self, msg, &e);
+                }
+                Err(SendBatchError::new(completed, e))
+            }
+        }
+    }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> ReSendNonBlocking<P::SendT> for CltSender<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ReSendNonBlocking<P::SendT> for CltSender<P, C, MAX_MSG_SIZE, T> {
     fn re_send(&mut self, msg: &P::SendT) -> Result<SendStatus, Error> {
         self.msg_sender.send(msg)
     }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> ConnectionId for CltSender<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionId for CltSender<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn con_id(&self) -> &ConId {
         &self.msg_sender.frm_writer.con_id
     }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> ConnectionStatus for CltSender<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionStatus for CltSender<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn is_connected(&self) -> bool {
         self.protocol.is_connected()
     }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> Display for CltSender<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Display for CltSender<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let recv_t = std::any::type_name::<P::RecvT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         let send_t = std::any::type_name::<P::SendT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         write!(f, "{}<{}, RecvT:{}, SendT:{}, {}>", asserted_short_name!("CltSender", Self), self.con_id(), recv_t, send_t, MAX_MSG_SIZE)
     }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> Drop for CltSender<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Drop for CltSender<P, C, MAX_MSG_SIZE, T> {
     fn drop(&mut self) {
         self.on_disconnect()
     }
@@ -257,37 +472,46 @@ impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> Drop for CltSen
 /// Dropping any of the [CltRecverRef] clones will terminate the connection across all remaining instances,
 /// including all clones of `paired` [CltSenderRef] instances.
 #[derive(Debug)]
-pub struct CltRecverRef<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> {
+pub struct CltRecverRef<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
     con_id: ConId, // this is a clone copy fro CltSender to avoid mutex call to id a connection
-    clt_recver: Arc<spin::Mutex<CltRecver<P, C, MAX_MSG_SIZE>>>,
-    clt_sender: CltSenderRef<P, C, MAX_MSG_SIZE>,
+    clt_recver: Arc<spin::Mutex<CltRecver<P, C, MAX_MSG_SIZE, T>>>,
+    clt_sender: CltSenderRef<P, C, MAX_MSG_SIZE, T>,
     protocol: Arc<P>,
+    is_hbeat_on_poll: bool, // set when [Protocol::send_heart_beat] is issued by [PollRead::on_timer] instead of a [Timer] thread
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> RecvNonBlocking<P::RecvT> for CltRecverRef<P, C, MAX_MSG_SIZE> {
-    /// Delegates to [CltRecver] and calls [Protocol::send_reply] when a message is received
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> CltRecverRef<P, C, MAX_MSG_SIZE, T> {
+    /// Delegates to [CltRecver] and calls [Protocol::send_reply] when a message is received followed by [Protocol::re_send_reply] when a message is received or withheld
     #[inline(always)]
-    fn recv(&mut self) -> Result<RecvStatus<<P as Messenger>::RecvT>, Error> {
+    fn recv_and_reply(&mut self) -> Result<RecvStatus<<P as Messenger>::RecvT>, Error> {
         use RecvStatus::Completed;
-        let res = self.clt_recver.lock().recv();
-        if let Ok(Completed(Some(ref msg))) = res {
+        let (status, is_withheld) = {
+            let mut clt_recver = self.clt_recver.lock(); // release lock quickly, before replying
+            (clt_recver.recv()?, clt_recver.take_recv_withheld())
+        };
+        if let Completed(Some(ref msg)) = status {
             self.protocol.send_reply(msg, &mut self.clt_sender)?;
+            self.protocol.re_send_reply(&mut self.clt_sender)?;
+        } else if is_withheld {
+            self.protocol.re_send_reply(&mut self.clt_sender)?;
         }
-        res
+        Ok(status)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> RecvNonBlocking<P::RecvT> for CltRecverRef<P, C, MAX_MSG_SIZE, T> {
+    /// Delegates to [CltRecver] and calls [Protocol::send_reply] & [Protocol::re_send_reply] when a message is received
+    #[inline(always)]
+    fn recv(&mut self) -> Result<RecvStatus<<P as Messenger>::RecvT>, Error> {
+        self.recv_and_reply()
     }
-    /// Delegates to [CltRecver] and calls [Protocol::send_reply] when a message is received
+    /// Delegates to [CltRecver] and calls [Protocol::send_reply] & [Protocol::re_send_reply] when a message is received
     #[inline(always)]
     fn recv_busywait_timeout(&mut self, timeout: Duration) -> Result<RecvStatus<<P as Messenger>::RecvT>, Error> {
         // NOTE: that the [RecvNonBlocking::recv_busywait_timeout] default implementation is overridden to reduce lock contention
         use RecvStatus::{Completed, WouldBlock};
         let start = Instant::now();
         loop {
-            let status = self.clt_recver.lock().recv()?;
-            match status {
-                Completed(Some(msg)) => {
-                    self.protocol.send_reply(&msg, &mut self.clt_sender)?;
-                    return Ok(Completed(Some(msg)));
-                }
-                Completed(None) => return Ok(Completed(None)),
+            match self.recv_and_reply()? {
+                Completed(msg) => return Ok(Completed(msg)),
                 WouldBlock => {
                     if start.elapsed() > timeout {
                         return Ok(WouldBlock);
@@ -296,37 +520,32 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> RecvNonBloc
             }
         }
     }
-    /// Delegates to [CltRecver] and calls [Protocol::send_reply] when the message is received.
+    /// Delegates to [CltRecver] and calls [Protocol::send_reply] & [Protocol::re_send_reply] when the message is received.
     #[inline(always)]
     fn recv_busywait(&mut self) -> Result<Option<<P as Messenger>::RecvT>, Error> {
         // NOTE: that the [RecvNonBlocking::recv_busywait] default implementation is overridden to reduce lock contention
         use RecvStatus::{Completed, WouldBlock};
         loop {
-            let status = self.clt_recver.lock().recv()?; // release lock quickly, don't lock using recv_busywait
-            match status {
-                Completed(Some(msg)) => {
-                    self.protocol.send_reply(&msg, &mut self.clt_sender)?;
-                    return Ok(Some(msg));
-                }
-                Completed(None) => return Ok(None),
+            match self.recv_and_reply()? {
+                Completed(msg) => return Ok(msg),
                 WouldBlock => continue,
             }
         }
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ConnectionId for CltRecverRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionId for CltRecverRef<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn con_id(&self) -> &ConId {
         &self.con_id
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ConnectionStatus for CltRecverRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionStatus for CltRecverRef<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn is_connected(&self) -> bool {
         self.protocol.is_connected()
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollRead for CltRecverRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> PollRead for CltRecverRef<P, C, MAX_MSG_SIZE, T> {
     fn on_readable_event(&mut self) -> Result<PollEventStatus, Error> {
         use RecvStatus::*;
         match self.recv()? {
@@ -335,8 +554,52 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollRead fo
             Completed(None) => Ok(PollEventStatus::Terminate),
         }
     }
+    /// Delegates to [CltRecver] once a spin lock is acquired.
+    #[inline(always)]
+    fn recv_deadline(&self) -> Option<Instant> {
+        self.clt_recver.lock().recv_deadline()
+    }
+    /// Delegates to [CltRecver] once a spin lock is acquired.
+    #[inline(always)]
+    fn on_recv_deadline(&mut self) -> Result<PollEventStatus, Error> {
+        self.clt_recver.lock().on_recv_deadline()
+    }
+    /// Includes [mio::Interest::WRITABLE] when [crate::prelude::ProtocolCore::conf_outbound_queue_high_water_mark] is enabled so that the outbound queue
+    /// of the [CltSenderRef] is drained as soon as the socket becomes writable
+    #[inline(always)]
+    fn interests(&self) -> mio::Interest {
+        match self.protocol.conf_outbound_queue_high_water_mark() {
+            Some(_) => mio::Interest::READABLE | mio::Interest::WRITABLE,
+            None => mio::Interest::READABLE,
+        }
+    }
+    #[inline(always)]
+    fn as_poll_write(&mut self) -> Option<&mut dyn PollWrite> {
+        Some(self)
+    }
+    /// Same as [Protocol::conf_heart_beat_interval] unless heart beats are triggered by a [Timer] thread, see [Clt::into_split_ref_on]
+    #[inline(always)]
+    fn timer_interval(&self) -> Option<Duration> {
+        match self.is_hbeat_on_poll {
+            true => self.protocol.conf_heart_beat_interval(),
+            false => None,
+        }
+    }
+    /// Calls [Protocol::send_heart_beat] on the [CltSenderRef] once a spin lock is acquired.
+    fn on_timer(&mut self) -> Result<TimerTaskStatus, Error> {
+        Ok(heart_beat_task_status(&self.con_id, self.clt_sender.send_heart_beat()))
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> PollWrite for CltRecverRef<P, C, MAX_MSG_SIZE, T> {
+    /// Drains the outbound queue of the [CltSenderRef] once a spin lock is acquired.
+    #[inline(always)]
+    fn on_writable_event(&mut self) -> Result<PollEventStatus, Error> {
+        // nothing else can be written until the next writable event whether the queue is fully drained or not
+        self.clt_sender.drain_outbound_queue()?;
+        Ok(PollEventStatus::WouldBlock)
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollAble for CltRecverRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> PollAble for CltRecverRef<P, C, MAX_MSG_SIZE, T> {
     fn register(&mut self, registry: &mio::Registry, token: mio::Token, interests: mio::Interest) -> Result<(), Error> {
         let mut guard = self.clt_recver.lock();
         registry.register(&mut guard.msg_recver.frm_reader.stream_reader, token, interests)
@@ -345,18 +608,22 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollAble fo
         let mut guard = self.clt_recver.lock();
         registry.deregister(&mut guard.msg_recver.frm_reader.stream_reader)
     }
+    fn reregister(&mut self, registry: &mio::Registry, token: mio::Token, interests: mio::Interest) -> Result<(), Error> {
+        let mut guard = self.clt_recver.lock();
+        registry.reregister(&mut guard.msg_recver.frm_reader.stream_reader, token, interests)
+    }
     fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
         panic!("Invalid API usage. PollReadable::register and PollReadable::deregister are overridden and this call shall never be issued.")
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Display for CltRecverRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Display for CltRecverRef<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let recv_t = std::any::type_name::<P::RecvT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         let send_t = std::any::type_name::<P::SendT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         write!(f, "{}<{}, RecvT:{}, SendT:{}, {}>", asserted_short_name!("CltRecverRef", Self), self.con_id(), recv_t, send_t, MAX_MSG_SIZE)
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Drop for CltRecverRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Drop for CltRecverRef<P, C, MAX_MSG_SIZE, T> {
     fn drop(&mut self) {
         // because this is a reference counted instance, need to manually shutdown reader to ensure other references
         // understand that this instance is terminated. This is part of the contract of [CltRecverRef] and [CltSenderRef]
@@ -364,13 +631,14 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Drop for Cl
         self.clt_recver.lock().msg_recver.frm_reader.shutdown(std::net::Shutdown::Both, "CltRecverRef::drop");
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Clone for CltRecverRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Clone for CltRecverRef<P, C, MAX_MSG_SIZE, T> {
     fn clone(&self) -> Self {
         Self {
             con_id: self.con_id.clone(),
             clt_recver: self.clt_recver.clone(),
             clt_sender: self.clt_sender.clone(),
             protocol: self.protocol.clone(),
+            is_hbeat_on_poll: self.is_hbeat_on_poll,
         }
     }
 }
@@ -379,27 +647,38 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Clone for C
 /// It is designed to cloned and shared across threads at the cost of spin lock on every call.
 ///
 /// # Important
-/// In addition to delegating method calls it enables enhanced features of the [Protocol] trait, such as [Protocol::send_heart_beat] & [Protocol::conf_heart_beat_interval]
-/// by registering a clone of [CltSenderRef] to run [static@crate::connect::DEFAULT_HBEAT_HANDLER] thread, when this instance is created during call to [`Clt::into_split_ref()`]
+/// In addition to delegating method calls it enables enhanced features of the [Protocol] trait, such as [Protocol::send_heart_beat] & [Protocol::conf_heart_beat_interval],
+/// which are triggered by the [crate::prelude::PollHandler] servicing the `paired` [CltRecverRef] when created using [Clt::into_sender_with_spawned_recver_ref],
+/// or by registering a clone of [CltSenderRef] to run [static@crate::connect::DEFAULT_HBEAT_HANDLER] thread, when this instance is created during call to [`Clt::into_split_ref()`]
 ///
 /// # Warning
 /// Dropping any of the [CltSenderRef] clones will terminate the connection across all remaining instances,
 /// including all clones of `paired` [CltRecverRef] instances.
 #[derive(Debug)]
-pub struct CltSenderRef<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> {
+pub struct CltSenderRef<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
     con_id: ConId, // this is a clone copy from CltSender to avoid mutex call to id a connection
-    clt_sender: Arc<spin::Mutex<CltSender<P, C, MAX_MSG_SIZE>>>,
+    clt_sender: Arc<spin::Mutex<CltSender<P, C, MAX_MSG_SIZE, T>>>,
     pub(crate) protocol: Arc<P>, // clone of protocol to avoid locking sender in order to access [ProtocolCore::is_connected] call
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> CltSenderRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> CltSenderRef<P, C, MAX_MSG_SIZE, T> {
     /// This method is only implemented for [CltSenderRef] and not for [CltSender] because it only makes sense to have this method
     /// when heart beats are sent in a different thread from user thread, but it is not possible to share [CltSender] across threads.
     pub(crate) fn send_heart_beat(&self) -> Result<SendStatus, Error> {
         let mut guard = self.clt_sender.lock();
         self.protocol.send_heart_beat(guard.deref_mut())
     }
+    /// Delegates to [CltSender] once a spin lock is acquired.
+    #[inline(always)]
+    pub fn drain_outbound_queue(&self) -> Result<SendStatus, Error> {
+        self.clt_sender.lock().drain_outbound_queue()
+    }
+    /// Delegates to [CltSender] once a spin lock is acquired.
+    #[inline(always)]
+    pub fn outbound_queue_len(&self) -> usize {
+        self.clt_sender.lock().outbound_queue_len()
+    }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> SendNonBlocking<P::SendT> for CltSenderRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> SendNonBlocking<P::SendT> for CltSenderRef<P, C, MAX_MSG_SIZE, T> {
     /// Delegates to [CltSender] once a spin lock is acquired.
     #[inline(always)]
     fn send(&mut self, msg: &mut <P as Messenger>::SendT) -> Result<SendStatus, Error> {
@@ -435,28 +714,40 @@ impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> SendNonBlocking
             }
         }
     }
+    /// Delegates to [CltSender] once a spin lock is acquired.
+    #[inline(always)]
+    fn send_batch(&mut self, msgs: &mut [<P as Messenger>::SendT]) -> Result<usize, SendBatchError> {
+        self.clt_sender.lock().send_batch(msgs)
+    }
+}
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ReSendNonBlocking<P::SendT> for CltSenderRef<P, C, MAX_MSG_SIZE, T> {
+    /// Delegates to [CltSender] once a spin lock is acquired.
+    #[inline(always)]
+    fn re_send(&mut self, msg: &P::SendT) -> Result<SendStatus, Error> {
+        self.clt_sender.lock().re_send(msg)
+    }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> ConnectionId for CltSenderRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionId for CltSenderRef<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn con_id(&self) -> &ConId {
         &self.con_id
     }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> ConnectionStatus for CltSenderRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionStatus for CltSenderRef<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn is_connected(&self) -> bool {
         // need to lock clt_sender to ensure __exit__ was not called
         self.clt_sender.lock().is_connected()
     }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> Display for CltSenderRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Display for CltSenderRef<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let recv_t = std::any::type_name::<P::RecvT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         let send_t = std::any::type_name::<P::SendT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         write!(f, "{}<{}, RecvT:{}, SendT:{}, {}>", asserted_short_name!("CltSenderRef", Self), self.con_id(), recv_t, send_t, MAX_MSG_SIZE)
     }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> Drop for CltSenderRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Drop for CltSenderRef<P, C, MAX_MSG_SIZE, T> {
     fn drop(&mut self) {
         // because this is a reference counted instance, need to manually shutdown reader to ensure other references
         // understand that this instance is terminated. This is part of the contract of [CltRecverRef] and [CltSenderRef]
@@ -464,7 +755,7 @@ impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> Drop for CltSen
         self.clt_sender.lock().on_disconnect()
     }
 }
-impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> Clone for CltSenderRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Clone for CltSenderRef<P, C, MAX_MSG_SIZE, T> {
     fn clone(&self) -> Self {
         Self {
             con_id: self.con_id.clone(),
@@ -513,61 +804,184 @@ impl<P: Protocol, C: CallbackSend<P>, const MAX_MSG_SIZE: usize> Clone for CltSe
 /// }
 /// ```
 #[derive(Debug)]
-pub struct Clt<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> {
+pub struct Clt<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
     // CRITICAL CltSender has a drop impl which allows protocol to send on_disconnect, which itself is called on drop
     // in order for this to work correctly CltSender must be dropped before CltRecver due to the fact that both will attempt
     // to shutdown underlying stream, and if CltRecver is dropped first then CltSender will not be able to send on_disconnect
-    clt_sender: CltSender<P, C, MAX_MSG_SIZE>, // DON"T MOVE below clt_recver
-    clt_recver: CltRecver<P, C, MAX_MSG_SIZE>,
+    clt_sender: CltSender<P, C, MAX_MSG_SIZE, T>, // DON"T MOVE below clt_recver
+    clt_recver: CltRecver<P, C, MAX_MSG_SIZE, T>,
 }
 impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Clt<P, C, MAX_MSG_SIZE> {
     pub fn connect(addr: &str, timeout: Duration, retry_after: Duration, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<Self, Error> {
-        assert!(timeout > retry_after, "timeout: {:?}, retry_after: {:?}", timeout, retry_after);
+        Self::connect_with(addr, timeout, retry_after, callback, protocol, name, SocketOptions::default())
+    }
+    /// Same as [Clt::connect] but the established socket is configured using the provided [SocketOptions], use [Clt::socket_options] to inspect the effective values
+    ///
+    /// # Important
+    /// Failure to apply any of the options is not retried and is returned as an error
+    pub fn connect_with(addr: &str, timeout: Duration, retry_after: Duration, callback: Arc<C>, protocol: P, name: Option<&str>, options: SocketOptions) -> Result<Self, Error> {
+        let con_id = ConId::clt(name, None, addr)?;
+        let stream = connect_retry(&con_id, timeout, retry_after, || options.connect(addr))?;
+        Self::from_stream(stream, con_id, callback, protocol, None)
+    }
+    /// Same as [Clt::connect] but once the connection is established it will perform a tls handshake using the provided [rustls::ClientConfig]
+    /// and `server_name`, after which every frame is encrypted. The handshake must complete within the remainder of the `timeout`.
+    #[cfg(feature = "tls")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn connect_tls(addr: &str, timeout: Duration, retry_after: Duration, callback: Arc<C>, protocol: P, name: Option<&str>, config: Arc<rustls::ClientConfig>, server_name: rustls::pki_types::ServerName<'static>) -> Result<Self, Error> {
         let now = Instant::now();
-        let con_id = ConId::clt(name, None, addr);
-        while now.elapsed() < timeout {
-            match TcpStream::connect(addr) {
-                Err(e) => {
-                    sleep(retry_after); // NOTE this will not be use by poll because it creates a client using a from_stream method
-                    if log_enabled!(log::Level::Debug) {
-                        debug!("{} connection failed. e: {:?}", con_id, e);
-                    }
-                    continue;
-                }
-                Ok(stream) => {
-                    let clt = Self::from_stream(stream, con_id, callback, protocol, None)?;
-                    return Ok(clt);
+        let con_id = ConId::clt(name, None, addr)?;
+        let mut stream = connect_retry(&con_id, timeout, retry_after, || TcpStream::connect(addr))?;
+        stream.set_nonblocking(true)?;
+        let connection = rustls::ClientConnection::new(config, server_name).map_err(|e| Error::new(std::io::ErrorKind::InvalidInput, format!("{} tls client config error: {}", con_id, e)))?;
+        let tls = crate::core::tls::TlsSession::handshake(connection, &mut stream, timeout.saturating_sub(now.elapsed())).map_err(|e| Error::new(e.kind(), format!("{} tls handshake failed: {}", con_id, e)))?;
+        let (msg_recver, msg_sender) = crate::prelude::into_split_messenger_tls::<P, MAX_MSG_SIZE>(con_id, stream, tls)?;
+        Self::from_messenger(msg_recver, msg_sender, callback, protocol, None)
+    }
+    pub(crate) fn from_stream(stream: TcpStream, con_id: ConId, callback: Arc<C>, protocol: P, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Result<Self, Error> {
+        let (msg_recver, msg_sender) = into_split_messenger::<P, MAX_MSG_SIZE>(con_id, stream)?;
+        Self::from_messenger(msg_recver, msg_sender, callback, protocol, acceptor_connection_gate)
+    }
+    /// Starts a non-blocking connect which will be completed by the [static@crate::connect::DEFAULT_POLL_HANDLER] thread, see [CltConnector] for details.
+    /// Unlike [Clt::connect] this call does not block and does not retry, hence it is suitable for bringing up many connections concurrently from a single thread
+    pub fn connect_nonblocking(addr: &str, timeout: Duration, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<PendingCltSenderRef<P, C, MAX_MSG_SIZE>, Error> {
+        Self::connect_nonblocking_on(&crate::connect::DEFAULT_POLL_HANDLER, addr, timeout, callback, protocol, name)
+    }
+    /// Same as [Clt::connect_nonblocking] but the connect is completed, and the resulting connection serviced, by the given `poll_handler` thread
+    pub fn connect_nonblocking_on(poll_handler: &SpawnedPollHandlerDynamic, addr: &str, timeout: Duration, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<PendingCltSenderRef<P, C, MAX_MSG_SIZE>, Error> {
+        let (connector, pending) = CltConnector::new(addr, timeout, callback, protocol, name)?;
+        poll_handler.add_connector(Box::new(connector))?;
+        Ok(pending)
+    }
+}
+/// Calls `connect` every `retry_after` until it succeeds or `timeout` elapses, shared by the blocking [Clt] connect variants regardless of the transport
+fn connect_retry<S>(con_id: &ConId, timeout: Duration, retry_after: Duration, mut connect: impl FnMut() -> Result<S, Error>) -> Result<S, Error> {
+    if timeout <= retry_after {
+        let msg = format!("{} connect timeout: {:?} must exceed retry_after: {:?}", con_id, timeout, retry_after);
+        return Err(Error::new(std::io::ErrorKind::InvalidInput, msg));
+    }
+    let now = Instant::now();
+    while now.elapsed() < timeout {
+        match connect() {
+            Ok(stream) => return Ok(stream),
+            Err(e) => {
+                sleep(retry_after); // NOTE this will not be use by poll because it creates a client using a from_stream method
+                if log_enabled!(log::Level::Debug) {
+                    debug!("{} connection failed. e: {:?}", con_id, e);
                 }
             }
         }
-        let msg = format!("{:?} connect timeout: {:?}", con_id, timeout);
-        Err(Error::new(std::io::ErrorKind::TimedOut, msg))
     }
-    pub(crate) fn from_stream(stream: TcpStream, con_id: ConId, callback: Arc<C>, protocol: P, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Result<Self, Error> {
-        let (msg_recver, msg_sender) = into_split_messenger::<P, MAX_MSG_SIZE>(con_id, stream);
+    Err(links_core::Error::Timeout {
+        con_id: Box::new(con_id.clone()),
+        reason: format!("connect timeout: {:?}", timeout),
+    }
+    .into())
+}
+#[cfg(target_family = "unix")]
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Clt<P, C, MAX_MSG_SIZE, mio::net::UnixStream> {
+    /// Same as [Clt::connect] but over a unix domain socket bound by [crate::prelude::Svc::bind_uds] at the given filesystem `path`
+    pub fn connect_uds(path: impl AsRef<std::path::Path>, timeout: Duration, retry_after: Duration, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<Self, Error> {
+        let con_id = ConId::clt_uds(name, path.as_ref());
+        let stream = connect_retry(&con_id, timeout, retry_after, || std::os::unix::net::UnixStream::connect(path.as_ref()))?;
+        Self::from_stream_uds(stream, con_id, callback, protocol, None)
+    }
+    pub(crate) fn from_stream_uds(stream: std::os::unix::net::UnixStream, con_id: ConId, callback: Arc<C>, protocol: P, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Result<Self, Error> {
+        let (msg_recver, msg_sender) = crate::prelude::into_split_messenger_uds::<P, MAX_MSG_SIZE>(con_id, stream)?;
+        Self::from_messenger(msg_recver, msg_sender, callback, protocol, acceptor_connection_gate)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Clt<P, C, MAX_MSG_SIZE, T> {
+    pub(crate) fn from_messenger(msg_recver: MessageRecver<P, MAX_MSG_SIZE, T>, msg_sender: MessageSender<P, MAX_MSG_SIZE, T>, callback: Arc<C>, protocol: P, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Result<Self, Error> {
+        let mut msg_sender = msg_sender;
+        if let Some(high_water_mark) = protocol.conf_outbound_queue_high_water_mark() {
+            msg_sender.frm_writer.enable_outbound_queue(high_water_mark);
+        }
         let protocol = Arc::new(protocol);
+        let mut clt_recver = CltRecver::new(msg_recver, callback.clone(), protocol.clone(), acceptor_connection_gate.clone());
+        clt_recver.outbound = msg_sender.frm_writer.outbound_queue();
         let mut con = Self {
-            clt_recver: CltRecver::new(msg_recver, callback.clone(), protocol.clone(), acceptor_connection_gate.clone()),
+            clt_recver,
             clt_sender: CltSender::new(msg_sender, callback.clone(), protocol.clone(), acceptor_connection_gate),
         };
         protocol.on_connect(&mut con)?;
 
         Ok(con)
     }
+    /// Creates a [Clt] from an already connected non blocking [Transport], which is split using [Transport::try_clone], and issues [Protocol::on_connect]
+    ///
+    /// # Note
+    /// `con_id` is used as is, hence it is expected to already identify the connection
+    pub fn from_transport(transport: T, con_id: ConId, callback: Arc<C>, protocol: P) -> Result<Self, Error> {
+        let (msg_recver, msg_sender) = into_split_messenger_transport::<P, MAX_MSG_SIZE, T>(con_id, transport)?;
+        Self::from_messenger(msg_recver, msg_sender, callback, protocol, None)
+    }
+    /// Same as [Clt::from_transport] but every frame is encrypted using the established [crate::prelude::TlsSession]
+    #[cfg(feature = "tls")]
+    pub(crate) fn from_tls_transport(transport: T, con_id: ConId, tls: crate::core::tls::TlsSession, callback: Arc<C>, protocol: P, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Result<Self, Error> {
+        let (mut msg_recver, mut msg_sender) = into_split_messenger_transport::<P, MAX_MSG_SIZE, T>(con_id, transport)?;
+        msg_recver.frm_reader.tls = Some(tls.clone());
+        msg_sender.frm_writer.tls = Some(tls);
+        Self::from_messenger(msg_recver, msg_sender, callback, protocol, acceptor_connection_gate)
+    }
+    /// Effective [SocketOptions] of the underlying connection, see [Transport::socket_options]
+    pub fn socket_options(&self) -> Result<SocketOptions, Error> {
+        self.clt_recver.socket_options()
+    }
+    /// Delegates to [CltRecver] and calls [Protocol::send_reply], if `is_send_reply` and a message is received, followed by [Protocol::re_send_reply] when a message is received or withheld
+    #[inline(always)]
+    fn recv_and_reply(&mut self, is_send_reply: bool) -> Result<RecvStatus<<P as Messenger>::RecvT>, Error> {
+        use RecvStatus::Completed;
+        let status = self.clt_recver.recv()?;
+        let is_withheld = self.clt_recver.take_recv_withheld();
+        if let Completed(Some(ref msg)) = status {
+            if is_send_reply {
+                self.clt_recver.protocol.send_reply(msg, &mut self.clt_sender)?;
+            }
+            self.clt_recver.protocol.re_send_reply(&mut self.clt_sender)?;
+        } else if is_withheld {
+            self.clt_recver.protocol.re_send_reply(&mut self.clt_sender)?;
+        }
+        Ok(status)
+    }
+    /// Delegates to [CltSender]
+    #[inline(always)]
+    pub fn drain_outbound_queue(&mut self) -> Result<SendStatus, Error> {
+        self.clt_sender.drain_outbound_queue()
+    }
+    /// Delegates to [CltSender]
+    #[inline(always)]
+    pub fn outbound_queue_len(&self) -> usize {
+        self.clt_sender.outbound_queue_len()
+    }
     /// Will split the [Clt] into its parts [CltRecver]/[CltSender].
     ///
     /// # Important
-    /// These parts will support only 'subset' of [Protocol] features which are part of [crate::prelude::ProtocolCore] trait
-    pub fn into_split(self) -> (CltRecver<P, C, MAX_MSG_SIZE>, CltSender<P, C, MAX_MSG_SIZE>) {
-        (self.clt_recver, self.clt_sender)
+    /// These parts will support only 'subset' of [Protocol] features which are part of [crate::prelude::ProtocolCore] trait, with the exception of [Protocol::re_send_reply]
+    /// and [Protocol::send_heart_beat] which [CltRecver] flags as due and [CltSender] sends ahead of its next message, see [CltSender::send_due],
+    /// hence heart beats are not sent unless [CltRecver] is added to a [crate::prelude::PollHandler] and no lock is taken by [CltSender]
+    pub fn into_split(self) -> (CltRecver<P, C, MAX_MSG_SIZE, T>, CltSender<P, C, MAX_MSG_SIZE, T>) {
+        let (mut recver, mut sender) = (self.clt_recver, self.clt_sender);
+        let sender_due = Arc::new(SenderDue::default());
+        recver.sender_due = Some(sender_due.clone());
+        sender.sender_due = Some(sender_due);
+        (recver, sender)
     }
     /// Will split the [Clt] into its parts [CltRecverRef]/[CltSenderRef]
     ///
     /// # Important
     /// This configuration will support `all` [Protocol] features,
     /// a clone of [CltSenderRef] will be moved to the [static@crate::connect::DEFAULT_HBEAT_HANDLER] thread to periodically trigger [Protocol::send_heart_beat]
-    pub fn into_split_ref(self) -> (CltRecverRef<P, C, MAX_MSG_SIZE>, CltSenderRef<P, C, MAX_MSG_SIZE>) {
-        let (recver, sender) = self.into_split();
+    pub fn into_split_ref(self) -> (CltRecverRef<P, C, MAX_MSG_SIZE, T>, CltSenderRef<P, C, MAX_MSG_SIZE, T>) {
+        self.into_split_ref_on(&crate::connect::DEFAULT_HBEAT_HANDLER)
+    }
+    /// Same as [Clt::into_split_ref] but [Protocol::send_heart_beat] is triggered by the given `hbeat_handler` thread
+    pub fn into_split_ref_on(self, hbeat_handler: &Timer) -> (CltRecverRef<P, C, MAX_MSG_SIZE, T>, CltSenderRef<P, C, MAX_MSG_SIZE, T>) {
+        self.into_split_ref_with(Some(&hbeat_handler.handle()))
+    }
+    /// Same as [Clt::into_split_ref_on] but when `hbeat_handler` is [None] [Protocol::send_heart_beat] is triggered by the [crate::prelude::PollHandler] thread servicing [CltRecverRef]
+    pub(crate) fn into_split_ref_with(self, hbeat_handler: Option<&TimerHandle>) -> (CltRecverRef<P, C, MAX_MSG_SIZE, T>, CltSenderRef<P, C, MAX_MSG_SIZE, T>) {
+        let (recver, sender) = (self.clt_recver, self.clt_sender);
 
         let sender = CltSenderRef {
             con_id: sender.con_id().to_owned(),
@@ -579,23 +993,18 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Clt<P, C, M
             protocol: recver.protocol.clone(),
             clt_recver: Arc::new(spin::Mutex::new(recver)),
             clt_sender: sender.clone(),
+            is_hbeat_on_poll: hbeat_handler.is_none(),
         };
 
-        match sender.protocol.conf_heart_beat_interval() {
-            Some(interval) => {
-                crate::connect::DEFAULT_HBEAT_HANDLER.schedule(sender.con_id().to_string().as_str(), interval, {
+        match (sender.protocol.conf_heart_beat_interval(), hbeat_handler) {
+            (Some(_), None) => {} // issued by PollRead::on_timer of the recver
+            (Some(interval), Some(hbeat_handler)) => {
+                hbeat_handler.schedule(sender.con_id().to_string().as_str(), interval, {
                     let sender = sender.clone();
-                    move || match sender.send_heart_beat() {
-                        Ok(SendStatus::Completed) => TimerTaskStatus::Completed,
-                        Ok(SendStatus::WouldBlock) => TimerTaskStatus::RetryAfter(Duration::from_secs(0)),
-                        Err(err) => {
-                            warn!("{} Failed to send heart beat. Will no longer attempt to send. err:\n{}", sender.con_id(), err);
-                            TimerTaskStatus::Terminate
-                        }
-                    }
+                    move || heart_beat_task_status(sender.con_id(), sender.send_heart_beat())
                 });
             }
-            None => {
+            (None, _) => {
                 #[cfg(debug_assertions)]
                 warn!(
                     "{}::conf_heart_beat_interval() is None, hence {}::send_heart_beat(..) will not be scheduled for this con_id: {}",
@@ -613,23 +1022,45 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Clt<P, C, M
     ///
     /// # Important
     /// This configuration will support only 'subset' of [Protocol] features which are part of [crate::prelude::ProtocolCore] trait
-    pub fn into_sender_with_spawned_recver(self) -> CltSender<P, C, MAX_MSG_SIZE> {
+    pub fn into_sender_with_spawned_recver(self) -> CltSender<P, C, MAX_MSG_SIZE, T> {
         let (recver, sender) = self.into_split();
-        crate::connect::DEFAULT_POLL_HANDLER.add_recver(recver.into());
+        if let Err(e) = crate::connect::DEFAULT_POLL_HANDLER.add_recver(recver.into()) {
+            warn!("{} recver was rejected by DEFAULT_POLL_HANDLER and dropped, error: {}", sender.con_id(), e);
+        }
         sender
     }
+    /// Same as [Clt::into_sender_with_spawned_recver] but [CltRecver] is moved to run in the given `poll_handler` thread
+    ///
+    /// # Errors
+    /// [links_core::Error::Rejected] if the `poll_handler` rejects [CltRecver], in which case the connection is dropped, see [crate::prelude::SpawnedPollHandler::add_recver]
+    pub fn into_sender_with_spawned_recver_on(self, poll_handler: &SpawnedPollHandlerDynamic) -> Result<CltSender<P, C, MAX_MSG_SIZE, T>, links_core::Error> {
+        let (recver, sender) = self.into_split();
+        poll_handler.add_recver(recver.into())?;
+        Ok(sender)
+    }
     /// Will split the [Clt] and only return [CltSenderRef] while moving [CltRecverRef] to run in the [static@crate::connect::DEFAULT_POLL_HANDLER] thread
     ///
     /// # Important
     /// This configuration will support `all` [Protocol] features, which means that `ref counted clone` of [CltSenderRef] will be returned,
-    /// while another `ref counted clone` of [CltSenderRef] will be moved to the [static@crate::connect::DEFAULT_HBEAT_HANDLER] thread
-    pub fn into_sender_with_spawned_recver_ref(self) -> CltSenderRef<P, C, MAX_MSG_SIZE> {
-        let (recver, sender) = self.into_split_ref();
-        crate::connect::DEFAULT_POLL_HANDLER.add_recver(recver.into());
+    /// while another `ref counted clone` of [CltSenderRef] is used by the same poll thread to trigger [Protocol::send_heart_beat]
+    pub fn into_sender_with_spawned_recver_ref(self) -> CltSenderRef<P, C, MAX_MSG_SIZE, T> {
+        let (recver, sender) = self.into_split_ref_with(None);
+        if let Err(e) = crate::connect::DEFAULT_POLL_HANDLER.add_recver(recver.into()) {
+            warn!("{} recver was rejected by DEFAULT_POLL_HANDLER and dropped, error: {}", sender.con_id(), e);
+        }
         sender
     }
+    /// Same as [Clt::into_sender_with_spawned_recver_ref] but [CltRecverRef] is moved to run in the given `poll_handler` thread, which also triggers [Protocol::send_heart_beat]
+    ///
+    /// # Errors
+    /// [links_core::Error::Rejected] if the `poll_handler` rejects [CltRecverRef], in which case the connection is dropped, see [crate::prelude::SpawnedPollHandler::add_recver]
+    pub fn into_sender_with_spawned_recver_ref_on(self, poll_handler: &SpawnedPollHandlerDynamic) -> Result<CltSenderRef<P, C, MAX_MSG_SIZE, T>, links_core::Error> {
+        let (recver, sender) = self.into_split_ref_with(None);
+        poll_handler.add_recver(recver.into())?;
+        Ok(sender)
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> SendNonBlocking<P::SendT> for Clt<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> SendNonBlocking<P::SendT> for Clt<P, C, MAX_MSG_SIZE, T> {
     /// Delegates to [CltSender]
     #[inline(always)]
     fn send(&mut self, msg: &mut <P as Messenger>::SendT) -> Result<SendStatus, Error> {
@@ -645,54 +1076,314 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> SendNonBloc
     fn send_busywait(&mut self, msg: &mut <P as Messenger>::SendT) -> Result<(), Error> {
         self.clt_sender.send_busywait(msg)
     }
-}
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> RecvNonBlocking<P::RecvT> for Clt<P, C, MAX_MSG_SIZE> {
-    /// Delegates to [CltRecver]
+    /// Delegates to [CltSender]
     #[inline(always)]
-    fn recv(&mut self) -> Result<RecvStatus<<P as Messenger>::RecvT>, Error> {
-        self.clt_recver.recv()
+    fn send_batch(&mut self, msgs: &mut [<P as Messenger>::SendT]) -> Result<usize, SendBatchError> {
+        self.clt_sender.send_batch(msgs)
     }
-    /// Delegates to [CltRecver]
-    #[inline(always)]
-    fn recv_busywait_timeout(&mut self, timeout: Duration) -> Result<RecvStatus<<P as Messenger>::RecvT>, Error> {
-        self.clt_recver.recv_busywait_timeout(timeout)
-    }
-    /// Delegates to [CltRecver]
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> RecvNonBlocking<P::RecvT> for Clt<P, C, MAX_MSG_SIZE, T> {
+    // NOTE: that the [RecvNonBlocking::recv_busywait] & [RecvNonBlocking::recv_busywait_timeout] default implementation
+    // is not overridden because it issues [RecvNonBlocking::recv], hence replies are sent on every iteration
+    /// Delegates to [CltRecver] and calls [Protocol::re_send_reply] when a message is received or withheld
     #[inline(always)]
-    fn recv_busywait(&mut self) -> Result<Option<<P as Messenger>::RecvT>, Error> {
-        self.clt_recver.recv_busywait()
+    fn recv(&mut self) -> Result<RecvStatus<<P as Messenger>::RecvT>, Error> {
+        self.recv_and_reply(false)
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ReSendNonBlocking<P::SendT> for Clt<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ReSendNonBlocking<P::SendT> for Clt<P, C, MAX_MSG_SIZE, T> {
     fn re_send(&mut self, msg: &P::SendT) -> Result<SendStatus, Error> {
         self.clt_sender.re_send(msg)
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ConnectionId for Clt<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionId for Clt<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn con_id(&self) -> &ConId {
         &self.clt_recver.msg_recver.frm_reader.con_id
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ConnectionStatus for Clt<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionStatus for Clt<P, C, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn is_connected(&self) -> bool {
         self.clt_recver.is_connected()
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Display for Clt<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> PollRead for Clt<P, C, MAX_MSG_SIZE, T> {
+    /// Delegates to [CltRecver] and calls [Protocol::send_reply] when a message is received followed by [Protocol::re_send_reply] when a message is received or withheld
+    fn on_readable_event(&mut self) -> Result<PollEventStatus, Error> {
+        use RecvStatus::*;
+        match self.recv_and_reply(true)? {
+            Completed(Some(_)) => Ok(PollEventStatus::Completed),
+            WouldBlock => Ok(PollEventStatus::WouldBlock),
+            Completed(None) => Ok(PollEventStatus::Terminate),
+        }
+    }
+    /// Delegates to [CltRecver]
+    #[inline(always)]
+    fn recv_deadline(&self) -> Option<Instant> {
+        self.clt_recver.recv_deadline()
+    }
+    /// Delegates to [CltRecver]
+    #[inline(always)]
+    fn on_recv_deadline(&mut self) -> Result<PollEventStatus, Error> {
+        self.clt_recver.on_recv_deadline()
+    }
+    /// Includes [mio::Interest::WRITABLE] when [crate::prelude::ProtocolCore::conf_outbound_queue_high_water_mark] is enabled so that the outbound queue
+    /// of the [CltSender] is drained as soon as the socket becomes writable
+    #[inline(always)]
+    fn interests(&self) -> mio::Interest {
+        match self.clt_sender.protocol.conf_outbound_queue_high_water_mark() {
+            Some(_) => mio::Interest::READABLE | mio::Interest::WRITABLE,
+            None => mio::Interest::READABLE,
+        }
+    }
+    #[inline(always)]
+    fn as_poll_write(&mut self) -> Option<&mut dyn PollWrite> {
+        Some(self)
+    }
+    /// Same as [Protocol::conf_heart_beat_interval]
+    #[inline(always)]
+    fn timer_interval(&self) -> Option<Duration> {
+        self.clt_sender.protocol.conf_heart_beat_interval()
+    }
+    /// Calls [Protocol::send_heart_beat] directly on the owned [CltSender], hence unlike [Clt::into_split_ref] no lock nor extra thread is involved
+    fn on_timer(&mut self) -> Result<TimerTaskStatus, Error> {
+        let res = self.clt_recver.protocol.send_heart_beat(&mut self.clt_sender);
+        Ok(heart_beat_task_status(self.clt_sender.con_id(), res))
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> PollWrite for Clt<P, C, MAX_MSG_SIZE, T> {
+    /// Drains the outbound queue of the [CltSender]
+    #[inline(always)]
+    fn on_writable_event(&mut self) -> Result<PollEventStatus, Error> {
+        // nothing else can be written until the next writable event whether the queue is fully drained or not
+        self.clt_sender.drain_outbound_queue()?;
+        Ok(PollEventStatus::WouldBlock)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> PollAble for Clt<P, C, MAX_MSG_SIZE, T> {
+    fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
+        self.clt_recver.source()
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Display for Clt<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}<{}, {}>", asserted_short_name!("Clt", Self), self.clt_recver, self.clt_sender)
     }
 }
 
+type PendingCltSenderRefSlot<P, C, const MAX_MSG_SIZE: usize, T> = Arc<spin::Mutex<Option<Result<CltSenderRef<P, C, MAX_MSG_SIZE, T>, Error>>>>;
+
+/// A [PollConnect] implementation which initiates a non-blocking connect and once the socket becomes writable creates a [Clt], including running [crate::prelude::ProtocolCore::on_connect],
+/// and splits it using [Clt::into_split_ref]. The [CltRecverRef] is serviced by the [crate::prelude::PollHandler], which also triggers [Protocol::send_heart_beat] from its thread,
+/// while the [CltSenderRef] is made available via [PendingCltSenderRef]
+///
+/// Connections of any [ConnectTransport] are supported, ex: [CltConnector::new_uds], and can be secured using [CltConnector::with_tls]
+///
+/// # Important
+/// [crate::prelude::ProtocolCore::on_connect], as well as the tls handshake, is executed by one of the connect worker threads of the [crate::prelude::PollHandler] so that it does not stall its poll thread,
+/// see [PollConnect::into_recver], its failure is yielded by [PendingCltSenderRef]
+#[derive(Debug)]
+pub struct CltConnector<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport = mio::net::TcpStream> {
+    con_id: ConId,
+    stream: Option<T>, // only None after it is moved into the Clt
+    deadline: Instant,
+    callback: Arc<C>,
+    protocol: Option<P>, // only None after it is moved into the Clt
+    #[cfg(feature = "tls")]
+    tls: Option<(Arc<rustls::ClientConfig>, rustls::pki_types::ServerName<'static>)>,
+    pending: PendingCltSenderRefSlot<P, C, MAX_MSG_SIZE, T>,
+    is_completed: bool, // to ensure that the result is only set once since the pending side can take it before Self is dropped
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> CltConnector<P, C, MAX_MSG_SIZE> {
+    /// Initiates the non-blocking connect, errors are only returned if the connect can't be initiated
+    ///
+    /// # Arguments
+    /// * `timeout` - time allowed to establish the connection, after which the [crate::prelude::PollHandler] will drop this instance and [PendingCltSenderRef] will yield [std::io::ErrorKind::TimedOut]
+    pub fn new(addr: &str, timeout: Duration, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<(Self, PendingCltSenderRef<P, C, MAX_MSG_SIZE>), Error> {
+        Self::new_with(addr, timeout, callback, protocol, name, SocketOptions::default())
+    }
+    /// Same as [CltConnector::new] but the socket is configured using the provided [SocketOptions] prior to initiating the connect, see [Clt::connect_with]
+    pub fn new_with(addr: &str, timeout: Duration, callback: Arc<C>, protocol: P, name: Option<&str>, options: SocketOptions) -> Result<(Self, PendingCltSenderRef<P, C, MAX_MSG_SIZE>), Error> {
+        Self::from_con_id(ConId::clt(name, None, addr)?, &options, timeout, callback, protocol)
+    }
+}
+#[cfg(target_family = "unix")]
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> CltConnector<P, C, MAX_MSG_SIZE, mio::net::UnixStream> {
+    /// Same as [CltConnector::new] but connects to a unix domain socket at the given filesystem `path`, see [Clt::connect_uds]
+    pub fn new_uds(path: impl AsRef<std::path::Path>, timeout: Duration, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<(Self, PendingCltSenderRef<P, C, MAX_MSG_SIZE, mio::net::UnixStream>), Error> {
+        Self::from_con_id(ConId::clt_uds(name, path.as_ref()), &SocketOptions::default(), timeout, callback, protocol)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> CltConnector<P, C, MAX_MSG_SIZE, T> {
+    /// Initiates the non-blocking connect to the peer identified by `con_id`, see [ConnectTransport::connect] and [CltConnector::new] for details
+    pub fn from_con_id(con_id: ConId, options: &SocketOptions, timeout: Duration, callback: Arc<C>, protocol: P) -> Result<(Self, PendingCltSenderRef<P, C, MAX_MSG_SIZE, T>), Error> {
+        let stream = T::connect(&con_id, options)?;
+        let pending = Arc::new(spin::Mutex::new(None));
+        let connector = Self {
+            con_id: con_id.clone(),
+            stream: Some(stream),
+            deadline: Instant::now() + timeout,
+            callback,
+            protocol: Some(protocol),
+            #[cfg(feature = "tls")]
+            tls: None,
+            pending: pending.clone(),
+            is_completed: false,
+        };
+        Ok((connector, PendingCltSenderRef { con_id, pending }))
+    }
+    /// Once connected a tls handshake is performed using the provided [rustls::ClientConfig] and `server_name`, after which every frame is encrypted, see [Clt::connect_tls].
+    /// The handshake must complete within the remainder of the connect `timeout`
+    #[cfg(feature = "tls")]
+    pub fn with_tls(mut self, config: Arc<rustls::ClientConfig>, server_name: rustls::pki_types::ServerName<'static>) -> Self {
+        self.tls = Some((config, server_name));
+        self
+    }
+    fn complete(&mut self, res: Result<CltSenderRef<P, C, MAX_MSG_SIZE, T>, Error>) {
+        self.is_completed = true;
+        *self.pending.lock() = Some(res);
+    }
+    fn clt_from_stream(&self, stream: T, protocol: P) -> Result<Clt<P, C, MAX_MSG_SIZE, T>, Error> {
+        let con_id = stream.connected(&self.con_id)?;
+        #[cfg(feature = "tls")]
+        if let Some((config, server_name)) = self.tls.clone() {
+            let mut stream = stream;
+            let connection = rustls::ClientConnection::new(config, server_name).map_err(|e| Error::new(std::io::ErrorKind::InvalidInput, format!("{} tls client config error: {}", con_id, e)))?;
+            let tls = crate::core::tls::TlsSession::handshake(connection, &mut stream, self.deadline.saturating_duration_since(Instant::now())).map_err(|e| Error::new(e.kind(), format!("{} tls handshake failed: {}", con_id, e)))?;
+            return Clt::from_tls_transport(stream, con_id, tls, self.callback.clone(), protocol, None);
+        }
+        Clt::from_transport(stream, con_id, self.callback.clone(), protocol)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport, R: PollRead + From<CltRecverRef<P, C, MAX_MSG_SIZE, T>>> PollConnect<R> for CltConnector<P, C, MAX_MSG_SIZE, T> {
+    fn on_writable_event(&mut self) -> Result<PollEventStatus, Error> {
+        let stream = self.stream.as_ref().expect("stream is only taken by into_recver");
+        let res = match stream.take_error() {
+            Ok(Some(e)) | Err(e) => Err(e),
+            // socket is connected once the peer address is available
+            Ok(None) => match stream.peer_addr() {
+                Ok(_) => Ok(PollEventStatus::Completed),
+                Err(e) if e.kind() == std::io::ErrorKind::NotConnected => Ok(PollEventStatus::WouldBlock),
+                Err(e) => Err(e),
+            },
+        };
+        if let Err(ref e) = res {
+            self.complete(Err(Error::new(e.kind(), format!("{} connect failed, err: {}", self.con_id, e))));
+        }
+        res
+    }
+    fn into_recver(mut self: Box<Self>) -> Result<R, Error> {
+        let stream = self.stream.take().expect("stream is only taken by into_recver");
+        let protocol = self.protocol.take().expect("protocol is only taken by into_recver");
+        match self.clt_from_stream(stream, protocol) {
+            Ok(clt) => {
+                let (recver, sender) = clt.into_split_ref_with(None);
+                self.complete(Ok(sender));
+                Ok(recver.into())
+            }
+            Err(e) => {
+                self.complete(Err(Error::new(e.kind(), format!("{} on_connect failed, err: {}", self.con_id, e))));
+                Err(e)
+            }
+        }
+    }
+    #[inline(always)]
+    fn connect_deadline(&self) -> Option<Instant> {
+        Some(self.deadline)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> PollAble for CltConnector<P, C, MAX_MSG_SIZE, T> {
+    fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
+        Box::new(self.stream.as_mut().expect("stream is only taken by into_recver"))
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> ConnectionId for CltConnector<P, C, MAX_MSG_SIZE, T> {
+    #[inline(always)]
+    fn con_id(&self) -> &ConId {
+        &self.con_id
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> Display for CltConnector<P, C, MAX_MSG_SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}<{}>", asserted_short_name!("CltConnector", Self), self.con_id)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> Drop for CltConnector<P, C, MAX_MSG_SIZE, T> {
+    fn drop(&mut self) {
+        if !self.is_completed {
+            let e = match Instant::now() >= self.deadline {
+                true => Error::new(std::io::ErrorKind::TimedOut, format!("{} connect timeout", self.con_id)),
+                false => Error::new(std::io::ErrorKind::ConnectionAborted, format!("{} connect aborted", self.con_id)),
+            };
+            self.complete(Err(e));
+        }
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> From<CltConnector<P, C, MAX_MSG_SIZE, T>> for Box<dyn PollConnect<Box<dyn PollRead>>> {
+    fn from(value: CltConnector<P, C, MAX_MSG_SIZE, T>) -> Self {
+        Box::new(value)
+    }
+}
+
+/// A handle to the [CltSenderRef] of a connection being established by [CltConnector]
+#[derive(Debug)]
+pub struct PendingCltSenderRef<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
+    con_id: ConId,
+    pending: PendingCltSenderRefSlot<P, C, MAX_MSG_SIZE, T>,
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> PendingCltSenderRef<P, C, MAX_MSG_SIZE, T> {
+    /// Returns [None] while the connection is still being established, otherwise the outcome which can only be taken once
+    pub fn try_take(&self) -> Option<Result<CltSenderRef<P, C, MAX_MSG_SIZE, T>, Error>> {
+        self.pending.lock().take()
+    }
+    /// Will call [PendingCltSenderRef::try_take] until it yields the outcome or return [std::io::ErrorKind::TimedOut] after the timeout
+    pub fn take_busywait_timeout(&self, timeout: Duration) -> Result<CltSenderRef<P, C, MAX_MSG_SIZE, T>, Error> {
+        let start = Instant::now();
+        loop {
+            if let Some(res) = self.try_take() {
+                return res;
+            }
+            if start.elapsed() > timeout {
+                return Err(Error::new(std::io::ErrorKind::TimedOut, format!("{} timeout: {:?} waiting for connect to complete", self.con_id, timeout)));
+            }
+        }
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionId for PendingCltSenderRef<P, C, MAX_MSG_SIZE, T> {
+    #[inline(always)]
+    fn con_id(&self) -> &ConId {
+        &self.con_id
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Display for PendingCltSenderRef<P, C, MAX_MSG_SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}<{}>", asserted_short_name!("PendingCltSenderRef", Self), self.con_id)
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "unittest")]
 mod test {
-    use super::Clt;
-    use crate::unittest::setup::protocol::CltTestProtocolAuthAndHbeat;
-    use links_core::callbacks::logger::LoggerCallback;
-    use links_core::unittest::setup::{self, framer::TEST_MSG_FRAME_SIZE};
+    use super::{Clt, CltConnector};
+    use crate::{
+        prelude::*,
+        unittest::setup::{
+            connection::SvcTest,
+            protocol::{CltTestProtocolAuthAndHbeat, CltTestProtocolManual, SvcTestProtocolManual},
+        },
+    };
+    use links_core::unittest::setup::{
+        self,
+        framer::TEST_MSG_FRAME_SIZE,
+        model::{CltTestMsgDebug, SvcTestMsgDebug},
+    };
+    use log::info;
+    use std::{
+        io::ErrorKind,
+        num::NonZeroUsize,
+        time::{Duration, Instant},
+    };
 
     #[test]
     fn test_clt_not_connected() {
@@ -703,4 +1394,211 @@ mod test {
         let res = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), callback, protocol, Some("unittest"));
         assert!(res.is_err());
     }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn test_clt_uds_not_connected() {
+        setup::log::configure();
+        let path = setup::net::rand_avail_uds_path();
+        let res = Clt::<_, _, TEST_MSG_FRAME_SIZE, _>::connect_uds(
+            &path,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            LoggerCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest"),
+        );
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn test_clt_connect_nonblocking_not_connected() {
+        setup::log::configure();
+        let addr = setup::net::rand_avail_addr_port();
+        let pending = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect_nonblocking(addr, setup::net::default_connect_timeout(), LoggerCallback::new_ref(), CltTestProtocolManual, Some("unittest")).unwrap();
+        let res = pending.take_busywait_timeout(setup::net::default_find_timeout());
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ConnectionRefused);
+    }
+
+    #[test]
+    fn test_clt_connect_nonblocking() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        const N: usize = 50;
+        let addr = setup::net::rand_avail_addr_port();
+        let counter = CounterCallback::new_ref();
+        let _svc = SvcTest::bind(addr, NonZeroUsize::new(N).unwrap(), counter.clone(), SvcTestProtocolManual, Some("unittest/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        // a single poll handler thread brings up all connections concurrently
+        let poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("Connect-Clt-Poll-Thread").unwrap();
+        let pending = (0..N)
+            .map(|_| {
+                let (connector, pending) = CltConnector::<_, _, TEST_MSG_FRAME_SIZE>::new(addr, setup::net::default_connect_timeout(), DevNullCallback::new_ref(), CltTestProtocolManual, Some("unittest/clt")).unwrap();
+                poll_handler.add_connector(connector.into()).unwrap();
+                pending
+            })
+            .collect::<Vec<_>>();
+
+        for pending in pending {
+            let mut clt = pending.take_busywait_timeout(setup::net::default_find_timeout()).unwrap();
+            assert!(clt.is_connected());
+            clt.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+        }
+        counter.assert_recv_count_busywait_timeout(N, setup::net::default_find_timeout());
+    }
+
+    #[test]
+    fn test_clt_connect_nonblocking_on_connect_not_blocking_poll_handler() {
+        setup::log::configure();
+        let addr = setup::net::rand_avail_addr_port();
+        let _svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        let other_addr = setup::net::rand_avail_addr_port();
+        let mut other_svc = SvcTest::bind(other_addr, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc/other"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("Connect-Clt-Poll-Thread").unwrap();
+        let counter = CounterCallback::new_ref();
+        let (connector, pending) = CltConnector::<_, _, TEST_MSG_FRAME_SIZE>::new(other_addr, setup::net::default_connect_timeout(), counter.clone(), CltTestProtocolManual, Some("unittest/clt")).unwrap();
+        poll_handler.add_connector(connector.into()).unwrap();
+        let clt = pending.take_busywait_timeout(setup::net::default_find_timeout()).unwrap();
+        assert!(clt.is_connected());
+
+        // svc never replies to the login request hence on_connect of this connection blocks until its timeout
+        let (connector, blocked) = CltConnector::<_, _, TEST_MSG_FRAME_SIZE>::new(addr, setup::net::default_connect_timeout(), DevNullCallback::new_ref(), CltTestProtocolAuthAndHbeat, Some("unittest/clt/blocked")).unwrap();
+        poll_handler.add_connector(connector.into()).unwrap();
+
+        // connected recver is still serviced by the poll thread while on_connect of the blocked connection is running
+        other_svc
+            .send_busywait_timeout(&mut SvcTestMsgDebug::new(b"Hello Frm Server Msg").into(), setup::net::default_find_timeout())
+            .unwrap()
+            .unwrap_completed();
+        counter.assert_recv_count_busywait_timeout(1, Duration::from_millis(500));
+        assert!(blocked.try_take().is_none());
+        // connection which is still running on_connect is counted along with the connected one
+        assert_eq!(poll_handler.connection_count(), 2);
+
+        // on_connect completes once its login times out and the connection is no longer counted after it is dropped
+        let blocked = blocked.take_busywait_timeout(Duration::from_secs(2)).unwrap();
+        assert_eq!(poll_handler.connection_count(), 2);
+        drop(blocked);
+        let start = Instant::now();
+        while poll_handler.connection_count() != 1 && start.elapsed() < setup::net::default_find_timeout() {}
+        assert_eq!(poll_handler.connection_count(), 1);
+    }
+
+    #[test]
+    fn test_clt_connect_nonblocking_on_connect_failed() {
+        setup::log::configure();
+        let addr = setup::net::rand_avail_addr_port();
+        let listener = std::net::TcpListener::bind(addr).unwrap();
+        let pending = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect_nonblocking(addr, setup::net::default_connect_timeout(), LoggerCallback::new_ref(), CltTestProtocolAuthAndHbeat, Some("unittest")).unwrap();
+        // peer disconnects without replying to the login request sent by on_connect
+        drop(listener.accept().unwrap());
+        let res = pending.take_busywait_timeout(setup::net::default_find_timeout());
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::ConnectionReset);
+    }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn test_clt_from_transport() {
+        setup::log::configure();
+        let (clt_transport, svc_transport) = mio::net::UnixStream::pair().unwrap();
+
+        let mut clt = Clt::<_, _, TEST_MSG_FRAME_SIZE, _>::from_transport(clt_transport, ConId::clt_uds(Some("unittest"), "pair"), LoggerCallback::new_ref(), CltTestProtocolManual).unwrap();
+        let mut svc = Clt::<_, _, TEST_MSG_FRAME_SIZE, _>::from_transport(svc_transport, ConId::svc_uds(Some("unittest"), "pair"), LoggerCallback::new_ref(), SvcTestProtocolManual).unwrap();
+        info!("clt: {}", clt);
+        info!("svc: {}", svc);
+
+        clt.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+        let msg = svc.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap().unwrap_completed_some();
+        assert_eq!(msg, CltTestMsgDebug::new(b"Hello Frm Client Msg").into());
+
+        drop(svc);
+        assert_eq!(clt.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap(), RecvStatus::Completed(None));
+    }
+
+    #[test]
+    fn test_clt_send_batch() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        const N: usize = 100;
+        let addr = setup::net::rand_avail_addr_port();
+        let svc_counter = CounterCallback::new_ref();
+        let _svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), svc_counter.clone(), SvcTestProtocolManual, Some("unittest/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let clt_counter = CounterCallback::new_ref();
+        let mut clt = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            clt_counter.clone(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        let mut msgs = (0..N).map(|idx| CltTestMsgDebug::new(format!("Hello Frm Client Msg #{}", idx).as_bytes()).into()).collect::<Vec<_>>();
+        let completed = clt.send_batch(&mut msgs).unwrap();
+        info!("clt: {}, completed: {}", clt, completed);
+        assert_eq!(completed, N);
+        assert_eq!(clt_counter.sent_count(), N);
+        svc_counter.assert_recv_count_busywait_timeout(N, setup::net::default_find_timeout());
+    }
+
+    #[test]
+    fn test_clt_connect_with_socket_options() {
+        setup::log::configure();
+        let addr = setup::net::rand_avail_addr_port();
+        let local_addr = setup::net::rand_avail_addr_port().parse().unwrap();
+        let keepalive = TcpKeepalive {
+            idle: Duration::from_secs(10),
+            interval: Duration::from_secs(2),
+            retries: 3,
+        };
+
+        // accepted connections inherit buffer sizes of the listener
+        let acceptor = SvcAcceptor::<_, _, TEST_MSG_FRAME_SIZE>::new(
+            ConId::svc(Some("unittest"), addr, None).unwrap(),
+            SocketOptions::default().with_recv_buffer_size(16 * 1024).bind(addr).unwrap(),
+            LoggerCallback::new_ref(),
+            SvcTestProtocolManual,
+            NonZeroUsize::new(1).unwrap(),
+        )
+        .with_socket_options(SocketOptions::default().with_nodelay(true).with_keepalive(keepalive));
+
+        let options = SocketOptions::default().with_nodelay(true).with_recv_buffer_size(64 * 1024).with_keepalive(keepalive).with_tos(0x10).with_local_addr(local_addr);
+        let clt = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect_with(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            LoggerCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest"),
+            options,
+        )
+        .unwrap();
+        let effective = clt.socket_options().unwrap();
+        info!("clt: {}, effective: {}", clt, effective);
+        assert_eq!(effective.nodelay, Some(true));
+        assert!(effective.recv_buffer_size.unwrap() >= 64 * 1024);
+        assert_eq!(effective.keepalive, Some(keepalive));
+        assert_eq!(effective.tos, Some(0x10));
+        assert_eq!(effective.local_addr, Some(local_addr));
+        assert_eq!(clt.con_id().get_local(), Some(local_addr));
+
+        let svc = acceptor.accept_busywait_timeout(setup::net::default_find_timeout()).unwrap().unwrap_accepted();
+        let effective = svc.socket_options().unwrap();
+        info!("svc: {}, effective: {}", svc, effective);
+        assert_eq!(effective.nodelay, Some(true));
+        assert_eq!(effective.keepalive, Some(keepalive));
+        assert!(effective.recv_buffer_size.unwrap() <= 2 * 16 * 1024);
+        assert_eq!(svc.con_id().get_peer(), Some(local_addr));
+    }
 }
@@ -1,13 +1,21 @@
 pub mod clt;
+pub mod moldudp64;
 pub mod poll;
 pub mod pool;
+pub mod reconnect;
+pub mod runtime;
 pub mod svc;
+pub mod udp;
 
 use self::poll::{PollHandlerDynamic, SpawnedPollHandlerDynamic};
 use crate::prelude::Timer;
 use lazy_static::lazy_static;
 
 lazy_static! {
-    pub static ref DEFAULT_POLL_HANDLER: SpawnedPollHandlerDynamic = PollHandlerDynamic::default().into_spawned_handler("Default-RecvPollHandler-Thread");
+    pub static ref DEFAULT_POLL_HANDLER: SpawnedPollHandlerDynamic = PollHandlerDynamic::new()
+        .and_then(|handler| handler.into_spawned_handler("Default-RecvPollHandler-Thread"))
+        .unwrap_or_else(|e| panic!("Failed to start Default-RecvPollHandler-Thread, error: {}", e));
     pub static ref DEFAULT_HBEAT_HANDLER: Timer = Timer::new("Default-HeartbeatHandler-Thread");
+    pub static ref DEFAULT_RECONNECT_HANDLER: Timer = Timer::new("Default-ReconnectHandler-Thread");
+    pub static ref DEFAULT_WATCHDOG_HANDLER: Timer = Timer::new("Default-WatchdogHandler-Thread");
 }
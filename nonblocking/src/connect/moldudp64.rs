@@ -0,0 +1,514 @@
+//! This module contains [MoldUdp64Recver] which turns a MoldUDP64 feed into a strictly ordered stream of application messages, recovering
+//! any gaps through a tcp request/response channel, and [MoldUdp64Publisher] which is a local publisher intended for tests.
+//!
+//! # Example
+//! ```
+//! use links_nonblocking::prelude::*;
+//! use links_core::unittest::setup::{self, framer::{CltTestMessenger, SvcTestMessenger, TEST_MSG_FRAME_SIZE}, model::{CltTestMsg, CltTestMsgDebug}};
+//!
+//! let feed_addr = setup::net::rand_avail_addr_port(); // "127.0.0.1:8080"
+//! let recovery_addr = setup::net::rand_avail_addr_port(); // "127.0.0.1:8081"
+//!
+//! let mut publisher = MoldUdp64Publisher::<CltTestMessenger, TEST_MSG_FRAME_SIZE>::bind("SESSION1", "127.0.0.1:0", feed_addr, recovery_addr, Some("unittest")).unwrap();
+//! let mut recver = MoldUdp64Recver::<SvcTestMessenger>::bind(feed_addr, recovery_addr, setup::net::default_connect_timeout(), Some("unittest")).unwrap();
+//!
+//! let msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Publisher"));
+//! publisher.drop_next(1); // simulate a lost packet which recver will recover over tcp
+//! publisher.publish(&[msg.clone()]).unwrap();
+//! publisher.publish(&[msg.clone()]).unwrap();
+//!
+//! assert_eq!(recver.recv_busywait().unwrap(), Some(msg.clone()));
+//! assert_eq!(recver.recv_busywait().unwrap(), Some(msg));
+//! ```
+use crate::prelude::{
+    asserted_short_name, into_split_messenger, ConId, DatagramRecver, DatagramSender, MessageRecver, MessageSender, Messenger, MoldUdp64CltMessenger, MoldUdp64Packet, MoldUdp64Request, MoldUdp64Session, MoldUdp64SvcMessenger, RecvNonBlocking,
+    RecvStatus, SendNonBlockingNonMut, SendStatus, MOLDUDP64_END_OF_SESSION, MOLDUDP64_HEADER_SIZE, MOLDUDP64_MAX_PACKET_SIZE,
+};
+use bytes::Bytes;
+use byteserde::utils::hex::to_hex_pretty;
+use log::{debug, info, log_enabled, warn};
+use std::{
+    collections::{BTreeMap, VecDeque},
+    fmt::Display,
+    io::{Error, ErrorKind},
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{sleep, Builder, JoinHandle},
+    time::{Duration, Instant},
+};
+
+/// Default time [MoldUdp64Recver] waits for a response to a recovery request before requesting the same gap again
+pub const MOLDUDP64_REQUEST_RETRY_AFTER: Duration = Duration::from_millis(100);
+
+/// Receives MoldUDP64 packets from a unicast or multicast feed and delivers their messages to the application strictly in sequence number order
+///
+/// # Gap recovery
+/// Messages received ahead of a gap are buffered, while the gap is requested from the tcp recovery channel using [MoldUdp64Request]. A gap is
+/// detected either from the sequence number of a downstream packet or of a heartbeat, the request is repeated if no response arrives within
+/// [MoldUdp64Recver::with_retry_after].
+///
+/// # Note
+/// * The session is learned from the first packet received and packets of any other session are ignored.
+/// * Delivery starts with sequence number `1`, hence a late joiner will recover all messages published before it joined.
+/// * [RecvStatus::Completed(None)] is returned once the end of session is received and all of its messages were delivered.
+#[derive(Debug)]
+pub struct MoldUdp64Recver<M: Messenger> {
+    feed: DatagramRecver<MoldUdp64CltMessenger, MOLDUDP64_MAX_PACKET_SIZE>,
+    recovery_recver: MessageRecver<MoldUdp64CltMessenger, MOLDUDP64_MAX_PACKET_SIZE>,
+    recovery_sender: MessageSender<MoldUdp64CltMessenger, MOLDUDP64_MAX_PACKET_SIZE>,
+    session: Option<MoldUdp64Session>,
+    next_seq: u64, // sequence number of the next message to be delivered
+    head_seq: u64, // sequence number following the last message known to be published
+    ready: VecDeque<Bytes>,
+    ahead: BTreeMap<u64, Bytes>,
+    requested_at: Option<Instant>,
+    retry_after: Duration,
+    end_of_session: bool,
+    dropped: usize,
+    phantom: std::marker::PhantomData<M>,
+}
+impl<M: Messenger> MoldUdp64Recver<M> {
+    /// Creates a new instance from a `feed` and a `blocking` [TcpStream] connected to the recovery server
+    pub fn new(feed: DatagramRecver<MoldUdp64CltMessenger, MOLDUDP64_MAX_PACKET_SIZE>, recovery: TcpStream) -> Result<Self, Error> {
+        let con_id = ConId::clt(Some(feed.con_id.name()), None, &recovery.peer_addr()?.to_string())?;
+        let (recovery_recver, recovery_sender) = into_split_messenger(con_id, recovery)?;
+        Ok(Self {
+            feed,
+            recovery_recver,
+            recovery_sender,
+            session: None,
+            next_seq: 1,
+            head_seq: 1,
+            ready: VecDeque::new(),
+            ahead: BTreeMap::new(),
+            requested_at: None,
+            retry_after: MOLDUDP64_REQUEST_RETRY_AFTER,
+            end_of_session: false,
+            dropped: 0,
+            phantom: std::marker::PhantomData,
+        })
+    }
+    /// Binds the feed to a given unicast `addr` and connects to the recovery server at `recovery_addr`, example `127.0.0.1:8080`
+    pub fn bind(addr: &str, recovery_addr: &str, timeout: Duration, name: Option<&str>) -> Result<Self, Error> {
+        Self::new(DatagramRecver::bind(addr, name)?, Self::connect_recovery(recovery_addr, timeout)?)
+    }
+    /// Binds the feed to `port` joining multicast `group` on the local `interface` and connects to the recovery server at `recovery_addr`
+    pub fn bind_multicast_v4(group: Ipv4Addr, port: u16, interface: Ipv4Addr, recovery_addr: &str, timeout: Duration, name: Option<&str>) -> Result<Self, Error> {
+        Self::new(DatagramRecver::bind_multicast_v4(group, port, interface, name)?, Self::connect_recovery(recovery_addr, timeout)?)
+    }
+    fn connect_recovery(recovery_addr: &str, timeout: Duration) -> Result<TcpStream, Error> {
+        let addr = recovery_addr
+            .parse::<SocketAddr>()
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("recovery_addr: {:?} caused by: [{}]", recovery_addr, e)))?;
+        let stream = TcpStream::connect_timeout(&addr, timeout)?;
+        stream.set_nodelay(true)?;
+        Ok(stream)
+    }
+    pub fn with_retry_after(self, retry_after: Duration) -> Self {
+        Self { retry_after, ..self }
+    }
+    pub fn session(&self) -> Option<&MoldUdp64Session> {
+        self.session.as_ref()
+    }
+    /// Sequence number of the next message to be delivered by [RecvNonBlocking::recv]
+    pub fn next_sequence_number(&self) -> u64 {
+        self.next_seq
+    }
+    /// Number of messages dropped because they failed to deserialize, their sequence numbers are nevertheless consumed
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+
+    fn on_packet(&mut self, packet: MoldUdp64Packet) {
+        match self.session {
+            None => self.session = Some(*packet.session()),
+            Some(ref session) if session != packet.session() => {
+                if log_enabled!(log::Level::Warn) {
+                    warn!("{} ignoring packet of a different session: {:?}", self, packet);
+                }
+                return;
+            }
+            Some(_) => {}
+        }
+        let mut seq = packet.sequence_number();
+        for msg in packet.messages() {
+            if seq == self.next_seq {
+                self.ready.push_back(msg);
+                self.next_seq += 1;
+                while let Some(msg) = self.ahead.remove(&self.next_seq) {
+                    self.ready.push_back(msg);
+                    self.next_seq += 1;
+                }
+            } else if seq > self.next_seq {
+                self.ahead.entry(seq).or_insert(msg);
+            }
+            seq += 1;
+        }
+        self.head_seq = self.head_seq.max(seq);
+        if packet.is_end_of_session() {
+            self.end_of_session = true;
+        }
+    }
+
+    /// Requests messages missing between [Self::next_seq] and the first message already buffered, or [Self::head_seq] if none are buffered
+    fn request_gap(&mut self) -> Result<(), Error> {
+        if self.next_seq >= self.head_seq {
+            return Ok(());
+        }
+        if matches!(self.requested_at, Some(at) if at.elapsed() < self.retry_after) {
+            return Ok(());
+        }
+        let Some(session) = self.session else {
+            return Ok(());
+        };
+        let gap_end = self.ahead.keys().next().copied().unwrap_or(self.head_seq);
+        let request = MoldUdp64Request {
+            session,
+            sequence_number: self.next_seq,
+            message_count: (gap_end - self.next_seq).min((MOLDUDP64_END_OF_SESSION - 1) as u64) as u16,
+        };
+        if let SendStatus::Completed = self.recovery_sender.send(&request)? {
+            if log_enabled!(log::Level::Debug) {
+                debug!("{} gap detected, sent {:?}", self, request);
+            }
+            self.requested_at = Some(Instant::now());
+        }
+        Ok(())
+    }
+}
+impl<M: Messenger> RecvNonBlocking<M::RecvT> for MoldUdp64Recver<M> {
+    fn recv(&mut self) -> Result<RecvStatus<M::RecvT>, Error> {
+        loop {
+            if let Some(msg) = self.ready.pop_front() {
+                match M::deserialize(&msg) {
+                    Ok(msg) => return Ok(RecvStatus::Completed(Some(msg))),
+                    Err(e) => {
+                        self.dropped += 1;
+                        if log_enabled!(log::Level::Warn) {
+                            warn!("{} dropping message, dropped: {}, failed to deserialize:\n{}\ncaused by: [{}]", self, self.dropped, to_hex_pretty(&msg[..]), e);
+                        }
+                        continue;
+                    }
+                }
+            }
+            if self.end_of_session && self.next_seq >= self.head_seq {
+                return Ok(RecvStatus::Completed(None));
+            }
+            // recovery responses fill the oldest gap hence are processed first
+            match self.recovery_recver.recv()? {
+                RecvStatus::Completed(Some(packet)) => {
+                    self.requested_at = None; // any remainder of the gap is requested right away
+                    self.on_packet(packet);
+                    continue;
+                }
+                RecvStatus::Completed(None) => return Err(Error::new(ErrorKind::ConnectionAborted, format!("{} recovery channel closed by peer", self))),
+                RecvStatus::WouldBlock => {}
+            }
+            if let RecvStatus::Completed(Some(packet)) = self.feed.recv()? {
+                self.on_packet(packet);
+                continue;
+            }
+            self.request_gap()?;
+            return Ok(RecvStatus::WouldBlock);
+        }
+    }
+}
+impl<M: Messenger> Display for MoldUdp64Recver<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let recv_t = std::any::type_name::<M::RecvT>().split("::").last().unwrap_or("Unknown").replace('>', "");
+        let session = self.session.as_ref().map(|session| String::from_utf8_lossy(session).trim_end().to_owned()).unwrap_or_else(|| "pending".to_owned());
+        write!(
+            f,
+            "{}<{}, RecvT:{}, session: {}, next_seq: {}, head_seq: {}>",
+            asserted_short_name!("MoldUdp64Recver", Self),
+            self.feed.con_id,
+            recv_t,
+            session,
+            self.next_seq,
+            self.head_seq
+        )
+    }
+}
+
+/// A MoldUDP64 publisher intended for tests, it sends one [MoldUdp64Packet] per [MoldUdp64Publisher::publish] call to the feed and serves
+/// [MoldUdp64Request]s from all messages published so far on a tcp recovery channel running on a dedicated thread
+///
+/// # Note
+/// Use [MoldUdp64Publisher::drop_next] to simulate packets lost by the feed.
+#[derive(Debug)]
+pub struct MoldUdp64Publisher<M: Messenger, const MAX_MSG_SIZE: usize> {
+    session: MoldUdp64Session,
+    next_seq: u64,
+    feed: DatagramSender<MoldUdp64SvcMessenger, MOLDUDP64_MAX_PACKET_SIZE>,
+    history: Arc<spin::Mutex<Vec<Bytes>>>,
+    drop_next: usize,
+    shutdown: Arc<AtomicBool>,
+    recovery: Option<JoinHandle<()>>,
+    phantom: std::marker::PhantomData<M>,
+}
+impl<M: Messenger, const MAX_MSG_SIZE: usize> MoldUdp64Publisher<M, MAX_MSG_SIZE> {
+    /// Binds the feed to a `local` address sending to `peer` address and binds the recovery server to `recovery_addr`
+    ///
+    /// # Errors
+    /// * [ErrorKind::InvalidInput] - if `session` is longer than [crate::prelude::MOLDUDP64_SESSION_SIZE] bytes
+    pub fn bind(session: &str, local: &str, peer: &str, recovery_addr: &str, name: Option<&str>) -> Result<Self, Error> {
+        let session = crate::prelude::moldudp64_session(session)?;
+        let feed = DatagramSender::bind(local, peer, name)?;
+        let history = Arc::new(spin::Mutex::new(Vec::new()));
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let listener = TcpListener::bind(recovery_addr)?;
+        listener.set_nonblocking(true)?;
+        let con_id = ConId::svc(name, recovery_addr, None)?;
+        let recovery = {
+            let (history, shutdown) = (history.clone(), shutdown.clone());
+            Builder::new().name("MoldUdp64-Recovery-Thread".to_owned()).spawn(move || Self::serve_recovery(con_id, listener, session, history, shutdown))?
+        };
+        Ok(Self {
+            session,
+            next_seq: 1,
+            feed,
+            history,
+            drop_next: 0,
+            shutdown,
+            recovery: Some(recovery),
+            phantom: std::marker::PhantomData,
+        })
+    }
+    /// Publishes `msgs` in a single packet, the first of which is assigned [Self::next_sequence_number]
+    ///
+    /// # Errors
+    /// * [ErrorKind::InvalidInput] - if the packet would exceed [MOLDUDP64_MAX_PACKET_SIZE]
+    pub fn publish(&mut self, msgs: &[M::SendT]) -> Result<(), Error> {
+        let mut blocks = Vec::with_capacity(msgs.len());
+        for msg in msgs {
+            let (buf, len) = M::serialize::<MAX_MSG_SIZE>(msg)?;
+            blocks.push(Bytes::copy_from_slice(&buf[..len]));
+        }
+        let packet = MoldUdp64Packet::new(&self.session, self.next_seq, &blocks)?;
+        if packet.as_slice().len() > MOLDUDP64_MAX_PACKET_SIZE {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("{} packet length: {} exceeds {}", self, packet.as_slice().len(), MOLDUDP64_MAX_PACKET_SIZE)));
+        }
+        self.next_seq += blocks.len() as u64;
+        self.history.lock().extend(blocks);
+        if self.drop_next > 0 {
+            self.drop_next -= 1;
+            if log_enabled!(log::Level::Debug) {
+                debug!("{} dropping {:?}", self, packet);
+            }
+            return Ok(());
+        }
+        self.feed.send_busywait(&packet)
+    }
+    /// Sends a heartbeat packet which also allows the receivers to detect the loss of the most recently published packet
+    pub fn heartbeat(&mut self) -> Result<(), Error> {
+        self.feed.send_busywait(&MoldUdp64Packet::heartbeat(&self.session, self.next_seq))
+    }
+    /// Sends an end of session packet, receivers will terminate their stream once all messages of the session were delivered
+    pub fn end_of_session(&mut self) -> Result<(), Error> {
+        self.feed.send_busywait(&MoldUdp64Packet::end_of_session(&self.session, self.next_seq))
+    }
+    /// Records but does not send the next `packets` published, simulating their loss by the feed
+    pub fn drop_next(&mut self, packets: usize) {
+        self.drop_next = packets;
+    }
+    /// Sequence number to be assigned to the next published message
+    pub fn next_sequence_number(&self) -> u64 {
+        self.next_seq
+    }
+
+    fn serve_recovery(con_id: ConId, listener: TcpListener, session: MoldUdp64Session, history: Arc<spin::Mutex<Vec<Bytes>>>, shutdown: Arc<AtomicBool>) {
+        let mut clts = Vec::new();
+        while !shutdown.load(Ordering::Relaxed) {
+            let mut idle = true;
+            match listener.accept() {
+                Ok((stream, _)) => {
+                    idle = false;
+                    let _ = stream.set_nodelay(true);
+                    match into_split_messenger::<MoldUdp64SvcMessenger, MOLDUDP64_MAX_PACKET_SIZE>(con_id.clone(), stream) {
+                        Ok((recver, sender)) => {
+                            if log_enabled!(log::Level::Info) {
+                                info!("{} recovery accepted {}", con_id, recver);
+                            }
+                            clts.push((recver, sender));
+                        }
+                        Err(e) => warn!("{} recovery accept failed: {}", con_id, e),
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {}
+                Err(e) => warn!("{} recovery accept failed: {}", con_id, e),
+            }
+            clts.retain_mut(|(recver, sender)| match recver.recv() {
+                Ok(RecvStatus::Completed(Some(request))) => {
+                    idle = false;
+                    if request.session != session {
+                        warn!("{} ignoring request of a different session: {:?}", recver, request);
+                        return true;
+                    }
+                    let packet = Self::recover(&session, &history.lock(), &request);
+                    if log_enabled!(log::Level::Debug) {
+                        debug!("{} recovering {:?} with {:?}", sender, request, packet);
+                    }
+                    sender.send_busywait(&packet).map_err(|e| warn!("{} recovery send failed: {}", sender, e)).is_ok()
+                }
+                Ok(RecvStatus::WouldBlock) => true,
+                Ok(RecvStatus::Completed(None)) => false,
+                Err(e) => {
+                    warn!("{} recovery recv failed: {}", recver, e);
+                    false
+                }
+            });
+            if idle {
+                sleep(Duration::from_millis(1));
+            }
+        }
+    }
+    /// Packs as many of the requested messages as will fit into a single packet, the receiver will request the remainder
+    fn recover(session: &MoldUdp64Session, history: &[Bytes], request: &MoldUdp64Request) -> MoldUdp64Packet {
+        let start = request.sequence_number.max(1);
+        let mut size = MOLDUDP64_HEADER_SIZE;
+        let blocks = history
+            .iter()
+            .skip((start - 1) as usize)
+            .take(request.message_count as usize)
+            .take_while(|msg| {
+                size += 2 + msg.len();
+                size <= MOLDUDP64_MAX_PACKET_SIZE
+            })
+            .collect::<Vec<_>>();
+        MoldUdp64Packet::new(session, start, &blocks).expect("recovered messages fit into a packet")
+    }
+}
+impl<M: Messenger, const MAX_MSG_SIZE: usize> Drop for MoldUdp64Publisher<M, MAX_MSG_SIZE> {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        if let Some(recovery) = self.recovery.take() {
+            let _ = recovery.join();
+        }
+    }
+}
+impl<M: Messenger, const MAX_MSG_SIZE: usize> Display for MoldUdp64Publisher<M, MAX_MSG_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let send_t = std::any::type_name::<M::SendT>().split("::").last().unwrap_or("Unknown").replace('>', "");
+        let session = String::from_utf8_lossy(&self.session);
+        write!(
+            f,
+            "{}<{}, SendT:{}, session: {}, next_seq: {}>",
+            asserted_short_name!("MoldUdp64Publisher", Self),
+            self.feed.con_id,
+            send_t,
+            session.trim_end(),
+            self.next_seq
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::prelude::*;
+    use links_core::unittest::setup::{
+        self,
+        framer::{CltTestMessenger, SvcTestMessenger, TEST_MSG_FRAME_SIZE},
+        model::*,
+    };
+    use log::info;
+    use std::{net::Ipv4Addr, time::Duration};
+
+    fn msg(i: usize) -> CltTestMsg {
+        CltTestMsg::Dbg(CltTestMsgDebug::new(format!("Msg #{}", i).as_bytes()))
+    }
+
+    #[test]
+    fn test_moldudp64_recver_gap_fill() {
+        setup::log::configure();
+        let feed_addr = setup::net::rand_avail_addr_port();
+        let recovery_addr = setup::net::rand_avail_addr_port();
+        const N: usize = 100;
+
+        let mut publisher = MoldUdp64Publisher::<CltTestMessenger, TEST_MSG_FRAME_SIZE>::bind("SESSION1", "127.0.0.1:0", feed_addr, recovery_addr, Some("unittest")).unwrap();
+        let mut recver = MoldUdp64Recver::<SvcTestMessenger>::bind(feed_addr, recovery_addr, setup::net::default_connect_timeout(), Some("unittest")).unwrap();
+        info!("publisher: {}", publisher);
+        info!("recver: {}", recver);
+
+        // packets of 1..=3 messages where every 5th packet is lost, including the very first one
+        let mut seq = 1;
+        for i in 0..N {
+            if i % 5 == 0 {
+                publisher.drop_next(1);
+            }
+            let msgs = (seq..seq + 1 + i % 3).map(msg).collect::<Vec<_>>();
+            seq += msgs.len();
+            publisher.publish(&msgs).unwrap();
+        }
+        // last packet is lost, heartbeat lets recver detect it
+        publisher.drop_next(1);
+        publisher.publish(&[msg(seq)]).unwrap();
+        publisher.heartbeat().unwrap();
+        publisher.end_of_session().unwrap();
+        info!("publisher: {}", publisher);
+
+        for i in 1..=seq {
+            let out = recver.recv_busywait_timeout(Duration::from_secs(1)).unwrap().unwrap_completed_some();
+            assert_eq!(out, msg(i));
+        }
+        info!("recver: {}", recver);
+        assert_eq!(recver.session(), Some(&moldudp64_session("SESSION1").unwrap()));
+        assert_eq!(recver.next_sequence_number(), publisher.next_sequence_number());
+        assert!(recver.recv_busywait_timeout(Duration::from_secs(1)).unwrap().is_completed_none());
+    }
+
+    #[test]
+    fn test_moldudp64_recver_multicast() {
+        setup::log::configure();
+        let group = Ipv4Addr::new(239, 255, 0, 2);
+        let port = setup::net::find_available_port(2000..65000);
+        let recovery_addr = setup::net::rand_avail_addr_port();
+        const N: usize = 100;
+
+        let mut publisher = MoldUdp64Publisher::<CltTestMessenger, TEST_MSG_FRAME_SIZE>::bind("SESSION2", "127.0.0.1:0", &format!("{}:{}", group, port), recovery_addr, Some("unittest")).unwrap();
+        let mut recver = MoldUdp64Recver::<SvcTestMessenger>::bind_multicast_v4(group, port, Ipv4Addr::LOCALHOST, recovery_addr, setup::net::default_connect_timeout(), Some("unittest")).unwrap();
+        info!("recver: {}", recver);
+
+        for i in 1..=N {
+            publisher.publish(&[msg(i)]).unwrap();
+        }
+        for i in 1..=N {
+            assert_eq!(recver.recv_busywait_timeout(Duration::from_secs(1)).unwrap().unwrap_completed_some(), msg(i));
+        }
+        assert!(recver.recv().unwrap().is_wouldblock());
+    }
+
+    #[test]
+    fn test_moldudp64_recver_garbage_dropped() {
+        setup::log::configure();
+        let feed_addr = setup::net::rand_avail_addr_port();
+        let recovery_addr = setup::net::rand_avail_addr_port();
+        let _recovery = std::net::TcpListener::bind(recovery_addr).unwrap();
+        let mut recver = MoldUdp64Recver::<SvcTestMessenger>::bind(feed_addr, recovery_addr, setup::net::default_connect_timeout(), Some("unittest")).unwrap();
+        let feed = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+
+        let session = moldudp64_session("SESSION3").unwrap();
+        let encode = |i: usize| {
+            let (bytes, size) = CltTestMessenger::serialize::<TEST_MSG_FRAME_SIZE>(&msg(i)).unwrap();
+            bytes[..size].to_vec()
+        };
+        // message #1 carries garbage message type and is followed by a valid message in the same packet
+        let mut garbage = encode(1);
+        garbage[0] = b'?';
+        feed.send_to(MoldUdp64Packet::new(&session, 1, &[garbage, encode(2)]).unwrap().as_slice(), feed_addr).unwrap();
+        feed.send_to(MoldUdp64Packet::new(&session, 3, &[encode(3)]).unwrap().as_slice(), feed_addr).unwrap();
+
+        // garbage message is dropped while the feed keeps delivering subsequent messages in sequence
+        for i in 2..=3 {
+            assert_eq!(recver.recv_busywait_timeout(Duration::from_secs(1)).unwrap().unwrap_completed_some(), msg(i));
+        }
+        info!("recver: {}", recver);
+        assert_eq!(recver.dropped(), 1);
+        assert_eq!(recver.next_sequence_number(), 4);
+        assert!(recver.recv().unwrap().is_wouldblock());
+    }
+}
@@ -1,52 +1,127 @@
 use crate::{core::PollAccept, prelude::*};
 use core::panic;
 use log::{debug, info, log_enabled, warn, Level};
-use mio::{Events, Poll, Token, Waker};
+use mio::{event::Event, Events, Interest, Poll, Token, Waker};
 use slab::Slab;
 use std::{
-    fmt::Display,
-    io::Error,
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap, HashMap},
+    fmt::{Debug, Display},
+    io::{Error, ErrorKind},
+    num::NonZeroUsize,
     sync::{
-        atomic::{AtomicBool, Ordering},
-        mpsc::{channel, Receiver, Sender, TryRecvError},
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        mpsc::{channel, sync_channel, Receiver, Sender, TryRecvError},
+        Arc, Mutex,
     },
     thread::Builder,
+    time::{Duration, Instant},
 };
 
 // setting up these macros to reuse code where borrow checker, iterating over self.events while modifying self.serviceable
-macro_rules! register_recver_as_readable {
-    ($self:ident, $recver:ident, $token:ident) => {
+// yields the result of registering with the poll, the recver is only tracked if it succeeds
+macro_rules! register_recver {
+    ($self:ident, $recver:ident, $token:ident) => {{
         // USING register method instead of source to enable overriding of register method when locking is required
-        // $self.poll.registry().register(*$recver.source(), $token, mio::Interest::READABLE).expect("Failed to poll register recver");
-        $recver.register($self.poll.registry(), $token, mio::Interest::READABLE).expect("Failed to poll register recver");
-        if log_enabled!(Level::Debug) {
-            debug!("registered recver: {} with token: {:?}", $recver.con_id(), $token);
+        // $self.poll.registry().register(*$recver.source(), $token, mio::Interest::READABLE)
+        let interests = $recver.interests();
+        let res = $recver.register($self.poll.registry(), $token, interests);
+        if res.is_ok() {
+            $self.registered_interests.insert($token.into(), interests);
+            if log_enabled!(Level::Debug) {
+                debug!("registered recver: {} with token: {:?}, interests: {:?}", $recver.con_id(), $token, interests);
+            }
+            $self.stats.insert($token.into(), $recver.con_id().clone(), ServiceableKind::Recver);
+            if let Some(interval) = $recver.timer_interval() {
+                $self.timers.schedule($token.into(), Instant::now() + interval);
+            }
+            // only recvers which expect to become readable by a deadline at the time of registration are tracked
+            if let Some(deadline) = $recver.recv_deadline() {
+                $self.deadlines.schedule($token.into(), deadline);
+            }
         }
-    };
+        res
+    }};
+}
+// yields `false` if the recver failed to reregister, in which case it is dropped without affecting the rest of the serviceable
+macro_rules! reregister_recver_if_interests_changed {
+    ($self:ident, $recver:ident, $key:ident) => {{
+        let interests = $recver.interests();
+        if $self.registered_interests.get(&$key) == Some(&interests) {
+            true
+        } else {
+            // USING reregister method instead of source to enable overriding of reregister method when locking is required
+            match $recver.reregister($self.poll.registry(), Token($key), interests) {
+                Ok(()) => {
+                    $self.registered_interests.insert($key, interests);
+                    if log_enabled!(Level::Debug) {
+                        debug!("reregistered recver: {} with token: {:?}, interests: {:?}", $recver.con_id(), Token($key), interests);
+                    }
+                    true
+                }
+                Err(e) => {
+                    if log_enabled!(Level::Warn) {
+                        warn!("Dirty, service loop termination recver: {}, failed to reregister interests: {:?}, error: {}", $recver, interests, e);
+                    }
+                    $self.drop_serviceable($key);
+                    false
+                }
+            }
+        }
+    }};
 }
+// yields the result of registering with the poll, the acceptor is only tracked if it succeeds
 macro_rules! register_acceptor_as_readable {
-    ($self:ident, $acceptor:ident, $token:ident) => {
+    ($self:ident, $acceptor:ident, $token:ident) => {{
         // USING $acceptor.register method instead of $acceptor.source to enable overriding of register method when locking is required
-        // $self.poll.registry().register(*$acceptor.source(), $token, mio::Interest::READABLE).expect("Failed to poll register acceptor");
-        $acceptor.register($self.poll.registry(), $token, mio::Interest::READABLE).expect("Failed to poll register acceptor");
-        if log_enabled!(Level::Debug) {
-            debug!("registered acceptor: {} with token: {:?}", $acceptor.con_id(), $token);
+        // $self.poll.registry().register(*$acceptor.source(), $token, mio::Interest::READABLE)
+        let res = $acceptor.register($self.poll.registry(), $token, mio::Interest::READABLE);
+        if res.is_ok() {
+            if log_enabled!(Level::Debug) {
+                debug!("registered acceptor: {} with token: {:?}", $acceptor.con_id(), $token);
+            }
+            $self.stats.insert($token.into(), $acceptor.con_id().clone(), ServiceableKind::Acceptor);
         }
-    };
+        res
+    }};
 }
-macro_rules! register_serviceable_as_readable {
-    ($self:ident, $serviceable:ident) => {
+// yields the result of registering with the poll, a serviceable which fails to register is dropped without affecting the rest of the serviceable
+macro_rules! register_serviceable {
+    ($self:ident, $serviceable:ident) => {{
         let token = Token($self.serviceable.insert($serviceable));
-        match $self.serviceable[token.into()] {
-            Serviceable::Recver(ref mut recver) => {
-                register_recver_as_readable!($self, recver, token);
+        let res = match $self.serviceable[token.into()] {
+            Serviceable::Recver(ref mut recver) => register_recver!($self, recver, token),
+            Serviceable::Acceptor(ref mut acceptor) => register_acceptor_as_readable!($self, acceptor, token),
+            Serviceable::Connector(ref mut connector) => {
+                // connect completion is signaled by the socket becoming writable
+                let res = connector.register($self.poll.registry(), token, mio::Interest::WRITABLE);
+                if res.is_ok() {
+                    if log_enabled!(Level::Debug) {
+                        debug!("registered connector: {} with token: {:?}", connector.con_id(), token);
+                    }
+                    $self.stats.insert(token.into(), connector.con_id().clone(), ServiceableKind::Connector);
+                    if let Some(deadline) = connector.connect_deadline() {
+                        $self.deadlines.schedule(token.into(), deadline);
+                    }
+                }
+                res
             }
-            Serviceable::Acceptor(ref mut acceptor) => {
-                register_acceptor_as_readable!($self, acceptor, token);
+            Serviceable::Waker(_) => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Invalid API usage. Waker should not be manually registered as serviceable. It is auto registered when calling [PollHandler::into_spawned_handler]",
+            )),
+        };
+        match res {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                let serviceable = $self.serviceable.remove(token.into());
+                if serviceable.is_connection() {
+                    $self.connections.fetch_sub(1, Ordering::Relaxed);
+                }
+                Err(Error::new(e.kind(), format!("Failed to poll register {}, error: {}", serviceable, e)))
             }
-            Serviceable::Waker(_) => panic!("Invalid API usage. Waker should not be manually registered as serviceable. It is auto registered when calling [PollHandler::into_spawned_handler]"),
         }
-    };
+    }};
 }
 
 macro_rules! deregister_and_drop_some_serviceable {
@@ -71,7 +146,10 @@ macro_rules! deregister_and_drop_some_serviceable {
                 if $con_id.is_none() || ($con_id.is_some() && $con_id.unwrap().from_same_lineage(recver.con_id())) {
                     // USING recver.deregister method instead of recver.source to enable overriding of deregister method when locking is required
                     // self.poll.registry().deregister(*recver.source())?;
-                    recver.deregister($self.poll.registry()).unwrap();
+                    if let Err(e) = recver.deregister($self.poll.registry()) {
+                        warn!("Dirty, failed to deregister recver: {}, error: {}", recver, e);
+                    }
+                    $self.connections.fetch_sub(1, Ordering::Relaxed);
                     false // don't retain
                 } else {
                     true
@@ -81,7 +159,20 @@ macro_rules! deregister_and_drop_some_serviceable {
                 if $con_id.is_none() || ($con_id.is_some() && $con_id.unwrap().from_same_lineage(acceptor.con_id())) {
                     // USING acceptor.deregister method instead of acceptor.source to enable overriding of deregister method when locking is required
                     // self.poll.registry().deregister(*acceptor.source())?;
-                    acceptor.deregister($self.poll.registry()).unwrap();
+                    if let Err(e) = acceptor.deregister($self.poll.registry()) {
+                        warn!("Dirty, failed to deregister acceptor: {}, error: {}", acceptor, e);
+                    }
+                    false // don't retain
+                } else {
+                    true
+                }
+            }
+            Connector(ref mut connector) => {
+                if $con_id.is_none() || ($con_id.is_some() && $con_id.unwrap().from_same_lineage(connector.con_id())) {
+                    if let Err(e) = connector.deregister($self.poll.registry()) {
+                        warn!("Dirty, failed to deregister connector: {}, error: {}", connector, e);
+                    }
+                    $self.connections.fetch_sub(1, Ordering::Relaxed);
                     false // don't retain
                 } else {
                     true
@@ -96,6 +187,10 @@ macro_rules! deregister_and_drop_some_serviceable {
                 }
             }
         });
+        $self.registered_interests.retain(|key, _| $self.serviceable.contains(*key));
+        $self.timers.retain(|key| $self.serviceable.contains(key));
+        $self.deadlines.retain(|key| $self.serviceable.contains(key));
+        $self.stats.retain(|key| $self.serviceable.contains(key));
     };
 }
 
@@ -106,14 +201,31 @@ enum ServiceStatus {
 enum Serviceable<R: PollRead, A: PollAccept<R>> {
     Acceptor(A),
     Recver(R),
+    Connector(Box<dyn PollConnect<R>>),
     Waker(Option<ConId>),
 }
+impl<R: PollRead, A: PollAccept<R>> Serviceable<R, A> {
+    /// `true` if it counts towards [SpawnedPollHandler::connection_count], a [PollConnect] is counted from the moment it is added since it becomes a [PollRead] once connected
+    #[inline(always)]
+    fn is_connection(&self) -> bool {
+        matches!(self, Serviceable::Recver(_) | Serviceable::Connector(_))
+    }
+    fn con_id(&self) -> Option<&ConId> {
+        match self {
+            Serviceable::Acceptor(acceptor) => Some(acceptor.con_id()),
+            Serviceable::Recver(recver) => Some(recver.con_id()),
+            Serviceable::Connector(connector) => Some(connector.con_id()),
+            Serviceable::Waker(con_id) => con_id.as_ref(),
+        }
+    }
+}
 impl<R: PollRead, A: PollAccept<R>> Display for Serviceable<R, A> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = asserted_short_name!("Serviceable", Self);
         match self {
             Serviceable::Acceptor(acceptor) => write!(f, "{}::Acceptor({})", name, acceptor.con_id()),
             Serviceable::Recver(recver) => write!(f, "{}::Recver({})", name, recver.con_id()),
+            Serviceable::Connector(connector) => write!(f, "{}::Connector({})", name, connector.con_id()),
             Serviceable::Waker(opt) => write!(f, "{}::Waker({})", name, {
                 if let Some(ref con_id) = opt {
                     format!("{}", con_id)
@@ -125,138 +237,769 @@ impl<R: PollRead, A: PollAccept<R>> Display for Serviceable<R, A> {
     }
 }
 
+/// Options applied by [PollHandler::into_spawned_handler_with] to the designated poll thread
+///
+/// # Example
+/// ```
+/// use links_nonblocking::prelude::*;
+///
+/// use std::num::NonZeroUsize;
+///
+/// let options = PollHandlerOptions::default().with_busy_spin(true).with_core_id(0).with_budget(NonZeroUsize::new(16).unwrap());
+/// assert!(options.busy_spin);
+/// assert_eq!(options.budget.get(), 16);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PollHandlerOptions {
+    /// When `true` the thread polls with a zero timeout in a tight loop instead of parking in the os, trading a fully utilized cpu core for lower latency
+    pub busy_spin: bool,
+    /// Cpu core the thread is pinned to, linux only
+    pub core_id: Option<usize>,
+    /// `SCHED_FIFO` priority of the thread, on linux `1` is the lowest and `99` the highest and raising it typically requires `CAP_SYS_NICE`, unix only
+    pub realtime_priority: Option<u8>,
+    /// Maximum number of yielding calls a single serviceable gets per pass of [PollHandler] before the remaining serviceable are given a turn and the os is polled again,
+    /// serviceable that exhaust it are carried over and continued on the next pass in round-robin fashion, defaults to [DEFAULT_POLL_HANDLER_BUDGET]
+    pub budget: NonZeroUsize,
+    /// When `true` the thread records [ServiceableStats] of every call it issues, at the cost of reading the clock twice per call, required by
+    /// [SpawnedPollHandler::start_watchdog] and for [SpawnedPollHandler::snapshot] to report anything other than zero counters
+    pub stats: bool,
+}
+impl Default for PollHandlerOptions {
+    fn default() -> Self {
+        Self {
+            busy_spin: false,
+            core_id: None,
+            realtime_priority: None,
+            budget: DEFAULT_POLL_HANDLER_BUDGET,
+            stats: false,
+        }
+    }
+}
+impl PollHandlerOptions {
+    pub fn with_busy_spin(self, busy_spin: bool) -> Self {
+        Self { busy_spin, ..self }
+    }
+    pub fn with_core_id(self, core_id: usize) -> Self {
+        Self { core_id: Some(core_id), ..self }
+    }
+    pub fn with_realtime_priority(self, priority: u8) -> Self {
+        Self { realtime_priority: Some(priority), ..self }
+    }
+    pub fn with_budget(self, budget: NonZeroUsize) -> Self {
+        Self { budget, ..self }
+    }
+    pub fn with_stats(self, stats: bool) -> Self {
+        Self { stats, ..self }
+    }
+    /// Applies the thread level options to the calling thread
+    fn apply_to_current_thread(&self) -> Result<(), Error> {
+        if let Some(core_id) = self.core_id {
+            sys::pin_current_thread(core_id)?;
+        }
+        if let Some(priority) = self.realtime_priority {
+            sys::set_current_thread_realtime_priority(priority)?;
+        }
+        Ok(())
+    }
+}
+impl Display for PollHandlerOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}<busy_spin: {}, core_id: {:?}, realtime_priority: {:?}, budget: {}, stats: {}>",
+            asserted_short_name!("PollHandlerOptions", Self),
+            self.busy_spin,
+            self.core_id,
+            self.realtime_priority,
+            self.budget,
+            self.stats
+        )
+    }
+}
+
+/// Default [PollHandlerOptions::budget]
+pub const DEFAULT_POLL_HANDLER_BUDGET: NonZeroUsize = match NonZeroUsize::new(64) {
+    Some(budget) => budget,
+    None => panic!("DEFAULT_POLL_HANDLER_BUDGET must be greater than zero"), // evaluated at compile time
+};
+
+/// Readiness of a single serviceable tracked across the passes of [PollHandler::service], mio is edge triggered hence a serviceable that exhausted its budget
+/// will not be reported by the os again and has to be carried over to the next pass until it stops yielding
+#[derive(Debug, Clone, Copy)]
+struct PendingEvent {
+    key: usize,
+    readable: bool,
+    writable: bool,
+    serviced: usize,
+}
+impl PendingEvent {
+    fn merge(&mut self, other: &PendingEvent) {
+        self.readable |= other.readable;
+        self.writable |= other.writable;
+    }
+}
+impl From<&Event> for PendingEvent {
+    fn from(event: &Event) -> Self {
+        Self {
+            key: event.token().into(),
+            // read closed & error conditions are also signaled to the reader so that it can observe them on the next read
+            readable: event.is_readable() || event.is_read_closed() || event.is_error() || !event.is_writable(),
+            writable: event.is_writable(),
+            serviced: 0,
+        }
+    }
+}
+
+/// Kind of a serviceable reported by [ServiceableStats]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceableKind {
+    Acceptor,
+    Recver,
+    Connector,
+}
+
+/// Statistics of a single [PollAccept], [PollRead] or [PollConnect] serviced by a [PollHandler], see [SpawnedPollHandler::snapshot]
+#[derive(Debug, Clone)]
+pub struct ServiceableStats {
+    pub con_id: ConId,
+    pub kind: ServiceableKind,
+    /// Number of calls issued to service poll events, such as [PollRead::on_readable_event]
+    pub events: u64,
+    /// Number of calls which yielded [PollEventStatus::Completed] or [AcceptStatus::Accepted], for a [PollRead] this is typically the number of messages received
+    pub messages: u64,
+    /// Instant the most recent call returned, [None] if it was never called
+    pub last_activity: Option<Instant>,
+    /// Longest time a single call took to return
+    pub max_service_time: Duration,
+    /// Time the current call has been running for, [None] unless the [PollHandler] thread was servicing it when the statistics were taken
+    pub busy_for: Option<Duration>,
+}
+impl Display for ServiceableStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}<{:?}({}), events: {}, messages: {}, last_activity: {:?} ago, max_service_time: {:?}, busy_for: {:?}>",
+            asserted_short_name!("ServiceableStats", Self),
+            self.kind,
+            self.con_id,
+            self.events,
+            self.messages,
+            self.last_activity.map(|instant| instant.elapsed()),
+            self.max_service_time,
+            self.busy_for
+        )
+    }
+}
+
+/// Counters of a single serviceable, only ever written by the [PollHandler] thread hence updated without locking
+#[derive(Debug)]
+struct ServiceableCounters {
+    con_id: ConId,
+    kind: ServiceableKind,
+    events: AtomicU64,
+    messages: AtomicU64,
+    // nanos since [PollHandlerStats::epoch], `0` if never called
+    last_activity: AtomicU64,
+    max_service_time: AtomicU64,
+}
+impl ServiceableCounters {
+    fn new(con_id: ConId, kind: ServiceableKind) -> Self {
+        Self {
+            con_id,
+            kind,
+            events: AtomicU64::new(0),
+            messages: AtomicU64::new(0),
+            last_activity: AtomicU64::new(0),
+            max_service_time: AtomicU64::new(0),
+        }
+    }
+}
+
+const NOT_BUSY: usize = usize::MAX;
+
+/// Statistics recorded by the [PollHandler] thread and read by [SpawnedPollHandler], the lock only guards registration and removal of serviceable
+/// while the counters of each serviceable and the busy marker are atomics which the [PollHandler] thread updates without locking
+#[derive(Debug)]
+struct PollHandlerStats {
+    epoch: Instant,
+    serviceable: spin::Mutex<BTreeMap<usize, Arc<ServiceableCounters>>>,
+    // key of the serviceable being called or [NOT_BUSY], `busy_since` is always stored before `busy_key`
+    busy_key: AtomicUsize,
+    busy_since: AtomicU64,
+}
+impl Default for PollHandlerStats {
+    fn default() -> Self {
+        Self {
+            epoch: Instant::now(),
+            serviceable: spin::Mutex::new(BTreeMap::new()),
+            busy_key: AtomicUsize::new(NOT_BUSY),
+            busy_since: AtomicU64::new(0),
+        }
+    }
+}
+impl PollHandlerStats {
+    #[inline]
+    fn nanos_since_epoch(&self, instant: Instant) -> u64 {
+        // `0` is reserved for never hence the minimum of `1`
+        (instant.saturating_duration_since(self.epoch).as_nanos() as u64).max(1)
+    }
+    fn to_serviceable_stats(&self, counters: &ServiceableCounters) -> ServiceableStats {
+        let last_activity = counters.last_activity.load(Ordering::Relaxed);
+        ServiceableStats {
+            con_id: counters.con_id.clone(),
+            kind: counters.kind,
+            events: counters.events.load(Ordering::Relaxed),
+            messages: counters.messages.load(Ordering::Relaxed),
+            last_activity: if last_activity == 0 { None } else { Some(self.epoch + Duration::from_nanos(last_activity)) },
+            max_service_time: Duration::from_nanos(counters.max_service_time.load(Ordering::Relaxed)),
+            busy_for: None,
+        }
+    }
+    /// Key and start of the call currently being serviced, if any
+    fn busy_call(&self) -> Option<(usize, Instant)> {
+        let key = self.busy_key.load(Ordering::Acquire);
+        if key == NOT_BUSY {
+            return None;
+        }
+        let since = self.busy_since.load(Ordering::Acquire);
+        // the call ended or a different serviceable started while reading
+        if self.busy_key.load(Ordering::Acquire) != key {
+            return None;
+        }
+        Some((key, self.epoch + Duration::from_nanos(since)))
+    }
+    fn snapshot(&self) -> Vec<ServiceableStats> {
+        let busy = self.busy_call();
+        let now = Instant::now();
+        self.serviceable
+            .lock()
+            .iter()
+            .map(|(key, counters)| {
+                let mut stats = self.to_serviceable_stats(counters);
+                if let Some((busy_key, since)) = busy {
+                    if busy_key == *key {
+                        stats.busy_for = Some(now.saturating_duration_since(since));
+                    }
+                }
+                stats
+            })
+            .collect()
+    }
+    /// Key, start and statistics of the serviceable currently being called, if any
+    fn busy(&self) -> Option<(usize, Instant, ServiceableStats)> {
+        let (key, since) = self.busy_call()?;
+        let mut stats = self.to_serviceable_stats(self.serviceable.lock().get(&key)?);
+        stats.busy_for = Some(since.elapsed());
+        Some((key, since, stats))
+    }
+}
+
+/// [PollHandler] thread side of [PollHandlerStats] which keeps its own index of the counters so that servicing an event never takes the shared lock,
+/// nothing is recorded while servicing unless [PollHandlerOptions::stats] is enabled
+#[derive(Debug, Default)]
+struct PollHandlerStatsRecorder {
+    enabled: bool,
+    shared: Arc<PollHandlerStats>,
+    counters: HashMap<usize, Arc<ServiceableCounters>>,
+}
+impl PollHandlerStatsRecorder {
+    fn insert(&mut self, key: usize, con_id: ConId, kind: ServiceableKind) {
+        let counters = Arc::new(ServiceableCounters::new(con_id, kind));
+        self.shared.serviceable.lock().insert(key, counters.clone());
+        self.counters.insert(key, counters);
+    }
+    fn remove(&mut self, key: usize) {
+        if self.counters.remove(&key).is_some() {
+            self.shared.serviceable.lock().remove(&key);
+        }
+    }
+    fn retain(&mut self, f: impl Fn(usize) -> bool) {
+        let len = self.counters.len();
+        self.counters.retain(|key, _| f(*key));
+        if self.counters.len() != len {
+            self.shared.serviceable.lock().retain(|key, _| f(*key));
+        }
+    }
+    /// Marks the serviceable as busy for the duration of `service` and records the call once it returns, `is_completed` decides if the call counts towards [ServiceableStats::messages]
+    #[inline]
+    fn service<T>(&self, key: usize, service: impl FnOnce() -> T, is_completed: impl FnOnce(&T) -> bool) -> T {
+        if !self.enabled {
+            return service();
+        }
+        let shared = &self.shared;
+        let started = shared.nanos_since_epoch(Instant::now());
+        shared.busy_since.store(started, Ordering::Release);
+        shared.busy_key.store(key, Ordering::Release);
+        let res = service();
+        let completed = is_completed(&res);
+        let now = shared.nanos_since_epoch(Instant::now());
+        shared.busy_key.store(NOT_BUSY, Ordering::Release);
+        if let Some(counters) = self.counters.get(&key) {
+            // single writer hence plain load & store suffice
+            counters.events.store(counters.events.load(Ordering::Relaxed) + 1, Ordering::Relaxed);
+            if completed {
+                counters.messages.store(counters.messages.load(Ordering::Relaxed) + 1, Ordering::Relaxed);
+            }
+            counters.last_activity.store(now, Ordering::Relaxed);
+            let service_time = now - started;
+            if service_time > counters.max_service_time.load(Ordering::Relaxed) {
+                counters.max_service_time.store(service_time, Ordering::Relaxed);
+            }
+        }
+        res
+    }
+}
+
+/// Callback issued by [PollHandlerWatchdog] with the statistics of the offending serviceable
+pub type PollHandlerWatchdogCallback = Arc<dyn Fn(&ServiceableStats) + Send + Sync>;
+
+/// Watchdog started using [SpawnedPollHandler::start_watchdog] that reports any single call into a [PollAccept], [PollRead] or [PollConnect] holding the
+/// [PollHandler] thread for longer than `threshold`, either by logging a warning or by calling a [PollHandlerWatchdogCallback]. Each offending call is only reported once.
+///
+/// # Example
+/// ```
+/// use links_nonblocking::prelude::*;
+/// use std::time::Duration;
+///
+/// let watchdog = PollHandlerWatchdog::new(Duration::from_millis(100)).with_callback(|stats: &ServiceableStats| println!("hogging: {}", stats));
+/// let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler_with("doctest", PollHandlerOptions::default().with_stats(true)).unwrap();
+/// spawned_poll_handler.start_watchdog(&DEFAULT_WATCHDOG_HANDLER, watchdog);
+/// ```
+#[derive(Clone)]
+pub struct PollHandlerWatchdog {
+    threshold: Duration,
+    check_interval: Duration,
+    callback: Option<PollHandlerWatchdogCallback>,
+}
+impl PollHandlerWatchdog {
+    /// Checks every `threshold / 2` and logs a warning when `threshold` is exceeded
+    pub fn new(threshold: Duration) -> Self {
+        Self {
+            threshold,
+            check_interval: threshold / 2,
+            callback: None,
+        }
+    }
+    pub fn with_check_interval(self, check_interval: Duration) -> Self {
+        Self { check_interval, ..self }
+    }
+    pub fn with_callback(self, callback: impl Fn(&ServiceableStats) + Send + Sync + 'static) -> Self {
+        Self { callback: Some(Arc::new(callback)), ..self }
+    }
+}
+impl Debug for PollHandlerWatchdog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollHandlerWatchdog")
+            .field("threshold", &self.threshold)
+            .field("check_interval", &self.check_interval)
+            .field("callback", &self.callback.as_ref().map(|_| "Fn(&ServiceableStats)"))
+            .finish()
+    }
+}
+
+/// Connectors channel shared by the [Handoff] workers along with the number of them which are idle
+struct Workers<R: PollRead> {
+    tx_connector: Sender<Box<dyn PollConnect<R>>>,
+    rx_connector: Arc<Mutex<Receiver<Box<dyn PollConnect<R>>>>>,
+    idle: Arc<AtomicUsize>,
+}
+
+/// Completes [PollConnect::into_recver] on a pool of worker threads, since it may block, for example while running [crate::prelude::ProtocolCore::on_connect],
+/// and hands the resulting [PollRead] back to the [PollHandler] thread using the same channel as [SpawnedPollHandler]
+///
+/// # Note
+/// A worker is only spawned when all the others are busy completing a connector and is reused once done, hence the pool grows to the max number of concurrent
+/// completions instead of a thread per connection, workers exit once the [PollHandler] is dropped. Completions never wait for one another, which is required
+/// when both ends of a connection, for example a tls handshake, are completed by the same [PollHandler]
+struct Handoff<R: PollRead, A: PollAccept<R>> {
+    name: String,
+    tx_serviceable: Sender<Serviceable<R, A>>,
+    waker: Arc<Waker>,
+    connections: Arc<AtomicUsize>,
+    workers: Option<Workers<R>>,
+}
+impl<R: PollRead, A: PollAccept<R>> Handoff<R, A> {
+    fn new(name: &str, tx_serviceable: Sender<Serviceable<R, A>>, waker: Arc<Waker>, connections: Arc<AtomicUsize>) -> Self {
+        Self {
+            name: name.to_owned(),
+            tx_serviceable,
+            waker,
+            connections,
+            workers: None,
+        }
+    }
+    /// Queues the `connector` for an idle worker, a new worker is spawned if none is idle
+    fn queue(&mut self, connector: Box<dyn PollConnect<R>>) {
+        let Workers { tx_connector, rx_connector, idle } = self.workers.get_or_insert_with(|| {
+            let (tx_connector, rx_connector) = channel();
+            Workers {
+                tx_connector,
+                rx_connector: Arc::new(Mutex::new(rx_connector)),
+                idle: Arc::new(AtomicUsize::new(0)),
+            }
+        });
+        // claiming an idle worker guarantees the connector is picked up without waiting for another completion to finish
+        if idle.fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| count.checked_sub(1)).is_ok() {
+            // receiving end is held by self hence send can't fail
+            let _ = tx_connector.send(connector);
+            return;
+        }
+        let (rx_connector, idle) = (rx_connector.clone(), idle.clone());
+        let (tx_serviceable, waker, connections) = (self.tx_serviceable.clone(), self.waker.clone(), self.connections.clone());
+        let con_id = connector.con_id().clone();
+        let res = Builder::new().name(format!("{}-Connect", self.name)).spawn(move || {
+            Self::complete(connector, &tx_serviceable, &waker, &connections);
+            loop {
+                idle.fetch_add(1, Ordering::AcqRel);
+                // lock is released before completing so that other idle workers can pick up the next connector
+                let next = match rx_connector.lock() {
+                    Ok(rx_connector) => rx_connector.recv(),
+                    Err(_) => return, // poisoned only if a worker panicked holding it, which recv does not
+                };
+                match next {
+                    Ok(connector) => Self::complete(connector, &tx_serviceable, &waker, &connections),
+                    Err(_) => return, // PollHandler dropped
+                }
+            }
+        });
+        if let Err(e) = res {
+            warn!("Dirty, connector: {} dropped, failed to spawn thread to complete it, error: {}", con_id, e);
+            self.connections.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+    /// Errors of [PollConnect::into_recver] are expected to be reported by the `connector` itself, see [crate::prelude::PendingCltSenderRef]
+    fn complete(connector: Box<dyn PollConnect<R>>, tx_serviceable: &Sender<Serviceable<R, A>>, waker: &Waker, connections: &AtomicUsize) {
+        let con_id = connector.con_id().clone();
+        match connector.into_recver() {
+            Ok(recver) => {
+                if log_enabled!(Level::Info) {
+                    info!("Connected, handing off recver: {}", recver);
+                }
+                // the recver remains counted as it was while connecting
+                if tx_serviceable.send(Serviceable::Recver(recver)).is_err() {
+                    if log_enabled!(Level::Debug) {
+                        debug!("PollHandler thread is terminated, dropping recver: {}", con_id);
+                    }
+                    connections.fetch_sub(1, Ordering::Relaxed);
+                    return;
+                }
+                if let Err(e) = waker.wake() {
+                    warn!("Failed to wake PollHandler after handing off recver: {}, error: {}", con_id, e);
+                }
+            }
+            Err(e) => {
+                if log_enabled!(Level::Warn) {
+                    warn!("Dirty, connector: {} failed to complete, error: {}", con_id, e);
+                }
+                connections.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
 /// A wrapper struct to that will use a designated thread to handle [TransmittingSvcAcceptor] or [TransmittingSvcAcceptorRef] events
 /// and resulting respective [CltRecver] & [CltRecverRef] instances
 pub struct PollHandler<R: PollRead, A: PollAccept<R>> {
     poll: Poll,
     serviceable: Slab<Serviceable<R, A>>,
+    registered_interests: HashMap<usize, Interest>,
+    timers: Deadlines,
+    // recv & connect deadlines, see [PollRead::recv_deadline] & [PollConnect::connect_deadline]
+    deadlines: Deadlines,
     events: Events,
+    connections: Arc<AtomicUsize>,
+    stats: PollHandlerStatsRecorder,
+    busy_spin: bool,
+    budget: NonZeroUsize,
+    // reused between passes to avoid allocating on every poll
+    pending: Vec<PendingEvent>,
+    carried_over: Vec<PendingEvent>,
+    // only set once the handler is spawned, see [PollHandler::into_spawned_handler_with]
+    handoff: Option<Handoff<R, A>>,
 }
 impl<R: PollRead, A: PollAccept<R>> PollHandler<R, A> {
+    /// Create a new [PollHandler] with a capacity of 1024 Events on a single poll call
+    ///
+    /// # Errors
+    /// [links_core::Error::Io] if the os poll instance could not be created
+    pub fn new() -> Result<Self, links_core::Error> {
+        Self::with_events_capacity(1024)
+    }
     /// Create a new [PollHandler] with a given capacity of Events on a single poll call
-    pub fn with_events_capacity(capacity: usize) -> Self {
-        Self {
-            poll: Poll::new().expect("Failed to create Poll"),
+    ///
+    /// # Errors
+    /// [links_core::Error::Io] if the os poll instance could not be created
+    pub fn with_events_capacity(capacity: usize) -> Result<Self, links_core::Error> {
+        Ok(Self {
+            poll: Poll::new()?,
             serviceable: Slab::new(),
+            registered_interests: HashMap::new(),
+            timers: Deadlines::default(),
+            deadlines: Deadlines::default(),
             events: Events::with_capacity(capacity),
-        }
+            connections: Arc::new(AtomicUsize::new(0)),
+            stats: PollHandlerStatsRecorder::default(),
+            busy_spin: false,
+            budget: DEFAULT_POLL_HANDLER_BUDGET,
+            pending: Vec::with_capacity(capacity),
+            carried_over: vec![],
+            handoff: None,
+        })
     }
     /// Add a [TransmittingSvcAcceptor] or [TransmittingSvcAcceptorRef] to the [PollHandler] to be polled for incoming connections. All resulting connections in the form
     /// of [CltRecver] will also be serviced by this [PollHandler] instance.
-    pub fn add_acceptor(&mut self, acceptor: A) {
+    ///
+    /// # Errors
+    /// [links_core::Error::Io] if the `acceptor` could not be registered with the poll, in which case it is dropped
+    pub fn add_acceptor(&mut self, acceptor: A) -> Result<(), links_core::Error> {
         self.add_serviceable(Serviceable::Acceptor(acceptor))
     }
-    pub fn add_recver(&mut self, recver: R) {
+    /// # Errors
+    /// [links_core::Error::Io] if the `recver` could not be registered with the poll, in which case it is dropped
+    pub fn add_recver(&mut self, recver: R) -> Result<(), links_core::Error> {
         self.add_serviceable(Serviceable::Recver(recver))
     }
+    /// Add a [PollConnect] to the [PollHandler] to be polled for connection completion, once connected the resulting [PollRead] will also be serviced by this [PollHandler] instance.
+    ///
+    /// # Errors
+    /// [links_core::Error::Io] if the `connector` could not be registered with the poll, in which case it is dropped
+    pub fn add_connector(&mut self, connector: Box<dyn PollConnect<R>>) -> Result<(), links_core::Error> {
+        self.add_serviceable(Serviceable::Connector(connector))
+    }
     /// Spawns a new thread with a given name that will continuously poll for events of [TransmittingSvcAcceptor] or [TransmittingSvcAcceptorRef] and resulting [CltRecver]s instances
-    pub fn into_spawned_handler(mut self, name: &str) -> SpawnedPollHandler<R, A> {
+    ///
+    /// # Errors
+    /// Same as [PollHandler::into_spawned_handler_with] using [PollHandlerOptions::default]
+    pub fn into_spawned_handler(self, name: &str) -> Result<SpawnedPollHandler<R, A>, links_core::Error> {
+        self.into_spawned_handler_with(name, PollHandlerOptions::default())
+    }
+    /// Same as [PollHandler::into_spawned_handler] but the thread is configured using [PollHandlerOptions], fails if the thread could not be spawned or any of the options could not be applied
+    pub fn into_spawned_handler_with(mut self, name: &str, options: PollHandlerOptions) -> Result<SpawnedPollHandler<R, A>, links_core::Error> {
+        self.busy_spin = options.busy_spin;
+        self.budget = options.budget;
+        self.stats.enabled = options.stats;
+        let connections = self.connections.clone();
+        let stats = self.stats.shared.clone();
         let waker = {
             let entry = self.serviceable.vacant_entry();
             let key = entry.key();
-            let waker = Waker::new(self.poll.registry(), Token(key)).expect("Failed to create Waker");
+            let waker = Waker::new(self.poll.registry(), Token(key))?;
             entry.insert(Serviceable::Waker(None));
             if log_enabled!(Level::Debug) {
                 debug!("{}::into_spawned_handler registering waker with token: {:?}", asserted_short_name!("PollHandler", Self), Token(key));
             }
-            waker
+            Arc::new(waker)
         };
+        // the poll thread shares ownership of the waker so that its fd is not closed, discarding a pending wake up, when the [SpawnedPollHandler] is dropped right after calling shutdown
+        let thread_waker = waker.clone();
         // have to use synch_channel of just 1 so that if adding serviceable back to back the wake call on the poll is only issued after the first wake is processed
         // otherwise the poll will not wake up on back to back wake calls and serviceable will end up being stuck in the channel
         // let (tx_serviceable, rx_serviceable) = sync_channel::<Serviceable<R, A>>(1);
         let (tx_serviceable, rx_serviceable) = channel::<Serviceable<R, A>>();
         // let (tx_serviceable, rx_serviceable) = channel::<Serviceable<R, A>>();
+        self.handoff = Some(Handoff::new(name, tx_serviceable.clone(), waker.clone(), connections.clone()));
 
-        Builder::new()
-            .name(name.to_owned())
-            .spawn(move || loop {
+        let (tx_applied, rx_applied) = sync_channel::<Result<(), Error>>(1);
+        Builder::new().name(name.to_owned()).spawn(move || {
+            let _waker = thread_waker;
+            let applied = options.apply_to_current_thread();
+            let failed = applied.is_err();
+            // the caller is blocked receiving the status hence it is only gone if it was itself terminated
+            if tx_applied.send(applied).is_err() || failed {
+                return;
+            }
+            loop {
                 match self.service(&rx_serviceable) {
                     Ok(ServiceStatus::Continue) => {}
                     Ok(ServiceStatus::Terminate) => break,
                     Err(e) => {
-                        panic!("Error, service loop termination: {}", e);
+                        // connections owned by the thread are dropped on exit and subsequent adds are rejected since the receiving end of the channel is gone
+                        warn!("Dirty, service loop termination: {}", e);
+                        break;
                     }
                 }
-            })
-            .unwrap_or_else(|_| panic!("Failed to start a poll thread name: '{}'", name));
-        SpawnedPollHandler {
+            }
+            // connections still owned by the thread or waiting in the channel are dropped along with it
+            let dropped = self.serviceable.iter().filter(|(_, serviceable)| serviceable.is_connection()).count() + rx_serviceable.try_iter().filter(Serviceable::is_connection).count();
+            self.connections.fetch_sub(dropped, Ordering::Relaxed);
+        })?;
+        match rx_applied.recv() {
+            Ok(Ok(())) => {}
+            Ok(Err(e)) => return Err(Error::new(e.kind(), format!("Failed to apply PollHandlerOptions to poll thread name: '{}', error: {}", name, e)).into()),
+            Err(e) => return Err(Error::new(ErrorKind::Other, e.to_string()).into()),
+        }
+        Ok(SpawnedPollHandler {
+            name: name.to_owned(),
             tx_serviceable,
             waker,
             total_shutdown: AtomicBool::new(false),
-        }
+            connections,
+            stats,
+        })
     }
 
-    fn add_serviceable(&mut self, serviceable: Serviceable<R, A>) {
-        register_serviceable_as_readable!(self, serviceable);
+    fn add_serviceable(&mut self, serviceable: Serviceable<R, A>) -> Result<(), links_core::Error> {
+        if serviceable.is_connection() {
+            self.connections.fetch_add(1, Ordering::Relaxed);
+        }
+        let con_id = serviceable.con_id().cloned().map(Box::new);
+        register_serviceable!(self, serviceable).map_err(|source| links_core::Error::Io { con_id, source })
     }
 
     fn service(&mut self, rx_serviceable: &Receiver<Serviceable<R, A>>) -> Result<ServiceStatus, Error> {
         use PollEventStatus::*;
         use Serviceable::*;
-        self.poll.poll(&mut self.events, None)?;
+        let timeout = self.service_deadlines();
+        // in busy spin mode the thread is never parked while deadlines are still serviced on every spin
+        // serviceable carried over from the previous pass still have work pending hence the os is only checked for new events without parking the thread
+        let timeout = if self.busy_spin || !self.carried_over.is_empty() { Some(Duration::ZERO) } else { timeout };
+        self.poll.poll(&mut self.events, timeout)?;
+
+        // new events go first so that serviceable which exhausted their budget on the previous pass are continued after everyone else had a turn
+        let mut pending = std::mem::take(&mut self.pending);
+        pending.clear();
+        pending.extend(self.events.iter().map(PendingEvent::from));
+        for carried in self.carried_over.drain(..) {
+            match pending.iter_mut().find(|pending| pending.key == carried.key) {
+                Some(pending) => pending.merge(&carried),
+                None => pending.push(carried),
+            }
+        }
+        let budget = self.budget.get();
+        let count = pending.len();
 
         loop {
-            // keep going until all serviceable for the given poll events can't yield anymore
+            // keep going until all serviceable for the given poll events can't yield anymore or have exhausted their budget
             let mut had_yield = false;
             let mut iteration = 0;
-            for event in &self.events {
+            for pending in pending.iter_mut() {
                 iteration += 1;
-                let key = event.token().into();
+                let key = pending.key;
                 let opt = self.serviceable.get_mut(key);
                 // below if else ==> None  // possible when the serviceable is removed during error or terminate request but other serviceable still yielding
                 if let Some(serviceable) = opt {
+                    // waker is exempt from the budget so that shutdown requests and new serviceable are always processed promptly
+                    if pending.serviced >= budget && !matches!(serviceable, Waker(_)) {
+                        continue;
+                    }
                     if log_enabled!(Level::Debug) {
-                        debug!("Iteration #{}, Servicing {} with token: {:?} 1 of #{}", iteration, serviceable, Token(key), self.events.iter().count());
+                        debug!("Iteration #{}, Servicing {} with token: {:?} 1 of #{}", iteration, serviceable, Token(key), count);
                     }
 
                     match serviceable {
                         // FROM self.serviceable.get_mut(key)
-                        Recver(recver) => match recver.on_readable_event() {
+                        Recver(recver) => match self.stats.service(key, || on_recver_event(recver, pending), |res| matches!(res, Ok(Completed))) {
                             Ok(Completed) => {
-                                had_yield = true;
+                                if reregister_recver_if_interests_changed!(self, recver, key) {
+                                    had_yield = true;
+                                    pending.serviced += 1;
+                                }
+                                continue;
+                            }
+                            Ok(WouldBlock) => {
+                                reregister_recver_if_interests_changed!(self, recver, key);
                                 continue;
                             }
-                            Ok(WouldBlock) => continue,
                             Ok(Terminate) => {
                                 if log_enabled!(Level::Info) {
                                     info!("Clean, service loop termination recver: {}", recver);
                                 }
-                                // USING recver.deregister method instead of recver.source to enable overriding of deregister method when locking is required
-                                // self.poll.registry().deregister(*recver.source())?;
-                                recver.deregister(self.poll.registry())?;
-                                self.serviceable.remove(key);
+                                self.drop_serviceable(key);
                             }
                             Err(e) => {
                                 if log_enabled!(Level::Warn) {
                                     warn!("Dirty, service loop termination recver: {}, error: {}", recver, e);
                                 }
-                                // USING recver.deregister method instead of recver.source to enable overriding of deregister method when locking is required
-                                // self.poll.registry().deregister(*recver.source())?;
-                                recver.deregister(self.poll.registry())?;
-                                self.serviceable.remove(key);
+                                self.drop_serviceable(key);
                             }
                         },
                         // FROM self.serviceable.get_mut(key)
-                        Acceptor(acceptor) => match acceptor.poll_accept() {
-                            Ok(AcceptStatus::Accepted(recver)) => {
-                                let token = Token(self.serviceable.insert(Recver(recver)));
-                                if let Recver(ref mut recver) = self.serviceable[token.into()] {
-                                    register_recver_as_readable!(self, recver, token);
+                        Acceptor(acceptor) => match self.stats.service(key, || acceptor.poll_accept_connect(), |res| matches!(res, Ok(AcceptStatus::Accepted(_)))) {
+                            Ok(AcceptStatus::Accepted(PollAccepted::Recver(recver))) => {
+                                self.connections.fetch_add(1, Ordering::Relaxed);
+                                let serviceable = Recver(recver);
+                                if let Err(e) = register_serviceable!(self, serviceable) {
+                                    warn!("Dirty, dropping accepted recver, error: {}", e);
                                 }
                                 had_yield = true;
+                                pending.serviced += 1;
+                            }
+                            // completed by the connector branch, which hands it off, once its source is reported writable
+                            Ok(AcceptStatus::Accepted(PollAccepted::Connector(connector))) => {
+                                self.connections.fetch_add(1, Ordering::Relaxed);
+                                let serviceable = Connector(connector);
+                                if let Err(e) = register_serviceable!(self, serviceable) {
+                                    warn!("Dirty, dropping accepted connector, error: {}", e);
+                                }
+                                had_yield = true;
+                                pending.serviced += 1;
                             }
                             Ok(AcceptStatus::Rejected) => {
                                 had_yield = true;
+                                pending.serviced += 1;
                             }
                             Ok(AcceptStatus::WouldBlock) => continue,
                             Err(e) => {
                                 if log_enabled!(Level::Warn) {
                                     warn!("Error, service loop termination acceptor: {}, error: {}", acceptor, e);
                                 }
-                                // USING acceptor.deregister method instead of acceptor.source to enable overriding of deregister method when locking is required
-                                // self.poll.registry().deregister(*acceptor.source())?;
-                                acceptor.deregister(self.poll.registry())?;
-                                self.serviceable.remove(key);
+                                self.drop_serviceable(key);
+                            }
+                        },
+                        // FROM self.serviceable.get_mut(key)
+                        Connector(connector) => match self.stats.service(key, || connector.on_writable_event(), |res| matches!(res, Ok(Completed))) {
+                            Ok(Completed) => {
+                                if let Err(e) = connector.deregister(self.poll.registry()) {
+                                    if log_enabled!(Level::Warn) {
+                                        warn!("Dirty, service loop termination connector: {}, failed to deregister, error: {}", connector, e);
+                                    }
+                                    self.drop_serviceable(key);
+                                    continue;
+                                }
+                                self.stats.remove(key);
+                                self.deadlines.remove(key);
+                                if let Connector(connector) = self.serviceable.remove(key) {
+                                    // completing the connection may block hence it is handed off instead of stalling every other serviceable on this thread
+                                    if let Some(ref mut handoff) = self.handoff {
+                                        handoff.queue(connector);
+                                        had_yield = true;
+                                        pending.serviced += 1;
+                                        continue;
+                                    }
+                                    let con_id = connector.con_id().clone();
+                                    match connector.into_recver() {
+                                        Ok(recver) => {
+                                            if log_enabled!(Level::Info) {
+                                                info!("Connected, service loop recver: {}", recver);
+                                            }
+                                            let serviceable = Recver(recver);
+                                            if let Err(e) = register_serviceable!(self, serviceable) {
+                                                warn!("Dirty, dropping connected recver, error: {}", e);
+                                            }
+                                            had_yield = true;
+                                            pending.serviced += 1;
+                                        }
+                                        Err(e) => {
+                                            if log_enabled!(Level::Warn) {
+                                                warn!("Dirty, service loop termination connector: {}, error: {}", con_id, e);
+                                            }
+                                            self.connections.fetch_sub(1, Ordering::Relaxed);
+                                        }
+                                    }
+                                }
+                            }
+                            Ok(WouldBlock) => continue,
+                            Ok(Terminate) => {
+                                if log_enabled!(Level::Info) {
+                                    info!("Clean, service loop termination connector: {}", connector);
+                                }
+                                self.drop_serviceable(key);
+                            }
+                            Err(e) => {
+                                if log_enabled!(Level::Warn) {
+                                    warn!("Dirty, service loop termination connector: {}, error: {}", connector, e);
+                                }
+                                self.drop_serviceable(key);
                             }
                         },
                         // FROM self.serviceable.get_mut(key)
@@ -285,10 +1028,16 @@ impl<R: PollRead, A: PollAccept<R>> PollHandler<R, A> {
                                             );
                                         }
                                         deregister_and_drop_some_serviceable!(self, Some(con_id.clone()));
+                                        // waker notifications coalesce hence check if there are more serviceable messages queued behind this one
+                                        had_yield = true;
+                                        pending.serviced += 1;
                                     }
-                                    Acceptor(_) | Recver(_) => {
-                                        register_serviceable_as_readable!(self, serviceable);
+                                    Acceptor(_) | Recver(_) | Connector(_) => {
+                                        if let Err(e) = register_serviceable!(self, serviceable) {
+                                            warn!("Dirty, dropping added serviceable, error: {}", e);
+                                        }
                                         had_yield = true;
+                                        pending.serviced += 1;
                                     }
                                 }
                             }
@@ -307,44 +1056,305 @@ impl<R: PollRead, A: PollAccept<R>> PollHandler<R, A> {
                         // self.serviceable.get_mut(key) can never yield Wake(Some(_)) because only Waker(None) is added to the self.serviceable and only on [PollHandler::into_spawned_handler]
                         // Waker(Some(_)) however can be sent via rx_serviceable which is why when Waker(None) branch must check for both Waker(None) and Waker(Some(_)) variants
                         Waker(Some(_)) => {
-                            panic!("Invalid API usage. Waker can only be registered once and as Waker(None)")
+                            warn!("Invalid API usage. Waker can only be registered once and as Waker(None), dropping {} with token: {:?}", serviceable, Token(key));
+                            self.drop_serviceable(key);
                         }
                     }
                 }
             }
-            // only return once every event in the for loop yields WouldBlock
+            // only return once every event in the for loop yields WouldBlock or has exhausted its budget, the later are carried over to the next pass
             if !had_yield {
+                self.carried_over.extend(pending.iter().filter(|pending| pending.serviced >= budget).map(|pending| PendingEvent { serviced: 0, ..*pending }));
+                self.pending = pending;
                 return Ok(ServiceStatus::Continue);
             }
         }
     }
-}
-impl<R: PollRead, A: PollAccept<R>> Default for PollHandler<R, A> {
-    fn default() -> Self {
-        Self::with_events_capacity(1024)
-    }
-}
 
-impl PollAccept<Box<dyn PollRead>> for Box<dyn PollAccept<Box<dyn PollRead>>> {
-    fn poll_accept(&mut self) -> Result<AcceptStatus<Box<dyn PollRead>>, Error> {
-        self.as_mut().poll_accept()
+    /// Calls [PollRead::on_recv_deadline] on every [PollRead] whose [PollRead::recv_deadline] has passed, drops every [PollConnect] whose [PollConnect::connect_deadline] has passed,
+    /// services due timers and returns the time remaining until the nearest deadline, which is used as the poll timeout
+    ///
+    /// # Note
+    /// Only the serviceable whose tracked deadline is due are visited, a [PollRead::recv_deadline] that moved forward since it was tracked is simply rescheduled
+    fn service_deadlines(&mut self) -> Option<Duration> {
+        use PollEventStatus::*;
+        use Serviceable::*;
+        let now = Instant::now();
+        let next_timer = self.service_timers(now);
+        let mut expired = vec![];
+        // rescheduled deadlines are only pushed back once all due deadlines are serviced so that a deadline which has not moved is not retried in the same pass
+        let mut rescheduled = vec![];
+        while let Some(key) = self.deadlines.pop_due(now) {
+            match self.serviceable.get_mut(key) {
+                Some(Recver(recver)) => match recver.recv_deadline() {
+                    // a message was received since the deadline was tracked
+                    Some(deadline) if deadline > now => rescheduled.push((key, deadline)),
+                    Some(_) => match recver.on_recv_deadline() {
+                        Ok(Terminate) => {
+                            if log_enabled!(Level::Info) {
+                                info!("Clean, recv deadline termination recver: {}", recver);
+                            }
+                            expired.push(key);
+                        }
+                        Ok(Completed) | Ok(WouldBlock) => {
+                            // deadline could have moved if the recver was serviced elsewhere, hence must be rechecked on the next iteration
+                            if let Some(deadline) = recver.recv_deadline() {
+                                rescheduled.push((key, deadline));
+                            }
+                        }
+                        Err(e) => {
+                            if log_enabled!(Level::Warn) {
+                                warn!("Dirty, recv deadline termination recver: {}, error: {}", recver, e);
+                            }
+                            expired.push(key);
+                        }
+                    },
+                    None => {}
+                },
+                Some(Connector(connector)) => {
+                    if log_enabled!(Level::Warn) {
+                        warn!("Dirty, connect deadline termination connector: {}", connector);
+                    }
+                    expired.push(key);
+                }
+                Some(Acceptor(_)) | Some(Waker(_)) | None => {}
+            }
+        }
+        for (key, deadline) in rescheduled {
+            self.deadlines.schedule(key, deadline);
+        }
+        for key in expired {
+            self.drop_serviceable(key);
+        }
+        let next_deadline = match (next_timer, self.deadlines.next_deadline()) {
+            (Some(timer), Some(deadline)) => Some(timer.min(deadline)),
+            (timer, deadline) => timer.or(deadline),
+        };
+        next_deadline.map(|deadline| deadline.saturating_duration_since(now))
     }
-}
-impl PollAble for Box<dyn PollAccept<Box<dyn PollRead>>> {
-    fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
-        self.as_mut().source()
+    /// Calls [PollRead::on_timer] on every [PollRead] whose timer is due, reschedules it according to the resulting [TimerTaskStatus] and returns the nearest timer deadline
+    fn service_timers(&mut self, now: Instant) -> Option<Instant> {
+        use Serviceable::*;
+        // rescheduled timers are only pushed back once all due timers are serviced so that `RetryAfter(Duration::ZERO)` is not retried in the same pass
+        let mut rescheduled = vec![];
+        while let Some(key) = self.timers.pop_due(now) {
+            let Some(Recver(recver)) = self.serviceable.get_mut(key) else {
+                continue;
+            };
+            let next = match recver.on_timer() {
+                Ok(TimerTaskStatus::Completed) => recver.timer_interval().map(|interval| now + interval),
+                Ok(TimerTaskStatus::RetryAfter(delay)) => Some(now + delay),
+                Ok(TimerTaskStatus::Terminate) => None,
+                Err(e) => {
+                    if log_enabled!(Level::Warn) {
+                        warn!("Dirty, timer termination recver: {}, error: {}", recver, e);
+                    }
+                    self.drop_serviceable(key);
+                    continue;
+                }
+            };
+            if let Some(next) = next {
+                rescheduled.push((key, next));
+            }
+        }
+        for (key, next) in rescheduled {
+            self.timers.schedule(key, next);
+        }
+        self.timers.next_deadline()
     }
-}
-impl ConnectionId for Box<dyn PollAccept<Box<dyn PollRead>>> {
-    fn con_id(&self) -> &ConId {
-        self.as_ref().con_id()
+    /// Deregisters and drops the serviceable along with its interests, timer, deadline and stats. Failure to deregister is only logged because the serviceable
+    /// is dropped regardless and must not terminate the thread servicing the rest of them
+    fn drop_serviceable(&mut self, key: usize) {
+        use Serviceable::*;
+        self.registered_interests.remove(&key);
+        self.timers.remove(key);
+        self.deadlines.remove(key);
+        self.stats.remove(key);
+        // USING deregister method instead of source to enable overriding of deregister method when locking is required
+        let (serviceable, res) = match self.serviceable.try_remove(key) {
+            Some(Recver(mut recver)) => {
+                let res = recver.deregister(self.poll.registry());
+                (Recver(recver), res)
+            }
+            Some(Acceptor(mut acceptor)) => {
+                let res = acceptor.deregister(self.poll.registry());
+                (Acceptor(acceptor), res)
+            }
+            Some(Connector(mut connector)) => {
+                let res = connector.deregister(self.poll.registry());
+                (Connector(connector), res)
+            }
+            Some(waker @ Waker(_)) => (waker, Ok(())),
+            None => return,
+        };
+        if serviceable.is_connection() {
+            self.connections.fetch_sub(1, Ordering::Relaxed);
+        }
+        if let Err(e) = res {
+            if log_enabled!(Level::Warn) {
+                warn!("Dirty, failed to deregister {}, error: {}", serviceable, e);
+            }
+        }
     }
 }
 
-impl PollRead for Box<dyn PollRead> {
-    fn on_readable_event(&mut self) -> Result<PollEventStatus, Error> {
-        self.as_mut().on_readable_event()
-    }
+/// A min heap of deadlines keyed by serviceable, an entry is only valid while it matches the deadline tracked for the same key, the rest are skipped when popped
+#[derive(Debug, Default)]
+struct Deadlines {
+    heap: BinaryHeap<Reverse<(Instant, usize)>>,
+    tracked: HashMap<usize, Instant>,
+}
+impl Deadlines {
+    /// Replaces the deadline tracked for the `key`, if any
+    fn schedule(&mut self, key: usize, deadline: Instant) {
+        self.tracked.insert(key, deadline);
+        self.heap.push(Reverse((deadline, key)));
+    }
+    fn remove(&mut self, key: usize) {
+        self.tracked.remove(&key);
+    }
+    fn retain(&mut self, f: impl Fn(usize) -> bool) {
+        self.tracked.retain(|key, _| f(*key));
+    }
+    /// Yields the `key` of the earliest deadline that is due by `now` and stops tracking it
+    fn pop_due(&mut self, now: Instant) -> Option<usize> {
+        while let Some(Reverse((deadline, key))) = self.heap.peek().copied() {
+            if deadline > now {
+                return None;
+            }
+            self.heap.pop();
+            if self.tracked.get(&key) == Some(&deadline) {
+                self.tracked.remove(&key);
+                return Some(key);
+            }
+        }
+        None
+    }
+    /// Earliest tracked deadline, stale entries ahead of it are discarded
+    fn next_deadline(&mut self) -> Option<Instant> {
+        while let Some(Reverse((deadline, key))) = self.heap.peek().copied() {
+            if self.tracked.get(&key) == Some(&deadline) {
+                return Some(deadline);
+            }
+            self.heap.pop();
+        }
+        None
+    }
+}
+#[cfg(not(target_family = "unix"))]
+mod sys {
+    use std::io::{Error, ErrorKind};
+    pub(super) fn pin_current_thread(_core_id: usize) -> Result<(), Error> {
+        Err(Error::new(ErrorKind::Unsupported, "PollHandlerOptions::core_id is only supported on linux"))
+    }
+    pub(super) fn set_current_thread_realtime_priority(_priority: u8) -> Result<(), Error> {
+        Err(Error::new(ErrorKind::Unsupported, "PollHandlerOptions::realtime_priority is only supported on unix"))
+    }
+}
+#[cfg(target_family = "unix")]
+mod sys {
+    use std::io::{Error, ErrorKind};
+
+    #[cfg(target_os = "linux")]
+    pub(super) fn pin_current_thread(core_id: usize) -> Result<(), Error> {
+        if core_id >= libc::CPU_SETSIZE as usize {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("core_id: {} exceeds CPU_SETSIZE: {}", core_id, libc::CPU_SETSIZE)));
+        }
+        let res = unsafe {
+            let mut set = std::mem::zeroed::<libc::cpu_set_t>();
+            libc::CPU_SET(core_id, &mut set);
+            libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &set)
+        };
+        if res == -1 {
+            Err(Error::last_os_error())
+        } else {
+            Ok(())
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    pub(super) fn pin_current_thread(_core_id: usize) -> Result<(), Error> {
+        Err(Error::new(ErrorKind::Unsupported, "PollHandlerOptions::core_id is only supported on linux"))
+    }
+    pub(super) fn set_current_thread_realtime_priority(priority: u8) -> Result<(), Error> {
+        let res = unsafe {
+            let mut param = std::mem::zeroed::<libc::sched_param>();
+            param.sched_priority = priority as libc::c_int;
+            libc::pthread_setschedparam(libc::pthread_self(), libc::SCHED_FIFO, &param)
+        };
+        // pthread functions return the error number instead of setting errno
+        if res != 0 {
+            Err(Error::from_raw_os_error(res))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Dispatches readable and writable events to the [PollRead] and its [PollRead::as_poll_write] counterpart respectively, yields [PollEventStatus::Terminate]
+/// if either one terminates or [PollEventStatus::Completed] if either one completes
+fn on_recver_event<R: PollRead>(recver: &mut R, event: &PendingEvent) -> Result<PollEventStatus, Error> {
+    use PollEventStatus::*;
+    let mut status = WouldBlock;
+    if event.readable {
+        match recver.on_readable_event()? {
+            Completed => status = Completed,
+            WouldBlock => {}
+            Terminate => return Ok(Terminate),
+        }
+    }
+    if event.writable {
+        if let Some(writer) = recver.as_poll_write() {
+            match writer.on_writable_event()? {
+                Completed => status = Completed,
+                WouldBlock => {}
+                Terminate => return Ok(Terminate),
+            }
+        }
+    }
+    Ok(status)
+}
+
+impl PollAccept<Box<dyn PollRead>> for Box<dyn PollAccept<Box<dyn PollRead>>> {
+    fn poll_accept(&mut self) -> Result<AcceptStatus<Box<dyn PollRead>>, Error> {
+        self.as_mut().poll_accept()
+    }
+    fn poll_accept_connect(&mut self) -> Result<AcceptStatus<PollAccepted<Box<dyn PollRead>>>, Error> {
+        self.as_mut().poll_accept_connect()
+    }
+}
+impl PollAble for Box<dyn PollAccept<Box<dyn PollRead>>> {
+    fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
+        self.as_mut().source()
+    }
+}
+impl ConnectionId for Box<dyn PollAccept<Box<dyn PollRead>>> {
+    fn con_id(&self) -> &ConId {
+        self.as_ref().con_id()
+    }
+}
+
+impl PollRead for Box<dyn PollRead> {
+    fn on_readable_event(&mut self) -> Result<PollEventStatus, Error> {
+        self.as_mut().on_readable_event()
+    }
+    fn recv_deadline(&self) -> Option<Instant> {
+        self.as_ref().recv_deadline()
+    }
+    fn on_recv_deadline(&mut self) -> Result<PollEventStatus, Error> {
+        self.as_mut().on_recv_deadline()
+    }
+    fn interests(&self) -> Interest {
+        self.as_ref().interests()
+    }
+    fn as_poll_write(&mut self) -> Option<&mut dyn PollWrite> {
+        self.as_mut().as_poll_write()
+    }
+    fn timer_interval(&self) -> Option<Duration> {
+        self.as_ref().timer_interval()
+    }
+    fn on_timer(&mut self) -> Result<TimerTaskStatus, Error> {
+        self.as_mut().on_timer()
+    }
 }
 impl PollAble for Box<dyn PollRead> {
     fn register(&mut self, registry: &mio::Registry, token: Token, interests: mio::Interest) -> Result<(), Error> {
@@ -353,6 +1363,9 @@ impl PollAble for Box<dyn PollRead> {
     fn deregister(&mut self, registry: &mio::Registry) -> Result<(), Error> {
         self.as_mut().deregister(registry)
     }
+    fn reregister(&mut self, registry: &mio::Registry, token: Token, interests: mio::Interest) -> Result<(), Error> {
+        self.as_mut().reregister(registry, token, interests)
+    }
     fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
         self.as_mut().source()
     }
@@ -362,13 +1375,24 @@ impl ConnectionId for Box<dyn PollRead> {
         self.as_ref().con_id()
     }
 }
-impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize> From<CltRecver<P, C, MAX_MSG_SIZE>> for Box<dyn PollRead> {
-    fn from(value: CltRecver<P, C, MAX_MSG_SIZE>) -> Self {
+impl<P: Protocol, C: CallbackRecv<P>, const MAX_MSG_SIZE: usize, T: Transport> From<CltRecver<P, C, MAX_MSG_SIZE, T>> for Box<dyn PollRead> {
+    fn from(value: CltRecver<P, C, MAX_MSG_SIZE, T>) -> Self {
+        Box::new(value)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> From<CltRecverRef<P, C, MAX_MSG_SIZE, T>> for Box<dyn PollRead> {
+    fn from(value: CltRecverRef<P, C, MAX_MSG_SIZE, T>) -> Self {
         Box::new(value)
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> From<CltRecverRef<P, C, MAX_MSG_SIZE>> for Box<dyn PollRead> {
-    fn from(value: CltRecverRef<P, C, MAX_MSG_SIZE>) -> Self {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> From<Clt<P, C, MAX_MSG_SIZE, T>> for Box<dyn PollRead> {
+    fn from(value: Clt<P, C, MAX_MSG_SIZE, T>) -> Self {
+        Box::new(value)
+    }
+}
+
+impl<M: Messenger, C: CallbackRecv<M>, const MAX_DATAGRAM_SIZE: usize> From<UdpRecver<M, C, MAX_DATAGRAM_SIZE>> for Box<dyn PollRead> {
+    fn from(value: UdpRecver<M, C, MAX_DATAGRAM_SIZE>) -> Self {
         Box::new(value)
     }
 }
@@ -376,74 +1400,295 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> From<CltRec
 /// A helper struct to add [PollAccept] and [PollRead] instances to a [PollHandler] from a different thread
 /// to create an instance of this struct use [PollHandler::into_spawned_handler]
 pub struct SpawnedPollHandler<R: PollRead, A: PollAccept<R>> {
+    name: String,
     tx_serviceable: Sender<Serviceable<R, A>>,
-    waker: Waker,
+    waker: Arc<Waker>,
     total_shutdown: AtomicBool,
+    connections: Arc<AtomicUsize>,
+    stats: Arc<PollHandlerStats>,
 }
 impl<R: PollRead, A: PollAccept<R>> SpawnedPollHandler<R, A> {
-    pub fn add_acceptor(&self, acceptor: A) {
-        self.total_shutdown_check();
+    /// # Errors
+    /// [links_core::Error::Rejected] if [SpawnedPollHandler::shutdown] with `None` has been issued or the [PollHandler] thread has terminated, in which case the `acceptor` is dropped
+    pub fn add_acceptor(&self, acceptor: A) -> Result<(), links_core::Error> {
+        let con_id = acceptor.con_id().clone();
+        self.total_shutdown_check(&con_id)?;
         if log_enabled!(Level::Debug) {
             debug!("{}::add_acceptor sending acceptor: {} to PollHandler and called waker", asserted_short_name!("SpawnedPollHandler", Self), acceptor);
         }
-        self.tx_serviceable.send(Serviceable::Acceptor(acceptor)).expect("Failed to send acceptor to PollHandler");
-        self.waker.wake().expect("Failed to wake PollHandler after sending acceptor");
+        self.send(&con_id, Serviceable::Acceptor(acceptor))
     }
-    pub fn add_recver(&self, recver: R) {
-        self.total_shutdown_check();
+    /// # Errors
+    /// Same as [SpawnedPollHandler::add_acceptor]
+    pub fn add_connector(&self, connector: Box<dyn PollConnect<R>>) -> Result<(), links_core::Error> {
+        let con_id = connector.con_id().clone();
+        self.total_shutdown_check(&con_id)?;
+        if log_enabled!(Level::Debug) {
+            debug!("{}::add_connector sending connector: {} to PollHandler and called waker", asserted_short_name!("SpawnedPollHandler", Self), connector);
+        }
+        self.connections.fetch_add(1, Ordering::Relaxed);
+        self.send(&con_id, Serviceable::Connector(connector)).map_err(|e| {
+            self.connections.fetch_sub(1, Ordering::Relaxed);
+            e
+        })
+    }
+    /// # Errors
+    /// Same as [SpawnedPollHandler::add_acceptor]
+    pub fn add_recver(&self, recver: R) -> Result<(), links_core::Error> {
+        let con_id = recver.con_id().clone();
+        self.total_shutdown_check(&con_id)?;
         if log_enabled!(Level::Debug) {
             debug!("{}::add_recver sending recver: {} to PollHandler and called waker", asserted_short_name!("SpawnedPollHandler", Self), recver);
         }
-        self.tx_serviceable.send(Serviceable::Recver(recver)).expect("Failed to send recver to PollHandler");
-        self.waker.wake().expect("Failed to wake PollHandler after sending recver");
+        self.connections.fetch_add(1, Ordering::Relaxed);
+        self.send(&con_id, Serviceable::Recver(recver)).map_err(|e| {
+            self.connections.fetch_sub(1, Ordering::Relaxed);
+            e
+        })
     }
-    /// Will deregister and drop serviceable [PollRead] & [PollAccept] associated with the [PollHandler] instance 
-    /// 
+    /// Will deregister and drop serviceable [PollRead] & [PollAccept] associated with the [PollHandler] instance
+    ///
     /// # Arguments
     /// * `con_id` - If None will deregister and drop `all`, otherwise `only` those that share lineage with the given `con_id`
-    pub fn shutdown(&self, con_id: Option<ConId>) {
+    ///
+    /// # Errors
+    /// [links_core::Error::Io] if the [PollHandler] thread could not be woken up to process the request
+    pub fn shutdown(&self, con_id: Option<ConId>) -> Result<(), links_core::Error> {
         if self.total_shutdown.load(Ordering::Acquire) {
-            return;
+            return Ok(());
         } else if con_id.is_none() {
             self.total_shutdown.store(true, Ordering::Release);
         }
-        self.tx_serviceable.send(Serviceable::Waker(con_id.clone())).expect("Failed to send waker/terminate to PollHandler");
-        self.waker.wake().expect("Failed to wake PollHandler after sending waker/terminate");
+        if self.tx_serviceable.send(Serviceable::Waker(con_id.clone())).is_err() {
+            if log_enabled!(Level::Debug) {
+                debug!("{}::shutdown {} thread is terminated, ignoring Waker({con_id:?})", asserted_short_name!("SpawnedPollHandler", Self), self.name);
+            }
+            return Ok(());
+        }
+        self.waker.wake().map_err(|source| links_core::Error::Io { con_id: con_id.clone().map(Box::new), source })?;
         if log_enabled!(Level::Debug) {
             debug!("{}::shutdown sent Waker({con_id:?}) to PollHandler and called waker", asserted_short_name!("SpawnedPollHandler", Self));
         }
+        Ok(())
+    }
+    /// Returns a cloneable [PollHandlerShutdownHandle] which can request [SpawnedPollHandler::shutdown] of a given `con_id` lineage without borrowing this instance
+    pub fn shutdown_handle(&self) -> PollHandlerShutdownHandle<R, A> {
+        PollHandlerShutdownHandle {
+            name: self.name.clone(),
+            tx_serviceable: self.tx_serviceable.clone(),
+            waker: self.waker.clone(),
+        }
+    }
+    /// Approximate number of [PollRead] instances serviced by the [PollHandler] thread, including those which were added but not yet registered and [PollConnect] instances
+    /// which are still connecting
+    pub fn connection_count(&self) -> usize {
+        self.connections.load(Ordering::Relaxed)
+    }
+    /// Statistics of every [PollRead], [PollAccept] and [PollConnect] currently registered with the [PollHandler] thread, ordered by registration token.
+    /// It does not require cooperation of the [PollHandler] thread hence can be taken while the thread is stuck servicing one of them, see [ServiceableStats::busy_for].
+    /// Counters remain zero unless the thread was spawned with [PollHandlerOptions::stats] enabled
+    pub fn snapshot(&self) -> Vec<ServiceableStats> {
+        self.stats.snapshot()
+    }
+    /// Periodically checks, using the given [Timer] thread, for example [static@crate::connect::DEFAULT_WATCHDOG_HANDLER], whether a single serviceable holds
+    /// the [PollHandler] thread for longer than [PollHandlerWatchdog] threshold, the watchdog stops once this [SpawnedPollHandler] and its thread are gone.
+    /// It only reports if the thread was spawned with [PollHandlerOptions::stats] enabled
+    pub fn start_watchdog(&self, timer: &Timer, watchdog: PollHandlerWatchdog) {
+        let stats = Arc::downgrade(&self.stats);
+        let name = self.name.clone();
+        let mut reported = None::<(usize, Instant)>;
+        timer.schedule(format!("{}-Watchdog", self.name).as_str(), watchdog.check_interval, move || {
+            let Some(stats) = stats.upgrade() else {
+                return TimerTaskStatus::Terminate;
+            };
+            if let Some((key, since, serviceable)) = stats.busy() {
+                // report each offending call only once no matter how many checks it spans
+                if since.elapsed() >= watchdog.threshold && reported != Some((key, since)) {
+                    reported = Some((key, since));
+                    match watchdog.callback {
+                        Some(ref callback) => callback(&serviceable),
+                        None => warn!("{} watchdog threshold: {:?} exceeded by {}", name, watchdog.threshold, serviceable),
+                    }
+                }
+            }
+            TimerTaskStatus::Completed
+        });
     }
-    pub fn wake(&self) {
-        self.total_shutdown_check();
-        self.waker.wake().expect("Failed to wake PollHandler");
+    /// # Errors
+    /// [ErrorKind::NotConnected] if [SpawnedPollHandler::shutdown] with `None` has been issued
+    pub fn wake(&self) -> Result<(), links_core::Error> {
+        if self.total_shutdown.load(Ordering::Relaxed) {
+            let msg = format!("{} {} shutdown(None) has been issued", asserted_short_name!("SpawnedPollHandler", Self), self.name);
+            return Err(Error::new(ErrorKind::NotConnected, msg).into());
+        }
+        self.waker.wake()?;
         if log_enabled!(Level::Debug) {
             debug!("{}::wake to PollHandler", asserted_short_name!("SpawnedPollHandler", Self));
         }
+        Ok(())
     }
-    #[track_caller]
-    fn total_shutdown_check(&self) {
+    fn total_shutdown_check(&self, con_id: &ConId) -> Result<(), links_core::Error> {
         if self.total_shutdown.load(Ordering::Relaxed) {
-            panic!(
-                "Invalid API usage. Trying to use {} after {}::shutdown(None) has been issued.",
-                asserted_short_name!("SpawnedPollHandler", Self),
-                asserted_short_name!("SpawnedPollHandler", Self)
-            );
+            return Err(links_core::Error::Rejected {
+                con_id: Box::new(con_id.clone()),
+                reason: format!("{} {} shutdown(None) has been issued", asserted_short_name!("SpawnedPollHandler", Self), self.name),
+            });
         }
+        Ok(())
+    }
+    /// Drops the `serviceable` if the [PollHandler] thread has terminated
+    fn send(&self, con_id: &ConId, serviceable: Serviceable<R, A>) -> Result<(), links_core::Error> {
+        if self.tx_serviceable.send(serviceable).is_err() {
+            return Err(links_core::Error::Rejected {
+                con_id: Box::new(con_id.clone()),
+                reason: format!("{} {} thread is terminated", asserted_short_name!("SpawnedPollHandler", Self), self.name),
+            });
+        }
+        self.waker.wake().map_err(|source| links_core::Error::Io {
+            con_id: Some(Box::new(con_id.clone())),
+            source,
+        })
     }
 }
 impl<R: PollRead, A: PollAccept<R>> Drop for SpawnedPollHandler<R, A> {
     fn drop(&mut self) {
-        self.shutdown(None);
+        if let Err(e) = self.shutdown(None) {
+            warn!("{}::drop {} failed to shutdown, error: {}", asserted_short_name!("SpawnedPollHandler", Self), self.name, e);
+        }
+    }
+}
+/// A cloneable handle issued by [SpawnedPollHandler::shutdown_handle], it allows a connection to deregister and drop its lineage from the [PollHandler] thread it
+/// is serviced by, requests issued after the thread has terminated are ignored
+pub struct PollHandlerShutdownHandle<R: PollRead, A: PollAccept<R>> {
+    name: String,
+    tx_serviceable: Sender<Serviceable<R, A>>,
+    waker: Arc<Waker>,
+}
+impl<R: PollRead, A: PollAccept<R>> PollHandlerShutdownHandle<R, A> {
+    /// Same as [SpawnedPollHandler::shutdown] with `Some(con_id)`
+    pub fn shutdown(&self, con_id: ConId) -> Result<(), links_core::Error> {
+        if self.tx_serviceable.send(Serviceable::Waker(Some(con_id.clone()))).is_err() {
+            if log_enabled!(Level::Debug) {
+                debug!("{}::shutdown {} thread is terminated, ignoring Waker({})", asserted_short_name!("PollHandlerShutdownHandle", Self), self.name, con_id);
+            }
+            return Ok(());
+        }
+        self.waker.wake().map_err(|source| links_core::Error::Io { con_id: Some(Box::new(con_id)), source })
     }
 }
+impl<R: PollRead, A: PollAccept<R>> Clone for PollHandlerShutdownHandle<R, A> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            tx_serviceable: self.tx_serviceable.clone(),
+            waker: self.waker.clone(),
+        }
+    }
+}
+impl<R: PollRead, A: PollAccept<R>> Debug for PollHandlerShutdownHandle<R, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PollHandlerShutdownHandle").field("name", &self.name).finish()
+    }
+}
+
 /// A [PollHandler] that can handle any [PollAccept] and [PollRead] instances using dynamic dispatch at the cost of performance
 pub type PollHandlerDynamic = PollHandler<Box<dyn PollRead>, Box<dyn PollAccept<Box<dyn PollRead>>>>;
 pub type SpawnedPollHandlerDynamic = SpawnedPollHandler<Box<dyn PollRead>, Box<dyn PollAccept<Box<dyn PollRead>>>>;
+pub type PollHandlerShutdownHandleDynamic = PollHandlerShutdownHandle<Box<dyn PollRead>, Box<dyn PollAccept<Box<dyn PollRead>>>>;
 
 /// A [PollHandler] that will only handle [PollAccept] and [PollRead] of same type
 pub type PollHandlerStatic<P, C, const MAX_MSG_SIZE: usize> = PollHandler<CltRecver<P, C, MAX_MSG_SIZE>, TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE>>;
 pub type SpawnedPollHandlerStatic<M, C, const MAX_MSG_SIZE: usize> = SpawnedPollHandler<CltRecver<M, C, MAX_MSG_SIZE>, TransmittingSvcAcceptor<M, C, MAX_MSG_SIZE>>;
 
+/// Policy used by [PollHandlerGroup] to pick the [SpawnedPollHandler] which will service a newly added [PollRead], [PollAccept] or [PollConnect]
+#[derive(Debug, Clone, Copy, Default)]
+pub enum PlacementPolicy {
+    /// Cycles through the handlers in order
+    #[default]
+    RoundRobin,
+    /// Picks the handler with the lowest [SpawnedPollHandler::connection_count]
+    LeastConnections,
+    /// Picks the handler at index `hash(con_id) % handler count`, so that connections with the same hash always share a thread
+    Hash(fn(&ConId) -> u64),
+}
+
+/// A group of [SpawnedPollHandler] threads which places each added [PollRead], [PollAccept] or [PollConnect] on one of the threads
+/// according to a [PlacementPolicy], so that one busy connection only delays those which share its thread.
+///
+/// Use [TransmittingSvcAcceptor::with_poll_handler_group] or [TransmittingSvcAcceptorRef::with_poll_handler_group] to have connections accepted
+/// on one thread placed across the entire [PollHandlerGroupDynamic]
+pub struct PollHandlerGroup<R: PollRead, A: PollAccept<R>> {
+    handlers: Vec<SpawnedPollHandler<R, A>>,
+    policy: PlacementPolicy,
+    next: AtomicUsize,
+}
+impl<R: PollRead, A: PollAccept<R>> PollHandlerGroup<R, A> {
+    /// Spawns `size` [PollHandler] threads named `{name}-{index}`
+    ///
+    /// # Errors
+    /// If any of the threads could not be spawned, in which case those already spawned are shut down
+    pub fn new(size: NonZeroUsize, policy: PlacementPolicy, name: &str) -> Result<Self, links_core::Error> {
+        let handlers = (0..size.get())
+            .map(|idx| PollHandler::new().and_then(|handler| handler.into_spawned_handler_with(format!("{}-{}", name, idx).as_str(), PollHandlerOptions::default())))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { handlers, policy, next: AtomicUsize::new(0) })
+    }
+    /// Places the [PollRead] on one of the threads and returns the index of the [SpawnedPollHandler] servicing it
+    pub fn add_recver(&self, recver: R) -> Result<usize, links_core::Error> {
+        let idx = self.place(recver.con_id());
+        self.handlers[idx].add_recver(recver)?;
+        Ok(idx)
+    }
+    /// Places the [PollAccept] on one of the threads and returns the index of the [SpawnedPollHandler] servicing it, resulting connections
+    /// are serviced by the same thread unless the acceptor hands them back to the group
+    pub fn add_acceptor(&self, acceptor: A) -> Result<usize, links_core::Error> {
+        let idx = self.place(acceptor.con_id());
+        self.handlers[idx].add_acceptor(acceptor)?;
+        Ok(idx)
+    }
+    /// Places the [PollConnect] on one of the threads and returns the index of the [SpawnedPollHandler] servicing it
+    pub fn add_connector(&self, connector: Box<dyn PollConnect<R>>) -> Result<usize, links_core::Error> {
+        let idx = self.place(connector.con_id());
+        self.handlers[idx].add_connector(connector)?;
+        Ok(idx)
+    }
+    /// Will call [SpawnedPollHandler::shutdown] on every thread of the group
+    ///
+    /// # Errors
+    /// First error returned by [SpawnedPollHandler::shutdown], every thread is issued the request regardless
+    pub fn shutdown(&self, con_id: Option<ConId>) -> Result<(), links_core::Error> {
+        let mut res = Ok(());
+        for handler in self.handlers.iter() {
+            let shutdown = handler.shutdown(con_id.clone());
+            if res.is_ok() {
+                res = shutdown;
+            }
+        }
+        res
+    }
+    pub fn handlers(&self) -> &[SpawnedPollHandler<R, A>] {
+        &self.handlers
+    }
+    pub fn policy(&self) -> PlacementPolicy {
+        self.policy
+    }
+    fn place(&self, con_id: &ConId) -> usize {
+        match self.policy {
+            PlacementPolicy::RoundRobin => self.next.fetch_add(1, Ordering::Relaxed) % self.handlers.len(),
+            PlacementPolicy::LeastConnections => self.handlers.iter().enumerate().min_by_key(|(_, handler)| handler.connection_count()).map_or(0, |(idx, _)| idx),
+            PlacementPolicy::Hash(hash) => (hash(con_id) % self.handlers.len() as u64) as usize,
+        }
+    }
+}
+impl<R: PollRead, A: PollAccept<R>> Display for PollHandlerGroup<R, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let connections = self.handlers.iter().map(|handler| handler.connection_count()).collect::<Vec<_>>();
+        write!(f, "{}<{:?}, connections: {:?}>", asserted_short_name!("PollHandlerGroup", Self), self.policy, connections)
+    }
+}
+pub type PollHandlerGroupDynamic = PollHandlerGroup<Box<dyn PollRead>, Box<dyn PollAccept<Box<dyn PollRead>>>>;
+pub type PollHandlerGroupStatic<P, C, const MAX_MSG_SIZE: usize> = PollHandlerGroup<CltRecver<P, C, MAX_MSG_SIZE>, TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE>>;
+
 #[cfg(test)]
 #[cfg(feature = "unittest")]
 mod test {
@@ -451,7 +1696,7 @@ mod test {
         prelude::*,
         unittest::setup::{
             connection::{CltTest, SvcTest},
-            protocol::{CltTestProtocolManual, SvcTestProtocolManual},
+            protocol::{CltTestProtocolAuthAndHbeat, CltTestProtocolManual, CltTestProtocolOutboundQueue, SvcTestProtocolAuthAndHBeat, SvcTestProtocolManual, SvcTestProtocolRecvTimeout, HBEAT_INTERVAL},
         },
     };
     use links_core::unittest::setup::{
@@ -459,7 +1704,13 @@ mod test {
         model::{CltTestMsg, CltTestMsgDebug, SvcTestMsg, SvcTestMsgDebug, UniTestMsg},
     };
     use log::info;
-    use std::{num::NonZeroUsize, time::Instant};
+    use std::{
+        fmt::Display,
+        num::NonZeroUsize,
+        sync::Arc,
+        thread::sleep,
+        time::{Duration, Instant},
+    };
 
     #[test]
     fn test_poller_static() {
@@ -482,10 +1733,10 @@ mod test {
 
         let (acceptor, _, _sender_pool) = svc.into_split();
 
-        let mut poll_handler = PollHandlerStatic::default();
-        poll_handler.add_acceptor(acceptor);
+        let mut poll_handler = PollHandlerStatic::new().unwrap();
+        poll_handler.add_acceptor(acceptor).unwrap();
 
-        let _spawned_poll_handler = poll_handler.into_spawned_handler("Static-Svc-Poll-Thread");
+        let _spawned_poll_handler = poll_handler.into_spawned_handler("Static-Svc-Poll-Thread").unwrap();
 
         let mut msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Client Msg"));
         let write_count = 10;
@@ -566,15 +1817,15 @@ mod test {
         let (clt1_recver, mut clt1) = clt1.into_split();
         let (clt2_recver, mut clt2) = clt2.into_split();
 
-        let mut poll_handler = PollHandlerDynamic::default();
+        let mut poll_handler = PollHandlerDynamic::new().unwrap();
         // try adding before spawning
-        poll_handler.add_acceptor(acceptor1.into());
-        poll_handler.add_acceptor(acceptor2.into());
+        poll_handler.add_acceptor(acceptor1.into()).unwrap();
+        poll_handler.add_acceptor(acceptor2.into()).unwrap();
 
-        let spawned_poll_handler = poll_handler.into_spawned_handler("Dynamic-Svc/Clt-Poll-Thread");
+        let spawned_poll_handler = poll_handler.into_spawned_handler("Dynamic-Svc/Clt-Poll-Thread").unwrap();
         // try adding after spawning
-        spawned_poll_handler.add_recver(Box::new(clt1_recver));
-        spawned_poll_handler.add_recver(Box::new(clt2_recver));
+        spawned_poll_handler.add_recver(Box::new(clt1_recver)).unwrap();
+        spawned_poll_handler.add_recver(Box::new(clt2_recver)).unwrap();
 
         clt1.send_busywait(&mut CltTestMsgDebug::new(b"Hello From Clt1").into()).unwrap();
         clt2.send_busywait(&mut CltTestMsgDebug::new(b"Hello From Clt2").into()).unwrap();
@@ -604,19 +1855,815 @@ mod test {
         info!("store: {}", store);
     }
 
+    #[test]
+    fn test_poller_deadlines() {
+        let now = Instant::now();
+        let mut deadlines = super::Deadlines::default();
+        deadlines.schedule(1, now + Duration::from_millis(10));
+        deadlines.schedule(2, now + Duration::from_millis(20));
+        deadlines.schedule(3, now + Duration::from_millis(30));
+        // rescheduled and removed keys leave stale entries behind which are skipped
+        deadlines.schedule(1, now + Duration::from_millis(40));
+        deadlines.remove(2);
+        assert_eq!(deadlines.next_deadline(), Some(now + Duration::from_millis(30)));
+        assert_eq!(deadlines.pop_due(now + Duration::from_millis(20)), None);
+        assert_eq!(deadlines.pop_due(now + Duration::from_millis(40)), Some(3));
+        assert_eq!(deadlines.pop_due(now + Duration::from_millis(40)), Some(1));
+        assert_eq!(deadlines.pop_due(now + Duration::from_millis(40)), None);
+        assert_eq!(deadlines.next_deadline(), None);
+    }
+
+    #[test]
+    fn test_poller_recv_timeout() {
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr = setup::net::rand_avail_addr_port();
+        let recv_timeout = Duration::from_millis(200);
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), SvcTestProtocolRecvTimeout::new(recv_timeout), Some("unittest/svc")).unwrap();
+        let mut clt = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            DevNullCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+
+        let (acceptor, _, _sender_pool) = svc.into_split();
+        let mut poll_handler = PollHandlerStatic::new().unwrap();
+        poll_handler.add_acceptor(acceptor).unwrap();
+        let _spawned_poll_handler = poll_handler.into_spawned_handler("RecvTimeout-Svc-Poll-Thread").unwrap();
+
+        // connection stays up for as long as clt keeps sending more often then recv_timeout
+        let start = Instant::now();
+        while start.elapsed() < recv_timeout * 3 {
+            clt.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+            sleep(recv_timeout / 4);
+        }
+        let status = clt.recv().unwrap();
+        info!("status: {:?}", status);
+        assert!(status.is_wouldblock());
+
+        // once clt goes silent svc shall terminate the connection
+        let start = Instant::now();
+        let status = clt.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap();
+        info!("status: {:?}, elapsed: {:?}", status, start.elapsed());
+        assert!(status.is_completed_none());
+        assert!(start.elapsed() >= recv_timeout / 2);
+    }
+
+    #[test]
+    fn test_poller_drains_outbound_queue() {
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr = setup::net::rand_avail_addr_port();
+        let high_water_mark = NonZeroUsize::new(64 * 1024).unwrap();
+        let mut svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let clt = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            DevNullCallback::new_ref(),
+            CltTestProtocolOutboundQueue::new(high_water_mark),
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        svc.accept_into_pool_busywait().unwrap();
+
+        let (clt_recver, mut clt_sender) = clt.into_split_ref();
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("OutboundQueue-Clt-Poll-Thread").unwrap();
+        spawned_poll_handler.add_recver(clt_recver.into()).unwrap();
+
+        // svc is not reading hence clt will eventually fill up socket buffers and the outbound queue
+        let mut msg_send_count = 0_usize;
+        while clt_sender.send(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap().is_completed() {
+            msg_send_count += 1;
+        }
+        info!("msg_send_count: {}, outbound_queue_len: {}", msg_send_count, clt_sender.outbound_queue_len());
+        assert!(clt_sender.outbound_queue_len() > 0);
+
+        // clt no longer sends, once svc starts reading the queue is drained by the poll handler on writable events
+        let mut msg_recv_count = 0_usize;
+        let start = Instant::now();
+        while msg_recv_count < msg_send_count && start.elapsed() < setup::net::default_find_timeout() {
+            if let RecvStatus::Completed(Some(_)) = svc.recv().unwrap() {
+                msg_recv_count += 1;
+            }
+        }
+        info!("msg_recv_count: {}, outbound_queue_len: {}", msg_recv_count, clt_sender.outbound_queue_len());
+        assert_eq!(msg_recv_count, msg_send_count);
+        assert_eq!(clt_sender.outbound_queue_len(), 0);
+    }
+
+    #[test]
+    fn test_poller_drains_outbound_queue_owned_split() {
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr = setup::net::rand_avail_addr_port();
+        let high_water_mark = NonZeroUsize::new(64 * 1024).unwrap();
+        let mut svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let clt = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            DevNullCallback::new_ref(),
+            CltTestProtocolOutboundQueue::new(high_water_mark),
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        svc.accept_into_pool_busywait().unwrap();
+
+        // owned recver shares the outbound queue with the owned sender hence it is able to drain it on writable events
+        let (clt_recver, mut clt_sender) = clt.into_split();
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("OutboundQueue-Owned-Clt-Poll-Thread").unwrap();
+        spawned_poll_handler.add_recver(clt_recver.into()).unwrap();
+
+        let mut msg_send_count = 0_usize;
+        while clt_sender.send(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap().is_completed() {
+            msg_send_count += 1;
+        }
+        info!("msg_send_count: {}, outbound_queue_len: {}", msg_send_count, clt_sender.outbound_queue_len());
+        assert!(clt_sender.outbound_queue_len() > 0);
+
+        // clt no longer sends, hence the last queued frames can only be delivered by the poll handler
+        let mut msg_recv_count = 0_usize;
+        let start = Instant::now();
+        while msg_recv_count < msg_send_count && start.elapsed() < setup::net::default_find_timeout() {
+            if let RecvStatus::Completed(Some(_)) = svc.recv().unwrap() {
+                msg_recv_count += 1;
+            }
+        }
+        info!("msg_recv_count: {}, outbound_queue_len: {}", msg_recv_count, clt_sender.outbound_queue_len());
+        assert_eq!(msg_recv_count, msg_send_count);
+        assert_eq!(clt_sender.outbound_queue_len(), 0);
+    }
+
+    #[test]
+    fn test_poller_busy_spin() {
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr = setup::net::rand_avail_addr_port();
+        let counter = CounterCallback::new_ref();
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), counter.clone(), SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let (acceptor, _, _svc_senders) = svc.into_split();
+
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler_with("BusySpin-Svc-Poll-Thread", PollHandlerOptions::default().with_busy_spin(true)).unwrap();
+        spawned_poll_handler.add_acceptor(acceptor.into()).unwrap();
+
+        let mut clt = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            DevNullCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        clt.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+        counter.assert_recv_count_busywait_timeout(1, setup::net::default_find_timeout());
+
+        // options which can't be applied must fail the spawn instead of running an unconfigured thread
+        let res = PollHandlerDynamic::new().unwrap().into_spawned_handler_with("InvalidCore-Poll-Thread", PollHandlerOptions::default().with_core_id(usize::MAX));
+        info!("res: {:?}", res.as_ref().err());
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_poller_heart_beat_timers() {
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr = setup::net::rand_avail_addr_port();
+        let svc_counter = CounterCallback::new_ref();
+        let clt_counter = CounterCallback::new_ref();
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), svc_counter.clone(), SvcTestProtocolAuthAndHBeat, Some("unittest/svc")).unwrap();
+
+        // owned connections on both ends are serviced as a whole hence heart beats are sent by the poll thread
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("HBeat-Poll-Thread").unwrap();
+        spawned_poll_handler.add_acceptor(SvcAcceptor::from(svc).into()).unwrap();
+
+        let clt = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            clt_counter.clone(),
+            CltTestProtocolAuthAndHbeat,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        spawned_poll_handler.add_recver(clt.into()).unwrap();
+
+        // login request/accept followed by at least two heart beats in each direction
+        svc_counter.assert_recv_count_busywait_timeout(3, HBEAT_INTERVAL * 3);
+        clt_counter.assert_recv_count_busywait_timeout(3, HBEAT_INTERVAL * 3);
+        info!("svc_counter: {}, clt_counter: {}", svc_counter, clt_counter);
+    }
+
+    #[test]
+    fn test_poller_heart_beat_timers_owned_split() {
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr = setup::net::rand_avail_addr_port();
+        let svc_counter = CounterCallback::new_ref();
+        let clt_counter = CounterCallback::new_ref();
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), svc_counter.clone(), SvcTestProtocolAuthAndHBeat, Some("unittest/svc")).unwrap();
+
+        // svc heart beats are sent by the poll thread servicing the accepted connections
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("HBeat-Owned-Split-Poll-Thread").unwrap();
+        let svc_sender = svc.into_sender_with_spawned_recver_ref_on(&spawned_poll_handler).unwrap();
+
+        let clt = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            clt_counter.clone(),
+            CltTestProtocolAuthAndHbeat,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        let (clt_recver, mut clt_sender) = clt.into_split();
+        spawned_poll_handler.add_recver(clt_recver.into()).unwrap();
+
+        // clt heart beats are only flagged as due by the poll thread since the owned clt sender is the only writer of the connection
+        clt_counter.assert_recv_count_busywait_timeout(3, HBEAT_INTERVAL * 3);
+        assert_eq!(svc_counter.recv_count(), 1);
+
+        // login request followed by at least two heart beats sent by the owned clt sender
+        let start = Instant::now();
+        while svc_counter.recv_count() < 3 {
+            clt_sender.send_due().unwrap();
+            assert!(start.elapsed() < HBEAT_INTERVAL * 3, "svc_counter: {}", svc_counter);
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        info!("svc_counter: {}, clt_counter: {}", svc_counter, clt_counter);
+        drop((clt_sender, svc_sender));
+    }
+
+    #[test]
+    fn test_poller_snapshot() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        const N: usize = 10;
+
+        let addr = setup::net::rand_avail_addr_port();
+        let counter = CounterCallback::new_ref();
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), counter.clone(), SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let (acceptor, _, _svc_senders) = svc.into_split();
+
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler_with("Snapshot-Svc-Poll-Thread", PollHandlerOptions::default().with_stats(true)).unwrap();
+        spawned_poll_handler.add_acceptor(acceptor.into()).unwrap();
+
+        let mut clt = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            DevNullCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        for _ in 0..N {
+            clt.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+        }
+        counter.assert_recv_count_busywait_timeout(N, setup::net::default_find_timeout());
+
+        // statistics are recorded once the call returns which is after the callback is issued
+        let start = Instant::now();
+        let snapshot = loop {
+            let snapshot = spawned_poll_handler.snapshot();
+            if snapshot.iter().any(|stats| stats.kind == ServiceableKind::Recver && stats.messages == N as u64) || start.elapsed() > setup::net::default_find_timeout() {
+                break snapshot;
+            }
+        };
+        for stats in snapshot.iter() {
+            info!("stats: {}", stats);
+        }
+        assert_eq!(snapshot.len(), 2);
+        let acceptor = &snapshot[0];
+        assert_eq!(acceptor.kind, ServiceableKind::Acceptor);
+        assert_eq!(acceptor.messages, 1);
+        let recver = &snapshot[1];
+        assert_eq!(recver.kind, ServiceableKind::Recver);
+        assert_eq!(recver.messages, N as u64);
+        assert!(recver.events > recver.messages); // the last call on each event yields WouldBlock
+        assert!(recver.last_activity.is_some());
+        assert_eq!(recver.busy_for, None);
+    }
+
+    #[test]
+    fn test_poller_watchdog() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        const RECV_DELAY: Duration = Duration::from_millis(200);
+
+        /// Stalls the [PollHandler] thread on every message it receives
+        #[derive(Debug)]
+        struct SlowRecvCallback;
+        impl Display for SlowRecvCallback {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "SlowRecvCallback")
+            }
+        }
+        impl<M: Messenger> CallbackRecv<M> for SlowRecvCallback {
+            fn on_recv(&self, _con_id: &ConId, _msg: &M::RecvT) {
+                sleep(RECV_DELAY);
+            }
+        }
+        impl<M: Messenger> CallbackSend<M> for SlowRecvCallback {
+            fn on_sent(&self, _con_id: &ConId, _msg: &M::SendT) {}
+        }
+        impl<M: Messenger> CallbackRecvSend<M> for SlowRecvCallback {}
+
+        let addr = setup::net::rand_avail_addr_port();
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), Arc::new(SlowRecvCallback), SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let (acceptor, _, _svc_senders) = svc.into_split();
+
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler_with("Watchdog-Svc-Poll-Thread", PollHandlerOptions::default().with_stats(true)).unwrap();
+        spawned_poll_handler.add_acceptor(acceptor.into()).unwrap();
+        let reported = Arc::new(spin::Mutex::new(vec![]));
+        let timer = Timer::new("Watchdog-Timer-Thread");
+        spawned_poll_handler.start_watchdog(
+            &timer,
+            PollHandlerWatchdog::new(RECV_DELAY / 4).with_callback({
+                let reported = reported.clone();
+                move |stats: &ServiceableStats| reported.lock().push(stats.clone())
+            }),
+        );
+
+        let mut clt = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            DevNullCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        clt.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+
+        let start = Instant::now();
+        while reported.lock().is_empty() && start.elapsed() < setup::net::default_find_timeout() {
+            sleep(RECV_DELAY / 10);
+        }
+        // a stalled call is only reported once even though it spans several checks
+        sleep(RECV_DELAY);
+        let reported = reported.lock();
+        info!("reported: {:?}", reported);
+        assert_eq!(reported.len(), 1);
+        assert_eq!(reported[0].kind, ServiceableKind::Recver);
+        assert!(reported[0].busy_for.unwrap() >= RECV_DELAY / 4);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_poller_budget_fairness() {
+        use links_core::core::conid::ConnectionId;
+        use mio::{event::Source, net::UnixStream};
+        use std::{
+            io::{Error, Write},
+            sync::atomic::{AtomicUsize, Ordering},
+        };
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        /// Never runs out of data, every readable event yields [PollEventStatus::Completed]
+        struct FloodRecver {
+            con_id: ConId,
+            stream: UnixStream,
+            _peer: UnixStream,
+            count: Arc<AtomicUsize>,
+        }
+        impl Display for FloodRecver {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "FloodRecver<{}>", self.con_id)
+            }
+        }
+        impl ConnectionId for FloodRecver {
+            fn con_id(&self) -> &ConId {
+                &self.con_id
+            }
+        }
+        impl PollAble for FloodRecver {
+            fn source(&mut self) -> Box<&mut dyn Source> {
+                Box::new(&mut self.stream)
+            }
+        }
+        impl PollRead for FloodRecver {
+            fn on_readable_event(&mut self) -> Result<PollEventStatus, Error> {
+                self.count.fetch_add(1, Ordering::Relaxed);
+                Ok(PollEventStatus::Completed)
+            }
+        }
+
+        /// Captures the flood count at the moment the quiet connection's message is delivered on the [PollHandler] thread
+        #[derive(Debug)]
+        struct FloodCountAtRecvCallback {
+            flood_count: Arc<AtomicUsize>,
+            at_recv: Arc<AtomicUsize>,
+        }
+        impl Display for FloodCountAtRecvCallback {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "FloodCountAtRecvCallback")
+            }
+        }
+        impl<M: Messenger> CallbackRecv<M> for FloodCountAtRecvCallback {
+            fn on_recv(&self, _con_id: &ConId, _msg: &M::RecvT) {
+                self.at_recv.store(self.flood_count.load(Ordering::Relaxed), Ordering::Relaxed);
+            }
+        }
+        impl<M: Messenger> CallbackSend<M> for FloodCountAtRecvCallback {
+            fn on_sent(&self, _con_id: &ConId, _msg: &M::SendT) {}
+        }
+        impl<M: Messenger> CallbackRecvSend<M> for FloodCountAtRecvCallback {}
+
+        const BUDGET: usize = 16;
+        let (stream, mut peer) = UnixStream::pair().unwrap();
+        peer.write_all(b"x").unwrap();
+        let flood_count = Arc::new(AtomicUsize::new(0));
+        let flood = FloodRecver {
+            con_id: ConId::clt_uds(Some("unittest/flood"), "flood"),
+            stream,
+            _peer: peer,
+            count: flood_count.clone(),
+        };
+
+        let spawned_poll_handler = PollHandlerDynamic::new()
+            .unwrap()
+            .into_spawned_handler_with("Budget-Poll-Thread", PollHandlerOptions::default().with_budget(NonZeroUsize::new(BUDGET).unwrap()))
+            .unwrap();
+        spawned_poll_handler.add_recver(Box::new(flood)).unwrap();
+        let start = Instant::now();
+        while flood_count.load(Ordering::Relaxed) == 0 && start.elapsed() < setup::net::default_find_timeout() {
+            sleep(Duration::from_millis(1));
+        }
+        assert!(flood_count.load(Ordering::Relaxed) > 0);
+
+        // acceptor is added through the waker while the flood is in progress
+        let addr = setup::net::rand_avail_addr_port();
+        let at_recv = Arc::new(AtomicUsize::new(usize::MAX));
+        let callback = Arc::new(FloodCountAtRecvCallback {
+            flood_count: flood_count.clone(),
+            at_recv: at_recv.clone(),
+        });
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), callback, SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let (acceptor, _, _svc_senders) = svc.into_split();
+        spawned_poll_handler.add_acceptor(acceptor.into()).unwrap();
+
+        let mut clt = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            DevNullCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        // wait for the quiet connection to be accepted and registered so that only servicing of its message is measured
+        let start = Instant::now();
+        while spawned_poll_handler.connection_count() != 2 && start.elapsed() < setup::net::default_find_timeout() {
+            sleep(Duration::from_millis(1));
+        }
+        assert_eq!(spawned_poll_handler.connection_count(), 2);
+
+        let start = Instant::now();
+        clt.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Quiet Client Msg").into()).unwrap();
+        let flood_count_at_send = flood_count.load(Ordering::Relaxed);
+        while at_recv.load(Ordering::Relaxed) == usize::MAX && start.elapsed() < setup::net::default_find_timeout() {
+            sleep(Duration::from_millis(1));
+        }
+        let flood_count_at_recv = at_recv.load(Ordering::Relaxed);
+        info!("quiet connection latency: {:?}, flood count at send: {}, at recv: {}", start.elapsed(), flood_count_at_send, flood_count_at_recv);
+        assert_ne!(flood_count_at_recv, usize::MAX);
+        // the message becomes readable at most while one pass is in progress and is serviced on the next, each pass servicing the flood no more than budget times,
+        // one additional pass allows for the flood being serviced ahead of the quiet connection on the pass that picks it up
+        assert!(
+            flood_count_at_recv.saturating_sub(flood_count_at_send) <= BUDGET * 3,
+            "flood was serviced {} times before the quiet connection, budget: {}",
+            flood_count_at_recv.saturating_sub(flood_count_at_send),
+            BUDGET
+        );
+
+        // flood keeps being serviced in round-robin fashion next to the quiet connection
+        let start = Instant::now();
+        while flood_count.load(Ordering::Relaxed) == flood_count_at_recv && start.elapsed() < setup::net::default_find_timeout() {
+            sleep(Duration::from_millis(1));
+        }
+        assert!(flood_count.load(Ordering::Relaxed) > flood_count_at_recv);
+        info!("snapshot: {:?}", spawned_poll_handler.snapshot());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_poller_survives_failed_deregister() {
+        use links_core::core::conid::ConnectionId;
+        use mio::{event::Source, net::UnixStream, Registry};
+        use std::{
+            io::{Error, Write},
+            sync::atomic::{AtomicUsize, Ordering},
+        };
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        /// Counts readable events, when `is_failing` it terminates on the first one and fails to deregister
+        struct CountingRecver {
+            con_id: ConId,
+            stream: UnixStream,
+            _peer: UnixStream,
+            count: Arc<AtomicUsize>,
+            is_failing: bool,
+        }
+        impl Display for CountingRecver {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "CountingRecver<{}>", self.con_id)
+            }
+        }
+        impl ConnectionId for CountingRecver {
+            fn con_id(&self) -> &ConId {
+                &self.con_id
+            }
+        }
+        impl PollAble for CountingRecver {
+            fn source(&mut self) -> Box<&mut dyn Source> {
+                Box::new(&mut self.stream)
+            }
+            fn deregister(&mut self, registry: &Registry) -> Result<(), Error> {
+                registry.deregister(&mut self.stream)?;
+                match self.is_failing {
+                    true => Err(Error::new(std::io::ErrorKind::Other, "unittest deregister failure")),
+                    false => Ok(()),
+                }
+            }
+        }
+        impl PollRead for CountingRecver {
+            fn on_readable_event(&mut self) -> Result<PollEventStatus, Error> {
+                self.count.fetch_add(1, Ordering::Relaxed);
+                match self.is_failing {
+                    true => Ok(PollEventStatus::Terminate),
+                    false => Ok(PollEventStatus::WouldBlock),
+                }
+            }
+        }
+        fn new_recver(name: &str, is_failing: bool) -> (CountingRecver, Arc<AtomicUsize>) {
+            let (stream, mut peer) = UnixStream::pair().unwrap();
+            peer.write_all(b"x").unwrap();
+            let count = Arc::new(AtomicUsize::new(0));
+            let recver = CountingRecver {
+                con_id: ConId::clt_uds(Some(name), name),
+                stream,
+                _peer: peer,
+                count: count.clone(),
+                is_failing,
+            };
+            (recver, count)
+        }
+        fn wait_for(count: &AtomicUsize) {
+            let start = Instant::now();
+            while count.load(Ordering::Relaxed) == 0 && start.elapsed() < setup::net::default_find_timeout() {
+                sleep(Duration::from_millis(1));
+            }
+        }
+
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("Deregister-Poll-Thread").unwrap();
+        let (failing, failing_count) = new_recver("unittest/failing", true);
+        spawned_poll_handler.add_recver(Box::new(failing)).unwrap();
+        wait_for(&failing_count);
+        assert_eq!(failing_count.load(Ordering::Relaxed), 1);
+
+        // only the failing recver is dropped while the thread keeps servicing the ones added after it
+        let (healthy, healthy_count) = new_recver("unittest/healthy", false);
+        spawned_poll_handler.add_recver(Box::new(healthy)).unwrap();
+        wait_for(&healthy_count);
+        assert_eq!(healthy_count.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_poller_group_acceptor_round_robin() {
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr = setup::net::rand_avail_addr_port();
+        let counter = CounterCallback::new_ref();
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(2).unwrap(), counter.clone(), SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let group = Arc::new(PollHandlerGroupDynamic::new(NonZeroUsize::new(2).unwrap(), PlacementPolicy::RoundRobin, "RoundRobin-Group-Poll-Thread").unwrap());
+
+        let (acceptor, _, svc_senders) = svc.into_split();
+        let svc_senders = svc_senders.with_poll_handler_group(&group);
+        assert_eq!(group.add_acceptor(acceptor.with_poll_handler_group(&group).into()).unwrap(), 0);
+
+        let mut clts = (0..2)
+            .map(|_| {
+                CltTest::connect(
+                    addr,
+                    setup::net::default_connect_timeout(),
+                    setup::net::default_connect_retry_after(),
+                    DevNullCallback::new_ref(),
+                    CltTestProtocolManual,
+                    Some("unittest/clt"),
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        for clt in clts.iter_mut() {
+            clt.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+        }
+        counter.assert_recv_count_busywait_timeout(2, setup::net::default_find_timeout());
+
+        // acceptor was placed on the first thread while each accepted connection was placed on the next thread in turn
+        let start = Instant::now();
+        while group.handlers().iter().any(|handler| handler.connection_count() != 1) && start.elapsed() < setup::net::default_find_timeout() {
+            sleep(Duration::from_millis(10));
+        }
+        info!("group: {}", group);
+        assert!(group.handlers().iter().all(|handler| handler.connection_count() == 1));
+
+        // dropping the senders shuts down the acceptor and its connections on every thread of the group
+        drop(svc_senders);
+        let start = Instant::now();
+        while group.handlers().iter().any(|handler| !handler.snapshot().is_empty() || handler.connection_count() != 0) && start.elapsed() < setup::net::default_find_timeout() {
+            sleep(Duration::from_millis(10));
+        }
+        info!("group: {}", group);
+        assert!(group.handlers()[0].snapshot().iter().all(|stats| !matches!(stats.kind, ServiceableKind::Acceptor)));
+        assert!(group.handlers().iter().all(|handler| handler.snapshot().is_empty() && handler.connection_count() == 0));
+    }
+
+    #[test]
+    fn test_poller_group_acceptor_survives_failed_placement() {
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr = setup::net::rand_avail_addr_port();
+        let counter = CounterCallback::new_ref();
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(3).unwrap(), counter.clone(), SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let group = Arc::new(PollHandlerGroupDynamic::new(NonZeroUsize::new(2).unwrap(), PlacementPolicy::RoundRobin, "FailedPlacement-Group-Poll-Thread").unwrap());
+        // second thread of the group no longer accepts connections hence every other placement fails
+        group.handlers()[1].shutdown(None).unwrap();
+
+        let (acceptor, _, _svc_senders) = svc.into_split();
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("FailedPlacement-Acceptor-Poll-Thread").unwrap();
+        spawned_poll_handler.add_acceptor(acceptor.with_poll_handler_group(&group).into()).unwrap();
+
+        let connect = || {
+            CltTest::connect(
+                addr,
+                setup::net::default_connect_timeout(),
+                setup::net::default_connect_retry_after(),
+                DevNullCallback::new_ref(),
+                CltTestProtocolManual,
+                Some("unittest/clt"),
+            )
+            .unwrap()
+        };
+
+        let mut clt_placed = connect();
+        clt_placed.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+        counter.assert_recv_count_busywait_timeout(1, setup::net::default_find_timeout());
+
+        // connection placed on the shutdown thread is dropped on its own
+        let mut clt_dropped = connect();
+        let status = clt_dropped.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap();
+        info!("clt_dropped status: {:?}", status);
+        assert!(status.is_completed_none());
+
+        // while the acceptor keeps accepting and placing connections
+        let mut clt_placed_after = connect();
+        clt_placed_after.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+        counter.assert_recv_count_busywait_timeout(2, setup::net::default_find_timeout());
+        info!("group: {}", group);
+    }
+
+    #[test]
+    fn test_poller_group_placement() {
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr = setup::net::rand_avail_addr_port();
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(4).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let _svc_sender = svc.into_sender_with_spawned_recver();
+        let connect = || {
+            CltTest::connect(
+                addr,
+                setup::net::default_connect_timeout(),
+                setup::net::default_connect_retry_after(),
+                DevNullCallback::new_ref(),
+                CltTestProtocolManual,
+                Some("unittest/clt"),
+            )
+            .unwrap()
+            .into_split()
+        };
+
+        // least connections fills up the thread with fewest connections first
+        let group = PollHandlerGroupDynamic::new(NonZeroUsize::new(2).unwrap(), PlacementPolicy::LeastConnections, "LeastConnections-Group-Poll-Thread").unwrap();
+        let (recver, _sender1) = connect();
+        assert_eq!(group.add_recver(recver.into()).unwrap(), 0);
+        let (recver, _sender2) = connect();
+        assert_eq!(group.add_recver(recver.into()).unwrap(), 1);
+        let (recver, _sender3) = connect();
+        assert_eq!(group.add_recver(recver.into()).unwrap(), 0);
+        info!("group: {}", group);
+
+        // hash always places connections with the same hash on the same thread
+        let group = PollHandlerGroupDynamic::new(NonZeroUsize::new(2).unwrap(), PlacementPolicy::Hash(|_con_id| 1), "Hash-Group-Poll-Thread").unwrap();
+        let (recver, _sender4) = connect();
+        assert_eq!(group.add_recver(recver.into()).unwrap(), 1);
+        assert_eq!(group.handlers()[1].connection_count(), 1);
+        assert_eq!(group.handlers()[0].connection_count(), 0);
+        info!("group: {}", group);
+    }
+
+    #[test]
+    fn test_poller_spawned_back_to_back_shutdown() {
+        use std::{
+            fmt::Display,
+            sync::{
+                atomic::{AtomicBool, Ordering},
+                Arc, Mutex,
+            },
+        };
+        /// Blocks the poll thread inside [CallbackRecv::on_recv] for as long as the test holds the `gate`
+        #[derive(Debug, Default)]
+        struct GateCallback {
+            entered: AtomicBool,
+            gate: Mutex<()>,
+        }
+        impl Display for GateCallback {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "GateCallback")
+            }
+        }
+        impl<M: Messenger> CallbackRecvSend<M> for GateCallback {}
+        impl<M: Messenger> CallbackRecv<M> for GateCallback {
+            fn on_recv(&self, _con_id: &ConId, _msg: &<M as Messenger>::RecvT) {
+                self.entered.store(true, Ordering::Release);
+                drop(self.gate.lock().unwrap());
+            }
+        }
+        impl<M: Messenger> CallbackSend<M> for GateCallback {
+            fn on_sent(&self, _con_id: &ConId, _msg: &<M as Messenger>::SendT) {}
+        }
+        setup::log::configure_level(log::LevelFilter::Info);
+
+        let addr1 = setup::net::rand_avail_addr_port();
+        let addr2 = setup::net::rand_avail_addr_port();
+        let gate = Arc::new(GateCallback::default());
+        let svc1 = SvcTest::bind(addr1, NonZeroUsize::new(1).unwrap(), gate.clone(), SvcTestProtocolManual, Some("unittest/svc1")).unwrap();
+        let svc2 = SvcTest::bind(addr2, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc2")).unwrap();
+        let (acceptor1, _, _svc1) = svc1.into_split();
+        let (acceptor2, _, _svc2) = svc2.into_split();
+        let (con_id1, con_id2) = (acceptor1.con_id().clone(), acceptor2.con_id().clone());
+
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("Back-To-Back-Shutdown-Poll-Thread").unwrap();
+        spawned_poll_handler.add_acceptor(acceptor1.into()).unwrap();
+        spawned_poll_handler.add_acceptor(acceptor2.into()).unwrap();
+
+        let mut clts = [(addr1, "unittest/clt1"), (addr2, "unittest/clt2")].map(|(addr, name)| {
+            CltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), DevNullCallback::new_ref(), CltTestProtocolManual, Some(name))
+                .unwrap()
+                .into_sender_with_spawned_recver_ref()
+        });
+
+        // hold the poll thread inside svc1 callback so that both shutdown requests are queued behind a single waker notification
+        let guard = gate.gate.lock().unwrap();
+        clts[0].send_busywait(&mut CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello From Clt1"))).unwrap();
+        let start = Instant::now();
+        while !gate.entered.load(Ordering::Acquire) {
+            assert!(start.elapsed() < setup::net::default_connect_timeout(), "poll thread did not enter {}", gate);
+        }
+        spawned_poll_handler.shutdown(Some(con_id1)).unwrap();
+        spawned_poll_handler.shutdown(Some(con_id2)).unwrap();
+        drop(guard);
+
+        // dropping the accepted connections will eventually cause each clt sender to fail
+        for clt in clts.iter_mut() {
+            let start = Instant::now();
+            while clt.send(&mut CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello From Clt"))).is_ok() {
+                assert!(start.elapsed() < setup::net::default_connect_timeout(), "{} is still connected, its acceptor lineage was not shutdown", clt);
+            }
+        }
+    }
+
     #[test]
     fn test_poller_spawned_double_shutdown_pass() {
         setup::log::configure_level(log::LevelFilter::Info);
-        crate::connect::DEFAULT_POLL_HANDLER.shutdown(Some(ConId::default()));
-        crate::connect::DEFAULT_POLL_HANDLER.total_shutdown_check();
+        crate::connect::DEFAULT_POLL_HANDLER.shutdown(Some(ConId::default())).unwrap();
+        crate::connect::DEFAULT_POLL_HANDLER.total_shutdown_check(&ConId::default()).unwrap();
     }
 
     #[test]
-    #[should_panic(expected = "Invalid API usage. Trying to use SpawnedPollHandler after SpawnedPollHandler::shutdown(None) has been issued.")]
-    fn test_poller_spawned_double_shutdown_fail() {
+    fn test_poller_spawned_double_shutdown_rejected() {
         setup::log::configure_level(log::LevelFilter::Info);
-        crate::connect::DEFAULT_POLL_HANDLER.shutdown(None);
-        crate::connect::DEFAULT_POLL_HANDLER.total_shutdown_check();
+        let addr = setup::net::rand_avail_addr_port();
+        let spawned_poll_handler = PollHandlerDynamic::new().unwrap().into_spawned_handler("unittest-shutdown").unwrap();
+        spawned_poll_handler.shutdown(None).unwrap();
+        spawned_poll_handler.shutdown(None).unwrap();
+
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc")).unwrap();
+        let (acceptor, _, _sender) = svc.into_split();
+        let err = spawned_poll_handler.add_acceptor(acceptor.into()).unwrap_err();
+        info!("err: {}", err);
+        assert!(matches!(err, links_core::Error::Rejected { ref con_id, .. } if con_id.name() == "unittest/svc"));
+        assert_eq!(std::io::Error::from(err).kind(), std::io::ErrorKind::ConnectionRefused);
+        assert!(spawned_poll_handler.wake().is_err());
+
+        // spawning onto a shut down handler is reported instead of panicking
+        let svc = SvcTest::bind(setup::net::rand_avail_addr_port(), NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc/ref")).unwrap();
+        let err = svc.into_sender_with_spawned_recver_ref_on(&spawned_poll_handler).unwrap_err();
+        info!("err: {}", err);
+        assert!(matches!(err, links_core::Error::Rejected { ref con_id, .. } if con_id.name() == "unittest/svc/ref"));
     }
 
     #[test]
@@ -1,7 +1,8 @@
 use super::clt::{Clt, CltRecverRef, CltSenderRef};
 use crate::prelude::{
-    asserted_short_name, AcceptStatus, CallbackRecvSend, CltRecver, CltSender, ConId, ConnectionId, ConnectionStatus, Messenger, PollAble, PollAccept, PollRead, PoolAcceptStatus, PoolConnectionStatus, PoolSvcAcceptorOfCltNonBlocking, Protocol,
-    RecvNonBlocking, RecvStatus, RoundRobinPool, SendNonBlocking, SendStatus, SvcAcceptor, SvcAcceptorOfCltNonBlocking,
+    asserted_short_name, AcceptStatus, AcceptTransport, CallbackRecvSend, CltRecver, CltSender, ConId, ConnectionId, ConnectionStatus, Messenger, PollAble, PollAccept, PollAccepted, PollHandlerGroupDynamic, PollHandlerShutdownHandleDynamic, PollRead, PoolAcceptStatus,
+    PoolConnectionStatus, PoolSvcAcceptorOfCltNonBlocking, Protocol, RecvNonBlocking, RecvStatus, RoundRobinPool, SendBatchError, SendNonBlocking, SendStatus, SpawnedPollHandlerDynamic, SvcAcceptor, SvcAcceptorOfCltNonBlocking, Timer,
+    TimerHandle, Transport,
 };
 use log::{info, log_enabled, warn, Level};
 use slab::Iter;
@@ -10,7 +11,10 @@ use std::{
     io::{Error, ErrorKind},
     marker::PhantomData,
     num::NonZeroUsize,
-    sync::mpsc::{channel, Receiver, Sender},
+    sync::{
+        mpsc::{channel, Receiver, Sender},
+        Arc, Weak,
+    },
     time::Instant,
 };
 
@@ -24,7 +28,7 @@ pub type SplitCltsPool<M, R, S> = ((Sender<R>, Sender<S>), (CltRecversPool<M, R>
 /// use std::{time::Duration, num::NonZeroUsize};
 ///
 /// let addr = setup::net::rand_avail_addr_port();
-/// let mut pool = CltsPool::new( ConId::clt(None, None, addr), NonZeroUsize::new(1).unwrap());
+/// let mut pool = CltsPool::new( ConId::clt(None, None, addr).unwrap(), NonZeroUsize::new(1).unwrap());
 ///
 /// let res = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect(
 ///     addr, // "127.0.0.1:9090" generates a random port
@@ -50,11 +54,11 @@ pub type SplitCltsPool<M, R, S> = ((Sender<R>, Sender<S>), (CltRecversPool<M, R>
 /// }
 /// ```
 #[derive(Debug)]
-pub struct CltsPool<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> {
+pub struct CltsPool<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
     con_id: ConId,
-    clts: RoundRobinPool<Clt<P, C, MAX_MSG_SIZE>>,
+    clts: RoundRobinPool<Clt<P, C, MAX_MSG_SIZE, T>>,
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> CltsPool<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> CltsPool<P, C, MAX_MSG_SIZE, T> {
     /// Creates a new [CltsPool]
     /// # Arguments
     ///  * max_connections - the maximum number of connections that can be added to the pool.
@@ -83,7 +87,7 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> CltsPool<P,
     }
     /// Adds a [Clt] to the pool
     #[inline(always)]
-    pub fn add(&mut self, clt: Clt<P, C, MAX_MSG_SIZE>) -> Result<(), Error> {
+    pub fn add(&mut self, clt: Clt<P, C, MAX_MSG_SIZE, T>) -> Result<(), Error> {
         self.clts.add(clt)
     }
     #[inline(always)]
@@ -93,7 +97,7 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> CltsPool<P,
     /// Splits [CltsPool] into a a pair of transmitter channels and their respective [CltRecversPool] and [CltSendersPool] pools
     /// leveraging [CltRecver] and [CltSender] respectively.
     #[inline(always)]
-    pub fn into_split(self) -> SplitCltsPool<P, CltRecver<P, C, MAX_MSG_SIZE>, CltSender<P, C, MAX_MSG_SIZE>> {
+    pub fn into_split(self) -> SplitCltsPool<P, CltRecver<P, C, MAX_MSG_SIZE, T>, CltSender<P, C, MAX_MSG_SIZE, T>> {
         let (tx_recver, rx_recver) = channel();
         let (tx_sender, rx_sender) = channel();
         let max_capacity = self.clts.max_capacity();
@@ -111,8 +115,18 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> CltsPool<P,
         ((tx_recver, tx_sender), (recver_pool, sender_pool))
     }
 
+    /// Same as [CltsPool::into_split_ref_on] using [static@crate::connect::DEFAULT_HBEAT_HANDLER]
     #[inline(always)]
-    pub fn into_split_ref(self) -> SplitCltsPool<P, CltRecverRef<P, C, MAX_MSG_SIZE>, CltSenderRef<P, C, MAX_MSG_SIZE>> {
+    pub fn into_split_ref(self) -> SplitCltsPool<P, CltRecverRef<P, C, MAX_MSG_SIZE, T>, CltSenderRef<P, C, MAX_MSG_SIZE, T>> {
+        self.into_split_ref_on(&crate::connect::DEFAULT_HBEAT_HANDLER)
+    }
+    /// Splits [CltsPool] into a a pair of transmitter channels and their respective [CltRecversPool] and [CltSendersPool] pools
+    /// leveraging [CltRecverRef] and [CltSenderRef] respectively, heart beats of every [Clt] are scheduled on the `hbeat_handler`, see [Clt::into_split_ref_on]
+    pub fn into_split_ref_on(self, hbeat_handler: &Timer) -> SplitCltsPool<P, CltRecverRef<P, C, MAX_MSG_SIZE, T>, CltSenderRef<P, C, MAX_MSG_SIZE, T>> {
+        self.into_split_ref_with(Some(&hbeat_handler.handle()))
+    }
+    /// Same as [CltsPool::into_split_ref_on] but see [Clt::into_split_ref_with] for the meaning of `hbeat_handler` being [None]
+    pub(crate) fn into_split_ref_with(self, hbeat_handler: Option<&TimerHandle>) -> SplitCltsPool<P, CltRecverRef<P, C, MAX_MSG_SIZE, T>, CltSenderRef<P, C, MAX_MSG_SIZE, T>> {
         let (tx_recver, rx_recver) = channel();
         let (tx_sender, rx_sender) = channel();
         let max_capacity = self.clts.max_capacity();
@@ -120,7 +134,7 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> CltsPool<P,
         let mut sender_pool = CltSendersPool::new(self.con_id().clone(), rx_sender, max_capacity);
 
         for clt in self.clts.into_iter() {
-            let (clt_recver, clt_sender) = clt.into_split_ref();
+            let (clt_recver, clt_sender) = clt.into_split_ref_with(hbeat_handler);
             tx_recver.send(clt_recver).expect("CltsPool::into_split_ref - Failed to send CltRecver to CltRecversPool");
             assert_eq!(recver_pool.accept_into_pool().expect("CltsPool::into_split_ref - Failed to service CltRecversPool rx_queue"), PoolAcceptStatus::Accepted);
 
@@ -130,7 +144,7 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> CltsPool<P,
         ((tx_recver, tx_sender), (recver_pool, sender_pool))
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> SendNonBlocking<P::SendT> for CltsPool<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> SendNonBlocking<P::SendT> for CltsPool<P, C, MAX_MSG_SIZE, T> {
     /// Will round robin [Clt]'s in the pool to propagate the call.
     ///
     /// # Important
@@ -143,8 +157,20 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> SendNonBloc
             None => Err(Error::new(ErrorKind::NotConnected, "Not Connected, 0 clts available in the pool")),
         }
     }
+    /// Will round robin [Clt]'s in the pool and deliver all of the `msgs` using the same [Clt].
+    ///
+    /// # Important
+    ///
+    /// Will return [Err(ErrorKind::NotConnected)] if the pool is empty.
+    #[inline(always)]
+    fn send_batch(&mut self, msgs: &mut [<P as Messenger>::SendT]) -> Result<usize, SendBatchError> {
+        match self.clts.round_robin() {
+            Some(clt) => clt.send_batch(msgs),
+            None => Err(Error::new(ErrorKind::NotConnected, "Not Connected, 0 clts available in the pool").into()),
+        }
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> RecvNonBlocking<P::RecvT> for CltsPool<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> RecvNonBlocking<P::RecvT> for CltsPool<P, C, MAX_MSG_SIZE, T> {
     /// Will round robin [Clt]'s in the pool to propagate the call.
     /// Will return [Err(ErrorKind::NotConnected)] if the pool is empty, so that the [Self::recv_busywait] does not block indefinitely.
     #[inline(always)]
@@ -171,7 +197,7 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> RecvNonBloc
         }
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolConnectionStatus for CltsPool<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> PoolConnectionStatus for CltsPool<P, C, MAX_MSG_SIZE, T> {
     /// Will only test connection status of the next [Clt] in the pool that will be used to service [SendNonBlocking::send] or [RecvNonBlocking::recv]
     #[inline(always)]
     fn is_next_connected(&mut self) -> bool {
@@ -193,12 +219,12 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolConnect
         true
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ConnectionId for CltsPool<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> ConnectionId for CltsPool<P, C, MAX_MSG_SIZE, T> {
     fn con_id(&self) -> &ConId {
         &self.con_id
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Display for CltsPool<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: Transport> Display for CltsPool<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let recv_t = std::any::type_name::<<P as Messenger>::RecvT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         let send_t = std::any::type_name::<<P as Messenger>::SendT>().split("::").last().unwrap_or("Unknown").replace('>', "");
@@ -217,7 +243,7 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Display for
 ///
 /// let addr = setup::net::rand_avail_addr_port();
 /// let (tx_recver, rx_recver) = channel();
-/// let mut pool = CltRecversPool::<CltTestProtocolManual, _>::new(ConId::clt(None, None, addr), rx_recver, NonZeroUsize::new(2).unwrap());
+/// let mut pool = CltRecversPool::<CltTestProtocolManual, _>::new(ConId::clt(None, None, addr).unwrap(), rx_recver, NonZeroUsize::new(2).unwrap());
 ///
 /// let res = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect(
 ///     addr, // "127.0.0.1:8080" generates a random port
@@ -457,7 +483,7 @@ impl<M: Messenger, R: RecvNonBlocking<M::RecvT> + ConnectionStatus> Display for
 ///
 /// let addr = setup::net::rand_avail_addr_port(); // "127.0.0.1:8080" generates a random port
 /// let (tx_recver, rx_recver) = channel();
-/// let mut pool = CltSendersPool::<CltTestProtocolManual, _>::new(ConId::clt(None, None, addr), rx_recver, NonZeroUsize::new(2).unwrap());
+/// let mut pool = CltSendersPool::<CltTestProtocolManual, _>::new(ConId::clt(None, None, addr).unwrap(), rx_recver, NonZeroUsize::new(2).unwrap());
 ///
 /// let res = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect(
 ///     addr,
@@ -479,6 +505,7 @@ pub struct CltSendersPool<M: Messenger, S: SendNonBlocking<M::SendT> + Connectio
     con_id: ConId,
     rx_sender: Receiver<S>,
     senders: RoundRobinPool<S>,
+    poll_handlers: Vec<PollHandlerShutdownHandleDynamic>,
     phantom: PhantomData<M>,
 }
 impl<M: Messenger, S: SendNonBlocking<M::SendT> + ConnectionStatus> CltSendersPool<M, S> {
@@ -488,9 +515,21 @@ impl<M: Messenger, S: SendNonBlocking<M::SendT> + ConnectionStatus> CltSendersPo
             con_id,
             rx_sender,
             senders: RoundRobinPool::new(max_connections),
+            poll_handlers: Vec::new(),
             phantom: PhantomData,
         }
     }
+    /// On `drop` the acceptor and all receivers that share its lineage will be shut down on the given `poll_handler` instead of [static@crate::connect::DEFAULT_POLL_HANDLER]
+    pub fn with_poll_handler(mut self, poll_handler: &SpawnedPollHandlerDynamic) -> Self {
+        self.poll_handlers = vec![poll_handler.shutdown_handle()];
+        self
+    }
+    /// On `drop` the acceptor and all receivers that share its lineage will be shut down on every thread of the given `group` instead of
+    /// [static@crate::connect::DEFAULT_POLL_HANDLER], use it when the acceptor hands its connections to the `group`, see [TransmittingSvcAcceptor::with_poll_handler_group]
+    pub fn with_poll_handler_group(mut self, group: &PollHandlerGroupDynamic) -> Self {
+        self.poll_handlers = group.handlers().iter().map(|handler| handler.shutdown_handle()).collect();
+        self
+    }
     pub fn len(&self) -> usize {
         self.senders.len()
     }
@@ -637,6 +676,34 @@ impl<M: Messenger, S: SendNonBlocking<M::SendT> + ConnectionStatus> SendNonBlock
             }
         }
     }
+    /// Same as [Self::send] but delivers all of the `msgs` using the same [CltSender], see [SendNonBlocking::send_batch]
+    #[inline(always)]
+    fn send_batch(&mut self, msgs: &mut [<M as Messenger>::SendT]) -> Result<usize, SendBatchError> {
+        match self.senders.round_robin() {
+            Some(s) => match s.send_batch(msgs) {
+                Ok(completed) => {
+                    self.accept_into_pool()?;
+                    Ok(completed)
+                }
+                Err(e) => {
+                    let sender = self.senders.remove_last_used();
+                    let source = Error::new(e.kind(), format!("sender: {} is dead and will be dropped, senders: {}.  error: ({})", sender, self.senders, e.source));
+                    if let Err(e) = self.accept_into_pool() {
+                        warn!("{}::send_batch failed to accept into pool after sender failure, error: {}", asserted_short_name!("CltSendersPool", Self), e);
+                    }
+                    Err(SendBatchError::new(e.sent, source))
+                }
+            },
+            None => {
+                // no senders available try processing rx_queue
+                if let PoolAcceptStatus::Accepted = self.accept_into_pool()? {
+                    self.send_batch(msgs)
+                } else {
+                    Err(Error::new(ErrorKind::NotConnected, "Not Connected, 0 senders available in the pool").into())
+                }
+            }
+        }
+    }
 }
 impl<M: Messenger, S: SendNonBlocking<M::SendT> + ConnectionStatus> Drop for CltSendersPool<M, S> {
     fn drop(&mut self) {
@@ -647,7 +714,16 @@ impl<M: Messenger, S: SendNonBlocking<M::SendT> + ConnectionStatus> Drop for Clt
         self.clear();
         // # 2
         // this will drop the acceptor and all receivers that share acceptor lineage with self.con_id()
-        crate::connect::DEFAULT_POLL_HANDLER.shutdown(Some(self.con_id().clone()));
+        if self.poll_handlers.is_empty() {
+            if let Err(e) = crate::connect::DEFAULT_POLL_HANDLER.shutdown(Some(self.con_id().clone())) {
+                warn!("{}::drop failed to shutdown acceptor lineage, error: {}", asserted_short_name!("CltSendersPool", Self), e);
+            }
+        }
+        for poll_handler in self.poll_handlers.iter() {
+            if let Err(e) = poll_handler.shutdown(self.con_id().clone()) {
+                warn!("{}::drop failed to shutdown acceptor lineage on {:?}, error: {}", asserted_short_name!("CltSendersPool", Self), poll_handler, e);
+            }
+        }
     }
 }
 impl<M: Messenger, S: SendNonBlocking<M::SendT> + ConnectionStatus> PoolConnectionStatus for CltSendersPool<M, S> {
@@ -713,7 +789,7 @@ impl<M: Messenger, S: SendNonBlocking<M::SendT> + ConnectionStatus> Display for
 ///
 /// let addr = setup::net::rand_avail_addr_port(); // will return random port "127.0.0.1:8080"
 /// let acceptor = SvcAcceptor::<_,_, TEST_MSG_FRAME_SIZE>::new(
-///     ConId::svc(Some("doctest"), addr, None),
+///     ConId::svc(Some("doctest"), addr, None).unwrap(),
 ///     std::net::TcpListener::bind(addr).unwrap(),
 ///     DevNullCallback::default().into(),
 ///     SvcTestProtocolManual::default(),
@@ -744,17 +820,61 @@ impl<M: Messenger, S: SendNonBlocking<M::SendT> + ConnectionStatus> Display for
 /// //assert!(false); // uncomment to see output
 /// ```
 #[derive(Debug)]
-pub struct TransmittingSvcAcceptor<P: Protocol + 'static, C: CallbackRecvSend<P> + 'static, const MAX_MSG_SIZE: usize> {
-    tx_recver: Sender<CltRecver<P, C, MAX_MSG_SIZE>>,
-    tx_sender: Sender<CltSender<P, C, MAX_MSG_SIZE>>,
-    acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE>,
+pub struct TransmittingSvcAcceptor<P: Protocol + 'static, C: CallbackRecvSend<P> + 'static, const MAX_MSG_SIZE: usize, T: AcceptTransport = mio::net::TcpStream> {
+    tx_recver: Sender<CltRecver<P, C, MAX_MSG_SIZE, T>>,
+    tx_sender: Sender<CltSender<P, C, MAX_MSG_SIZE, T>>,
+    acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE, T>,
+    poll_handler_group: Option<Weak<PollHandlerGroupDynamic>>,
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE> {
-    pub fn new(tx_recver: Sender<CltRecver<P, C, MAX_MSG_SIZE>>, tx_sender: Sender<CltSender<P, C, MAX_MSG_SIZE>>, acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE>) -> Self {
-        Self { tx_recver, tx_sender, acceptor }
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T> {
+    pub fn new(tx_recver: Sender<CltRecver<P, C, MAX_MSG_SIZE, T>>, tx_sender: Sender<CltSender<P, C, MAX_MSG_SIZE, T>>, acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE, T>) -> Self {
+        Self {
+            tx_recver,
+            tx_sender,
+            acceptor,
+            poll_handler_group: None,
+        }
+    }
+    /// When polled via [PollAccept::poll_accept] every accepted [CltRecver] is placed on the [PollHandlerGroupDynamic] instead of being returned to the polling thread.
+    /// Only a weak reference to the `group` is kept and once the `group` is dropped [PollAccept::poll_accept] will yield an error
+    pub fn with_poll_handler_group(self, group: &Arc<PollHandlerGroupDynamic>) -> Self {
+        Self {
+            poll_handler_group: Some(Arc::downgrade(group)),
+            ..self
+        }
+    }
+    /// Places every pending connection on the `group` and yields [AcceptStatus::WouldBlock] once there are no more connections to accept,
+    /// a connection the `group` fails to add is logged and dropped without affecting the acceptor
+    fn accept_into_poll_handler_group<R>(&mut self, group: &Weak<PollHandlerGroupDynamic>) -> Result<AcceptStatus<R>, Error> {
+        use AcceptStatus::{Accepted, Rejected, WouldBlock};
+        let group = match group.upgrade() {
+            Some(group) => group,
+            None => return Err(Error::new(ErrorKind::NotConnected, format!("{} PollHandlerGroup has been dropped", self))),
+        };
+        loop {
+            // a connection which the group fails to take is dropped on its own, the acceptor keeps accepting
+            match self.accept_recver_connect()? {
+                Accepted(PollAccepted::Recver(recver)) => {
+                    if let Err(e) = group.add_recver(recver) {
+                        if log_enabled!(Level::Warn) {
+                            warn!("{} dropping accepted recver which PollHandlerGroup failed to add, e: {}", self, e);
+                        }
+                    }
+                }
+                Accepted(PollAccepted::Connector(connector)) => {
+                    if let Err(e) = group.add_connector(connector) {
+                        if log_enabled!(Level::Warn) {
+                            warn!("{} dropping accepted connector which PollHandlerGroup failed to add, e: {}", self, e);
+                        }
+                    }
+                }
+                Rejected => continue,
+                WouldBlock => return Ok(WouldBlock),
+            }
+        }
     }
     /// Will interrogate the [SvcAcceptor] for new connections and if available will return [CltRecver] and send [CltSender] to the respective [CltSender] pools.
-    pub fn accept_recver(&mut self) -> Result<AcceptStatus<CltRecver<P, C, MAX_MSG_SIZE>>, Error> {
+    pub fn accept_recver(&mut self) -> Result<AcceptStatus<CltRecver<P, C, MAX_MSG_SIZE, T>>, Error> {
         use AcceptStatus::{Accepted, Rejected, WouldBlock};
         match self.acceptor.accept()? {
             Accepted(clt) => {
@@ -768,8 +888,19 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Transmittin
             WouldBlock => Ok(WouldBlock),
         }
     }
+    /// Same as [TransmittingSvcAcceptor::accept_recver] but see [PollAccept::poll_accept_connect]
+    fn accept_recver_connect<R: PollRead + From<CltRecver<P, C, MAX_MSG_SIZE, T>>>(&mut self) -> Result<AcceptStatus<PollAccepted<R>>, Error> {
+        let tx_sender = self.tx_sender.clone();
+        self.acceptor.accept_connect(move |clt| {
+            let (recver, sender) = clt.into_split();
+            if let Err(e) = tx_sender.send(sender) {
+                return Err(Error::new(ErrorKind::Other, e.to_string()));
+            }
+            Ok(recver.into())
+        })
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolSvcAcceptorOfCltNonBlocking for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PoolSvcAcceptorOfCltNonBlocking for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T> {
     /// Will interrogate the [SvcAcceptor] for new connections and if available will send them to the respective [CltRecver] & [CltSender] pools.
     fn accept_into_pool(&mut self) -> Result<PoolAcceptStatus, Error> {
         use AcceptStatus::{Accepted, Rejected, WouldBlock};
@@ -789,43 +920,61 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolSvcAcce
         }
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollAble for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PollAble for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T> {
     fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
         Box::new(&mut self.acceptor.listener)
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ConnectionId for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> ConnectionId for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T> {
     fn con_id(&self) -> &ConId {
         self.acceptor.con_id()
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollAccept<CltRecver<P, C, MAX_MSG_SIZE>> for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE> {
-    fn poll_accept(&mut self) -> Result<AcceptStatus<CltRecver<P, C, MAX_MSG_SIZE>>, Error> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PollAccept<CltRecver<P, C, MAX_MSG_SIZE, T>> for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T> {
+    fn poll_accept(&mut self) -> Result<AcceptStatus<CltRecver<P, C, MAX_MSG_SIZE, T>>, Error> {
         use AcceptStatus::{Accepted, Rejected, WouldBlock};
+        if let Some(group) = self.poll_handler_group.clone() {
+            return self.accept_into_poll_handler_group(&group);
+        }
         match self.accept_recver()? {
             Accepted(recver) => Ok(Accepted(recver)),
             Rejected => Ok(Rejected),
             WouldBlock => Ok(WouldBlock),
         }
     }
+    fn poll_accept_connect(&mut self) -> Result<AcceptStatus<PollAccepted<CltRecver<P, C, MAX_MSG_SIZE, T>>>, Error> {
+        if let Some(group) = self.poll_handler_group.clone() {
+            return self.accept_into_poll_handler_group(&group);
+        }
+        self.accept_recver_connect()
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollAccept<Box<dyn PollRead>> for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PollAccept<Box<dyn PollRead>> for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T> {
     fn poll_accept(&mut self) -> Result<AcceptStatus<Box<dyn PollRead>>, Error> {
         use AcceptStatus::{Accepted, Rejected, WouldBlock};
+        if let Some(group) = self.poll_handler_group.clone() {
+            return self.accept_into_poll_handler_group(&group);
+        }
         match self.accept_recver()? {
             Accepted(recver) => Ok(Accepted(Box::new(recver))),
             Rejected => Ok(Rejected),
             WouldBlock => Ok(WouldBlock),
         }
     }
+    fn poll_accept_connect(&mut self) -> Result<AcceptStatus<PollAccepted<Box<dyn PollRead>>>, Error> {
+        if let Some(group) = self.poll_handler_group.clone() {
+            return self.accept_into_poll_handler_group(&group);
+        }
+        self.accept_recver_connect()
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Display for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> Display for TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}<{}>", asserted_short_name!("TransmittingSvcAcceptor", Self), self.con_id())
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> From<TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE>> for Box<dyn PollAccept<Box<dyn PollRead>>> {
-    fn from(value: TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE>) -> Self {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> From<TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T>> for Box<dyn PollAccept<Box<dyn PollRead>>> {
+    fn from(value: TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T>) -> Self {
         Box::new(value)
     }
 }
@@ -843,7 +992,7 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> From<Transm
 ///
 /// let addr = setup::net::rand_avail_addr_port(); // will return random port "127.0.0.1:8080"
 /// let acceptor = SvcAcceptor::<_,_, TEST_MSG_FRAME_SIZE>::new(
-///     ConId::svc(Some("doctest"), addr, None),
+///     ConId::svc(Some("doctest"), addr, None).unwrap(),
 ///     std::net::TcpListener::bind(addr).unwrap(),
 ///     DevNullCallback::default().into(),
 ///     SvcTestProtocolManual::default(),
@@ -874,21 +1023,87 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> From<Transm
 /// //assert!(false); // uncomment to see output
 /// ```
 #[derive(Debug)]
-pub struct TransmittingSvcAcceptorRef<P: Protocol + 'static, C: CallbackRecvSend<P> + 'static, const MAX_MSG_SIZE: usize> {
-    tx_recver: Sender<CltRecverRef<P, C, MAX_MSG_SIZE>>,
-    tx_sender: Sender<CltSenderRef<P, C, MAX_MSG_SIZE>>,
-    acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE>,
+pub struct TransmittingSvcAcceptorRef<P: Protocol + 'static, C: CallbackRecvSend<P> + 'static, const MAX_MSG_SIZE: usize, T: AcceptTransport = mio::net::TcpStream> {
+    tx_recver: Sender<CltRecverRef<P, C, MAX_MSG_SIZE, T>>,
+    tx_sender: Sender<CltSenderRef<P, C, MAX_MSG_SIZE, T>>,
+    acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE, T>,
+    poll_handler_group: Option<Weak<PollHandlerGroupDynamic>>,
+    hbeat_handler: Option<TimerHandle>,
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE> {
-    pub fn new(tx_recver: Sender<CltRecverRef<P, C, MAX_MSG_SIZE>>, tx_sender: Sender<CltSenderRef<P, C, MAX_MSG_SIZE>>, acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE>) -> Self {
-        Self { tx_recver, tx_sender, acceptor }
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T> {
+    pub fn new(tx_recver: Sender<CltRecverRef<P, C, MAX_MSG_SIZE, T>>, tx_sender: Sender<CltSenderRef<P, C, MAX_MSG_SIZE, T>>, acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE, T>) -> Self {
+        Self {
+            tx_recver,
+            tx_sender,
+            acceptor,
+            poll_handler_group: None,
+            hbeat_handler: None,
+        }
+    }
+    /// When polled via [PollAccept::poll_accept] every accepted [CltRecverRef] is placed on the [PollHandlerGroupDynamic] instead of being returned to the polling thread.
+    /// Only a weak reference to the `group` is kept and once the `group` is dropped [PollAccept::poll_accept] will yield an error
+    pub fn with_poll_handler_group(self, group: &Arc<PollHandlerGroupDynamic>) -> Self {
+        Self {
+            poll_handler_group: Some(Arc::downgrade(group)),
+            ..self
+        }
+    }
+    /// Heart beats of every accepted connection are scheduled on the `hbeat_handler` instead of the [crate::prelude::PollHandler] thread servicing it,
+    /// or [static@crate::connect::DEFAULT_HBEAT_HANDLER] if it is not serviced by one
+    pub fn with_hbeat_handler(self, hbeat_handler: &Timer) -> Self {
+        Self {
+            hbeat_handler: Some(hbeat_handler.handle()),
+            ..self
+        }
+    }
+    /// Heart beats are triggered by the `hbeat_handler` if set, otherwise by the [crate::prelude::PollHandler] thread when `is_polled` or else by [static@crate::connect::DEFAULT_HBEAT_HANDLER]
+    fn split_ref(&self, clt: Clt<P, C, MAX_MSG_SIZE, T>, is_polled: bool) -> (CltRecverRef<P, C, MAX_MSG_SIZE, T>, CltSenderRef<P, C, MAX_MSG_SIZE, T>) {
+        match (&self.hbeat_handler, is_polled) {
+            (Some(hbeat_handler), _) => clt.into_split_ref_with(Some(hbeat_handler)),
+            (None, true) => clt.into_split_ref_with(None),
+            (None, false) => clt.into_split_ref(),
+        }
+    }
+    /// Places every pending connection on the `group` and yields [AcceptStatus::WouldBlock] once there are no more connections to accept,
+    /// a connection the `group` fails to add is logged and dropped without affecting the acceptor
+    fn accept_into_poll_handler_group<R>(&mut self, group: &Weak<PollHandlerGroupDynamic>) -> Result<AcceptStatus<R>, Error> {
+        use AcceptStatus::{Accepted, Rejected, WouldBlock};
+        let group = match group.upgrade() {
+            Some(group) => group,
+            None => return Err(Error::new(ErrorKind::NotConnected, format!("{} PollHandlerGroup has been dropped", self))),
+        };
+        loop {
+            // a connection which the group fails to take is dropped on its own, the acceptor keeps accepting
+            match self.accept_recver_connect()? {
+                Accepted(PollAccepted::Recver(recver)) => {
+                    if let Err(e) = group.add_recver(recver) {
+                        if log_enabled!(Level::Warn) {
+                            warn!("{} dropping accepted recver which PollHandlerGroup failed to add, e: {}", self, e);
+                        }
+                    }
+                }
+                Accepted(PollAccepted::Connector(connector)) => {
+                    if let Err(e) = group.add_connector(connector) {
+                        if log_enabled!(Level::Warn) {
+                            warn!("{} dropping accepted connector which PollHandlerGroup failed to add, e: {}", self, e);
+                        }
+                    }
+                }
+                Rejected => continue,
+                WouldBlock => return Ok(WouldBlock),
+            }
+        }
     }
     /// Will interrogate the [SvcAcceptor] for new connections and if available will return [CltRecver] and send [CltSender] to the respective [CltSender] pools.
-    pub fn accept_recver(&mut self) -> Result<AcceptStatus<CltRecverRef<P, C, MAX_MSG_SIZE>>, Error> {
+    pub fn accept_recver(&mut self) -> Result<AcceptStatus<CltRecverRef<P, C, MAX_MSG_SIZE, T>>, Error> {
+        self.accept_recver_split(false)
+    }
+    /// Same as [TransmittingSvcAcceptorRef::accept_recver] but see [TransmittingSvcAcceptorRef::split_ref] for the meaning of `is_polled`
+    fn accept_recver_split(&mut self, is_polled: bool) -> Result<AcceptStatus<CltRecverRef<P, C, MAX_MSG_SIZE, T>>, Error> {
         use AcceptStatus::{Accepted, Rejected, WouldBlock};
         match self.acceptor.accept()? {
             Accepted(clt) => {
-                let (recver, sender) = clt.into_split_ref();
+                let (recver, sender) = self.split_ref(clt, is_polled);
 
                 if let Err(e) = self.tx_sender.send(sender) {
                     return Err(Error::new(ErrorKind::Other, e.to_string()));
@@ -899,14 +1114,27 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Transmittin
             WouldBlock => Ok(WouldBlock),
         }
     }
+    /// Same as [TransmittingSvcAcceptorRef::accept_recver] but see [PollAccept::poll_accept_connect]
+    fn accept_recver_connect<R: PollRead + From<CltRecverRef<P, C, MAX_MSG_SIZE, T>>>(&mut self) -> Result<AcceptStatus<PollAccepted<R>>, Error> {
+        let tx_sender = self.tx_sender.clone();
+        let hbeat_handler = self.hbeat_handler.clone();
+        self.acceptor.accept_connect(move |clt| {
+            // recver is serviced by a PollHandler which also triggers heart beats unless a hbeat_handler is set
+            let (recver, sender) = clt.into_split_ref_with(hbeat_handler.as_ref());
+            if let Err(e) = tx_sender.send(sender) {
+                return Err(Error::new(ErrorKind::Other, e.to_string()));
+            }
+            Ok(recver.into())
+        })
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolSvcAcceptorOfCltNonBlocking for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PoolSvcAcceptorOfCltNonBlocking for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T> {
     /// Will interrogate the [SvcAcceptor] for new connections and if available will send them to the respective [CltRecver] & [CltSender] pools.
     fn accept_into_pool(&mut self) -> Result<PoolAcceptStatus, Error> {
         use AcceptStatus::{Accepted, Rejected, WouldBlock};
         match self.acceptor.accept()? {
             Accepted(clt) => {
-                let (recver, sender) = clt.into_split_ref();
+                let (recver, sender) = self.split_ref(clt, false);
                 if let Err(e) = self.tx_recver.send(recver) {
                     return Err(Error::new(ErrorKind::Other, e.to_string()));
                 }
@@ -921,43 +1149,61 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolSvcAcce
         }
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollAble for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PollAble for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T> {
     fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
         Box::new(&mut self.acceptor.listener)
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ConnectionId for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> ConnectionId for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T> {
     fn con_id(&self) -> &ConId {
         self.acceptor.con_id()
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollAccept<CltRecverRef<P, C, MAX_MSG_SIZE>> for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE> {
-    fn poll_accept(&mut self) -> Result<AcceptStatus<CltRecverRef<P, C, MAX_MSG_SIZE>>, Error> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PollAccept<CltRecverRef<P, C, MAX_MSG_SIZE, T>> for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T> {
+    fn poll_accept(&mut self) -> Result<AcceptStatus<CltRecverRef<P, C, MAX_MSG_SIZE, T>>, Error> {
         use AcceptStatus::{Accepted, Rejected, WouldBlock};
-        match self.accept_recver()? {
+        if let Some(group) = self.poll_handler_group.clone() {
+            return self.accept_into_poll_handler_group(&group);
+        }
+        match self.accept_recver_split(true)? {
             Accepted(recver) => Ok(Accepted(recver)),
             Rejected => Ok(Rejected),
             WouldBlock => Ok(WouldBlock),
         }
     }
+    fn poll_accept_connect(&mut self) -> Result<AcceptStatus<PollAccepted<CltRecverRef<P, C, MAX_MSG_SIZE, T>>>, Error> {
+        if let Some(group) = self.poll_handler_group.clone() {
+            return self.accept_into_poll_handler_group(&group);
+        }
+        self.accept_recver_connect()
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PollAccept<Box<dyn PollRead>> for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PollAccept<Box<dyn PollRead>> for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T> {
     fn poll_accept(&mut self) -> Result<AcceptStatus<Box<dyn PollRead>>, Error> {
         use AcceptStatus::{Accepted, Rejected, WouldBlock};
-        match self.accept_recver()? {
+        if let Some(group) = self.poll_handler_group.clone() {
+            return self.accept_into_poll_handler_group(&group);
+        }
+        match self.accept_recver_split(true)? {
             Accepted(recver) => Ok(Accepted(Box::new(recver))),
             Rejected => Ok(Rejected),
             WouldBlock => Ok(WouldBlock),
         }
     }
+    fn poll_accept_connect(&mut self) -> Result<AcceptStatus<PollAccepted<Box<dyn PollRead>>>, Error> {
+        if let Some(group) = self.poll_handler_group.clone() {
+            return self.accept_into_poll_handler_group(&group);
+        }
+        self.accept_recver_connect()
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Display for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> Display for TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}<{}>", asserted_short_name!("TransmittingSvcAcceptorRef", Self), self.con_id())
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> From<TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE>> for Box<dyn PollAccept<Box<dyn PollRead>>> {
-    fn from(value: TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE>) -> Self {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> From<TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T>> for Box<dyn PollAccept<Box<dyn PollRead>>> {
+    fn from(value: TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T>) -> Self {
         Box::new(value)
     }
 }
@@ -972,10 +1218,60 @@ mod test {
         framer::TEST_MSG_FRAME_SIZE,
         model::{CltTestMsg, CltTestMsgDebug},
     };
-    use std::{io::ErrorKind, num::NonZeroUsize, time::Duration};
+    use std::{
+        fmt::Display,
+        io::{Error, ErrorKind},
+        num::NonZeroUsize,
+        sync::mpsc::channel,
+        time::Duration,
+    };
 
     use log::{info, LevelFilter};
 
+    /// Sends `fail_at` messages after which every send fails
+    #[derive(Debug)]
+    struct FailingSender {
+        sent: usize,
+        fail_at: usize,
+    }
+    impl SendNonBlocking<CltTestMsg> for FailingSender {
+        fn send(&mut self, _msg: &mut CltTestMsg) -> Result<SendStatus, Error> {
+            if self.sent == self.fail_at {
+                return Err(Error::new(ErrorKind::BrokenPipe, format!("{} failed after sent: {}", self, self.sent)));
+            }
+            self.sent += 1;
+            Ok(SendStatus::Completed)
+        }
+    }
+    impl ConnectionStatus for FailingSender {
+        fn is_connected(&self) -> bool {
+            true
+        }
+    }
+    impl Display for FailingSender {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "FailingSender<fail_at: {}>", self.fail_at)
+        }
+    }
+
+    #[test]
+    fn test_svcpool_send_batch_fails_mid_batch() {
+        setup::log::configure_compact(LevelFilter::Info);
+        let (tx_sender, rx_sender) = channel();
+        let mut pool = CltSendersPool::<CltTestProtocolManual, _>::new(ConId::default(), rx_sender, NonZeroUsize::new(1).unwrap());
+        tx_sender.send(FailingSender { sent: 0, fail_at: 3 }).unwrap();
+
+        let mut msgs = (0..5).map(|idx| CltTestMsgDebug::new(format!("Hello Frm Client Msg #{}", idx).as_bytes()).into()).collect::<Vec<_>>();
+        let err = pool.send_batch(&mut msgs).unwrap_err();
+        info!("err: {}", err);
+        // messages sent prior to the failure are reported so that they are not sent again
+        assert_eq!(err.sent, 3);
+        assert_eq!(err.kind(), ErrorKind::BrokenPipe);
+        // failed sender is dropped from the pool
+        assert!(pool.is_empty());
+        assert_eq!(pool.send_batch(&mut msgs).unwrap_err().kind(), ErrorKind::NotConnected);
+    }
+
     #[test]
     fn test_svcpool_cltpool_connected() {
         setup::log::configure_compact(LevelFilter::Info);
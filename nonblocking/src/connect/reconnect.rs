@@ -0,0 +1,785 @@
+use crate::prelude::{
+    asserted_short_name, CallbackRecvSend, Clt, CltConnector, CltRecverRef, CltSenderRef, ConId, ConnectTransport, ConnectionId, ConnectionStatus, Messenger, PendingCltSenderRef, PollAble, PollConnect, PollEventStatus, PollRead, PollWrite, Protocol,
+    SendNonBlocking, SendStatus, SocketOptions, SpawnedPollHandlerDynamic, Timer, TimerHandle, TimerTaskStatus,
+};
+use log::{info, log_enabled, warn};
+use std::{
+    fmt::{Debug, Display},
+    io::{Error, ErrorKind},
+    sync::{Arc, Weak},
+    time::{Duration, Instant},
+};
+
+/// Exponential backoff with jitter used by [ReconnectingClt] to space out reconnect attempts
+///
+/// The delay before attempt `n` is `initial_delay * multiplier^(n-1)` capped at `max_delay`, and then reduced by a random fraction of up to `jitter`
+/// to avoid a herd of clients reconnecting at the same instant
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconnectPolicy {
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: u32,
+    jitter: f64,
+    max_attempts: Option<usize>,
+}
+impl ReconnectPolicy {
+    /// Defaults to doubling the delay with each attempt, `10%` jitter and retrying indefinitely
+    pub fn new(initial_delay: Duration, max_delay: Duration) -> Self {
+        assert!(initial_delay <= max_delay, "initial_delay: {:?}, max_delay: {:?}", initial_delay, max_delay);
+        Self {
+            initial_delay,
+            max_delay,
+            multiplier: 2,
+            jitter: 0.1,
+            max_attempts: None,
+        }
+    }
+    pub fn with_multiplier(self, multiplier: u32) -> Self {
+        assert!(multiplier >= 1, "multiplier: {}", multiplier);
+        Self { multiplier, ..self }
+    }
+    /// `jitter` is a fraction of the delay in `[0.0, 1.0]`, `0.0` disables jitter
+    pub fn with_jitter(self, jitter: f64) -> Self {
+        assert!((0.0..=1.0).contains(&jitter), "jitter: {}", jitter);
+        Self { jitter, ..self }
+    }
+    /// Number of consecutive failed attempts after which [ReconnectingClt] gives up, [None] retries indefinitely
+    pub fn with_max_attempts(self, max_attempts: Option<usize>) -> Self {
+        Self { max_attempts, ..self }
+    }
+    pub fn max_attempts(&self) -> Option<usize> {
+        self.max_attempts
+    }
+    /// Delay to wait after `attempt` consecutive failed attempts, `attempt` is `1` based
+    pub fn delay(&self, attempt: usize) -> Duration {
+        let exp = u32::try_from(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        let factor = self.multiplier.checked_pow(exp).unwrap_or(u32::MAX);
+        let delay = self.initial_delay.checked_mul(factor).map_or(self.max_delay, |delay| delay.min(self.max_delay));
+        if self.jitter == 0.0 {
+            return delay;
+        }
+        delay.mul_f64(1.0 - self.jitter * rand::random::<f64>())
+    }
+}
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(100), Duration::from_secs(30))
+    }
+}
+
+/// Threads servicing a [ReconnectingClt], the poll thread runs every connection and completes reconnect attempts while the timer paces them
+#[derive(Clone)]
+struct ReconnectHandlers {
+    poll_handler: Option<Arc<SpawnedPollHandlerDynamic>>, // None stands for [static@crate::connect::DEFAULT_POLL_HANDLER]
+    reconnect_handler: TimerHandle,
+}
+impl ReconnectHandlers {
+    /// Uses [static@crate::connect::DEFAULT_POLL_HANDLER] and [static@crate::connect::DEFAULT_RECONNECT_HANDLER]
+    fn default_handlers() -> Self {
+        Self {
+            poll_handler: None,
+            reconnect_handler: crate::connect::DEFAULT_RECONNECT_HANDLER.handle(),
+        }
+    }
+    fn new(poll_handler: &Arc<SpawnedPollHandlerDynamic>, reconnect_handler: &Timer) -> Self {
+        Self {
+            poll_handler: Some(poll_handler.clone()),
+            reconnect_handler: reconnect_handler.handle(),
+        }
+    }
+    fn poll_handler(&self) -> &SpawnedPollHandlerDynamic {
+        match self.poll_handler {
+            Some(ref poll_handler) => poll_handler,
+            None => &crate::connect::DEFAULT_POLL_HANDLER,
+        }
+    }
+}
+impl Debug for ReconnectHandlers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReconnectHandlers")
+            .field("poll_handler", &self.poll_handler.as_ref().map_or("DEFAULT_POLL_HANDLER", |_| "SpawnedPollHandlerDynamic"))
+            .field("reconnect_handler", &self.reconnect_handler)
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+struct ReconnectingCltState<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> {
+    sender: Option<CltSenderRef<P, C, MAX_MSG_SIZE, T>>,
+    generation: usize, // incremented on every successful connect to tell apart disconnect notifications of previous connections
+    reconnecting: bool,
+    attempts: usize,
+}
+
+#[derive(Debug)]
+struct ReconnectingCltInner<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> {
+    con_id: ConId,
+    options: SocketOptions,
+    timeout: Duration, // time allowed for every reconnect attempt to establish the connection
+    #[cfg(feature = "tls")]
+    tls: Option<(Arc<rustls::ClientConfig>, rustls::pki_types::ServerName<'static>)>,
+    policy: ReconnectPolicy,
+    callback: Arc<C>,
+    protocol: P, // template which is cloned for every connection, hence only [crate::prelude::ProtocolSessionState] survives reconnects
+    handlers: ReconnectHandlers,
+    state: spin::Mutex<ReconnectingCltState<P, C, MAX_MSG_SIZE, T>>,
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> ReconnectingCltInner<P, C, MAX_MSG_SIZE, T> {
+    fn new(con_id: ConId, options: SocketOptions, timeout: Duration, policy: ReconnectPolicy, callback: Arc<C>, protocol: P, handlers: ReconnectHandlers) -> Self {
+        Self {
+            con_id,
+            options,
+            timeout,
+            #[cfg(feature = "tls")]
+            tls: None,
+            policy,
+            callback,
+            protocol,
+            handlers,
+            state: spin::Mutex::new(ReconnectingCltState {
+                sender: None,
+                generation: 0,
+                reconnecting: false,
+                attempts: 0,
+            }),
+        }
+    }
+    /// Moves [CltRecverRef] of the initial connection to run in the [ReconnectHandlers] poll thread and returns [CltSenderRef]
+    ///
+    /// # Important
+    /// If the poll handler rejects the connection [ReconnectingCltRecverRef] is dropped, which issues [Self::on_disconnected], hence must not be called while holding the `state` lock
+    fn spawn(self: &Arc<Self>, clt: Clt<P, C, MAX_MSG_SIZE, T>, generation: usize) -> Result<CltSenderRef<P, C, MAX_MSG_SIZE, T>, Error> {
+        let (recver, sender) = clt.into_split_ref_with(None); // heart beats are triggered by the poll thread
+        let recver = ReconnectingCltRecverRef {
+            clt_recver: recver,
+            inner: Arc::downgrade(self),
+            generation,
+        };
+        self.handlers.poll_handler().add_recver(Box::new(recver))?;
+        Ok(sender)
+    }
+    /// Drops the sender of the connection identified by `generation` and schedules reconnect attempts,
+    /// has no effect if that connection has already been replaced or the reconnect is already in progress
+    fn on_disconnected(self: &Arc<Self>, generation: usize) {
+        let sender = {
+            let mut state = self.state.lock();
+            if state.generation != generation || state.reconnecting {
+                return;
+            }
+            state.reconnecting = true;
+            state.attempts = 0;
+            state.sender.take()
+        };
+        // dropping outside of the lock since it will call ProtocolCore::on_disconnect
+        drop(sender);
+        if log_enabled!(log::Level::Info) {
+            info!("{} disconnected, scheduling reconnect using {:?}", self.con_id, self.policy);
+        }
+        self.schedule_reconnect(None);
+    }
+    /// Schedules [Self::reconnect] on the [ReconnectHandlers] timer to run after the `delay`, or immediately if [None]
+    fn schedule_reconnect(self: &Arc<Self>, delay: Option<Duration>) {
+        let inner = Arc::downgrade(self);
+        let mut delay = delay;
+        self.handlers.reconnect_handler.schedule(self.con_id.to_string().as_str(), self.policy.initial_delay, move || {
+            // newly scheduled tasks are executed immediately hence the delay is applied by deferring the first execution
+            if let Some(delay) = delay.take() {
+                return TimerTaskStatus::RetryAfter(delay);
+            }
+            if let Some(inner) = inner.upgrade() {
+                inner.reconnect();
+            }
+            TimerTaskStatus::Terminate
+        });
+    }
+    /// Initiates a non-blocking reconnect attempt using [CltConnector] which is completed by the [ReconnectHandlers] poll thread,
+    /// the outcome is reported by [ReconnectingCltConnector] to either [Self::on_connected] or [Self::on_reconnect_failed]
+    fn reconnect(self: &Arc<Self>) {
+        if !self.state.lock().reconnecting {
+            // gave up while this attempt was scheduled
+            return;
+        }
+        let (connector, pending) = match CltConnector::<P, C, MAX_MSG_SIZE, T>::from_con_id(self.con_id.clone(), &self.options, self.timeout, self.callback.clone(), self.protocol.clone()) {
+            Ok(connector) => connector,
+            Err(e) => return self.on_reconnect_failed(e),
+        };
+        #[cfg(feature = "tls")]
+        let connector = match self.tls.clone() {
+            Some((config, server_name)) => connector.with_tls(config, server_name),
+            None => connector,
+        };
+        let connector = ReconnectingCltConnector {
+            connector: Some(connector),
+            pending,
+            inner: Arc::downgrade(self),
+        };
+        // rejected connector is dropped which in turn reports the failed attempt
+        if let Err(e) = self.handlers.poll_handler().add_connector(Box::new(connector)) {
+            warn!("{} reconnect attempt rejected, err: {}", self.con_id, e);
+        }
+    }
+    /// Makes `sender` current and returns its generation, or [None] if no longer reconnecting in which case `sender` is dropped
+    fn on_connected(&self, sender: CltSenderRef<P, C, MAX_MSG_SIZE, T>) -> Option<usize> {
+        let mut state = self.state.lock();
+        if !state.reconnecting {
+            drop(state);
+            // dropping outside of the lock since it will call ProtocolCore::on_disconnect
+            drop(sender);
+            return None;
+        }
+        if log_enabled!(log::Level::Info) {
+            info!("{} reconnected after attempts: {}", sender.con_id(), state.attempts + 1);
+        }
+        state.generation += 1;
+        state.reconnecting = false;
+        state.sender = Some(sender); // always None while reconnecting
+        Some(state.generation)
+    }
+    /// Schedules the next attempt according to [ReconnectPolicy] or gives up once [ReconnectPolicy::max_attempts] is exhausted
+    fn on_reconnect_failed(self: &Arc<Self>, e: Error) {
+        let delay = {
+            let mut state = self.state.lock();
+            if !state.reconnecting {
+                return;
+            }
+            state.attempts += 1;
+            if self.policy.max_attempts.map_or(false, |max| state.attempts >= max) {
+                warn!("{} reconnect failed, giving up after attempts: {}, err: {}", self.con_id, state.attempts, e);
+                state.reconnecting = false;
+                return;
+            }
+            let delay = self.policy.delay(state.attempts);
+            if log_enabled!(log::Level::Info) {
+                info!("{} reconnect attempt: {} failed, will retry after: {:?}, err: {}", self.con_id, state.attempts, delay, e);
+            }
+            delay
+        };
+        self.schedule_reconnect(Some(delay));
+    }
+}
+
+/// Delegates to [CltConnector] and reports the outcome of a reconnect attempt to [ReconnectingClt], a failed attempt is reported once it is dropped
+/// by the [crate::prelude::PollHandler] or the thread completing the connection
+struct ReconnectingCltConnector<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> {
+    connector: Option<CltConnector<P, C, MAX_MSG_SIZE, T>>, // only None after into_recver
+    pending: PendingCltSenderRef<P, C, MAX_MSG_SIZE, T>,
+    inner: Weak<ReconnectingCltInner<P, C, MAX_MSG_SIZE, T>>,
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> PollConnect<Box<dyn PollRead>> for ReconnectingCltConnector<P, C, MAX_MSG_SIZE, T> {
+    #[inline(always)]
+    fn on_writable_event(&mut self) -> Result<PollEventStatus, Error> {
+        PollConnect::<CltRecverRef<P, C, MAX_MSG_SIZE, T>>::on_writable_event(self.connector.as_mut().expect("connector is only taken by into_recver"))
+    }
+    fn into_recver(mut self: Box<Self>) -> Result<Box<dyn PollRead>, Error> {
+        let connector = Box::new(self.connector.take().expect("connector is only taken by into_recver"));
+        // on failure the error is yielded by pending and reported once Self is dropped
+        let recver: CltRecverRef<P, C, MAX_MSG_SIZE, T> = connector.into_recver()?;
+        let sender = self.pending.try_take().expect("connector yields the sender once completed")?;
+        let inner = match self.inner.upgrade() {
+            Some(inner) => inner,
+            None => return Err(Error::new(ErrorKind::NotConnected, format!("{} ReconnectingClt has been dropped", self.pending.con_id()))),
+        };
+        match inner.on_connected(sender) {
+            Some(generation) => Ok(Box::new(ReconnectingCltRecverRef {
+                clt_recver: recver,
+                inner: self.inner.clone(),
+                generation,
+            })),
+            None => Err(Error::new(ErrorKind::NotConnected, format!("{} gave up reconnecting", self.pending.con_id()))),
+        }
+    }
+    #[inline(always)]
+    fn connect_deadline(&self) -> Option<Instant> {
+        PollConnect::<CltRecverRef<P, C, MAX_MSG_SIZE, T>>::connect_deadline(self.connector.as_ref().expect("connector is only taken by into_recver"))
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> PollAble for ReconnectingCltConnector<P, C, MAX_MSG_SIZE, T> {
+    fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
+        self.connector.as_mut().expect("connector is only taken by into_recver").source()
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> ConnectionId for ReconnectingCltConnector<P, C, MAX_MSG_SIZE, T> {
+    #[inline(always)]
+    fn con_id(&self) -> &ConId {
+        self.pending.con_id()
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> Display for ReconnectingCltConnector<P, C, MAX_MSG_SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}<{}>", asserted_short_name!("ReconnectingCltConnector", Self), self.pending.con_id())
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> Drop for ReconnectingCltConnector<P, C, MAX_MSG_SIZE, T> {
+    fn drop(&mut self) {
+        // CltConnector yields the failure once dropped, while a completed attempt has already taken the outcome
+        drop(self.connector.take());
+        if let Some(Err(e)) = self.pending.try_take() {
+            if let Some(inner) = self.inner.upgrade() {
+                inner.on_reconnect_failed(e);
+            }
+        }
+    }
+}
+
+/// Delegates to [CltRecverRef] and notifies [ReconnectingClt] once the [crate::prelude::PollHandler] drops it, which happens when the connection terminates
+struct ReconnectingCltRecverRef<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> {
+    clt_recver: CltRecverRef<P, C, MAX_MSG_SIZE, T>,
+    inner: Weak<ReconnectingCltInner<P, C, MAX_MSG_SIZE, T>>,
+    generation: usize,
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> PollRead for ReconnectingCltRecverRef<P, C, MAX_MSG_SIZE, T> {
+    #[inline(always)]
+    fn on_readable_event(&mut self) -> Result<PollEventStatus, Error> {
+        self.clt_recver.on_readable_event()
+    }
+    #[inline(always)]
+    fn recv_deadline(&self) -> Option<Instant> {
+        self.clt_recver.recv_deadline()
+    }
+    #[inline(always)]
+    fn on_recv_deadline(&mut self) -> Result<PollEventStatus, Error> {
+        self.clt_recver.on_recv_deadline()
+    }
+    #[inline(always)]
+    fn interests(&self) -> mio::Interest {
+        self.clt_recver.interests()
+    }
+    #[inline(always)]
+    fn as_poll_write(&mut self) -> Option<&mut dyn PollWrite> {
+        self.clt_recver.as_poll_write()
+    }
+    #[inline(always)]
+    fn timer_interval(&self) -> Option<Duration> {
+        self.clt_recver.timer_interval()
+    }
+    #[inline(always)]
+    fn on_timer(&mut self) -> Result<TimerTaskStatus, Error> {
+        self.clt_recver.on_timer()
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> PollAble for ReconnectingCltRecverRef<P, C, MAX_MSG_SIZE, T> {
+    fn register(&mut self, registry: &mio::Registry, token: mio::Token, interests: mio::Interest) -> Result<(), Error> {
+        self.clt_recver.register(registry, token, interests)
+    }
+    fn deregister(&mut self, registry: &mio::Registry) -> Result<(), Error> {
+        self.clt_recver.deregister(registry)
+    }
+    fn reregister(&mut self, registry: &mio::Registry, token: mio::Token, interests: mio::Interest) -> Result<(), Error> {
+        self.clt_recver.reregister(registry, token, interests)
+    }
+    fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
+        self.clt_recver.source()
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> ConnectionId for ReconnectingCltRecverRef<P, C, MAX_MSG_SIZE, T> {
+    #[inline(always)]
+    fn con_id(&self) -> &ConId {
+        self.clt_recver.con_id()
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> Display for ReconnectingCltRecverRef<P, C, MAX_MSG_SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}<{}>", asserted_short_name!("ReconnectingCltRecverRef", Self), self.clt_recver)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> Drop for ReconnectingCltRecverRef<P, C, MAX_MSG_SIZE, T> {
+    fn drop(&mut self) {
+        if let Some(inner) = self.inner.upgrade() {
+            inner.on_disconnected(self.generation);
+        }
+    }
+}
+
+/// A stable sender handle to a [Clt] which is automatically reconnected according to [ReconnectPolicy] when the connection terminates.
+///
+/// Every connection is established using a clone of the `protocol` provided to [ReconnectingClt::connect], which means [ProtocolCore::on_connect](crate::prelude::ProtocolCore::on_connect)
+/// is issued on every reconnect and any [crate::prelude::ProtocolSessionState] is retained, allowing sequenced protocols to resume the session,
+/// while [crate::prelude::ProtocolConnectionState] starts from a clean slate.
+///
+/// # Important
+/// * Every connection is split using [Clt::into_split_ref], its [CltRecverRef] runs in the [static@crate::connect::DEFAULT_POLL_HANDLER] thread, or the one given
+///   to the `*_on` variants such as [ReconnectingClt::connect_on], and messages are only delivered via [CallbackRecvSend], exactly like with [Clt::into_sender_with_spawned_recver_ref]
+/// * Reconnect attempts are paced by the [static@crate::connect::DEFAULT_RECONNECT_HANDLER] thread, or the [Timer] given to the `*_on` variants, for example
+///   [crate::prelude::Runtime::reconnect_handler], and established without blocking using [CltConnector],
+///   over the same [ConnectTransport], [SocketOptions] and tls configuration as the initial connection
+/// * While reconnecting [SendNonBlocking::send] yields [SendStatus::WouldBlock] and once [ReconnectPolicy::with_max_attempts] is exhausted it yields [ErrorKind::NotConnected]
+#[derive(Debug)]
+pub struct ReconnectingClt<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport = mio::net::TcpStream> {
+    inner: Arc<ReconnectingCltInner<P, C, MAX_MSG_SIZE, T>>,
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ReconnectingClt<P, C, MAX_MSG_SIZE> {
+    /// Establishes the initial connection using [Clt::connect] arguments and fails if it can't be established,
+    /// subsequent reconnects are paced using `policy` and each is given `timeout` to complete
+    pub fn connect(addr: &str, timeout: Duration, retry_after: Duration, policy: ReconnectPolicy, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<Self, Error> {
+        Self::connect_with(addr, timeout, retry_after, policy, callback, protocol, name, SocketOptions::default())
+    }
+    /// Same as [ReconnectingClt::connect] but every connection runs in the `poll_handler` thread and reconnect attempts are paced by the `reconnect_handler` thread,
+    /// for example those owned by a [crate::prelude::Runtime]
+    #[allow(clippy::too_many_arguments)]
+    pub fn connect_on(addr: &str, timeout: Duration, retry_after: Duration, policy: ReconnectPolicy, callback: Arc<C>, protocol: P, name: Option<&str>, poll_handler: &Arc<SpawnedPollHandlerDynamic>, reconnect_handler: &Timer) -> Result<Self, Error> {
+        Self::connect_with_on(addr, timeout, retry_after, policy, callback, protocol, name, SocketOptions::default(), poll_handler, reconnect_handler)
+    }
+    /// Same as [ReconnectingClt::connect] but every connection is configured using the provided [SocketOptions], see [Clt::connect_with]
+    #[allow(clippy::too_many_arguments)]
+    pub fn connect_with(addr: &str, timeout: Duration, retry_after: Duration, policy: ReconnectPolicy, callback: Arc<C>, protocol: P, name: Option<&str>, options: SocketOptions) -> Result<Self, Error> {
+        Self::connect_with_handlers(addr, timeout, retry_after, policy, callback, protocol, name, options, ReconnectHandlers::default_handlers())
+    }
+    /// Same as [ReconnectingClt::connect_with] but runs on the given threads, see [ReconnectingClt::connect_on]
+    #[allow(clippy::too_many_arguments)]
+    pub fn connect_with_on(
+        addr: &str,
+        timeout: Duration,
+        retry_after: Duration,
+        policy: ReconnectPolicy,
+        callback: Arc<C>,
+        protocol: P,
+        name: Option<&str>,
+        options: SocketOptions,
+        poll_handler: &Arc<SpawnedPollHandlerDynamic>,
+        reconnect_handler: &Timer,
+    ) -> Result<Self, Error> {
+        Self::connect_with_handlers(addr, timeout, retry_after, policy, callback, protocol, name, options, ReconnectHandlers::new(poll_handler, reconnect_handler))
+    }
+    #[allow(clippy::too_many_arguments)]
+    fn connect_with_handlers(addr: &str, timeout: Duration, retry_after: Duration, policy: ReconnectPolicy, callback: Arc<C>, protocol: P, name: Option<&str>, options: SocketOptions, handlers: ReconnectHandlers) -> Result<Self, Error> {
+        let clt = Clt::<P, C, MAX_MSG_SIZE>::connect_with(addr, timeout, retry_after, callback.clone(), protocol.clone(), name, options.clone())?;
+        let inner = ReconnectingCltInner::new(ConId::clt(name, None, addr)?, options, timeout, policy, callback, protocol, handlers);
+        Self::from_clt(inner, clt)
+    }
+    /// Same as [ReconnectingClt::connect] but every connection is secured using the provided [rustls::ClientConfig] and `server_name`, see [Clt::connect_tls]
+    #[cfg(feature = "tls")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn connect_tls(
+        addr: &str,
+        timeout: Duration,
+        retry_after: Duration,
+        policy: ReconnectPolicy,
+        callback: Arc<C>,
+        protocol: P,
+        name: Option<&str>,
+        config: Arc<rustls::ClientConfig>,
+        server_name: rustls::pki_types::ServerName<'static>,
+    ) -> Result<Self, Error> {
+        Self::connect_tls_with_handlers(addr, timeout, retry_after, policy, callback, protocol, name, config, server_name, ReconnectHandlers::default_handlers())
+    }
+    /// Same as [ReconnectingClt::connect_tls] but runs on the given threads, see [ReconnectingClt::connect_on]
+    #[cfg(feature = "tls")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn connect_tls_on(
+        addr: &str,
+        timeout: Duration,
+        retry_after: Duration,
+        policy: ReconnectPolicy,
+        callback: Arc<C>,
+        protocol: P,
+        name: Option<&str>,
+        config: Arc<rustls::ClientConfig>,
+        server_name: rustls::pki_types::ServerName<'static>,
+        poll_handler: &Arc<SpawnedPollHandlerDynamic>,
+        reconnect_handler: &Timer,
+    ) -> Result<Self, Error> {
+        let handlers = ReconnectHandlers::new(poll_handler, reconnect_handler);
+        Self::connect_tls_with_handlers(addr, timeout, retry_after, policy, callback, protocol, name, config, server_name, handlers)
+    }
+    #[cfg(feature = "tls")]
+    #[allow(clippy::too_many_arguments)]
+    fn connect_tls_with_handlers(
+        addr: &str,
+        timeout: Duration,
+        retry_after: Duration,
+        policy: ReconnectPolicy,
+        callback: Arc<C>,
+        protocol: P,
+        name: Option<&str>,
+        config: Arc<rustls::ClientConfig>,
+        server_name: rustls::pki_types::ServerName<'static>,
+        handlers: ReconnectHandlers,
+    ) -> Result<Self, Error> {
+        let clt = Clt::<P, C, MAX_MSG_SIZE>::connect_tls(addr, timeout, retry_after, callback.clone(), protocol.clone(), name, config.clone(), server_name.clone())?;
+        let inner = ReconnectingCltInner {
+            tls: Some((config, server_name)),
+            ..ReconnectingCltInner::new(ConId::clt(name, None, addr)?, SocketOptions::default(), timeout, policy, callback, protocol, handlers)
+        };
+        Self::from_clt(inner, clt)
+    }
+}
+#[cfg(target_family = "unix")]
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ReconnectingClt<P, C, MAX_MSG_SIZE, mio::net::UnixStream> {
+    /// Same as [ReconnectingClt::connect] but over a unix domain socket at the given filesystem `path`, see [Clt::connect_uds]
+    pub fn connect_uds(path: impl AsRef<std::path::Path>, timeout: Duration, retry_after: Duration, policy: ReconnectPolicy, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<Self, Error> {
+        Self::connect_uds_with_handlers(path.as_ref(), timeout, retry_after, policy, callback, protocol, name, ReconnectHandlers::default_handlers())
+    }
+    /// Same as [ReconnectingClt::connect_uds] but runs on the given threads, see [ReconnectingClt::connect_on]
+    #[allow(clippy::too_many_arguments)]
+    pub fn connect_uds_on(
+        path: impl AsRef<std::path::Path>,
+        timeout: Duration,
+        retry_after: Duration,
+        policy: ReconnectPolicy,
+        callback: Arc<C>,
+        protocol: P,
+        name: Option<&str>,
+        poll_handler: &Arc<SpawnedPollHandlerDynamic>,
+        reconnect_handler: &Timer,
+    ) -> Result<Self, Error> {
+        Self::connect_uds_with_handlers(path.as_ref(), timeout, retry_after, policy, callback, protocol, name, ReconnectHandlers::new(poll_handler, reconnect_handler))
+    }
+    #[allow(clippy::too_many_arguments)]
+    fn connect_uds_with_handlers(path: &std::path::Path, timeout: Duration, retry_after: Duration, policy: ReconnectPolicy, callback: Arc<C>, protocol: P, name: Option<&str>, handlers: ReconnectHandlers) -> Result<Self, Error> {
+        let clt = Clt::<P, C, MAX_MSG_SIZE, _>::connect_uds(path, timeout, retry_after, callback.clone(), protocol.clone(), name)?;
+        let inner = ReconnectingCltInner::new(ConId::clt_uds(name, path), SocketOptions::default(), timeout, policy, callback, protocol, handlers);
+        Self::from_clt(inner, clt)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> ReconnectingClt<P, C, MAX_MSG_SIZE, T> {
+    fn from_clt(inner: ReconnectingCltInner<P, C, MAX_MSG_SIZE, T>, clt: Clt<P, C, MAX_MSG_SIZE, T>) -> Result<Self, Error> {
+        let inner = Arc::new(inner);
+        let sender = inner.spawn(clt, 0)?;
+        inner.state.lock().sender = Some(sender);
+        Ok(Self { inner })
+    }
+    /// `true` while reconnect attempts are in progress
+    pub fn is_reconnecting(&self) -> bool {
+        self.inner.state.lock().reconnecting
+    }
+    /// The `protocol` instance which is cloned for every connection
+    pub fn protocol(&self) -> &P {
+        &self.inner.protocol
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> SendNonBlocking<P::SendT> for ReconnectingClt<P, C, MAX_MSG_SIZE, T> {
+    /// Delegates to [CltSenderRef] of the current connection, the connection is terminated and reconnect scheduled if the delegate call fails
+    #[inline(always)]
+    fn send(&mut self, msg: &mut <P as Messenger>::SendT) -> Result<SendStatus, Error> {
+        let (res, generation) = {
+            let mut guard = self.inner.state.lock();
+            let state = &mut *guard;
+            match state.sender {
+                Some(ref mut sender) => (sender.send(msg), state.generation),
+                None if state.reconnecting => return Ok(SendStatus::WouldBlock),
+                None => return Err(Error::new(ErrorKind::NotConnected, format!("{} gave up reconnecting after attempts: {}", self.inner.con_id, state.attempts))),
+            }
+        };
+        if res.is_err() {
+            self.inner.on_disconnected(generation);
+        }
+        res
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> ConnectionId for ReconnectingClt<P, C, MAX_MSG_SIZE, T> {
+    #[inline(always)]
+    fn con_id(&self) -> &ConId {
+        &self.inner.con_id
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> ConnectionStatus for ReconnectingClt<P, C, MAX_MSG_SIZE, T> {
+    #[inline(always)]
+    fn is_connected(&self) -> bool {
+        self.inner.state.lock().sender.as_ref().map_or(false, |sender| sender.is_connected())
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: ConnectTransport> Display for ReconnectingClt<P, C, MAX_MSG_SIZE, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.inner.state.lock();
+        match state.sender {
+            Some(ref sender) => write!(f, "{}<{}>", asserted_short_name!("ReconnectingClt", Self), sender),
+            None => write!(f, "{}<{}, reconnecting: {}, attempts: {}>", asserted_short_name!("ReconnectingClt", Self), self.inner.con_id, state.reconnecting, state.attempts),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use super::{ReconnectPolicy, ReconnectingClt};
+    use crate::{
+        prelude::*,
+        unittest::setup::{
+            connection::SvcTest,
+            protocol::{CltTestProtocolManual, SvcTestProtocolManual},
+        },
+    };
+    use links_core::unittest::setup::{self, framer::TEST_MSG_FRAME_SIZE, model::CltTestMsgDebug};
+    use log::info;
+    use std::{
+        io::ErrorKind,
+        num::NonZeroUsize,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    fn test_reconnect_policy() {
+        setup::log::configure();
+        let policy = ReconnectPolicy::new(Duration::from_millis(10), Duration::from_millis(100)).with_jitter(0.0);
+        let delays = (1..=6).map(|attempt| policy.delay(attempt)).collect::<Vec<_>>();
+        info!("delays: {:?}", delays);
+        assert_eq!(delays, [10, 20, 40, 80, 100, 100].map(Duration::from_millis));
+        assert_eq!(policy.delay(usize::MAX), Duration::from_millis(100));
+
+        let policy = policy.with_jitter(0.5);
+        for _ in 0..100 {
+            let delay = policy.delay(4);
+            assert!(delay > Duration::from_millis(40) && delay <= Duration::from_millis(80), "delay: {:?}", delay);
+        }
+    }
+
+    #[test]
+    fn test_reconnecting_clt() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let policy = ReconnectPolicy::new(Duration::from_millis(10), Duration::from_millis(50)).with_max_attempts(Some(20));
+
+        let svc_counter = CounterCallback::new_ref();
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), svc_counter.clone(), SvcTestProtocolManual, Some("unittest/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        let mut clt = ReconnectingClt::<_, _, TEST_MSG_FRAME_SIZE>::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            policy,
+            LoggerCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        info!("clt: {}", clt);
+        assert!(clt.is_connected());
+        clt.send_busywait_timeout(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into(), setup::net::default_find_timeout()).unwrap().unwrap_completed();
+        svc_counter.assert_recv_count_busywait_timeout(1, setup::net::default_find_timeout());
+
+        // svc going away terminates the connection and while svc is down sends yield WouldBlock
+        drop(svc);
+        let start = Instant::now();
+        while clt.is_connected() && start.elapsed() < setup::net::default_find_timeout() {}
+        info!("clt: {}", clt);
+        assert!(!clt.is_connected());
+        assert!(clt.is_reconnecting());
+        let status = clt.send(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+        assert_eq!(status, SendStatus::WouldBlock);
+
+        // same handle resumes sending once svc is back
+        let svc_counter = CounterCallback::new_ref();
+        let _svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), svc_counter.clone(), SvcTestProtocolManual, Some("unittest/svc/restart"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        clt.send_busywait_timeout(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into(), setup::net::default_find_timeout()).unwrap().unwrap_completed();
+        svc_counter.assert_recv_count_busywait_timeout(1, setup::net::default_find_timeout());
+        info!("clt: {}", clt);
+        assert!(clt.is_connected());
+        assert!(!clt.is_reconnecting());
+    }
+
+    #[test]
+    fn test_reconnecting_clt_on_runtime() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let policy = ReconnectPolicy::new(Duration::from_millis(10), Duration::from_millis(50)).with_max_attempts(Some(20));
+        let runtime = Runtime::new("unittest-reconnect").unwrap();
+
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        let mut clt = ReconnectingClt::<_, _, TEST_MSG_FRAME_SIZE>::connect_on(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            policy,
+            LoggerCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+            runtime.poll_handler(),
+            runtime.reconnect_handler(),
+        )
+        .unwrap();
+        info!("clt: {}, runtime: {}", clt, runtime);
+        assert!(clt.is_connected());
+        assert_eq!(runtime.poll_handler().connection_count(), 1);
+
+        // reconnected connection lands on the same runtime poll thread
+        drop(svc);
+        let start = Instant::now();
+        while clt.is_connected() && start.elapsed() < setup::net::default_find_timeout() {}
+        assert!(clt.is_reconnecting());
+        let svc_counter = CounterCallback::new_ref();
+        let _svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), svc_counter.clone(), SvcTestProtocolManual, Some("unittest/svc/restart"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        clt.send_busywait_timeout(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into(), setup::net::default_find_timeout()).unwrap().unwrap_completed();
+        svc_counter.assert_recv_count_busywait_timeout(1, setup::net::default_find_timeout());
+        info!("clt: {}, runtime: {}", clt, runtime);
+        assert!(clt.is_connected());
+        assert_eq!(runtime.poll_handler().connection_count(), 1);
+    }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn test_reconnecting_clt_uds() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        let path = setup::net::rand_avail_uds_path();
+        let policy = ReconnectPolicy::new(Duration::from_millis(10), Duration::from_millis(50)).with_max_attempts(Some(20));
+
+        let svc = Svc::<_, _, TEST_MSG_FRAME_SIZE, _>::bind_uds(&path, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        let mut clt = ReconnectingClt::<_, _, TEST_MSG_FRAME_SIZE, _>::connect_uds(
+            &path,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            policy,
+            LoggerCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        info!("clt: {}", clt);
+        assert!(clt.is_connected());
+
+        drop(svc);
+        std::fs::remove_file(&path).unwrap();
+        let start = Instant::now();
+        while clt.is_connected() && start.elapsed() < setup::net::default_find_timeout() {}
+        assert!(clt.is_reconnecting());
+
+        // reconnect is attempted over the same unix domain socket
+        let svc_counter = CounterCallback::new_ref();
+        let _svc = Svc::<_, _, TEST_MSG_FRAME_SIZE, _>::bind_uds(&path, NonZeroUsize::new(1).unwrap(), svc_counter.clone(), SvcTestProtocolManual, Some("unittest/svc/restart"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        clt.send_busywait_timeout(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into(), setup::net::default_find_timeout()).unwrap().unwrap_completed();
+        svc_counter.assert_recv_count_busywait_timeout(1, setup::net::default_find_timeout());
+        info!("clt: {}", clt);
+        assert!(clt.is_connected());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_reconnecting_clt_gives_up() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let policy = ReconnectPolicy::new(Duration::from_millis(10), Duration::from_millis(10)).with_max_attempts(Some(3));
+
+        let svc = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), DevNullCallback::new_ref(), SvcTestProtocolManual, Some("unittest/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        let mut clt = ReconnectingClt::<_, _, TEST_MSG_FRAME_SIZE>::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            policy,
+            DevNullCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest/clt"),
+        )
+        .unwrap();
+        drop(svc);
+        let start = Instant::now();
+        while clt.is_connected() && start.elapsed() < setup::net::default_find_timeout() {}
+        assert!(!clt.is_connected());
+
+        let res = clt.send_busywait_timeout(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into(), setup::net::default_find_timeout());
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::NotConnected);
+        assert!(!clt.is_reconnecting());
+    }
+}
@@ -0,0 +1,145 @@
+use crate::prelude::{asserted_short_name, PollHandlerDynamic, PollHandlerOptions, PollHandlerWatchdog, SpawnedPollHandlerDynamic, Timer};
+use log::warn;
+use std::{fmt::Display, sync::Arc};
+
+/// A context which owns its own [SpawnedPollHandlerDynamic] and heart beat, reconnect & watchdog [Timer] threads, use it instead of [static@crate::connect::DEFAULT_POLL_HANDLER],
+/// [static@crate::connect::DEFAULT_HBEAT_HANDLER], [static@crate::connect::DEFAULT_RECONNECT_HANDLER] and [static@crate::connect::DEFAULT_WATCHDOG_HANDLER] to isolate a set of connections,
+/// for example one per latency tier or one per test case, by passing [Runtime::poll_handler] to the `*_on` variants such as [crate::prelude::Clt::into_sender_with_spawned_recver_ref_on]
+/// and [crate::prelude::Svc::into_sender_with_spawned_recver_ref_on], which also trigger heart beats, [Runtime::hbeat_handler] to the variants which split a connection that is not serviced
+/// by a poll thread, such as [crate::prelude::Clt::into_split_ref_on], or [Runtime::poll_handler] together with [Runtime::reconnect_handler] to [crate::prelude::ReconnectingClt::connect_on].
+///
+/// # Important
+/// Dropping [Runtime] will deregister and drop every connection serviced by its poll thread and terminate all of its threads
+///
+/// # Example
+/// ```
+/// use links_nonblocking::prelude::*;
+/// use std::time::Duration;
+///
+/// let runtime = Runtime::new("doctest").unwrap();
+/// assert_eq!(runtime.poll_handler().connection_count(), 0);
+/// runtime.start_watchdog(PollHandlerWatchdog::new(Duration::from_millis(100)));
+/// ```
+pub struct Runtime {
+    name: String,
+    poll_handler: Arc<SpawnedPollHandlerDynamic>,
+    // Option so that Timer::terminate, which consumes the timer, can be called on drop
+    hbeat_handler: Option<Timer>,
+    reconnect_handler: Option<Timer>,
+    watchdog_handler: Option<Timer>,
+}
+impl Runtime {
+    /// Spawns `{name}-RecvPollHandler-Thread`, `{name}-HeartbeatHandler-Thread`, `{name}-ReconnectHandler-Thread` and `{name}-WatchdogHandler-Thread` threads
+    ///
+    /// # Errors
+    /// If the poll thread could not be spawned
+    pub fn new(name: &str) -> Result<Self, links_core::Error> {
+        Self::with_poll_handler_options(name, PollHandlerOptions::default())
+    }
+    /// Same as [Runtime::new] but the poll thread is configured using [PollHandlerOptions], see [crate::prelude::PollHandler::into_spawned_handler_with]
+    pub fn with_poll_handler_options(name: &str, options: PollHandlerOptions) -> Result<Self, links_core::Error> {
+        let poll_handler = PollHandlerDynamic::new()?.into_spawned_handler_with(format!("{}-RecvPollHandler-Thread", name).as_str(), options)?;
+        Ok(Self {
+            name: name.to_owned(),
+            poll_handler: Arc::new(poll_handler),
+            hbeat_handler: Some(Timer::new(format!("{}-HeartbeatHandler-Thread", name).as_str())),
+            reconnect_handler: Some(Timer::new(format!("{}-ReconnectHandler-Thread", name).as_str())),
+            watchdog_handler: Some(Timer::new(format!("{}-WatchdogHandler-Thread", name).as_str())),
+        })
+    }
+    pub fn poll_handler(&self) -> &Arc<SpawnedPollHandlerDynamic> {
+        &self.poll_handler
+    }
+    pub fn hbeat_handler(&self) -> &Timer {
+        self.hbeat_handler.as_ref().expect("Runtime hbeat_handler is only taken on drop")
+    }
+    pub fn reconnect_handler(&self) -> &Timer {
+        self.reconnect_handler.as_ref().expect("Runtime reconnect_handler is only taken on drop")
+    }
+    pub fn watchdog_handler(&self) -> &Timer {
+        self.watchdog_handler.as_ref().expect("Runtime watchdog_handler is only taken on drop")
+    }
+    /// Same as [crate::prelude::SpawnedPollHandler::start_watchdog] of [Runtime::poll_handler] using [Runtime::watchdog_handler],
+    /// it only reports if the runtime was created with [PollHandlerOptions::stats] enabled
+    pub fn start_watchdog(&self, watchdog: PollHandlerWatchdog) {
+        self.poll_handler.start_watchdog(self.watchdog_handler(), watchdog)
+    }
+}
+impl Display for Runtime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}<{}, connections: {}>", asserted_short_name!("Runtime", Self), self.name, self.poll_handler.connection_count())
+    }
+}
+impl Drop for Runtime {
+    fn drop(&mut self) {
+        if let Err(e) = self.poll_handler.shutdown(None) {
+            warn!("{}::drop {} failed to shutdown poll handler, error: {}", asserted_short_name!("Runtime", Self), self.name, e);
+        }
+        for timer in [self.hbeat_handler.take(), self.reconnect_handler.take(), self.watchdog_handler.take()].into_iter().flatten() {
+            timer.terminate();
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::{
+        prelude::*,
+        unittest::setup::{
+            connection::{CltTest, SvcTest},
+            protocol::{CltTestProtocolAuthAndHbeat, SvcTestProtocolAuthAndHBeat, HBEAT_INTERVAL},
+        },
+    };
+    use links_core::unittest::setup::{self, model::CltTestMsgDebug};
+    use log::{info, LevelFilter};
+    use std::{
+        num::NonZeroUsize,
+        thread::sleep,
+        time::{Duration, Instant},
+    };
+
+    #[test]
+    fn test_runtime_isolated_handlers() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let svc_runtime = Runtime::new("unittest-svc").unwrap();
+        let clt_runtime = Runtime::new("unittest-clt").unwrap();
+
+        let svc_count = CounterCallback::new_ref();
+        let mut svc_sender = SvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), svc_count.clone(), SvcTestProtocolAuthAndHBeat, Some("unittest/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref_on(svc_runtime.poll_handler())
+            .unwrap();
+        let mut clt_sender = CltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            DevNullCallback::new_ref(),
+            CltTestProtocolAuthAndHbeat,
+            Some("unittest/clt"),
+        )
+        .unwrap()
+        .into_sender_with_spawned_recver_ref_on(clt_runtime.poll_handler())
+        .unwrap();
+        assert!(svc_sender.all_connected_busywait_timeout(setup::net::default_find_timeout()));
+        info!("svc_runtime: {}, clt_runtime: {}", svc_runtime, clt_runtime);
+
+        clt_sender.send_busywait(&mut CltTestMsgDebug::new(b"Hello Frm Client Msg").into()).unwrap();
+        // login, debug & at least one heart beat issued by the clt_runtime poll thread
+        svc_count.assert_recv_count_busywait_timeout(3, HBEAT_INTERVAL * 2);
+
+        // acceptor & accepted connection live on the svc_runtime while the clt connection lives on the clt_runtime
+        assert_eq!(svc_runtime.poll_handler().snapshot().len(), 2);
+        assert_eq!(clt_runtime.poll_handler().snapshot().len(), 1);
+
+        // dropping the sender shuts down the acceptor lineage on the svc_runtime poll handler it lives on
+        drop(svc_sender);
+        let start = Instant::now();
+        while !svc_runtime.poll_handler().snapshot().is_empty() && start.elapsed() < setup::net::default_find_timeout() {
+            sleep(Duration::from_millis(1));
+        }
+        info!("svc_runtime: {}, snapshot: {:?}", svc_runtime, svc_runtime.poll_handler().snapshot());
+        assert!(svc_runtime.poll_handler().snapshot().is_empty());
+    }
+}
@@ -7,26 +7,18 @@ use crate::prelude::*;
 
 use super::{clt::CltRecverRef, pool::TransmittingSvcAcceptorRef};
 
-pub type SvcRecver<P, C, const MAX_MSG_SIZE: usize> = CltRecversPool<P, CltRecver<P, C, MAX_MSG_SIZE>>;
-pub type SvcSender<P, C, const MAX_MSG_SIZE: usize> = CltSendersPool<P, CltSender<P, C, MAX_MSG_SIZE>>;
+pub type SvcRecver<P, C, const MAX_MSG_SIZE: usize, T = mio::net::TcpStream> = CltRecversPool<P, CltRecver<P, C, MAX_MSG_SIZE, T>>;
+pub type SvcSender<P, C, const MAX_MSG_SIZE: usize, T = mio::net::TcpStream> = CltSendersPool<P, CltSender<P, C, MAX_MSG_SIZE, T>>;
 
-pub type SvcRecverRef<P, C, const MAX_MSG_SIZE: usize> = CltRecversPool<P, CltRecverRef<P, C, MAX_MSG_SIZE>>;
-pub type SvcSenderRef<P, C, const MAX_MSG_SIZE: usize> = CltSendersPool<P, CltSenderRef<P, C, MAX_MSG_SIZE>>;
+pub type SvcRecverRef<P, C, const MAX_MSG_SIZE: usize, T = mio::net::TcpStream> = CltRecversPool<P, CltRecverRef<P, C, MAX_MSG_SIZE, T>>;
+pub type SvcSenderRef<P, C, const MAX_MSG_SIZE: usize, T = mio::net::TcpStream> = CltSendersPool<P, CltSenderRef<P, C, MAX_MSG_SIZE, T>>;
 
-pub const SVC_MAX_CONNECTIONS_2_POOL_SIZE_FACTOR: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(2) };
+type SplitSvc<P, C, const MAX_MSG_SIZE: usize, T> = (TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE, T>, SvcRecver<P, C, MAX_MSG_SIZE, T>, SvcSender<P, C, MAX_MSG_SIZE, T>);
+type SplitSvcRef<P, C, const MAX_MSG_SIZE: usize, T> = (TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T>, SvcRecverRef<P, C, MAX_MSG_SIZE, T>, SvcSenderRef<P, C, MAX_MSG_SIZE, T>);
 
-#[cfg(target_family = "unix")]
-fn into_std_from_mio(stream: mio::net::TcpStream) -> std::net::TcpStream {
-    use std::os::fd::{FromRawFd, IntoRawFd};
-    unsafe { std::net::TcpStream::from_raw_fd(stream.into_raw_fd()) }
-}
-#[cfg(target_family = "windows")]
-fn into_std_from_mio(stream: mio::net::TcpStream) -> std::net::TcpStream {
-    use std::os::windows::io::{FromRawSocket, IntoRawSocket};
-    unsafe { std::net::TcpStream::from_raw_socket(stream.into_raw_socket()) }
-}
+pub const SVC_MAX_CONNECTIONS_2_POOL_SIZE_FACTOR: NonZeroUsize = unsafe { NonZeroUsize::new_unchecked(2) };
 
-/// Helper class that create [Clt] instances by accepting new connections on a [std::net::TcpListener]
+/// Helper class that create [Clt] instances by accepting new connections on a [AcceptTransport::Listener], which defaults to a [mio::net::TcpListener]
 ///
 /// # Example
 /// ```
@@ -36,7 +28,7 @@ fn into_std_from_mio(stream: mio::net::TcpStream) -> std::net::TcpStream {
 ///
 /// let addr = setup::net::rand_avail_addr_port(); // "127.0.0.1:8080" generates random port
 /// let acceptor = SvcAcceptor::<_, _, TEST_MSG_FRAME_SIZE>::new(
-///     ConId::svc(Some("doctest"), addr, None),
+///     ConId::svc(Some("doctest"), addr, None).unwrap(),
 ///     std::net::TcpListener::bind(addr).unwrap(),
 ///     DevNullCallback::default().into(),
 ///     SvcTestProtocolManual::default(),
@@ -48,83 +40,246 @@ fn into_std_from_mio(stream: mio::net::TcpStream) -> std::net::TcpStream {
 ///
 /// ```
 #[derive(Debug)]
-pub struct SvcAcceptor<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> {
+pub struct SvcAcceptor<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport = mio::net::TcpStream> {
     con_id: ConId,
-    pub(crate) listener: mio::net::TcpListener,
+    pub(crate) listener: T::Listener,
     acceptor_limiter: AcceptorConnectionGate,
     callback: Arc<C>,
     protocol: P,
+    socket_options: SocketOptions,
+    #[cfg(feature = "tls")]
+    tls: Option<Arc<rustls::ServerConfig>>,
+    #[cfg(feature = "tls")]
+    tls_handshake_timeout: std::time::Duration,
 }
 impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> SvcAcceptor<P, C, MAX_MSG_SIZE> {
     pub fn new(con_id: ConId, listener: std::net::TcpListener, callback: Arc<C>, protocol: P, max_connections: NonZeroUsize) -> Self {
         listener.set_nonblocking(true).expect("Failed to set nonblocking on listener");
+        Self::from_listener(con_id, mio::net::TcpListener::from_std(listener), callback, protocol, max_connections)
+    }
+    /// Same as [SvcAcceptor::new] but every accepted connection will perform a tls handshake using the provided [rustls::ServerConfig]
+    ///
+    /// # Important
+    /// The handshake is performed by the thread calling [SvcAcceptor::accept], which will busy wait until it completes or [SvcAcceptor::with_tls_handshake_timeout] elapses,
+    /// connections failing to complete it are [AcceptStatus::Rejected]. When polled by a [PollHandler] the handshake, along with [ProtocolCore::on_connect], is instead
+    /// completed by the connect worker thread of the [PollHandler], see [PollAccept::poll_accept_connect]
+    #[cfg(feature = "tls")]
+    pub fn new_tls(con_id: ConId, listener: std::net::TcpListener, callback: Arc<C>, protocol: P, max_connections: NonZeroUsize, config: Arc<rustls::ServerConfig>) -> Self {
+        Self {
+            tls: Some(config),
+            ..Self::new(con_id, listener, callback, protocol, max_connections)
+        }
+    }
+}
+#[cfg(target_family = "unix")]
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> SvcAcceptor<P, C, MAX_MSG_SIZE, mio::net::UnixStream> {
+    /// Same as [SvcAcceptor::new] but accepts connections on a unix domain socket, `con_id` is expected to be [ConId::UdsAcceptor]
+    pub fn new_uds(con_id: ConId, listener: std::os::unix::net::UnixListener, callback: Arc<C>, protocol: P, max_connections: NonZeroUsize) -> Self {
+        listener.set_nonblocking(true).expect("Failed to set nonblocking on listener");
+        Self::from_listener(con_id, mio::net::UnixListener::from_std(listener), callback, protocol, max_connections)
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> SvcAcceptor<P, C, MAX_MSG_SIZE, T> {
+    /// Creates an acceptor from an already bound `non blocking` [AcceptTransport::Listener]
+    pub fn from_listener(con_id: ConId, listener: T::Listener, callback: Arc<C>, protocol: P, max_connections: NonZeroUsize) -> Self {
         Self {
             con_id,
-            listener: mio::net::TcpListener::from_std(listener),
+            listener,
             acceptor_limiter: AcceptorConnectionGate::new(max_connections),
             callback,
             protocol,
+            socket_options: SocketOptions::default(),
+            #[cfg(feature = "tls")]
+            tls: None,
+            #[cfg(feature = "tls")]
+            tls_handshake_timeout: crate::core::tls::TLS_HANDSHAKE_TIMEOUT,
         }
     }
-}
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> SvcAcceptorOfCltNonBlocking<Clt<P, C, MAX_MSG_SIZE>> for SvcAcceptor<P, C, MAX_MSG_SIZE> {
-    fn accept(&self) -> Result<AcceptStatus<Clt<P, C, MAX_MSG_SIZE>>, Error> {
-        match self.listener.accept() {
-            Ok((stream, addr)) => {
-                let stream_dbg = format!("{:?}", stream);
-                match self.acceptor_limiter.increment() {
-                    Ok(()) => {}
-                    Err(e) => {
-                        if log_enabled!(log::Level::Warn) {
-                            warn!("{} Rejected stream: {} due to error: {}", self.con_id, stream_dbg, e);
-                        }
-                        return Ok(AcceptStatus::Rejected);
-                    }
+    /// Maximum time a client is given to complete the tls handshake after its connection was accepted, defaults to [crate::prelude::TLS_HANDSHAKE_TIMEOUT]
+    #[cfg(feature = "tls")]
+    pub fn with_tls_handshake_timeout(self, tls_handshake_timeout: std::time::Duration) -> Self {
+        Self { tls_handshake_timeout, ..self }
+    }
+    /// Every accepted connection will be configured using the provided [SocketOptions], connections failing to apply them are [AcceptStatus::Rejected]
+    ///
+    /// # Note
+    /// [SocketOptions::local_addr] is ignored as accepted connections are bound to the listener's address
+    pub fn with_socket_options(self, socket_options: SocketOptions) -> Self {
+        Self { socket_options, ..self }
+    }
+    /// [SocketOptions] applied to every accepted connection, use [Clt::socket_options] to inspect the effective values of a given connection
+    pub fn socket_options(&self) -> &SocketOptions {
+        &self.socket_options
+    }
+    fn on_accepted<X>(&self, con_id: ConId, stream_dbg: String, from_stream: impl FnOnce(ConId, Option<RemoveConnectionBarrierOnDrop>) -> Result<X, Error>) -> AcceptStatus<X> {
+        match self.acceptor_limiter.increment() {
+            Ok(()) => {}
+            Err(e) => {
+                if log_enabled!(log::Level::Warn) {
+                    warn!("{} Rejected stream: {} due to error: {}", self.con_id, stream_dbg, e);
                 }
-
-                let stream = into_std_from_mio(stream);
-
-                let con_id = {
-                    let mut con_id = self.con_id.clone();
-                    con_id.set_peer(addr);
-                    if log_enabled!(log::Level::Debug) {
-                        debug!("{} Accepted", con_id);
-                    };
-                    con_id
-                };
-                let acceptor_connection_gate = Some(self.acceptor_limiter.get_new_connection_barrier());
-                match Clt::<P, C, MAX_MSG_SIZE>::from_stream(stream, con_id, self.callback.clone(), self.protocol.clone(), acceptor_connection_gate) {
-                    Ok(clt) => Ok(AcceptStatus::Accepted(clt)),
-                    Err(e) => {
-                        if log_enabled!(log::Level::Warn) {
-                            warn!("{} Rejected stream: {} due to error: {}", self.con_id, stream_dbg, e);
-                        }
-                        Ok(AcceptStatus::Rejected)
-                    }
+                return AcceptStatus::Rejected;
+            }
+        }
+        if log_enabled!(log::Level::Debug) {
+            debug!("{} Accepted", con_id);
+        };
+        let acceptor_connection_gate = Some(self.acceptor_limiter.get_new_connection_barrier());
+        match from_stream(con_id, acceptor_connection_gate) {
+            Ok(clt) => AcceptStatus::Accepted(clt),
+            Err(e) => {
+                if log_enabled!(log::Level::Warn) {
+                    warn!("{} Rejected stream: {} due to error: {}", self.con_id, stream_dbg, e);
                 }
+                AcceptStatus::Rejected
             }
+        }
+    }
+    fn clt_from_transport(&self, transport: T, con_id: ConId, acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>) -> Result<Clt<P, C, MAX_MSG_SIZE, T>, Error> {
+        transport.apply_socket_options(&self.socket_options)?;
+        #[cfg(feature = "tls")]
+        if let Some(config) = &self.tls {
+            return Self::clt_from_tls_transport(transport, con_id, acceptor_connection_gate, self.callback.clone(), self.protocol.clone(), config.clone(), self.tls_handshake_timeout);
+        }
+        let (msg_recver, msg_sender) = into_split_messenger_transport::<P, MAX_MSG_SIZE, T>(con_id, transport)?;
+        Clt::from_messenger(msg_recver, msg_sender, self.callback.clone(), self.protocol.clone(), acceptor_connection_gate)
+    }
+    #[cfg(feature = "tls")]
+    fn clt_from_tls_transport(
+        mut transport: T,
+        con_id: ConId,
+        acceptor_connection_gate: Option<RemoveConnectionBarrierOnDrop>,
+        callback: Arc<C>,
+        protocol: P,
+        config: Arc<rustls::ServerConfig>,
+        timeout: std::time::Duration,
+    ) -> Result<Clt<P, C, MAX_MSG_SIZE, T>, Error> {
+        let connection = rustls::ServerConnection::new(config).map_err(|e| Error::new(std::io::ErrorKind::InvalidInput, format!("{} tls server config error: {}", con_id, e)))?;
+        let tls = crate::core::tls::TlsSession::handshake(connection, &mut transport, timeout).map_err(|e| Error::new(e.kind(), format!("{} tls handshake failed: {}", con_id, e)))?;
+        Clt::from_tls_transport(transport, con_id, tls, callback, protocol, acceptor_connection_gate)
+    }
+    /// Same as [SvcAcceptorOfCltNonBlocking::accept] except that the tls handshake, along with [ProtocolCore::on_connect], of a connection accepted by a tls acceptor
+    /// is deferred to the yielded [PollAccepted::Connector] so that it does not block the polling thread, see [PollAccept::poll_accept_connect].
+    /// `into_recver` converts the established [Clt] into the [PollRead] to be serviced
+    pub(crate) fn accept_connect<R: PollRead>(&self, into_recver: impl FnOnce(Clt<P, C, MAX_MSG_SIZE, T>) -> Result<R, Error> + Send + 'static) -> Result<AcceptStatus<PollAccepted<R>>, Error> {
+        use AcceptStatus::{Accepted, Rejected, WouldBlock};
+        #[cfg(feature = "tls")]
+        if let Some(config) = self.tls.clone() {
+            let (transport, con_id) = match T::accept(&self.listener, &self.con_id) {
+                Ok(accepted) => accepted,
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => return Ok(WouldBlock),
+                Err(e) => return Err(e),
+            };
+            let stream_dbg = format!("{:?}", transport);
+            let (callback, protocol, timeout) = (self.callback.clone(), self.protocol.clone(), self.tls_handshake_timeout);
+            return Ok(self.on_accepted(con_id, stream_dbg, |con_id, acceptor_connection_gate| {
+                transport.apply_socket_options(&self.socket_options)?;
+                let connector = TlsAccepted {
+                    con_id: con_id.clone(),
+                    transport,
+                    complete: Box::new(move |transport| into_recver(Self::clt_from_tls_transport(transport, con_id, acceptor_connection_gate, callback, protocol, config, timeout)?)),
+                };
+                Ok(PollAccepted::Connector(Box::new(connector)))
+            }));
+        }
+        match self.accept()? {
+            Accepted(clt) => Ok(Accepted(PollAccepted::Recver(into_recver(clt)?))),
+            Rejected => Ok(Rejected),
+            WouldBlock => Ok(WouldBlock),
+        }
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> SvcAcceptorOfCltNonBlocking<Clt<P, C, MAX_MSG_SIZE, T>> for SvcAcceptor<P, C, MAX_MSG_SIZE, T> {
+    fn accept(&self) -> Result<AcceptStatus<Clt<P, C, MAX_MSG_SIZE, T>>, Error> {
+        let res = T::accept(&self.listener, &self.con_id).map(|(transport, con_id)| {
+            let stream_dbg = format!("{:?}", transport);
+            self.on_accepted(con_id, stream_dbg, |con_id, acceptor_connection_gate| self.clt_from_transport(transport, con_id, acceptor_connection_gate))
+        });
+        match res {
+            Ok(status) => Ok(status),
             Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => Ok(AcceptStatus::WouldBlock),
             Err(e) => Err(e),
         }
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ConnectionId for SvcAcceptor<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> ConnectionId for SvcAcceptor<P, C, MAX_MSG_SIZE, T> {
     fn con_id(&self) -> &ConId {
         &self.con_id
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Display for SvcAcceptor<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> Display for SvcAcceptor<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let recv_t = std::any::type_name::<P::RecvT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         let send_t = std::any::type_name::<P::SendT>().split("::").last().unwrap_or("Unknown").replace('>', "");
         write!(f, "{}<{}, RecvT:{}, SendT:{}, {}>", asserted_short_name!("SvcAcceptor", Self), self.con_id, recv_t, send_t, MAX_MSG_SIZE)
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> From<Svc<P, C, MAX_MSG_SIZE>> for SvcAcceptor<P, C, MAX_MSG_SIZE> {
-    fn from(svc: Svc<P, C, MAX_MSG_SIZE>) -> Self {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> From<Svc<P, C, MAX_MSG_SIZE, T>> for SvcAcceptor<P, C, MAX_MSG_SIZE, T> {
+    fn from(svc: Svc<P, C, MAX_MSG_SIZE, T>) -> Self {
         svc.acceptor
     }
 }
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PollAble for SvcAcceptor<P, C, MAX_MSG_SIZE, T> {
+    fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
+        Box::new(&mut self.listener)
+    }
+}
+/// Every accepted [Clt] is serviced as a whole by the [PollHandler], which supports `all` [Protocol] features including [Protocol::send_heart_beat] without
+/// involving a lock or an extra thread, at the cost of the connection only sending from within [Protocol] hooks such as [Protocol::send_reply]
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PollAccept<Box<dyn PollRead>> for SvcAcceptor<P, C, MAX_MSG_SIZE, T> {
+    fn poll_accept(&mut self) -> Result<AcceptStatus<Box<dyn PollRead>>, Error> {
+        use AcceptStatus::{Accepted, Rejected, WouldBlock};
+        match self.accept()? {
+            Accepted(clt) => Ok(Accepted(Box::new(clt))),
+            Rejected => Ok(Rejected),
+            WouldBlock => Ok(WouldBlock),
+        }
+    }
+    fn poll_accept_connect(&mut self) -> Result<AcceptStatus<PollAccepted<Box<dyn PollRead>>>, Error> {
+        self.accept_connect(|clt| Ok(clt.into()))
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> From<SvcAcceptor<P, C, MAX_MSG_SIZE, T>> for Box<dyn PollAccept<Box<dyn PollRead>>> {
+    fn from(value: SvcAcceptor<P, C, MAX_MSG_SIZE, T>) -> Self {
+        Box::new(value)
+    }
+}
+
+/// A connection accepted by a tls [SvcAcceptor] whose handshake, along with [ProtocolCore::on_connect], is completed by [PollConnect::into_recver]
+#[cfg(feature = "tls")]
+struct TlsAccepted<R: PollRead, T: AcceptTransport> {
+    con_id: ConId,
+    transport: T,
+    complete: Box<dyn FnOnce(T) -> Result<R, Error> + Send>,
+}
+#[cfg(feature = "tls")]
+impl<R: PollRead, T: AcceptTransport> PollConnect<R> for TlsAccepted<R, T> {
+    /// The connection is already established
+    fn on_writable_event(&mut self) -> Result<PollEventStatus, Error> {
+        Ok(PollEventStatus::Completed)
+    }
+    fn into_recver(self: Box<Self>) -> Result<R, Error> {
+        (self.complete)(self.transport)
+    }
+}
+#[cfg(feature = "tls")]
+impl<R: PollRead, T: AcceptTransport> PollAble for TlsAccepted<R, T> {
+    fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
+        Box::new(&mut self.transport)
+    }
+}
+#[cfg(feature = "tls")]
+impl<R: PollRead, T: AcceptTransport> ConnectionId for TlsAccepted<R, T> {
+    fn con_id(&self) -> &ConId {
+        &self.con_id
+    }
+}
+#[cfg(feature = "tls")]
+impl<R: PollRead, T: AcceptTransport> Display for TlsAccepted<R, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}<{}>", asserted_short_name!("TlsAccepted", Self), self.con_id)
+    }
+}
 
 /// An abstraction over [MessageRecver] and [MessageSender] that calls a respective callback on every
 /// message being processed by internal pool of [Clt]'s managed by [CltsPool]
@@ -155,20 +310,50 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> From<Svc<P,
 /// assert_eq!(err.kind(), ErrorKind::NotConnected);
 /// ```
 #[derive(Debug)]
-pub struct Svc<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> {
-    acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE>,
-    clts_pool: CltsPool<P, C, MAX_MSG_SIZE>,
+pub struct Svc<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport = mio::net::TcpStream> {
+    acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE, T>,
+    clts_pool: CltsPool<P, C, MAX_MSG_SIZE, T>,
 }
 impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Svc<P, C, MAX_MSG_SIZE> {
     /// Binds to a given address and returns an instance [Svc]
     pub fn bind(addr: &str, max_connections: NonZeroUsize, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<Self, Error> {
-        let acceptor = SvcAcceptor::new(ConId::svc(name, addr, None), std::net::TcpListener::bind(addr)?, callback, protocol, max_connections);
+        let acceptor = SvcAcceptor::new(ConId::svc(name, addr, None)?, std::net::TcpListener::bind(addr)?, callback, protocol, max_connections);
+        Ok(Self::from_acceptor(acceptor, max_connections))
+    }
+    /// Same as [Svc::bind] but every accepted connection will be configured using the provided [SocketOptions], see [SvcAcceptor::with_socket_options] for details.
+    /// The listener is bound using [SocketOptions::bind] so that the buffer sizes are in effect while accepted connections are being established
+    pub fn bind_with(addr: &str, max_connections: NonZeroUsize, callback: Arc<C>, protocol: P, name: Option<&str>, options: SocketOptions) -> Result<Self, Error> {
+        let acceptor = SvcAcceptor::new(ConId::svc(name, addr, None)?, options.bind(addr)?, callback, protocol, max_connections).with_socket_options(options);
+        Ok(Self::from_acceptor(acceptor, max_connections))
+    }
+    /// Same as [Svc::bind] but every accepted connection will be secured using the provided [rustls::ServerConfig], see [SvcAcceptor::new_tls] for details
+    #[cfg(feature = "tls")]
+    pub fn bind_tls(addr: &str, max_connections: NonZeroUsize, callback: Arc<C>, protocol: P, name: Option<&str>, config: Arc<rustls::ServerConfig>) -> Result<Self, Error> {
+        let acceptor = SvcAcceptor::new_tls(ConId::svc(name, addr, None)?, std::net::TcpListener::bind(addr)?, callback, protocol, max_connections, config);
+        Ok(Self::from_acceptor(acceptor, max_connections))
+    }
+}
+#[cfg(target_family = "unix")]
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Svc<P, C, MAX_MSG_SIZE, mio::net::UnixStream> {
+    /// Same as [Svc::bind] but listens on a unix domain socket at the given filesystem `path`, see [Clt::connect_uds]
+    ///
+    /// # Important
+    /// The socket file is not removed when [Svc] is dropped, it is the caller's responsibility to remove a stale file before binding again
+    pub fn bind_uds(path: impl AsRef<std::path::Path>, max_connections: NonZeroUsize, callback: Arc<C>, protocol: P, name: Option<&str>) -> Result<Self, Error> {
+        let acceptor = SvcAcceptor::new_uds(ConId::svc_uds(name, path.as_ref()), std::os::unix::net::UnixListener::bind(path.as_ref())?, callback, protocol, max_connections);
+        Ok(Self::from_acceptor(acceptor, max_connections))
+    }
+}
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> Svc<P, C, MAX_MSG_SIZE, T> {
+    /// Creates an instance [Svc] which will accept connections using the provided [SvcAcceptor], `max_connections` is expected to match the one
+    /// the `acceptor` was created with
+    pub fn from_acceptor(acceptor: SvcAcceptor<P, C, MAX_MSG_SIZE, T>, max_connections: NonZeroUsize) -> Self {
         // make pool twice as big as acceptor will allow to be opened this is to ensure that acceptor is able to add new connections to the pool even
         // if some of the connections in the pool are dead but not closed yet
         let pool_size = max_connections.checked_mul(SVC_MAX_CONNECTIONS_2_POOL_SIZE_FACTOR).expect("pool size overflow");
 
         let clts_pool = CltsPool::new(acceptor.con_id().clone(), pool_size);
-        Ok(Self { acceptor, clts_pool })
+        Self { acceptor, clts_pool }
     }
     #[inline(always)]
     pub fn len(&self) -> usize {
@@ -179,14 +364,15 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Svc<P, C, M
         self.clts_pool.is_empty()
     }
     #[inline(always)]
-    pub fn pool(&self) -> &CltsPool<P, C, MAX_MSG_SIZE> {
+    pub fn pool(&self) -> &CltsPool<P, C, MAX_MSG_SIZE, T> {
         &self.clts_pool
     }
     /// Will split [Svc] into owned [TransmittingSvcAcceptor], [SvcRecver] and [SvcSender]
     ///
     /// # Important
-    /// These parts will support only 'subset' of [Protocol] features which are part of [crate::prelude::ProtocolCore] trait
-    pub fn into_split(self) -> (TransmittingSvcAcceptor<P, C, MAX_MSG_SIZE>, SvcRecver<P, C, MAX_MSG_SIZE>, SvcSender<P, C, MAX_MSG_SIZE>) {
+    /// These parts will support only 'subset' of [Protocol] features which are part of [crate::prelude::ProtocolCore] trait, with the exception of [Protocol::send_heart_beat]
+    /// which is flagged as due by the [PollHandler] thread servicing the respective [CltRecver] and sent by its `paired` [CltSender], see [Clt::into_split]
+    pub fn into_split(self) -> SplitSvc<P, C, MAX_MSG_SIZE, T> {
         let ((tx_recver, tx_sender), (svc_recver, svc_sender)) = self.clts_pool.into_split();
         let acceptor = TransmittingSvcAcceptor::new(tx_recver, tx_sender, self.acceptor);
         (acceptor, svc_recver, svc_sender)
@@ -196,9 +382,13 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Svc<P, C, M
     /// # Important
     /// These parts will support `all` [Protocol] features, which means that `ref counted clone` of [CltRecverRef] will be returned,
     /// while another `ref counted clone` will be moved to run in the [static@crate::connect::DEFAULT_HBEAT_HANDLER] thread
-    pub fn into_split_ref(self) -> (TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE>, SvcRecverRef<P, C, MAX_MSG_SIZE>, SvcSenderRef<P, C, MAX_MSG_SIZE>) {
-        let ((tx_recver, tx_sender), (svc_recver, svc_sender)) = self.clts_pool.into_split_ref();
-        let acceptor = TransmittingSvcAcceptorRef::new(tx_recver, tx_sender, self.acceptor);
+    pub fn into_split_ref(self) -> SplitSvcRef<P, C, MAX_MSG_SIZE, T> {
+        self.into_split_ref_on(&crate::connect::DEFAULT_HBEAT_HANDLER)
+    }
+    /// Same as [Svc::into_split_ref] but [Protocol::send_heart_beat] of existing and accepted connections is triggered by the given `hbeat_handler` thread
+    pub fn into_split_ref_on(self, hbeat_handler: &Timer) -> SplitSvcRef<P, C, MAX_MSG_SIZE, T> {
+        let ((tx_recver, tx_sender), (svc_recver, svc_sender)) = self.clts_pool.into_split_ref_on(hbeat_handler);
+        let acceptor = TransmittingSvcAcceptorRef::new(tx_recver, tx_sender, self.acceptor).with_hbeat_handler(hbeat_handler);
         (acceptor, svc_recver, svc_sender)
     }
 
@@ -213,49 +403,79 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Svc<P, C, M
     ///
     /// To mitigate `drop` this call will `panic` if the instance accepted any connections prior to calling this method.
     /// To avoid `panic` call this immediately after creating [Svc] instance
-    pub fn into_sender_with_spawned_recver(self) -> SvcSender<P, C, MAX_MSG_SIZE> {
-        if !self.clts_pool.is_empty() {
-            panic!(
-                "
-            Invalid API usage. 
-            Can't call Svc::into_sender_with_spawned_recver after it established its first connection.
-            Current connection pool: {}
-            ",
-                self.clts_pool
-            )
+    pub fn into_sender_with_spawned_recver(self) -> SvcSender<P, C, MAX_MSG_SIZE, T> {
+        if let Err(e) = self.no_connections_check("into_sender_with_spawned_recver") {
+            panic!("Invalid API usage. {}", e)
         }
+        let poll_handler = &*crate::connect::DEFAULT_POLL_HANDLER;
         let (acceptor, _recver_drop, sender) = self.into_split();
-        crate::connect::DEFAULT_POLL_HANDLER.add_acceptor(acceptor.into());
-        sender
+        if let Err(e) = poll_handler.add_acceptor(acceptor.into()) {
+            warn!("acceptor was rejected by DEFAULT_POLL_HANDLER and dropped, error: {}", e);
+        }
+        sender.with_poll_handler(poll_handler)
+    }
+    /// Same as [Svc::into_sender_with_spawned_recver] but [TransmittingSvcAcceptor] is moved to run in the given `poll_handler` thread, which is also
+    /// where the acceptor and its connections are shut down once [SvcSender] is dropped
+    ///
+    /// # Errors
+    /// [links_core::Error::Rejected] if the instance accepted any connections prior to calling this method or the `poll_handler` rejects [TransmittingSvcAcceptor],
+    /// in which case the acceptor is dropped, see [SpawnedPollHandler::add_acceptor]
+    pub fn into_sender_with_spawned_recver_on(self, poll_handler: &SpawnedPollHandlerDynamic) -> Result<SvcSender<P, C, MAX_MSG_SIZE, T>, links_core::Error> {
+        self.no_connections_check("into_sender_with_spawned_recver_on")?;
+        let (acceptor, _recver_drop, sender) = self.into_split();
+        poll_handler.add_acceptor(acceptor.into())?;
+        Ok(sender.with_poll_handler(poll_handler))
     }
 
     /// Will split using [`Self::into_split_ref()`] and only return [SvcSenderRef] while moving [TransmittingSvcAcceptorRef] to run in the [static@crate::connect::DEFAULT_POLL_HANDLER] thread
     ///
     /// # Important
     /// Please note [`Self::into_split_ref()`] will support `all` [Protocol] features, which means that `ref counted clone` of [CltRecverRef] will be returned,
-    /// while another `ref counted clone` will be moved to run in the [static@crate::connect::DEFAULT_HBEAT_HANDLER] thread
+    /// while another `ref counted clone` is used by the same poll thread to trigger [Protocol::send_heart_beat]
     ///
     /// # Warning
     /// This method `drops` [SvcRecverRef], as a result this call will panic if the instance accepted connections prior to calling this method.
     /// To avoid this call this immediately after creating [Svc] instance and prior to accepting any connections
-    pub fn into_sender_with_spawned_recver_ref(self) -> SvcSenderRef<P, C, MAX_MSG_SIZE> {
+    pub fn into_sender_with_spawned_recver_ref(self) -> SvcSenderRef<P, C, MAX_MSG_SIZE, T> {
+        if let Err(e) = self.no_connections_check("into_sender_with_spawned_recver_ref") {
+            panic!("Invalid API usage. {}", e)
+        }
+        let poll_handler = &*crate::connect::DEFAULT_POLL_HANDLER;
+        let (acceptor, sender) = self.into_spawned_acceptor_ref();
+        if let Err(e) = poll_handler.add_acceptor(acceptor.into()) {
+            warn!("acceptor was rejected by DEFAULT_POLL_HANDLER and dropped, error: {}", e);
+        }
+        sender.with_poll_handler(poll_handler)
+    }
+    /// Same as [Svc::into_sender_with_spawned_recver_ref] but [TransmittingSvcAcceptorRef] is moved to run in the given `poll_handler` thread, which is also
+    /// where the acceptor and its connections are shut down once [SvcSenderRef] is dropped and [Protocol::send_heart_beat] is triggered
+    ///
+    /// # Errors
+    /// [links_core::Error::Rejected] if the instance accepted any connections prior to calling this method or the `poll_handler` rejects [TransmittingSvcAcceptorRef],
+    /// in which case the acceptor is dropped, see [SpawnedPollHandler::add_acceptor]
+    pub fn into_sender_with_spawned_recver_ref_on(self, poll_handler: &SpawnedPollHandlerDynamic) -> Result<SvcSenderRef<P, C, MAX_MSG_SIZE, T>, links_core::Error> {
+        self.no_connections_check("into_sender_with_spawned_recver_ref_on")?;
+        let (acceptor, sender) = self.into_spawned_acceptor_ref();
+        poll_handler.add_acceptor(acceptor.into())?;
+        Ok(sender.with_poll_handler(poll_handler))
+    }
+    fn into_spawned_acceptor_ref(self) -> (TransmittingSvcAcceptorRef<P, C, MAX_MSG_SIZE, T>, SvcSenderRef<P, C, MAX_MSG_SIZE, T>) {
+        // pool is empty and the acceptor has no hbeat_handler hence heart beats of accepted connections are triggered by the poll_handler
+        let ((tx_recver, tx_sender), (_recver_drop, sender)) = self.clts_pool.into_split_ref_with(None);
+        (TransmittingSvcAcceptorRef::new(tx_recver, tx_sender, self.acceptor), sender)
+    }
+    /// Spawning the recver drops the pool of [SvcRecver] hence it is only permitted before the first connection is accepted
+    fn no_connections_check(&self, method: &str) -> Result<(), links_core::Error> {
         if !self.clts_pool.is_empty() {
-            panic!(
-                "
-            Invalid API usage. 
-            Can't call Svc::into_sender_with_spawned_recver_ref after it established its first connection.
-            Current connection pool: {}
-            ",
-                self.clts_pool
-            )
+            return Err(links_core::Error::Rejected {
+                con_id: Box::new(self.con_id().clone()),
+                reason: format!("can't call Svc::{} after it established its first connection, current connection pool: {}", method, self.clts_pool),
+            });
         }
-        let (acceptor, _recver_drop, sender) = self.into_split_ref();
-        crate::connect::DEFAULT_POLL_HANDLER.add_acceptor(acceptor.into());
-
-        sender
+        Ok(())
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolSvcAcceptorOfCltNonBlocking for Svc<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PoolSvcAcceptorOfCltNonBlocking for Svc<P, C, MAX_MSG_SIZE, T> {
     /// Will attempt to accept a new connection and add it to the pool. If the pool is full it will return an [std::io::ErrorKind::OutOfMemory].
     fn accept_into_pool(&mut self) -> Result<PoolAcceptStatus, Error> {
         match self.acceptor.accept()? {
@@ -268,33 +488,38 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolSvcAcce
         }
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> SvcAcceptorOfCltNonBlocking<Clt<P, C, MAX_MSG_SIZE>> for Svc<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> SvcAcceptorOfCltNonBlocking<Clt<P, C, MAX_MSG_SIZE, T>> for Svc<P, C, MAX_MSG_SIZE, T> {
     /// Instead of adding the accepted connection to the pool it will return it to the caller.
-    fn accept(&self) -> Result<AcceptStatus<Clt<P, C, MAX_MSG_SIZE>>, Error> {
+    fn accept(&self) -> Result<AcceptStatus<Clt<P, C, MAX_MSG_SIZE, T>>, Error> {
         self.acceptor.accept()
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> SendNonBlocking<P::SendT> for Svc<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> SendNonBlocking<P::SendT> for Svc<P, C, MAX_MSG_SIZE, T> {
     /// Will use the underling [CltsPool] to deliver the message to one of the [Clt]'s in the pool.
 
     #[inline(always)]
     fn send(&mut self, msg: &mut P::SendT) -> Result<SendStatus, Error> {
         self.clts_pool.send(msg)
     }
+    /// Will use the underling [CltsPool] to deliver all of the messages to one of the [Clt]'s in the pool.
+    #[inline(always)]
+    fn send_batch(&mut self, msgs: &mut [P::SendT]) -> Result<usize, SendBatchError> {
+        self.clts_pool.send_batch(msgs)
+    }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> RecvNonBlocking<P::RecvT> for Svc<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> RecvNonBlocking<P::RecvT> for Svc<P, C, MAX_MSG_SIZE, T> {
     /// Will use the underling [CltsPool] to receive a message from one of the [Clt]'s in the pool.
     #[inline(always)]
     fn recv(&mut self) -> Result<RecvStatus<<P as Messenger>::RecvT>, Error> {
         self.clts_pool.recv()
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> ConnectionId for Svc<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> ConnectionId for Svc<P, C, MAX_MSG_SIZE, T> {
     fn con_id(&self) -> &ConId {
         self.acceptor.con_id()
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolConnectionStatus for Svc<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> PoolConnectionStatus for Svc<P, C, MAX_MSG_SIZE, T> {
     /// Will delegate to [`CltsPool::is_next_connected()`]
     #[inline(always)]
     fn is_next_connected(&mut self) -> bool {
@@ -306,7 +531,7 @@ impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> PoolConnect
         self.clts_pool.all_connected()
     }
 }
-impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize> Display for Svc<P, C, MAX_MSG_SIZE> {
+impl<P: Protocol, C: CallbackRecvSend<P>, const MAX_MSG_SIZE: usize, T: AcceptTransport> Display for Svc<P, C, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}<{}, {}>", asserted_short_name!("Svc", Self), self.acceptor, self.clts_pool)
     }
@@ -810,4 +1035,84 @@ mod test {
         assert_eq!(svc_count.sent_count(), N);
         assert_eq!(clt_count.sent_count(), N);
     }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn test_svc_clt_uds_connected_not_split() {
+        setup::log::configure_level(LevelFilter::Info);
+        let path = setup::net::rand_avail_uds_path();
+        let mut svc = Svc::<_, _, TEST_MSG_FRAME_SIZE, _>::bind_uds(&path, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), SvcTestProtocolManual, Some("unittest")).unwrap();
+        info!("svc: {}", svc);
+
+        let mut clt = Clt::<_, _, TEST_MSG_FRAME_SIZE, _>::connect_uds(
+            &path,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            LoggerCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest"),
+        )
+        .unwrap();
+        info!("clt: {}", clt);
+        assert_eq!(clt.con_id().get_path(), Some(path.as_path()));
+
+        svc.accept_into_pool_busywait().unwrap();
+        assert!(svc.all_connected());
+
+        let mut clt_msg_inp = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Client Msg"));
+        let mut svc_msg_inp = SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"Hello Frm Server Msg"));
+
+        clt.send_busywait(&mut clt_msg_inp).unwrap();
+        let svc_msg_out = svc.recv_busywait().unwrap().unwrap();
+        assert_eq!(clt_msg_inp, svc_msg_out);
+
+        svc.send_busywait(&mut svc_msg_inp).unwrap();
+        let clt_msg_out = clt.recv_busywait().unwrap().unwrap();
+        assert_eq!(svc_msg_inp, clt_msg_out);
+
+        drop(clt);
+        assert!(svc.recv_busywait_timeout(setup::net::default_connect_timeout()).unwrap().is_completed_none());
+
+        drop(svc);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_family = "unix")]
+    fn test_svc_clt_uds_connected_spawned_recver_ref() {
+        setup::log::configure_level(LevelFilter::Info);
+        let path = setup::net::rand_avail_uds_path();
+        let clt_count = CounterCallback::new_ref();
+        let svc_count = CounterCallback::new_ref();
+        let clt_clbk = ChainCallback::new_ref(vec![LoggerCallback::with_level_ref(log::Level::Info, log::Level::Debug), clt_count.clone()]);
+        let svc_clbk = ChainCallback::new_ref(vec![LoggerCallback::with_level_ref(log::Level::Info, log::Level::Debug), svc_count.clone()]);
+        let io_timeout = setup::net::default_io_timeout();
+
+        let mut svc_sender = Svc::<_, _, TEST_MSG_FRAME_SIZE, _>::bind_uds(&path, NonZeroUsize::new(1).unwrap(), svc_clbk, SvcTestProtocolManual, Some("unittest"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let mut clt_sender = Clt::<_, _, TEST_MSG_FRAME_SIZE, _>::connect_uds(&path, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), clt_clbk, CltTestProtocolManual, Some("unittest"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        assert!(clt_sender.is_connected());
+        assert!(svc_sender.all_connected_busywait_timeout(setup::net::default_find_timeout()));
+
+        const N: usize = 10;
+        for i in 1..=N {
+            clt_sender.send_busywait_timeout(&mut CltTestMsgDebug::new(format!("Clt Msg  #{}", i).as_bytes()).into(), io_timeout).unwrap().unwrap_completed();
+        }
+        assert_eq!(svc_count.recv_count_busywait_timeout(N, setup::net::default_find_timeout()), N);
+
+        for i in 1..=N {
+            svc_sender.send_busywait_timeout(&mut SvcTestMsgDebug::new(format!("Svc Msg  #{}", i).as_bytes()).into(), io_timeout).unwrap().unwrap_completed();
+        }
+        assert_eq!(clt_count.recv_count_busywait_timeout(N, setup::net::default_find_timeout()), N);
+        info!("clt_count: {}, svc_count: {}", clt_count, svc_count);
+
+        drop(clt_sender);
+        drop(svc_sender);
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,170 @@
+use crate::prelude::{
+    asserted_short_name, CallbackRecv, CallbackSend, ConId, ConnectionId, DatagramRecver, DatagramSender, Messenger, PollAble, PollEventStatus, PollRead, RecvNonBlocking, RecvStatus, SendNonBlocking, SendNonBlockingNonMut, SendStatus,
+};
+use std::{
+    fmt::Display,
+    io::Error,
+    net::{Ipv4Addr, Ipv6Addr},
+    sync::Arc,
+};
+
+/// An abstraction over a [DatagramRecver] that executes [CallbackRecv::on_recv] callback on every message received from a unicast or multicast feed.
+/// It can be registered with a [crate::prelude::PollHandler] just like a [crate::prelude::CltRecver].
+///
+/// # Note
+/// To share a single callback chain between tcp and udp feeds use the same [Messenger] type for `M`, typically your [crate::prelude::Protocol] type.
+#[derive(Debug)]
+pub struct UdpRecver<M: Messenger, C: CallbackRecv<M>, const MAX_DATAGRAM_SIZE: usize> {
+    recver: DatagramRecver<M, MAX_DATAGRAM_SIZE>,
+    callback: Arc<C>,
+}
+impl<M: Messenger, C: CallbackRecv<M>, const MAX_DATAGRAM_SIZE: usize> UdpRecver<M, C, MAX_DATAGRAM_SIZE> {
+    pub fn new(recver: DatagramRecver<M, MAX_DATAGRAM_SIZE>, callback: Arc<C>) -> Self {
+        Self { recver, callback }
+    }
+    /// Binds to a given unicast address, example `127.0.0.1:8080`
+    pub fn bind(addr: &str, callback: Arc<C>, name: Option<&str>) -> Result<Self, Error> {
+        Ok(Self::new(DatagramRecver::bind(addr, name)?, callback))
+    }
+    /// Binds to a given `port` and joins multicast `group` on the local `interface`, see [DatagramRecver::bind_multicast_v4]
+    pub fn bind_multicast_v4(group: Ipv4Addr, port: u16, interface: Ipv4Addr, callback: Arc<C>, name: Option<&str>) -> Result<Self, Error> {
+        Ok(Self::new(DatagramRecver::bind_multicast_v4(group, port, interface, name)?, callback))
+    }
+    pub fn join_multicast_v4(&self, group: Ipv4Addr, interface: Ipv4Addr) -> Result<(), Error> {
+        self.recver.join_multicast_v4(group, interface)
+    }
+    pub fn leave_multicast_v4(&self, group: Ipv4Addr, interface: Ipv4Addr) -> Result<(), Error> {
+        self.recver.leave_multicast_v4(group, interface)
+    }
+    pub fn join_multicast_v6(&self, group: Ipv6Addr, interface: u32) -> Result<(), Error> {
+        self.recver.join_multicast_v6(group, interface)
+    }
+    pub fn leave_multicast_v6(&self, group: Ipv6Addr, interface: u32) -> Result<(), Error> {
+        self.recver.leave_multicast_v6(group, interface)
+    }
+    /// Number of datagrams dropped because one of their messages failed to deserialize, see [DatagramRecver::dropped]
+    pub fn dropped(&self) -> usize {
+        self.recver.dropped()
+    }
+}
+impl<M: Messenger, C: CallbackRecv<M>, const MAX_DATAGRAM_SIZE: usize> RecvNonBlocking<M::RecvT> for UdpRecver<M, C, MAX_DATAGRAM_SIZE> {
+    #[inline(always)]
+    fn recv(&mut self) -> Result<RecvStatus<M::RecvT>, Error> {
+        let status = self.recver.recv()?;
+        if let RecvStatus::Completed(Some(ref msg)) = status {
+            self.callback.on_recv(&self.recver.con_id, msg);
+        }
+        Ok(status)
+    }
+}
+impl<M: Messenger, C: CallbackRecv<M>, const MAX_DATAGRAM_SIZE: usize> ConnectionId for UdpRecver<M, C, MAX_DATAGRAM_SIZE> {
+    #[inline(always)]
+    fn con_id(&self) -> &ConId {
+        &self.recver.con_id
+    }
+}
+impl<M: Messenger, C: CallbackRecv<M>, const MAX_DATAGRAM_SIZE: usize> PollRead for UdpRecver<M, C, MAX_DATAGRAM_SIZE> {
+    /// Datagram sockets have no end of stream hence this source is only ever terminated by an [Err]
+    fn on_readable_event(&mut self) -> Result<PollEventStatus, Error> {
+        use RecvStatus::*;
+        match self.recv()? {
+            Completed(Some(_)) => Ok(PollEventStatus::Completed),
+            WouldBlock => Ok(PollEventStatus::WouldBlock),
+            Completed(None) => Ok(PollEventStatus::Terminate),
+        }
+    }
+}
+impl<M: Messenger, C: CallbackRecv<M>, const MAX_DATAGRAM_SIZE: usize> PollAble for UdpRecver<M, C, MAX_DATAGRAM_SIZE> {
+    fn source(&mut self) -> Box<&mut dyn mio::event::Source> {
+        Box::new(&mut self.recver.socket)
+    }
+}
+impl<M: Messenger, C: CallbackRecv<M>, const MAX_DATAGRAM_SIZE: usize> Display for UdpRecver<M, C, MAX_DATAGRAM_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let recv_t = std::any::type_name::<M::RecvT>().split("::").last().unwrap_or("Unknown").replace('>', "");
+        write!(f, "{}<{}, RecvT:{}, {}>", asserted_short_name!("UdpRecver", Self), self.con_id(), recv_t, MAX_DATAGRAM_SIZE)
+    }
+}
+
+/// An abstraction over a [DatagramSender] that executes [CallbackSend::on_sent] callback on every message sent to a unicast or multicast peer
+#[derive(Debug)]
+pub struct UdpSender<M: Messenger, C: CallbackSend<M>, const MAX_MSG_SIZE: usize> {
+    sender: DatagramSender<M, MAX_MSG_SIZE>,
+    callback: Arc<C>,
+}
+impl<M: Messenger, C: CallbackSend<M>, const MAX_MSG_SIZE: usize> UdpSender<M, C, MAX_MSG_SIZE> {
+    pub fn new(sender: DatagramSender<M, MAX_MSG_SIZE>, callback: Arc<C>) -> Self {
+        Self { sender, callback }
+    }
+    /// Binds to a `local` address and will send all messages to the `peer` address, see [DatagramSender::bind]
+    pub fn bind(local: &str, peer: &str, callback: Arc<C>, name: Option<&str>) -> Result<Self, Error> {
+        Ok(Self::new(DatagramSender::bind(local, peer, name)?, callback))
+    }
+    pub fn set_multicast_ttl_v4(&self, ttl: u32) -> Result<(), Error> {
+        self.sender.set_multicast_ttl_v4(ttl)
+    }
+    pub fn set_multicast_loop_v4(&self, on: bool) -> Result<(), Error> {
+        self.sender.set_multicast_loop_v4(on)
+    }
+}
+impl<M: Messenger, C: CallbackSend<M>, const MAX_MSG_SIZE: usize> SendNonBlocking<M::SendT> for UdpSender<M, C, MAX_MSG_SIZE> {
+    #[inline(always)]
+    fn send(&mut self, msg: &mut M::SendT) -> Result<SendStatus, Error> {
+        let status = self.sender.send(msg)?;
+        if let SendStatus::Completed = status {
+            self.callback.on_sent(&self.sender.con_id, msg);
+        }
+        Ok(status)
+    }
+}
+impl<M: Messenger, C: CallbackSend<M>, const MAX_MSG_SIZE: usize> ConnectionId for UdpSender<M, C, MAX_MSG_SIZE> {
+    #[inline(always)]
+    fn con_id(&self) -> &ConId {
+        &self.sender.con_id
+    }
+}
+impl<M: Messenger, C: CallbackSend<M>, const MAX_MSG_SIZE: usize> Display for UdpSender<M, C, MAX_MSG_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let send_t = std::any::type_name::<M::SendT>().split("::").last().unwrap_or("Unknown").replace('>', "");
+        write!(f, "{}<{}, SendT:{}, {}>", asserted_short_name!("UdpSender", Self), self.con_id(), send_t, MAX_MSG_SIZE)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::prelude::*;
+    use links_core::unittest::setup::{
+        self,
+        framer::{CltTestMessenger, SvcTestMessenger, TEST_MSG_FRAME_SIZE},
+        model::*,
+    };
+    use log::info;
+    use std::{net::Ipv4Addr, time::Duration};
+
+    #[test]
+    fn test_udp_multicast_poll_handler() {
+        setup::log::configure();
+        const N: usize = 100;
+        let group = Ipv4Addr::new(239, 255, 0, 1);
+        let interface = Ipv4Addr::LOCALHOST;
+        let port = setup::net::find_available_port(2000..65000);
+
+        let counter = CounterCallback::<SvcTestMessenger>::new_ref();
+        let clbk = ChainCallback::new_ref(vec![LoggerCallback::new_ref(), counter.clone()]);
+        let recver = UdpRecver::<_, _, 1500>::bind_multicast_v4(group, port, interface, clbk, Some("unittest")).unwrap();
+        info!("recver: {}", recver);
+        DEFAULT_POLL_HANDLER.add_recver(recver.into()).unwrap();
+
+        // binding local interface address selects the egress interface for multicast
+        let mut sender = UdpSender::<CltTestMessenger, _, TEST_MSG_FRAME_SIZE>::bind("127.0.0.1:0", &format!("{}:{}", group, port), LoggerCallback::new_ref(), Some("unittest")).unwrap();
+        info!("sender: {}", sender);
+
+        let mut msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Multicast"));
+        for _ in 0..N {
+            sender.send_busywait(&mut msg).unwrap();
+        }
+        counter.assert_recv_count_busywait_timeout(N, Duration::from_secs(1));
+        info!("counter: {}", counter);
+    }
+}
@@ -0,0 +1,284 @@
+//! This module contains a non blocking [DatagramRecver] and [DatagramSender] which are the datagram counterparts of [crate::prelude::MessageRecver]
+//! and [crate::prelude::MessageSender] and operate over a [mio::net::UdpSocket] for both unicast and multicast feeds.
+//!
+//! # Note
+//! Each datagram may carry one or more [crate::prelude::Framer] delimited messages, however a message can not span multiple datagrams.
+//!
+//! # Example
+//! ```
+//! use links_nonblocking::prelude::*;
+//! use links_core::unittest::setup::{self, framer::{CltTestMessenger, SvcTestMessenger, TEST_MSG_FRAME_SIZE}, model::{CltTestMsg, CltTestMsgDebug}};
+//!
+//! let addr = setup::net::rand_avail_addr_port(); // "127.0.0.1:8080"
+//!
+//! let mut recver = DatagramRecver::<SvcTestMessenger, 1500>::bind(addr, Some("unittest")).unwrap();
+//! let mut sender = DatagramSender::<CltTestMessenger, TEST_MSG_FRAME_SIZE>::bind("127.0.0.1:0", addr, Some("unittest")).unwrap();
+//!
+//! let msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Sender"));
+//! sender.send(&msg).unwrap();
+//! assert_eq!(recver.recv_busywait().unwrap(), Some(msg));
+//! ```
+use crate::prelude::{ConId, Messenger, RecvNonBlocking, RecvStatus, SendNonBlockingNonMut, SendStatus};
+use bytes::BytesMut;
+use byteserde::utils::hex::to_hex_pretty;
+use log::{log_enabled, warn};
+use mio::net::UdpSocket;
+use std::{
+    any::type_name,
+    fmt::Display,
+    io::{Error, ErrorKind},
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4},
+};
+
+/// Represents an abstraction for receiving exactly one message from a non blocking [UdpSocket], where each datagram may carry one or more messages
+///
+/// # Generic Parameters
+///  * `M` - a type that implements [Messenger] trait, whose [crate::prelude::Framer] is used to split messages within a datagram
+///  * `MAX_DATAGRAM_SIZE` - maximum size of a single datagram, larger datagrams will be truncated by the OS
+#[derive(Debug)]
+pub struct DatagramRecver<M: Messenger, const MAX_DATAGRAM_SIZE: usize> {
+    pub(crate) con_id: ConId,
+    pub(crate) socket: UdpSocket,
+    datagram: Vec<u8>,
+    buffer: BytesMut,
+    dropped: usize,
+    phantom: std::marker::PhantomData<M>,
+}
+impl<M: Messenger, const MAX_DATAGRAM_SIZE: usize> DatagramRecver<M, MAX_DATAGRAM_SIZE> {
+    /// Constructs a new instance of [DatagramRecver] from a bound [std::net::UdpSocket] which will be switched into non blocking mode
+    pub fn new(mut con_id: ConId, socket: std::net::UdpSocket) -> Result<Self, Error> {
+        socket.set_nonblocking(true)?;
+        con_id.set_local(socket.local_addr()?);
+        Ok(Self {
+            con_id,
+            socket: UdpSocket::from_std(socket),
+            datagram: vec![0; MAX_DATAGRAM_SIZE],
+            buffer: BytesMut::with_capacity(MAX_DATAGRAM_SIZE),
+            dropped: 0,
+            phantom: std::marker::PhantomData,
+        })
+    }
+    /// Number of datagrams dropped because one of their messages failed to deserialize
+    pub fn dropped(&self) -> usize {
+        self.dropped
+    }
+    /// Binds to a given unicast address, example `127.0.0.1:8080`
+    pub fn bind(addr: &str, name: Option<&str>) -> Result<Self, Error> {
+        Self::new(ConId::svc(name, addr, None)?, std::net::UdpSocket::bind(addr)?)
+    }
+    /// Binds to a given `port` on all interfaces and joins multicast `group` on the local `interface`, use [Ipv4Addr::UNSPECIFIED] to let the OS choose the interface
+    pub fn bind_multicast_v4(group: Ipv4Addr, port: u16, interface: Ipv4Addr, name: Option<&str>) -> Result<Self, Error> {
+        let addr = SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, port).to_string();
+        let recver = Self::new(ConId::svc(name, &addr, None)?, std::net::UdpSocket::bind(&addr)?)?;
+        recver.join_multicast_v4(group, interface)?;
+        Ok(recver)
+    }
+    /// Joins an additional multicast `group` on the local `interface`
+    pub fn join_multicast_v4(&self, group: Ipv4Addr, interface: Ipv4Addr) -> Result<(), Error> {
+        self.socket.join_multicast_v4(&group, &interface)
+    }
+    /// Leaves a multicast `group` previously joined on the local `interface`
+    pub fn leave_multicast_v4(&self, group: Ipv4Addr, interface: Ipv4Addr) -> Result<(), Error> {
+        self.socket.leave_multicast_v4(&group, &interface)
+    }
+    /// Joins a multicast `group` on the interface with the given `interface` index, `0` lets the OS choose the interface
+    pub fn join_multicast_v6(&self, group: Ipv6Addr, interface: u32) -> Result<(), Error> {
+        self.socket.join_multicast_v6(&group, interface)
+    }
+    /// Leaves a multicast `group` previously joined on the interface with the given `interface` index
+    pub fn leave_multicast_v6(&self, group: Ipv6Addr, interface: u32) -> Result<(), Error> {
+        self.socket.leave_multicast_v6(&group, interface)
+    }
+}
+impl<M: Messenger, const MAX_DATAGRAM_SIZE: usize> RecvNonBlocking<M::RecvT> for DatagramRecver<M, MAX_DATAGRAM_SIZE> {
+    /// Returns the next message of the current datagram or reads the next datagram from the [UdpSocket]
+    ///
+    /// # Note
+    /// Trailing bytes of a datagram which do not make up a complete frame are discarded. Since datagram sockets have no end of stream
+    /// this method never returns [RecvStatus::Completed(None)]
+    ///
+    /// A message which fails to deserialize does not terminate the feed, the remainder of its datagram is discarded and counted by [Self::dropped]
+    #[inline(always)]
+    fn recv(&mut self) -> Result<RecvStatus<M::RecvT>, Error> {
+        loop {
+            if let Some(frame) = M::get_frame(&mut self.buffer) {
+                match M::deserialize(&frame) {
+                    Ok(msg) => return Ok(RecvStatus::Completed(Some(msg))),
+                    Err(e) => {
+                        self.dropped += 1;
+                        if log_enabled!(log::Level::Warn) {
+                            warn!("{} dropping datagram, dropped: {}, failed to deserialize frame:\n{}\ncaused by: [{}]", self, self.dropped, to_hex_pretty(&frame[..]), e);
+                        }
+                        self.buffer.clear();
+                        continue;
+                    }
+                }
+            }
+            if !self.buffer.is_empty() {
+                if log_enabled!(log::Level::Warn) {
+                    warn!("{} discarding incomplete frame at the end of datagram, residual buf:\n{}", self, to_hex_pretty(&self.buffer[..]));
+                }
+                self.buffer.clear();
+            }
+            match self.socket.recv_from(&mut self.datagram) {
+                Ok((len, _)) => self.buffer.extend_from_slice(&self.datagram[..len]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(RecvStatus::WouldBlock),
+                Err(e) => return Err(Error::new(e.kind(), format!("{} recv_from caused by: [{}]", self, e))),
+            }
+        }
+    }
+}
+impl<M: Messenger, const MAX_DATAGRAM_SIZE: usize> Display for DatagramRecver<M, MAX_DATAGRAM_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = type_name::<M>().split("::").last().unwrap_or("Unknown");
+        write!(f, "{} DatagramRecver<{}, {}>", self.con_id, name, MAX_DATAGRAM_SIZE)
+    }
+}
+
+/// Represents an abstraction for sending exactly one message per datagram to a given unicast or multicast peer using a non blocking [UdpSocket]
+#[derive(Debug)]
+pub struct DatagramSender<M: Messenger, const MAX_MSG_SIZE: usize> {
+    pub(crate) con_id: ConId,
+    socket: UdpSocket,
+    peer: SocketAddr,
+    phantom: std::marker::PhantomData<M>,
+}
+impl<M: Messenger, const MAX_MSG_SIZE: usize> DatagramSender<M, MAX_MSG_SIZE> {
+    /// Constructs a new instance of [DatagramSender] from a bound [std::net::UdpSocket] which will be switched into non blocking mode
+    pub fn new(mut con_id: ConId, socket: std::net::UdpSocket, peer: SocketAddr) -> Result<Self, Error> {
+        socket.set_nonblocking(true)?;
+        con_id.set_local(socket.local_addr()?);
+        con_id.set_peer(peer);
+        Ok(Self {
+            con_id,
+            socket: UdpSocket::from_std(socket),
+            peer,
+            phantom: std::marker::PhantomData,
+        })
+    }
+    /// Binds to a `local` address and will send all datagrams to the `peer` address, example `local: 127.0.0.1:0`, `peer: 239.255.0.1:8080`
+    ///
+    /// # Note
+    /// Binding `local` to a specific interface address also selects the interface used for outgoing multicast datagrams
+    pub fn bind(local: &str, peer: &str, name: Option<&str>) -> Result<Self, Error> {
+        let con_id = ConId::clt(name, Some(local), peer)?;
+        let peer = con_id.get_peer().expect("ConId::clt always has a peer");
+        Self::new(con_id, std::net::UdpSocket::bind(local)?, peer)
+    }
+    /// Sets the time-to-live of outgoing multicast datagrams, default is `1` which keeps them within the local network
+    pub fn set_multicast_ttl_v4(&self, ttl: u32) -> Result<(), Error> {
+        self.socket.set_multicast_ttl_v4(ttl)
+    }
+    /// Sets whether outgoing multicast datagrams are delivered back to the local host, default is `true`
+    pub fn set_multicast_loop_v4(&self, on: bool) -> Result<(), Error> {
+        self.socket.set_multicast_loop_v4(on)
+    }
+}
+impl<M: Messenger, const MAX_MSG_SIZE: usize> SendNonBlockingNonMut<M::SendT> for DatagramSender<M, MAX_MSG_SIZE> {
+    /// Sends `entire` message as a single datagram or returns [SendStatus::WouldBlock] if the socket send buffer is full
+    #[inline(always)]
+    fn send(&mut self, msg: &<M as Messenger>::SendT) -> Result<SendStatus, Error> {
+        let (bytes, size) = M::serialize::<MAX_MSG_SIZE>(msg)?;
+        match self.socket.send_to(&bytes[..size], self.peer) {
+            Ok(len) if len == size => Ok(SendStatus::Completed),
+            Ok(len) => Err(Error::new(ErrorKind::WriteZero, format!("{} send_to truncated datagram, sent: {} of {}", self, len, size))),
+            Err(e) if e.kind() == ErrorKind::WouldBlock => Ok(SendStatus::WouldBlock),
+            Err(e) => Err(Error::new(e.kind(), format!("{} send_to caused by: [{}]", self, e))),
+        }
+    }
+}
+impl<M: Messenger, const MAX_MSG_SIZE: usize> Display for DatagramSender<M, MAX_MSG_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = type_name::<M>().split("::").last().unwrap_or("Unknown");
+        write!(f, "{} DatagramSender<{}, {}>", self.con_id, name, MAX_MSG_SIZE)
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::prelude::*;
+    use links_core::unittest::setup::{
+        self,
+        framer::{CltTestMessenger, SvcTestMessenger, TEST_MSG_FRAME_SIZE},
+        model::*,
+    };
+    use log::info;
+    use std::time::Duration;
+
+    #[test]
+    fn test_datagram_unicast() {
+        setup::log::configure();
+        let addr = setup::net::rand_avail_addr_port();
+        const N: usize = 100;
+
+        let mut recver = DatagramRecver::<SvcTestMessenger, 1500>::bind(addr, Some("unittest")).unwrap();
+        let mut sender = DatagramSender::<CltTestMessenger, TEST_MSG_FRAME_SIZE>::bind("127.0.0.1:0", addr, Some("unittest")).unwrap();
+        info!("recver: {}", recver);
+        info!("sender: {}", sender);
+
+        for i in 1..=N {
+            let msg = CltTestMsg::Dbg(CltTestMsgDebug::new(format!("Msg #{}", i).as_bytes()));
+            sender.send_busywait(&msg).unwrap();
+            let out = recver.recv_busywait_timeout(Duration::from_secs(1)).unwrap().unwrap_completed_some();
+            assert_eq!(msg, out);
+        }
+        assert!(recver.recv().unwrap().is_wouldblock());
+    }
+
+    #[test]
+    fn test_datagram_multiple_frames_per_datagram() {
+        setup::log::configure();
+        let addr = setup::net::rand_avail_addr_port();
+        let mut recver = DatagramRecver::<SvcTestMessenger, 1500>::bind(addr, Some("unittest")).unwrap();
+
+        // pack three frames followed by a truncated frame into a single datagram
+        let msgs = (1..=3).map(|i| CltTestMsg::Dbg(CltTestMsgDebug::new(format!("Msg #{}", i).as_bytes()))).collect::<Vec<_>>();
+        let mut datagram = vec![];
+        for msg in msgs.iter() {
+            let (bytes, size) = CltTestMessenger::serialize::<TEST_MSG_FRAME_SIZE>(msg).unwrap();
+            datagram.extend_from_slice(&bytes[..size]);
+        }
+        datagram.extend_from_within(..10);
+        let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket.send_to(&datagram, addr).unwrap();
+
+        for msg in msgs.iter() {
+            let out = recver.recv_busywait_timeout(Duration::from_secs(1)).unwrap().unwrap_completed_some();
+            info!("out: {:?}", out);
+            assert_eq!(msg, &out);
+        }
+        // truncated frame is discarded
+        assert!(recver.recv().unwrap().is_wouldblock());
+
+        // next datagram is processed normally
+        socket.send_to(&datagram[..datagram.len() - 10], addr).unwrap();
+        for msg in msgs.iter() {
+            assert_eq!(msg, &recver.recv_busywait_timeout(Duration::from_secs(1)).unwrap().unwrap_completed_some());
+        }
+    }
+
+    #[test]
+    fn test_datagram_garbage_dropped() {
+        setup::log::configure();
+        let addr = setup::net::rand_avail_addr_port();
+        let mut recver = DatagramRecver::<SvcTestMessenger, 1500>::bind(addr, Some("unittest")).unwrap();
+        let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+
+        let msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Sender"));
+        let (bytes, size) = CltTestMessenger::serialize::<TEST_MSG_FRAME_SIZE>(&msg).unwrap();
+        // garbage message type followed by a valid message in the same datagram
+        let mut garbage = bytes[..size].to_vec();
+        garbage[0] = b'?';
+        garbage.extend_from_slice(&bytes[..size]);
+        socket.send_to(&garbage, addr).unwrap();
+        socket.send_to(&bytes[..size], addr).unwrap();
+
+        // entire garbage datagram is dropped while the feed keeps delivering subsequent datagrams
+        let out = recver.recv_busywait_timeout(Duration::from_secs(1)).unwrap().unwrap_completed_some();
+        info!("recver: {}, out: {:?}", recver, out);
+        assert_eq!(out, msg);
+        assert_eq!(recver.dropped(), 1);
+        assert!(recver.recv().unwrap().is_wouldblock());
+    }
+}
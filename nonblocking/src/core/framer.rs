@@ -1,9 +1,9 @@
 //! This module contains a non blocking `paired` [FrameReader] and [FrameWriter] which are designed to be used in separate threads,
-//! where each thread is only doing either reading or writing to the underlying [mio::net::TcpStream].
+//! where each thread is only doing either reading or writing to the underlying [Transport], which defaults to a [mio::net::TcpStream].
 //!
 //! # Note
 //!
-//!  The underlying [mio::net::TcpStream] is cloned and therefore share a single underlying network socket.
+//!  The underlying [Transport] is cloned and therefore share a single underlying network socket.
 //!
 //! # Example
 //! ```
@@ -17,13 +17,13 @@
 //!
 //! let clt_stream = std::net::TcpStream::connect(addr).unwrap();
 //! let (clt_reader, clt_writer) = into_split_framer::<FixedSizeFramer<FRAME_SIZE>, FRAME_SIZE>(
-//!         ConId::clt(Some("unittest"), None, addr),
+//!         ConId::clt(Some("unittest"), None, addr).unwrap(),
 //!         clt_stream,
 //!     );
 //!
 //! let svc_stream = svc_listener.accept().unwrap().0;
 //! let (svc_reader, svc_writer) = into_split_framer::<FixedSizeFramer<FRAME_SIZE>, FRAME_SIZE>(
-//!         ConId::svc(Some("unittest"), addr, None),
+//!         ConId::svc(Some("unittest"), addr, None).unwrap(),
 //!         svc_stream,
 //!     );
 //!
@@ -42,36 +42,24 @@
 //!         // svc_reader & clt_writer
 //! ```
 
-use crate::prelude::{ConId, Framer, RecvStatus, SendStatus};
-use bytes::{Bytes, BytesMut};
+use crate::prelude::{ConId, Framer, RecvStatus, SendStatus, Transport};
+use bytes::{Buf, Bytes, BytesMut};
 use byteserde::utils::hex::to_hex_pretty;
 use links_core::asserted_short_name;
-use mio::net::TcpStream;
 use std::mem::MaybeUninit;
 use std::{
     fmt::Display,
-    io::{Error, ErrorKind, Read, Write},
+    io::{Error, ErrorKind, IoSlice, Write},
     net::Shutdown,
+    num::NonZeroUsize,
+    sync::Arc,
 };
 
-#[cfg(target_family = "unix")]
-#[inline]
-fn fd(stream: &TcpStream) -> std::os::fd::RawFd {
-    use std::os::fd::AsRawFd;
-    stream.as_raw_fd()
-}
-#[cfg(target_family = "windows")]
-#[inline]
-fn fd(stream: &TcpStream) -> std::os::windows::io::RawSocket {
-    use std::os::windows::io::AsRawSocket;
-    stream.as_raw_socket()
-}
-
-use log::{debug, log_enabled};
+use log::{debug, log_enabled, warn};
 const EOF: usize = 0;
 
-/// Represents an abstraction for reading exactly one frame from the [TcpStream].
-/// Each call to [Self::read_frame] will issue a [Read::read] system call on the underlying [TcpStream]
+/// Represents an abstraction for reading exactly one frame from the [Transport].
+/// Each call to [Self::read_frame] will issue a [std::io::Read::read] system call on the underlying [Transport]
 /// which will capture any bytes read into internal accumulator implemented as [BytesMut]. This internal buffer will be
 /// passed to the generic impl of [Framer::get_frame] where it is user's responsibility to inspect the buffer and split off a single frame.
 ///
@@ -79,28 +67,33 @@ const EOF: usize = 0;
 ///  * `F` - a type that implements [Framer] trait. This trait is used to split off a single frame from the internal buffer
 ///  * `MAX_MSG_SIZE` - a const generic that represents the maximum size of a single frame. This is used to preallocate the internal buffer.
 /// Set this number to the maximum size of a single frame for your protocol.
+///  * `T` - a type that implements [Transport] trait, defaults to [mio::net::TcpStream]
 #[derive(Debug)]
-pub struct FrameReader<F: Framer, const MAX_MSG_SIZE: usize> {
+pub struct FrameReader<F: Framer, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
     pub(crate) con_id: ConId,
-    pub(crate) stream_reader: TcpStream,
+    pub(crate) stream_reader: T,
+    #[cfg(feature = "tls")]
+    pub(crate) tls: Option<crate::core::tls::TlsSession>,
     buffer: BytesMut,
     phantom: std::marker::PhantomData<F>,
 }
-impl<F: Framer, const MAX_MSG_SIZE: usize> FrameReader<F, MAX_MSG_SIZE> {
+impl<F: Framer, const MAX_MSG_SIZE: usize, T: Transport> FrameReader<F, MAX_MSG_SIZE, T> {
     /// Constructs a new instance of [FrameReader]
     /// # Arguments
     /// * `con_id` - [ConId] a unique identifier for the connection and used for logging
-    /// * `reader` - [mio::net::TcpStream] the underlying stream that will be used for reading
-    pub fn new(con_id: ConId, reader: mio::net::TcpStream) -> FrameReader<F, MAX_MSG_SIZE> {
+    /// * `reader` - [Transport] the underlying stream that will be used for reading
+    pub fn new(con_id: ConId, reader: T) -> FrameReader<F, MAX_MSG_SIZE, T> {
         Self {
             con_id,
             stream_reader: reader,
+            #[cfg(feature = "tls")]
+            tls: None,
             buffer: BytesMut::with_capacity(MAX_MSG_SIZE),
             phantom: std::marker::PhantomData,
         }
     }
 
-    /// Reads `exactly one frame` from the underlying [TcpStream], see [RecvStatus] for more details on the meaning of
+    /// Reads `exactly one frame` from the underlying [Transport], see [RecvStatus] for more details on the meaning of
     /// each variant in the successful scenario.
     /// # Note
     /// If the [FrameWriter] `pair` is dropped this method will return [RecvStatus::Completed(None)]
@@ -113,7 +106,7 @@ impl<F: Framer, const MAX_MSG_SIZE: usize> FrameReader<F, MAX_MSG_SIZE> {
         #[allow(clippy::uninit_assumed_init)]
         let mut buf: [u8; MAX_MSG_SIZE] = unsafe { MaybeUninit::uninit().assume_init() };
 
-        match self.stream_reader.read(&mut buf) {
+        match self.read(&mut buf) {
             Ok(EOF) => {
                 // key to shutdown using Write as this will
                 self.shutdown(Shutdown::Write, "read_frame EOF"); // remember to shutdown on both exception and on EOF
@@ -151,7 +144,32 @@ impl<F: Framer, const MAX_MSG_SIZE: usize> FrameReader<F, MAX_MSG_SIZE> {
         }
     }
 
-    /// Shuts down the underlying [TcpStream] in the specified direction.
+    /// Issues a [std::io::Read::read] on the underlying [Transport] or, if the connection is secured, reads decrypted bytes from the [crate::core::tls::TlsSession]
+    #[inline(always)]
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize, Error> {
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            return tls.read(&mut self.stream_reader, buf);
+        }
+        self.stream_reader.read(buf)
+    }
+
+    /// Same as [FrameWriter::drain_outbound_queue] but writes the `queue` of the `paired` [FrameWriter] using this half of the connection,
+    /// which allows the thread reading the connection to drain the queue as soon as the [Transport] becomes writable
+    pub(crate) fn drain_outbound_queue(&mut self, queue: &SharedOutboundQueue) -> Result<SendStatus, Error> {
+        let mut queue = queue.lock();
+        match queue.drain(&mut self.stream_reader) {
+            Ok(status) => Ok(status),
+            Err(e) => {
+                let residual = queue.buf.len();
+                drop(queue);
+                self.shutdown(Shutdown::Both, "drain_outbound_queue error"); // remember to shutdown on both exception and on EOF
+                let msg = format!("{} {}::drain_outbound_queue caused by: [{}], residual len: {}", self.con_id, asserted_short_name!("FrameReader", Self), e, residual);
+                Err(Error::new(e.kind(), msg))
+            }
+        }
+    }
+    /// Shuts down the underlying [Transport] in the specified direction.
     /// # Note side effects of each variant below
     ///  * [Shutdown::Write] will send TCP FIN flag to the peer, as a result all subsequent `paired` [FrameWriter::write_frame] will fail with [ErrorKind::BrokenPipe]
     ///  * [Shutdown::Read] will `NOT` send any TCP flags to the peer, however, as a result all subsequent [Self::read_frame] will return [Ok(0)].
@@ -170,12 +188,15 @@ impl<F: Framer, const MAX_MSG_SIZE: usize> FrameReader<F, MAX_MSG_SIZE> {
                 }
             }
             Err(e) => {
-                panic!("{}::shutdown how: {:?}, reason: {}, caused by: [{}]", self, how, reason, e);
+                // called on EOF, error & drop paths where the original cause is already being reported, hence only warn
+                if log_enabled!(log::Level::Warn) {
+                    warn!("{}::shutdown failed how: {:?}, reason: {}, caused by: [{}]", self, how, reason, e);
+                }
             }
         }
     }
 }
-impl<F: Framer, const MAX_MSG_SIZE: usize> Drop for FrameReader<F, MAX_MSG_SIZE> {
+impl<F: Framer, const MAX_MSG_SIZE: usize, T: Transport> Drop for FrameReader<F, MAX_MSG_SIZE, T> {
     /// Will shutdown the underlying [mio::net::TcpStream] in both directions. This way
     /// the `peer` connection will receive a TCP FIN flag and and once it reaches the `peer` [FrameWriter] it will
     /// get a [ErrorKind::BrokenPipe] error which in turn shall issue a [Shutdown::Write]
@@ -183,36 +204,173 @@ impl<F: Framer, const MAX_MSG_SIZE: usize> Drop for FrameReader<F, MAX_MSG_SIZE>
         self.shutdown(Shutdown::Both, "FrameReader::drop")
     }
 }
-impl<F: Framer, const MAX_MSG_SIZE: usize> Display for FrameReader<F, MAX_MSG_SIZE> {
+impl<F: Framer, const MAX_MSG_SIZE: usize, T: Transport> Display for FrameReader<F, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "FrameReader<{}> {{ {}, addr: {}, peer: {}, fd: {} }}",
+            "FrameReader<{}> {{ {}, addr: {}, peer: {} }}",
             std::any::type_name::<F>().split("::").last().unwrap_or("Unknown"),
             self.con_id,
-            match self.stream_reader.local_addr() {
-                Ok(_) => "connected",
-                Err(_) => "disconnected",
-            },
-            match self.stream_reader.peer_addr() {
-                Ok(_) => "connected",
-                Err(_) => "disconnected",
-            },
-            fd(&self.stream_reader),
+            if self.stream_reader.local_addr().is_ok() { "connected" } else { "disconnected" },
+            if self.stream_reader.peer_addr().is_ok() { "connected" } else { "disconnected" },
         )
     }
 }
 
-/// Represents an abstraction for writing exactly one frame to the non blocking underlying [mio::net::TcpStream]
+/// Maximum number of frames passed to a single [std::io::Write::write_vectored] call by [FrameWriter::write_frames], matches the typical `IOV_MAX`
+pub const FRAME_WRITER_MAX_IO_SLICES: usize = 1024;
+
+/// Bytes accepted by [FrameWriter] which the [Transport] was not yet able to take, see [FrameWriter::enable_outbound_queue]
 #[derive(Debug)]
-pub struct FrameWriter {
+pub(crate) struct OutboundQueue {
+    buf: BytesMut,
+    high_water_mark: usize,
+}
+impl OutboundQueue {
+    fn new(high_water_mark: NonZeroUsize) -> Self {
+        Self { buf: BytesMut::new(), high_water_mark: high_water_mark.get() }
+    }
+    /// Writes queued bytes until the queue is empty or the `writer` would block
+    fn drain<W: Write>(&mut self, writer: &mut W) -> Result<SendStatus, Error> {
+        while !self.buf.is_empty() {
+            match writer.write(&self.buf) {
+                Ok(EOF) => return Err(Error::new(ErrorKind::ConnectionReset, "connection reset by peer")),
+                Ok(len) => self.buf.advance(len),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(SendStatus::WouldBlock),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(SendStatus::Completed)
+    }
+}
+/// [OutboundQueue] shared by the `paired` [FrameWriter] and [FrameReader] so that it can be drained by whichever thread is notified that the [Transport] is writable,
+/// the lock is held for the duration of every write to keep the frames in order
+pub(crate) type SharedOutboundQueue = Arc<spin::Mutex<OutboundQueue>>;
+
+/// Represents an abstraction for writing exactly one frame to the non blocking underlying [Transport], which defaults to a [mio::net::TcpStream]
+#[derive(Debug)]
+pub struct FrameWriter<T: Transport = mio::net::TcpStream> {
     pub(crate) con_id: ConId,
-    pub(crate) stream_writer: mio::net::TcpStream,
+    pub(crate) stream_writer: T,
+    outbound: Option<SharedOutboundQueue>,
+    #[cfg(feature = "tls")]
+    pub(crate) tls: Option<crate::core::tls::TlsSession>,
 }
-impl FrameWriter {
-    /// Constructs a new instance of [FrameWriter]
-    pub fn new(con_id: ConId, stream: mio::net::TcpStream) -> Self {
-        Self { con_id, stream_writer: stream }
+impl<T: Transport> FrameWriter<T> {
+    /// Constructs a new instance of [FrameWriter] from a [Transport]
+    pub fn new(con_id: ConId, stream: T) -> Self {
+        Self {
+            con_id,
+            stream_writer: stream,
+            outbound: None,
+            #[cfg(feature = "tls")]
+            tls: None,
+        }
+    }
+    /// Enables an outbound queue, or updates its `high_water_mark` if it is already enabled, which changes the behavior of [FrameWriter::write_frame] as follows:
+    ///  * Bytes of a frame which the [Transport] would not take are enqueued instead of `busy waiting` on the socket and the frame is reported as [SendStatus::Completed]
+    ///  * Queued bytes are written ahead of any new frame on every subsequent write or when [FrameWriter::drain_outbound_queue] is called
+    ///  * A new frame which would grow the queue beyond `high_water_mark` bytes is not accepted and [SendStatus::WouldBlock] is returned, signaling backpressure
+    ///
+    /// # Note
+    /// The queue is not used when the connection is secured using [crate::core::tls::TlsSession]
+    pub fn enable_outbound_queue(&mut self, high_water_mark: NonZeroUsize) {
+        match &self.outbound {
+            Some(queue) => queue.lock().high_water_mark = high_water_mark.get(),
+            None => self.outbound = Some(Arc::new(spin::Mutex::new(OutboundQueue::new(high_water_mark)))),
+        }
+    }
+    /// Number of bytes waiting in the outbound queue, always `0` if the queue is not enabled, see [FrameWriter::enable_outbound_queue]
+    #[inline(always)]
+    pub fn outbound_queue_len(&self) -> usize {
+        self.outbound.as_ref().map_or(0, |queue| queue.lock().buf.len())
+    }
+    /// Outbound queue, if enabled, which the `paired` [FrameReader] can drain using [FrameReader::drain_outbound_queue]
+    pub(crate) fn outbound_queue(&self) -> Option<SharedOutboundQueue> {
+        self.outbound.clone()
+    }
+    /// Writes bytes waiting in the outbound queue to the underlying stream without `busy waiting`, see [FrameWriter::enable_outbound_queue]
+    ///
+    /// # Returns
+    ///   * [SendStatus::Completed] - if the queue is empty or not enabled
+    ///   * [SendStatus::WouldBlock] - if the stream would block before the queue was emptied
+    pub fn drain_outbound_queue(&mut self) -> Result<SendStatus, Error> {
+        let mut queue = match &self.outbound {
+            Some(queue) => queue.lock(),
+            None => return Ok(SendStatus::Completed),
+        };
+        match queue.drain(&mut self.stream_writer) {
+            Ok(status) => Ok(status),
+            Err(e) => {
+                let residual = queue.buf.len();
+                drop(queue);
+                self.shutdown(Shutdown::Both, "drain_outbound_queue error"); // remember to shutdown on both exception and on EOF
+                let msg = format!("{} {}::drain_outbound_queue caused by: [{}], residual len: {}", self.con_id, asserted_short_name!("FrameWriter", Self), e, residual);
+                Err(Error::new(e.kind(), msg))
+            }
+        }
+    }
+    /// Same as [FrameWriter::write_frame] but with the outbound queue enabled, see [FrameWriter::enable_outbound_queue]
+    fn write_frame_queued(&mut self, bytes: &[u8]) -> Result<SendStatus, Error> {
+        let mut queue = self.outbound.as_ref().expect("outbound queue is enabled").lock();
+        match queue.drain(&mut self.stream_writer) {
+            Ok(SendStatus::Completed) => {}
+            Ok(SendStatus::WouldBlock) => {
+                if queue.buf.len() + bytes.len() > queue.high_water_mark {
+                    return Ok(SendStatus::WouldBlock);
+                }
+                queue.buf.extend_from_slice(bytes); // preserve the order of the frames
+                return Ok(SendStatus::Completed);
+            }
+            Err(e) => {
+                let residual = queue.buf.len();
+                drop(queue);
+                self.shutdown(Shutdown::Both, "drain_outbound_queue error"); // remember to shutdown on both exception and on EOF
+                let msg = format!("{} {}::drain_outbound_queue caused by: [{}], residual len: {}", self.con_id, asserted_short_name!("FrameWriter", Self), e, residual);
+                return Err(Error::new(e.kind(), msg));
+            }
+        }
+        let mut residual = bytes;
+        while !residual.is_empty() {
+            match self.stream_writer.write(residual) {
+                Ok(EOF) => {
+                    drop(queue);
+                    self.shutdown(Shutdown::Both, "write_frame EOF"); // remember to shutdown on both exception and on EOF
+                    let msg = format!("{} {}::write_frame connection reset by peer, residual buf:\n{}", self.con_id, asserted_short_name!("FrameWriter", Self), to_hex_pretty(residual));
+                    return Err(Error::new(ErrorKind::ConnectionReset, msg));
+                }
+                Ok(len) => residual = &residual[len..],
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    if bytes.len() == residual.len() && residual.len() > queue.high_water_mark {
+                        // no bytes where written and the frame would not fit so Just report back NotReady
+                        return Ok(SendStatus::WouldBlock);
+                    }
+                    // residual of a partially written frame is always enqueued to keep the stream consistent
+                    queue.buf.extend_from_slice(residual);
+                    return Ok(SendStatus::Completed);
+                }
+                Err(e) => {
+                    drop(queue);
+                    self.shutdown(Shutdown::Both, "write_frame error"); // remember to shutdown on both exception and on EOF
+                    let msg = format!("{} {}::write_frame caused by: [{}], residual len: {}", self.con_id, asserted_short_name!("FrameWriter", Self), e, residual.len());
+                    return Err(Error::new(e.kind(), msg));
+                }
+            }
+        }
+        drop(queue);
+        self.flush("write_frame")?;
+        Ok(SendStatus::Completed)
+    }
+    /// Flushes the underlying [Transport] and shuts it down if the flush fails
+    fn flush(&mut self, context: &str) -> Result<(), Error> {
+        match self.stream_writer.flush() {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                self.shutdown(Shutdown::Both, "flush error"); // remember to shutdown on both exception and on EOF
+                let msg = format!("{} {}::{} flush caused by: [{}]", self.con_id, asserted_short_name!("FrameWriter", Self), context, e);
+                Err(Error::new(e.kind(), msg))
+            }
+        }
     }
     /// Writes `entire` frame or `no` bytes at all to the underlying stream, see [SendStatus] for more details on the meaning of
     /// each variant in the successful scenario.
@@ -221,16 +379,34 @@ impl FrameWriter {
     ///    * bytes - a slice representing one complete frame
     ///
     /// # Important
-    /// The function will internally issue a [Write::write] system call repeatedly on the underlying [mio::net::TcpStream]
+    /// The function will internally issue a [std::io::Write::write] system call repeatedly on the underlying [Transport]
     /// until all of the bytes are written, while `busy waiting` on the socket if write returns [ErrorKind::WouldBlock].
     ///
-    /// However, if an only if, the first call to [Write::write] returns [ErrorKind::WouldBlock] and no bytes where written
+    /// However, if an only if, the first call to [std::io::Write::write] returns [ErrorKind::WouldBlock] and no bytes where written
     /// to the underlying socket, the method will return immediately with [Ok(SendStatus::WouldBlock)].
     ///
     /// # Note
     /// If the [FrameReader] `pair` is dropped this method will return [Err(ErrorKind::BrokenPipe)]
+    ///
+    /// If the connection is secured the frame is encrypted using [crate::core::tls::TlsSession] which follows the same semantics
+    ///
+    /// If the outbound queue is enabled the method never `busy waits`, see [FrameWriter::enable_outbound_queue]
     #[inline(always)]
     pub fn write_frame(&mut self, bytes: &[u8]) -> Result<SendStatus, Error> {
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            return match tls.write(&mut self.stream_writer, bytes) {
+                Ok(status) => Ok(status),
+                Err(e) => {
+                    self.shutdown(Shutdown::Both, "write_frame tls error"); // remember to shutdown on both exception and on EOF
+                    let msg = format!("{} {}::write_frame caused by: [{}], frame len: {}", self.con_id, asserted_short_name!("FrameWriter", Self), e, bytes.len());
+                    Err(Error::new(e.kind(), msg))
+                }
+            };
+        }
+        if self.outbound.is_some() {
+            return self.write_frame_queued(bytes);
+        }
         let mut residual = bytes;
         while !residual.is_empty() {
             match self.stream_writer.write(residual) {
@@ -276,7 +452,90 @@ impl FrameWriter {
         Ok(SendStatus::Completed)
     }
 
-    /// Shuts down the underlying [mio::net::TcpStream] in the specified direction.
+    /// Writes as many of the `frames` as possible to the underlying stream using [std::io::Write::write_vectored], which allows
+    /// many frames to be delivered by a single system call. Same as with [FrameWriter::write_frame] each frame is written `entirely` or `not at all`.
+    ///
+    /// # Arguments
+    ///    * frames - a slice of frames, each representing one complete frame
+    ///    * completed - will be set to the number of leading frames written in their entirety, including in the event of an error
+    ///
+    /// # Returns
+    ///   * [SendStatus::Completed] - if all of the frames were written
+    ///   * [SendStatus::WouldBlock] - if the stream would block on a frame boundary, `completed` indicates how many frames were written
+    ///
+    /// # Important
+    /// If the stream would block after some of the frame's bytes were written the method will `busy wait` until the rest of that frame is written
+    ///
+    /// If the connection is secured or the outbound queue is enabled the frames are written one at a time using [FrameWriter::write_frame]
+    pub fn write_frames(&mut self, frames: &[&[u8]], completed: &mut usize) -> Result<SendStatus, Error> {
+        *completed = 0;
+        #[cfg(feature = "tls")]
+        let write_one_at_a_time = self.tls.is_some() || self.outbound.is_some();
+        #[cfg(not(feature = "tls"))]
+        let write_one_at_a_time = self.outbound.is_some();
+        if write_one_at_a_time {
+            for frame in frames {
+                if self.write_frame(frame)?.is_wouldblock() {
+                    return Ok(SendStatus::WouldBlock);
+                }
+                *completed += 1;
+            }
+            return Ok(SendStatus::Completed);
+        }
+        let mut offset = 0; // bytes of frames[*completed] already written
+        let mut slices = Vec::with_capacity(frames.len().min(FRAME_WRITER_MAX_IO_SLICES));
+        loop {
+            // skip over fully written and empty frames
+            while *completed < frames.len() && offset == frames[*completed].len() {
+                *completed += 1;
+                offset = 0;
+            }
+            if *completed == frames.len() {
+                self.flush("write_frames")?;
+                return Ok(SendStatus::Completed);
+            }
+
+            slices.clear();
+            slices.push(IoSlice::new(&frames[*completed][offset..]));
+            slices.extend(frames[*completed + 1..].iter().take(FRAME_WRITER_MAX_IO_SLICES - 1).map(|frame| IoSlice::new(frame)));
+            match self.stream_writer.write_vectored(&slices) {
+                Ok(EOF) => {
+                    self.shutdown(Shutdown::Both, "write_frames EOF"); // remember to shutdown on both exception and on EOF
+                    let msg = format!("{} {}::write_frames connection reset by peer, completed frames: {} of {}", self.con_id, asserted_short_name!("FrameWriter", Self), *completed, frames.len());
+                    return Err(Error::new(ErrorKind::ConnectionReset, msg));
+                }
+                Ok(mut len) => {
+                    while len > 0 {
+                        let residual = frames[*completed].len() - offset;
+                        if len >= residual {
+                            len -= residual;
+                            *completed += 1;
+                            offset = 0;
+                        } else {
+                            offset += len;
+                            len = 0;
+                        }
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    if offset == 0 {
+                        // on a frame boundary so Just report back NotReady
+                        return Ok(SendStatus::WouldBlock);
+                    } else {
+                        // some bytes of the frame where written have to finish it
+                        continue;
+                    }
+                }
+                Err(e) => {
+                    self.shutdown(Shutdown::Both, "write_frames error"); // remember to shutdown on both exception and on EOF
+                    let msg = format!("{} {}::write_frames caused by: [{}], completed frames: {} of {}", self.con_id, asserted_short_name!("FrameWriter", Self), e, *completed, frames.len());
+                    return Err(Error::new(e.kind(), msg));
+                }
+            }
+        }
+    }
+
+    /// Shuts down the underlying [Transport] in the specified direction.
     pub(crate) fn shutdown(&mut self, how: Shutdown, reason: &str) {
         match self.stream_writer.shutdown(how) {
             Ok(_) => {
@@ -290,37 +549,42 @@ impl FrameWriter {
                 }
             }
             Err(e) => {
-                panic!("{}::shutdown how: {:?}, reason: {}, caused by: [{}]", self, how, reason, e);
+                // called on EOF, error & drop paths where the original cause is already being reported, hence only warn
+                if log_enabled!(log::Level::Warn) {
+                    warn!("{}::shutdown failed how: {:?}, reason: {}, caused by: [{}]", self, how, reason, e);
+                }
             }
         }
     }
 }
-impl Drop for FrameWriter {
-    /// Will shutdown the underlying [mio::net::TcpStream] in both directions.
+impl<T: Transport> Drop for FrameWriter<T> {
+    /// Will shutdown the underlying [Transport] in both directions, bytes remaining in the outbound queue after a final non blocking attempt to write them are discarded.
     fn drop(&mut self) {
+        if let Ok(SendStatus::WouldBlock) = self.drain_outbound_queue() {
+            if log_enabled!(log::Level::Warn) {
+                warn!("{}::drop discarding outbound queue len: {}", self, self.outbound_queue_len());
+            }
+        }
+        #[cfg(feature = "tls")]
+        if let Some(tls) = &self.tls {
+            tls.close(&mut self.stream_writer);
+        }
         self.shutdown(Shutdown::Both, "FrameWriter::drop")
     }
 }
-impl Display for FrameWriter {
+impl<T: Transport> Display for FrameWriter<T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "FrameWriter {{ {}, addr: {}, peer: {}, fd: {} }}",
+            "FrameWriter {{ {}, addr: {}, peer: {} }}",
             self.con_id,
-            match self.stream_writer.local_addr() {
-                Ok(_) => "connected",
-                Err(_) => "disconnected",
-            },
-            match self.stream_writer.peer_addr() {
-                Ok(_) => "connected",
-                Err(_) => "disconnected",
-            },
-            fd(&self.stream_writer),
+            if self.stream_writer.local_addr().is_ok() { "connected" } else { "disconnected" },
+            if self.stream_writer.peer_addr().is_ok() { "connected" } else { "disconnected" },
         )
     }
 }
 
-type FrameProcessor<F, const MAX_MSG_SIZE: usize> = (FrameReader<F, MAX_MSG_SIZE>, FrameWriter);
+type FrameProcessor<F, const MAX_MSG_SIZE: usize, T = mio::net::TcpStream> = (FrameReader<F, MAX_MSG_SIZE, T>, FrameWriter<T>);
 
 /// Creates a `paired` [FrameReader] and [FrameWriter] from a [std::net::TcpStream] by cloning it and converting
 /// the underlying stream to [mio::net::TcpStream]
@@ -342,6 +606,15 @@ pub fn into_split_framer<F: Framer, const MAX_MSG_SIZE: usize>(mut con_id: ConId
     (FrameReader::<F, MAX_MSG_SIZE>::new(con_id.clone(), reader), FrameWriter::new(con_id, writer))
 }
 
+/// Creates a `paired` [FrameReader] and [FrameWriter] from any non blocking [Transport] using [Transport::try_clone]
+///
+/// # Important
+/// Unlike [into_split_framer] the `con_id` is used as is, and the `transport` is expected to already be in non blocking mode
+pub fn into_split_framer_transport<F: Framer, const MAX_MSG_SIZE: usize, T: Transport>(con_id: ConId, transport: T) -> Result<FrameProcessor<F, MAX_MSG_SIZE, T>, Error> {
+    let reader = transport.try_clone()?;
+    Ok((FrameReader::<F, MAX_MSG_SIZE, T>::new(con_id.clone(), reader), FrameWriter::new(con_id, transport)))
+}
+
 #[cfg(test)]
 mod test {
     use crate::prelude::*;
@@ -388,7 +661,7 @@ mod test {
                     let (stream, _) = listener.accept().unwrap();
                     // keep _writer because if you drop it the reader connection will also be closed
 
-                    let (mut reader, _writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None), stream);
+                    let (mut reader, _writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), stream);
                     info!("svc: reader: {}", reader);
                     let mut frame_recv_count = 0_usize;
                     loop {
@@ -429,7 +702,7 @@ mod test {
         // CONFIGURE clt
         let stream = TcpStream::connect(addr).unwrap();
 
-        let (mut clt_reader, mut clt_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), stream);
+        let (mut clt_reader, mut clt_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), stream);
 
         info!("clt: writer: {}", clt_writer);
 
@@ -474,4 +747,97 @@ mod test {
         assert_eq!(frame_send_count, frame_recv_count);
         assert_eq!(frame_send_count, WRITE_N_TIMES);
     }
+    #[test]
+    fn test_write_frames() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        const TEST_SEND_FRAME_SIZE: usize = 128;
+        const WRITE_N_FRAMES: usize = 100_000;
+        pub type MsgFramer = FixedSizeFramer<TEST_SEND_FRAME_SIZE>;
+
+        let send_frames = (0..WRITE_N_FRAMES)
+            .map(|idx| {
+                let mut frame = [0_u8; TEST_SEND_FRAME_SIZE];
+                frame[..8].copy_from_slice(&(idx as u64).to_be_bytes());
+                frame
+            })
+            .collect::<Vec<_>>();
+        let send_frames = send_frames.iter().map(|frame| &frame[..]).collect::<Vec<_>>();
+
+        let addr = setup::net::rand_avail_addr_port();
+        let listener = TcpListener::bind(addr).unwrap();
+        let (_clt_reader, mut clt_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), TcpStream::connect(addr).unwrap());
+        let (mut svc_reader, _svc_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), listener.accept().unwrap().0);
+
+        // writes will fill up socket buffers and may block on a frame boundary, in which case the remaining frames must be written again
+        let svc = thread::Builder::new()
+            .name("Thread-Svc".to_owned())
+            .spawn(move || {
+                let mut recv_frames = Vec::with_capacity(WRITE_N_FRAMES);
+                while recv_frames.len() < WRITE_N_FRAMES {
+                    if let RecvStatus::Completed(Some(recv_frame)) = svc_reader.read_frame().unwrap() {
+                        recv_frames.push(recv_frame);
+                    }
+                }
+                recv_frames
+            })
+            .unwrap();
+
+        let (mut frame_send_count, mut wouldblock_count) = (0_usize, 0_usize);
+        while frame_send_count < WRITE_N_FRAMES {
+            let mut completed = 0;
+            let status = clt_writer.write_frames(&send_frames[frame_send_count..], &mut completed).unwrap();
+            frame_send_count += completed;
+            match status {
+                SendStatus::Completed => assert_eq!(frame_send_count, WRITE_N_FRAMES),
+                SendStatus::WouldBlock => wouldblock_count += 1,
+            }
+        }
+        let recv_frames = svc.join().unwrap();
+        info!("frame_send_count: {}, frame_recv_count: {}, wouldblock_count: {}", fmt_num!(frame_send_count), fmt_num!(recv_frames.len()), fmt_num!(wouldblock_count));
+        assert_eq!(recv_frames.len(), WRITE_N_FRAMES);
+        for (send_frame, recv_frame) in send_frames.iter().zip(recv_frames.iter()) {
+            assert_eq!(*send_frame, &recv_frame[..]);
+        }
+    }
+
+    #[test]
+    fn test_write_frame_outbound_queue() {
+        setup::log::configure_level(log::LevelFilter::Info);
+        const TEST_SEND_FRAME_SIZE: usize = 128;
+        const HIGH_WATER_MARK: usize = 64 * 1024;
+        pub type MsgFramer = FixedSizeFramer<TEST_SEND_FRAME_SIZE>;
+
+        let addr = setup::net::rand_avail_addr_port();
+        let listener = TcpListener::bind(addr).unwrap();
+        let (_clt_reader, mut clt_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), TcpStream::connect(addr).unwrap());
+        let (mut svc_reader, _svc_writer) = into_split_framer::<MsgFramer, TEST_SEND_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), listener.accept().unwrap().0);
+        clt_writer.enable_outbound_queue(std::num::NonZeroUsize::new(HIGH_WATER_MARK).unwrap());
+
+        // peer is not reading hence writer will eventually fill up socket buffers and the queue, at which point it must not busy wait
+        let mut frame_send_count = 0_u64;
+        loop {
+            let mut frame = [0_u8; TEST_SEND_FRAME_SIZE];
+            frame[..8].copy_from_slice(&frame_send_count.to_be_bytes());
+            match clt_writer.write_frame(&frame).unwrap() {
+                SendStatus::Completed => frame_send_count += 1,
+                SendStatus::WouldBlock => break,
+            }
+        }
+        info!("frame_send_count: {}, outbound_queue_len: {}", fmt_num!(frame_send_count), fmt_num!(clt_writer.outbound_queue_len()));
+        assert!(clt_writer.outbound_queue_len() > HIGH_WATER_MARK - TEST_SEND_FRAME_SIZE);
+        assert!(clt_writer.outbound_queue_len() <= HIGH_WATER_MARK);
+
+        // once peer starts reading the queue is drained and all frames are delivered in order
+        let mut frame_recv_count = 0_u64;
+        while frame_recv_count < frame_send_count {
+            clt_writer.drain_outbound_queue().unwrap();
+            while let RecvStatus::Completed(Some(recv_frame)) = svc_reader.read_frame().unwrap() {
+                assert_eq!(recv_frame[..8], frame_recv_count.to_be_bytes());
+                frame_recv_count += 1;
+            }
+        }
+        assert_eq!(clt_writer.drain_outbound_queue().unwrap(), SendStatus::Completed);
+        assert_eq!(clt_writer.outbound_queue_len(), 0);
+        assert_eq!(frame_recv_count, frame_send_count);
+    }
 }
@@ -1,5 +1,5 @@
 //! This module contains a non blocking `paired` [MessageRecver] and [MessageSender] which are designed to be used in separate threads,
-//! where each thread is only doing either send or recv to the underlying [mio::net::TcpStream] via respective [FrameReader] and [FrameWriter].
+//! where each thread is only doing either send or recv to the underlying [Transport], which defaults to a [mio::net::TcpStream], via respective [FrameReader] and [FrameWriter].
 //!
 //! # Note
 //! The underlying [std::net::TcpStream] is cloned and therefore share a single underlying network socket.
@@ -15,15 +15,15 @@
 //!
 //! let clt_stream = std::net::TcpStream::connect(addr).unwrap();
 //! let (clt_recv, clt_send) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(
-//!         ConId::clt(Some("unittest"), None, addr),
+//!         ConId::clt(Some("unittest"), None, addr).unwrap(),
 //!         clt_stream,
-//!     );
+//!     ).unwrap();
 //!
 //! let svc_stream = svc_listener.accept().unwrap().0;
 //! let (svc_recv, svc_send) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(
-//!         ConId::svc(Some("unittest"), addr, None),
+//!         ConId::svc(Some("unittest"), addr, None).unwrap(),
 //!         svc_stream,
-//!     );
+//!     ).unwrap();
 //!
 //! drop(clt_recv);
 //! drop(clt_send);
@@ -39,7 +39,7 @@
 //!         // clt_recv & svc_send
 //!         // svc_recv & clt_send
 //! ```
-use crate::prelude::{ConId, FrameReader, FrameWriter, Messenger, RecvNonBlocking, RecvStatus, SendNonBlockingNonMut, SendStatus};
+use crate::prelude::{ConId, FrameReader, FrameWriter, Messenger, RecvNonBlocking, RecvStatus, SendNonBlockingNonMut, SendStatus, Transport};
 use std::{
     any::type_name,
     fmt::Display,
@@ -49,33 +49,37 @@ use std::{
 
 /// Represents an abstraction for receiving exactly one message utilizing the underlying [FrameReader]
 #[derive(Debug)]
-pub struct MessageRecver<M: Messenger, const MAX_MSG_SIZE: usize> {
-    pub(crate) frm_reader: FrameReader<M, MAX_MSG_SIZE>,
+pub struct MessageRecver<M: Messenger, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
+    pub(crate) frm_reader: FrameReader<M, MAX_MSG_SIZE, T>,
     phantom: std::marker::PhantomData<M>,
 }
-impl<M: Messenger, const MAX_MSG_SIZE: usize> MessageRecver<M, MAX_MSG_SIZE> {
-    pub fn new(con_id: ConId, stream: mio::net::TcpStream) -> Self {
+impl<M: Messenger, const MAX_MSG_SIZE: usize, T: Transport> MessageRecver<M, MAX_MSG_SIZE, T> {
+    pub fn new(con_id: ConId, stream: T) -> Self {
         Self {
-            frm_reader: FrameReader::<M, MAX_MSG_SIZE>::new(con_id, stream),
+            frm_reader: FrameReader::<M, MAX_MSG_SIZE, T>::new(con_id, stream),
             phantom: std::marker::PhantomData,
         }
     }
 }
-impl<M: Messenger, const MAX_MSG_SIZE: usize> RecvNonBlocking<M::RecvT> for MessageRecver<M, MAX_MSG_SIZE> {
+impl<M: Messenger, const MAX_MSG_SIZE: usize, T: Transport> RecvNonBlocking<M::RecvT> for MessageRecver<M, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn recv(&mut self) -> Result<RecvStatus<M::RecvT>, Error> {
         let status = self.frm_reader.read_frame()?;
         match status {
-            RecvStatus::Completed(Some(frame)) => {
-                let msg = M::deserialize(&frame)?;
-                Ok(RecvStatus::Completed(Some(msg)))
-            }
+            RecvStatus::Completed(Some(frame)) => match M::deserialize(&frame) {
+                Ok(msg) => Ok(RecvStatus::Completed(Some(msg))),
+                Err(e) => Err(links_core::Error::Serialization {
+                    con_id: Box::new(self.frm_reader.con_id.clone()),
+                    reason: format!("{} failed to deserialize frame caused by: [{}]", self, e),
+                }
+                .into()),
+            },
             RecvStatus::Completed(None) => Ok(RecvStatus::Completed(None)),
             RecvStatus::WouldBlock => Ok(RecvStatus::WouldBlock),
         }
     }
 }
-impl<M: Messenger, const MAX_MSG_SIZE: usize> Display for MessageRecver<M, MAX_MSG_SIZE> {
+impl<M: Messenger, const MAX_MSG_SIZE: usize, T: Transport> Display for MessageRecver<M, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let name = type_name::<M>().split("::").last().unwrap_or("Unknown");
         write!(f, "{} MessageRecver<{}, {}>", self.frm_reader.con_id, name, MAX_MSG_SIZE)
@@ -84,19 +88,46 @@ impl<M: Messenger, const MAX_MSG_SIZE: usize> Display for MessageRecver<M, MAX_M
 
 /// Represents an abstraction for sending exactly one message utilizing the underlying [FrameWriter]
 #[derive(Debug)]
-pub struct MessageSender<M: Messenger, const MAX_MSG_SIZE: usize> {
-    pub(crate) frm_writer: FrameWriter,
+pub struct MessageSender<M: Messenger, const MAX_MSG_SIZE: usize, T: Transport = mio::net::TcpStream> {
+    pub(crate) frm_writer: FrameWriter<T>,
+    batch: Vec<u8>, // reused by send_batch to serialize messages into a contiguous buffer
     phantom: std::marker::PhantomData<M>,
 }
-impl<M: Messenger, const MAX_MSG_SIZE: usize> MessageSender<M, MAX_MSG_SIZE> {
-    pub fn new(con_id: ConId, stream: mio::net::TcpStream) -> Self {
+impl<M: Messenger, const MAX_MSG_SIZE: usize, T: Transport> MessageSender<M, MAX_MSG_SIZE, T> {
+    pub fn new(con_id: ConId, stream: T) -> Self {
         Self {
             frm_writer: FrameWriter::new(con_id, stream),
+            batch: Vec::new(),
             phantom: std::marker::PhantomData,
         }
     }
+    /// Serializes all of the `msgs` into a contiguous buffer and writes them using [FrameWriter::write_frames], see it for
+    /// the meaning of the `completed` argument and returned [SendStatus]
+    ///
+    /// # Note
+    /// If any of the messages fails to serialize no bytes are written and the error is returned
+    pub fn send_batch(&mut self, msgs: &[M::SendT], completed: &mut usize) -> Result<SendStatus, Error> {
+        *completed = 0;
+        self.batch.clear();
+        let mut ends = Vec::with_capacity(msgs.len());
+        for msg in msgs {
+            let (bytes, size) = M::serialize::<MAX_MSG_SIZE>(msg)?;
+            self.batch.extend_from_slice(&bytes[..size]);
+            ends.push(self.batch.len());
+        }
+        let mut start = 0;
+        let frames = ends
+            .into_iter()
+            .map(|end| {
+                let frame = &self.batch[start..end];
+                start = end;
+                frame
+            })
+            .collect::<Vec<_>>();
+        self.frm_writer.write_frames(&frames, completed)
+    }
 }
-impl<M: Messenger, const MAX_MSG_SIZE: usize> SendNonBlockingNonMut<M::SendT> for MessageSender<M, MAX_MSG_SIZE> {
+impl<M: Messenger, const MAX_MSG_SIZE: usize, T: Transport> SendNonBlockingNonMut<M::SendT> for MessageSender<M, MAX_MSG_SIZE, T> {
     #[inline(always)]
     fn send(&mut self, msg: &<M as Messenger>::SendT) -> Result<SendStatus, Error> {
         let (bytes, size) = M::serialize::<MAX_MSG_SIZE>(msg)?;
@@ -134,14 +165,14 @@ impl<M: Messenger, const MAX_MSG_SIZE: usize> SendNonBlockingNonMut<M::SendT> fo
         }
     }
 }
-impl<M: Messenger, const MAX_MSG_SIZE: usize> Display for MessageSender<M, MAX_MSG_SIZE> {
+impl<M: Messenger, const MAX_MSG_SIZE: usize, T: Transport> Display for MessageSender<M, MAX_MSG_SIZE, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let messenger_name = type_name::<M>().split("::").last().unwrap_or("Unknown");
         write!(f, "{} MessageSender<{}, {}>", self.frm_writer.con_id, messenger_name, MAX_MSG_SIZE)
     }
 }
 
-pub type MessageProcessor<M, const MAX_MSG_SIZE: usize> = (MessageRecver<M, MAX_MSG_SIZE>, MessageSender<M, MAX_MSG_SIZE>);
+pub type MessageProcessor<M, const MAX_MSG_SIZE: usize, T = mio::net::TcpStream> = (MessageRecver<M, MAX_MSG_SIZE, T>, MessageSender<M, MAX_MSG_SIZE, T>);
 
 /// Creates a `paired` [MessageRecver] and [MessageSender] from a [std::net::TcpStream] by cloning it and converting
 /// the underlying stream to [mio::net::TcpStream]
@@ -152,15 +183,55 @@ pub type MessageProcessor<M, const MAX_MSG_SIZE: usize> = (MessageRecver<M, MAX_
 ///
 /// # Important
 /// if either [MessageRecver] or [MessageSender] is dropped, the underlying stream will be shutdown and all actions on the remaining `pair` will fail
-pub fn into_split_messenger<M: Messenger, const MAX_MSG_SIZE: usize>(mut con_id: ConId, stream: std::net::TcpStream) -> MessageProcessor<M, MAX_MSG_SIZE> {
-    stream.set_nonblocking(true).expect("Failed to set nonblocking on TcpStream");
+///
+/// # Errors
+/// [links_core::Error::Disconnected] if the `stream` is no longer connected, for example when the peer resets it right after it was accepted
+pub fn into_split_messenger<M: Messenger, const MAX_MSG_SIZE: usize>(mut con_id: ConId, stream: std::net::TcpStream) -> Result<MessageProcessor<M, MAX_MSG_SIZE>, Error> {
+    stream.set_nonblocking(true)?;
 
-    con_id.set_local(stream.local_addr().unwrap_or_else(|err| panic!("Failed to get local_addr from stream: {:?}, err: {:?}", stream, err)));
-    con_id.set_peer(stream.peer_addr().unwrap_or_else(|err| panic!("Failed to get peer_addr from stream: {:?}, err: {:?}", stream, err)));
-    let (reader, writer) = (stream.try_clone().expect("Failed to try_clone TcpStream for MessageRecver"), stream);
+    let disconnected = |con_id: &ConId, addr: &str, e: Error| links_core::Error::Disconnected {
+        con_id: Box::new(con_id.clone()),
+        reason: format!("failed to get {} from stream: {:?}, error: {}", addr, stream, e),
+    };
+    con_id.set_local(stream.local_addr().map_err(|e| disconnected(&con_id, "local_addr", e))?);
+    con_id.set_peer(stream.peer_addr().map_err(|e| disconnected(&con_id, "peer_addr", e))?);
+    let (reader, writer) = (stream.try_clone()?, stream);
 
     let (reader, writer) = (mio::net::TcpStream::from_std(reader), mio::net::TcpStream::from_std(writer));
-    (MessageRecver::<M, MAX_MSG_SIZE>::new(con_id.clone(), reader), MessageSender::<M, MAX_MSG_SIZE>::new(con_id, writer))
+    Ok((MessageRecver::<M, MAX_MSG_SIZE>::new(con_id.clone(), reader), MessageSender::<M, MAX_MSG_SIZE>::new(con_id, writer)))
+}
+
+/// Creates a `paired` [MessageRecver] and [MessageSender] from a [std::os::unix::net::UnixStream] by cloning it and converting
+/// the underlying stream to [mio::net::UnixStream], see [into_split_messenger] for details
+///
+/// # Note
+/// Unix domain sockets have no [std::net::SocketAddr] hence `con_id` is expected to be one of [ConId::UdsInitiator] or [ConId::UdsAcceptor]
+#[cfg(target_family = "unix")]
+pub fn into_split_messenger_uds<M: Messenger, const MAX_MSG_SIZE: usize>(con_id: ConId, stream: std::os::unix::net::UnixStream) -> Result<MessageProcessor<M, MAX_MSG_SIZE, mio::net::UnixStream>, Error> {
+    stream.set_nonblocking(true)?;
+    let (reader, writer) = (stream.try_clone()?, stream);
+
+    let (reader, writer) = (mio::net::UnixStream::from_std(reader), mio::net::UnixStream::from_std(writer));
+    Ok((MessageRecver::<M, MAX_MSG_SIZE, _>::new(con_id.clone(), reader), MessageSender::<M, MAX_MSG_SIZE, _>::new(con_id, writer)))
+}
+
+/// Creates a `paired` [MessageRecver] and [MessageSender] from any non blocking [Transport] using [Transport::try_clone]
+///
+/// # Note
+/// Unlike [into_split_messenger] the `con_id` is used as is, hence it is expected to already identify the connection
+pub fn into_split_messenger_transport<M: Messenger, const MAX_MSG_SIZE: usize, T: Transport>(con_id: ConId, transport: T) -> Result<MessageProcessor<M, MAX_MSG_SIZE, T>, Error> {
+    let reader = transport.try_clone()?;
+    Ok((MessageRecver::<M, MAX_MSG_SIZE, T>::new(con_id.clone(), reader), MessageSender::<M, MAX_MSG_SIZE, T>::new(con_id, transport)))
+}
+
+/// Same as [into_split_messenger] but both [MessageRecver] and [MessageSender] will share an established [crate::core::tls::TlsSession]
+/// to decrypt and encrypt every frame
+#[cfg(feature = "tls")]
+pub fn into_split_messenger_tls<M: Messenger, const MAX_MSG_SIZE: usize>(con_id: ConId, stream: std::net::TcpStream, tls: crate::core::tls::TlsSession) -> Result<MessageProcessor<M, MAX_MSG_SIZE>, Error> {
+    let (mut msg_recver, mut msg_sender) = into_split_messenger::<M, MAX_MSG_SIZE>(con_id, stream)?;
+    msg_recver.frm_reader.tls = Some(tls.clone());
+    msg_sender.frm_writer.tls = Some(tls);
+    Ok((msg_recver, msg_sender))
 }
 
 #[cfg(test)]
@@ -194,7 +265,7 @@ mod test {
                 let (mut svc_msg_sent_count, mut svc_msg_recv_count) = (0_usize, 0_usize);
                 let listener = std::net::TcpListener::bind(addr).unwrap();
                 let (stream, _) = listener.accept().unwrap();
-                let (mut svc_recver, mut svc_sender) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None), stream);
+                let (mut svc_recver, mut svc_sender) = into_split_messenger::<SvcTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::svc(Some("unittest"), addr, None).unwrap(), stream).unwrap();
                 info!("svc recver: {}", svc_recver);
 
                 while let Ok(status) = svc_recver.recv() {
@@ -220,7 +291,7 @@ mod test {
         let inp_clt_msg = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Client Msg"));
         let (mut clt_msg_sent_count, mut clt_msg_recv_count) = (0, 0);
         let stream = std::net::TcpStream::connect(addr).unwrap();
-        let (mut clt_recver, mut clt_sender) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr), stream);
+        let (mut clt_recver, mut clt_sender) = into_split_messenger::<CltTestMessenger, TEST_MSG_FRAME_SIZE>(ConId::clt(Some("unittest"), None, addr).unwrap(), stream).unwrap();
         info!("clt sender: {}", clt_sender);
         let start = Instant::now();
         for _ in 0..WRITE_N_TIMES {
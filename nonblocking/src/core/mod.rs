@@ -1,12 +1,20 @@
+pub mod datagram;
 pub mod framer;
 pub mod messenger;
+pub mod moldudp64;
 pub mod protocol;
+pub mod socket;
+#[cfg(feature = "tls")]
+pub mod tls;
+pub mod transport;
 
-use links_core::core::conid::ConnectionId;
+use links_core::{core::conid::ConnectionId, prelude::TimerTaskStatus};
 use mio::{Interest, Registry, Token};
+use protocol::persistance::SequencedProtocolStorage;
 use std::{
     fmt::{Debug, Display},
     io::Error,
+    ops::Range,
     time::{Duration, Instant},
 };
 
@@ -301,6 +309,43 @@ impl SendStatus {
     }
 }
 
+/// Error of [SendNonBlocking::send_batch], `sent` is the number of leading messages which were sent in their entirety before the `source` error occurred,
+/// hence unlike an [Error] returned by [SendNonBlocking::send] it tells which of the messages must not be sent again
+#[derive(Debug)]
+pub struct SendBatchError {
+    pub sent: usize,
+    pub source: Error,
+}
+impl SendBatchError {
+    pub fn new(sent: usize, source: Error) -> Self {
+        Self { sent, source }
+    }
+    pub fn kind(&self) -> std::io::ErrorKind {
+        self.source.kind()
+    }
+}
+impl Display for SendBatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "send_batch failed after sent: {}, error: {}", self.sent, self.source)
+    }
+}
+impl std::error::Error for SendBatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+impl From<Error> for SendBatchError {
+    /// Error which occurred before any of the messages were sent
+    fn from(source: Error) -> Self {
+        Self { sent: 0, source }
+    }
+}
+impl From<SendBatchError> for Error {
+    fn from(error: SendBatchError) -> Self {
+        error.source
+    }
+}
+
 pub trait SendNonBlocking<T>: Debug + Display {
     /// The call will internally serialize the `T` and attempt to write the resulting bytes into a stream.
     /// If there was a successfull attempt which wrote some, not all, bytes from serialized message
@@ -350,6 +395,27 @@ pub trait SendNonBlocking<T>: Debug + Display {
             }
         }
     }
+    /// Will attempt to send `msgs` in order and return the number of leading messages which were sent in their entirety, hence a
+    /// value less than `msgs.len()` indicates that the underlying stream would block and the remaining messages must be sent again.
+    /// Every message is either sent completely or not at all, same as with [Self::send].
+    ///
+    /// # Errors
+    /// [SendBatchError] with the number of leading messages which were sent in their entirety before the error occurred
+    ///
+    /// # Note
+    /// This default implementation calls [Self::send] once per message, implementations which are able to coalesce
+    /// the messages into fewer system calls, ex: [crate::prelude::CltSender], shall override it
+    #[inline(always)]
+    fn send_batch(&mut self, msgs: &mut [T]) -> Result<usize, SendBatchError> {
+        for (idx, msg) in msgs.iter_mut().enumerate() {
+            match self.send(msg) {
+                Ok(status) if status.is_wouldblock() => return Ok(idx),
+                Ok(_) => {}
+                Err(source) => return Err(SendBatchError::new(idx, source)),
+            }
+        }
+        Ok(msgs.len())
+    }
 }
 
 pub trait SendNonBlockingNonMut<T> {
@@ -429,6 +495,17 @@ pub trait ReSendNonBlocking<T> {
             }
         }
     }
+    /// Will call [`Self::re_send_busywait`] for each item in the `storage` whose sequence number is in the `range` and return
+    /// the number of items re-sent. Sequence numbers which are not retained by the `storage` are skipped.
+    #[inline(always)]
+    fn re_send_range<S: SequencedProtocolStorage<Item = T>>(&mut self, storage: &S, range: Range<u64>) -> Result<usize, Error> {
+        let mut count = 0;
+        for msg in storage.range(range) {
+            self.re_send_busywait(msg)?;
+            count += 1;
+        }
+        Ok(count)
+    }
 }
 
 // ---- Pool ----
@@ -456,6 +533,14 @@ pub trait PollAble: ConnectionId + Display + Send + 'static {
         registry.deregister(*self.source())?;
         Ok(())
     }
+    /// this function exists as a hook in case you need to perform resource locking prior to re-registering the source with different interests
+    ///
+    /// # Warning
+    /// [PollAble::source] usage will depend on your override implementation and may not used
+    fn reregister(&mut self, registry: &Registry, token: Token, interests: Interest) -> Result<(), Error> {
+        registry.reregister(*self.source(), token, interests)?;
+        Ok(())
+    }
     /// represents the source of the event, typically implementing this function is sufficient as [PollAble::register] and [PollAble::deregister] functions
     /// are implemented using it to get the source for the poll. However You can choose to override [PollAble::register] and [PollAble::deregister] functions
     /// when for example you require to lock a mutes to get access to the source in which case source function will not be used.
@@ -488,10 +573,88 @@ pub trait PollRead: PollAble {
     // fn source(&mut self) -> Box<&mut dyn mio::event::Source>;
     /// Will be called when OS signals that the source is readable
     fn on_readable_event(&mut self) -> Result<PollEventStatus, Error>;
+    /// Instant by which the source expects to become readable, [None] if there is no such expectation
+    #[inline(always)]
+    fn recv_deadline(&self) -> Option<Instant> {
+        None
+    }
+    /// Will be called once [PollRead::recv_deadline] has passed, returning [PollEventStatus::Terminate] or [Err(_)] will result in the source being deregistered
+    #[inline(always)]
+    fn on_recv_deadline(&mut self) -> Result<PollEventStatus, Error> {
+        Ok(PollEventStatus::WouldBlock)
+    }
+    /// [Interest] the source is registered with, it is re-evaluated every time the source is serviced and the source is re-registered using
+    /// [PollAble::reregister] when it changes. Shall only include [Interest::WRITABLE] when [PollRead::as_poll_write] yields [Some]
+    #[inline(always)]
+    fn interests(&self) -> Interest {
+        Interest::READABLE
+    }
+    /// The writable counterpart of the source which will be called when OS signals that the source is writable, [None] by default
+    #[inline(always)]
+    fn as_poll_write(&mut self) -> Option<&mut dyn PollWrite> {
+        None
+    }
+    /// Interval at which [PollRead::on_timer] is called by the thread servicing the source, [None] disables the timer. It is evaluated when the source is registered
+    /// and every time [PollRead::on_timer] yields [TimerTaskStatus::Completed]
+    #[inline(always)]
+    fn timer_interval(&self) -> Option<Duration> {
+        None
+    }
+    /// Will be called once [PollRead::timer_interval] has elapsed, [TimerTaskStatus::RetryAfter] reschedules the call after the given delay while [TimerTaskStatus::Terminate]
+    /// stops the timer without affecting the source. Returning [Err(_)] will result in the source being deregistered
+    #[inline(always)]
+    fn on_timer(&mut self) -> Result<TimerTaskStatus, Error> {
+        Ok(TimerTaskStatus::Terminate)
+    }
+}
+
+/// A trait to be implemented for a type that needs to be notified when its source becomes writable, for example to flush queued data.
+/// It is serviced by a [mio::Poll] instance via [PollRead::as_poll_write] of the source it shares a registration with, see [PollRead::interests]
+/// The source will get automatically deregistered from the poll instance when [PollWrite::on_writable_event]
+/// returns [Ok(PollEventStatus::Terminate)] or [Err(_)]
+pub trait PollWrite {
+    /// Will be called when OS signals that the source is writable, shall only yield [PollEventStatus::Completed] if calling it again can make further progress
+    fn on_writable_event(&mut self) -> Result<PollEventStatus, Error>;
 }
 
 /// A trait to be implemented for a type that can be registered with a [mio::Poll] instance, it helps to
 /// abstract away details of [Token] generation registration and de-registration specifically monitoring
 pub trait PollAccept<R: PollRead>: PollAble {
     fn poll_accept(&mut self) -> Result<AcceptStatus<R>, Error>;
+    /// Same as [PollAccept::poll_accept] but a connection which requires a potentially blocking exchange before it can be serviced, such as a tls handshake,
+    /// can be yielded as [PollAccepted::Connector] whose [PollConnect::into_recver] completes the exchange. This is what [crate::prelude::PollHandler] calls and
+    /// defaults to [PollAccept::poll_accept]
+    fn poll_accept_connect(&mut self) -> Result<AcceptStatus<PollAccepted<R>>, Error> {
+        use AcceptStatus::{Accepted, Rejected, WouldBlock};
+        match self.poll_accept()? {
+            Accepted(recver) => Ok(Accepted(PollAccepted::Recver(recver))),
+            Rejected => Ok(Rejected),
+            WouldBlock => Ok(WouldBlock),
+        }
+    }
+}
+
+/// A connection yielded by [PollAccept::poll_accept_connect]
+pub enum PollAccepted<R: PollRead> {
+    /// Ready to be serviced
+    Recver(R),
+    /// Needs to be completed using [PollConnect::into_recver] before it can be serviced
+    Connector(Box<dyn PollConnect<R>>),
+}
+
+/// A trait to be implemented for a type representing a connection being established by a non-blocking connect, the source is registered
+/// with [Interest::WRITABLE] and once connected it is deregistered and converted into a [PollRead] which is registered in its place
+pub trait PollConnect<R: PollRead>: PollAble {
+    /// Will be called when OS signals that the source is writable, must yield [PollEventStatus::Completed] once the connection is established
+    /// and [PollEventStatus::WouldBlock] if the event was spurious. Returning [PollEventStatus::Terminate] or [Err(_)] will result in the source being deregistered and dropped
+    fn on_writable_event(&mut self) -> Result<PollEventStatus, Error>;
+    /// Will be called once [PollConnect::on_writable_event] yields [PollEventStatus::Completed] and the source is deregistered. A spawned [crate::prelude::PollHandler]
+    /// calls it from one of its connect worker threads, hence it may block without delaying other connectors of the same handler, and registers the resulting [PollRead] once it is returned. Errors are only logged, hence the implementation is
+    /// expected to report them to whoever awaits the connection
+    fn into_recver(self: Box<Self>) -> Result<R, Error>;
+    /// Instant by which the connection must be established, once passed the source is deregistered and dropped
+    #[inline(always)]
+    fn connect_deadline(&self) -> Option<Instant> {
+        None
+    }
 }
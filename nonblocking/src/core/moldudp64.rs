@@ -0,0 +1,328 @@
+//! This module contains [MoldUdp64Packet] and [MoldUdp64Request] wire types of the MoldUDP64 protocol along with [MoldUdp64Framer]
+//! and the [MoldUdp64CltMessenger] / [MoldUdp64SvcMessenger] pair used by [crate::prelude::MoldUdp64Recver] and [crate::prelude::MoldUdp64Publisher].
+//!
+//! # Note
+//! A downstream packet header and its message blocks carry their own lengths, hence the same [MoldUdp64Framer] splits packets received
+//! as datagrams from the feed as well as from the byte stream of the tcp recovery channel.
+use crate::prelude::{FixedSizeFramer, Framer, Messenger};
+use bytes::{BufMut, Bytes, BytesMut};
+use std::{
+    fmt::Debug,
+    io::{Error, ErrorKind},
+};
+
+/// Number of bytes in the session field of both [MoldUdp64Packet] and [MoldUdp64Request]
+pub const MOLDUDP64_SESSION_SIZE: usize = 10;
+/// Number of bytes in the [MoldUdp64Packet] header, which is also the size of a [MoldUdp64Request]
+pub const MOLDUDP64_HEADER_SIZE: usize = 20;
+/// Largest [MoldUdp64Packet] that fits into a single ethernet frame without ip fragmentation
+pub const MOLDUDP64_MAX_PACKET_SIZE: usize = 1472;
+/// Message count of a [MoldUdp64Packet] that signals the end of the session
+pub const MOLDUDP64_END_OF_SESSION: u16 = 0xFFFF;
+
+/// A MoldUDP64 session identifier, alphanumeric and right padded with spaces
+pub type MoldUdp64Session = [u8; MOLDUDP64_SESSION_SIZE];
+
+/// Creates a [MoldUdp64Session] from `name` by right padding it with spaces
+///
+/// # Errors
+/// * [ErrorKind::InvalidInput] - if `name` is longer than [MOLDUDP64_SESSION_SIZE] bytes
+pub fn moldudp64_session(name: &str) -> Result<MoldUdp64Session, Error> {
+    if name.len() > MOLDUDP64_SESSION_SIZE {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("MoldUdp64 session: {:?} exceeds {} bytes", name, MOLDUDP64_SESSION_SIZE)));
+    }
+    let mut session = [b' '; MOLDUDP64_SESSION_SIZE];
+    session[..name.len()].copy_from_slice(name.as_bytes());
+    Ok(session)
+}
+
+#[inline(always)]
+fn read_u16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_be_bytes([bytes[at], bytes[at + 1]])
+}
+#[inline(always)]
+fn read_u64(bytes: &[u8], at: usize) -> u64 {
+    u64::from_be_bytes(bytes[at..at + 8].try_into().expect("slice of 8 bytes"))
+}
+
+/// A MoldUDP64 downstream packet, which is a header followed by `message count` length prefixed message blocks
+///
+/// # Note
+/// A packet with zero message count is a heartbeat and one with [MOLDUDP64_END_OF_SESSION] message count signals the end of the session,
+/// in both cases the sequence number is the one of the next message to be published.
+#[derive(Clone, PartialEq)]
+pub struct MoldUdp64Packet {
+    frame: Bytes,
+}
+impl MoldUdp64Packet {
+    /// Creates a packet where the first of the `messages` has `sequence_number` and each subsequent one is incremented by one
+    ///
+    /// # Errors
+    /// * [ErrorKind::InvalidInput] - if there are too many messages or any of them is longer than [u16::MAX] bytes
+    pub fn new<B: AsRef<[u8]>>(session: &MoldUdp64Session, sequence_number: u64, messages: &[B]) -> Result<Self, Error> {
+        if messages.len() >= MOLDUDP64_END_OF_SESSION as usize {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("MoldUdp64 message count: {} exceeds {}", messages.len(), MOLDUDP64_END_OF_SESSION - 1)));
+        }
+        let size = MOLDUDP64_HEADER_SIZE + messages.iter().map(|msg| 2 + msg.as_ref().len()).sum::<usize>();
+        let mut frame = BytesMut::with_capacity(size);
+        Self::put_header(&mut frame, session, sequence_number, messages.len() as u16);
+        for msg in messages {
+            let msg = msg.as_ref();
+            let len = u16::try_from(msg.len()).map_err(|_| Error::new(ErrorKind::InvalidInput, format!("MoldUdp64 message length: {} exceeds {}", msg.len(), u16::MAX)))?;
+            frame.put_u16(len);
+            frame.put_slice(msg);
+        }
+        Ok(Self { frame: frame.freeze() })
+    }
+    /// Creates a heartbeat packet where `next_sequence_number` is the sequence number of the next message to be published
+    pub fn heartbeat(session: &MoldUdp64Session, next_sequence_number: u64) -> Self {
+        Self::header_only(session, next_sequence_number, 0)
+    }
+    /// Creates an end of session packet where `next_sequence_number` is one more than the sequence number of the last published message
+    pub fn end_of_session(session: &MoldUdp64Session, next_sequence_number: u64) -> Self {
+        Self::header_only(session, next_sequence_number, MOLDUDP64_END_OF_SESSION)
+    }
+    /// Wraps a `frame` produced by [MoldUdp64Framer]
+    ///
+    /// # Errors
+    /// * [ErrorKind::InvalidData] - if the length of the `frame` does not match the one declared by its header and message blocks
+    pub fn from_frame(frame: Bytes) -> Result<Self, Error> {
+        match MoldUdp64Framer::packet_length(&frame) {
+            Some(len) if len == frame.len() => Ok(Self { frame }),
+            _ => Err(Error::new(ErrorKind::InvalidData, format!("MoldUdp64 malformed packet of length: {}", frame.len()))),
+        }
+    }
+    fn header_only(session: &MoldUdp64Session, sequence_number: u64, message_count: u16) -> Self {
+        let mut frame = BytesMut::with_capacity(MOLDUDP64_HEADER_SIZE);
+        Self::put_header(&mut frame, session, sequence_number, message_count);
+        Self { frame: frame.freeze() }
+    }
+    fn put_header(frame: &mut BytesMut, session: &MoldUdp64Session, sequence_number: u64, message_count: u16) {
+        frame.put_slice(session);
+        frame.put_u64(sequence_number);
+        frame.put_u16(message_count);
+    }
+    #[inline(always)]
+    pub fn session(&self) -> &MoldUdp64Session {
+        self.frame[..MOLDUDP64_SESSION_SIZE].try_into().expect("packet has a header")
+    }
+    #[inline(always)]
+    pub fn sequence_number(&self) -> u64 {
+        read_u64(&self.frame, MOLDUDP64_SESSION_SIZE)
+    }
+    /// Raw message count field of the header, see [Self::messages] for the actual number of messages
+    #[inline(always)]
+    pub fn message_count(&self) -> u16 {
+        read_u16(&self.frame, MOLDUDP64_SESSION_SIZE + 8)
+    }
+    #[inline(always)]
+    pub fn is_heartbeat(&self) -> bool {
+        self.message_count() == 0
+    }
+    #[inline(always)]
+    pub fn is_end_of_session(&self) -> bool {
+        self.message_count() == MOLDUDP64_END_OF_SESSION
+    }
+    /// Iterates over message blocks in sequence number order, each is a zero copy slice of the packet
+    pub fn messages(&self) -> impl Iterator<Item = Bytes> + '_ {
+        let count = if self.is_end_of_session() { 0 } else { self.message_count() as usize };
+        let mut at = MOLDUDP64_HEADER_SIZE;
+        (0..count).map(move |_| {
+            let len = read_u16(&self.frame, at) as usize;
+            at += 2 + len;
+            self.frame.slice(at - len..at)
+        })
+    }
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.frame[..]
+    }
+}
+impl Debug for MoldUdp64Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MoldUdp64Packet")
+            .field("session", &String::from_utf8_lossy(self.session()))
+            .field("sequence_number", &self.sequence_number())
+            .field("message_count", &self.message_count())
+            .field("len", &self.frame.len())
+            .finish()
+    }
+}
+
+/// A MoldUDP64 request to retransmit `message_count` messages starting with `sequence_number`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoldUdp64Request {
+    pub session: MoldUdp64Session,
+    pub sequence_number: u64,
+    pub message_count: u16,
+}
+impl MoldUdp64Request {
+    pub fn to_bytes(&self) -> [u8; MOLDUDP64_HEADER_SIZE] {
+        let mut bytes = [0_u8; MOLDUDP64_HEADER_SIZE];
+        bytes[..MOLDUDP64_SESSION_SIZE].copy_from_slice(&self.session);
+        bytes[MOLDUDP64_SESSION_SIZE..MOLDUDP64_SESSION_SIZE + 8].copy_from_slice(&self.sequence_number.to_be_bytes());
+        bytes[MOLDUDP64_SESSION_SIZE + 8..].copy_from_slice(&self.message_count.to_be_bytes());
+        bytes
+    }
+    /// # Errors
+    /// * [ErrorKind::InvalidData] - if `bytes` is not exactly [MOLDUDP64_HEADER_SIZE] long
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != MOLDUDP64_HEADER_SIZE {
+            return Err(Error::new(ErrorKind::InvalidData, format!("MoldUdp64 request length: {} expected: {}", bytes.len(), MOLDUDP64_HEADER_SIZE)));
+        }
+        Ok(Self {
+            session: bytes[..MOLDUDP64_SESSION_SIZE].try_into().expect("slice of session size"),
+            sequence_number: read_u64(bytes, MOLDUDP64_SESSION_SIZE),
+            message_count: read_u16(bytes, MOLDUDP64_SESSION_SIZE + 8),
+        })
+    }
+}
+
+/// Splits [MoldUdp64Packet] frames by walking the header message count and the length prefix of each message block
+pub struct MoldUdp64Framer;
+impl MoldUdp64Framer {
+    /// Returns the length of the packet at the start of `bytes` or [None] if `bytes` does not contain a complete packet
+    fn packet_length(bytes: &[u8]) -> Option<usize> {
+        if bytes.len() < MOLDUDP64_HEADER_SIZE {
+            return None;
+        }
+        let count = read_u16(bytes, MOLDUDP64_SESSION_SIZE + 8);
+        if count == MOLDUDP64_END_OF_SESSION {
+            return Some(MOLDUDP64_HEADER_SIZE);
+        }
+        let mut at = MOLDUDP64_HEADER_SIZE;
+        for _ in 0..count {
+            if bytes.len() < at + 2 {
+                return None;
+            }
+            at += 2 + read_u16(bytes, at) as usize;
+        }
+        if bytes.len() < at {
+            None
+        } else {
+            Some(at)
+        }
+    }
+}
+impl Framer for MoldUdp64Framer {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        Self::packet_length(bytes)
+    }
+}
+
+/// [Messenger] of a MoldUDP64 client which receives [MoldUdp64Packet] and sends [MoldUdp64Request]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoldUdp64CltMessenger;
+impl Framer for MoldUdp64CltMessenger {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        MoldUdp64Framer::get_frame_length(bytes)
+    }
+}
+impl Messenger for MoldUdp64CltMessenger {
+    type SendT = MoldUdp64Request;
+    type RecvT = MoldUdp64Packet;
+    #[inline(always)]
+    fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+        serialize_into::<MAX_MSG_SIZE>(&msg.to_bytes())
+    }
+    #[inline(always)]
+    fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+        MoldUdp64Packet::from_frame(Bytes::copy_from_slice(frame))
+    }
+}
+
+/// [Messenger] of a MoldUDP64 server which receives [MoldUdp64Request] and sends [MoldUdp64Packet]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoldUdp64SvcMessenger;
+impl Framer for MoldUdp64SvcMessenger {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        FixedSizeFramer::<MOLDUDP64_HEADER_SIZE>::get_frame_length(bytes)
+    }
+}
+impl Messenger for MoldUdp64SvcMessenger {
+    type SendT = MoldUdp64Packet;
+    type RecvT = MoldUdp64Request;
+    #[inline(always)]
+    fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+        serialize_into::<MAX_MSG_SIZE>(msg.as_slice())
+    }
+    #[inline(always)]
+    fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+        MoldUdp64Request::from_bytes(frame)
+    }
+}
+
+#[inline(always)]
+fn serialize_into<const MAX_MSG_SIZE: usize>(bytes: &[u8]) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+    if bytes.len() > MAX_MSG_SIZE {
+        return Err(Error::new(ErrorKind::InvalidInput, format!("MoldUdp64 frame length: {} exceeds buffer size: {}", bytes.len(), MAX_MSG_SIZE)));
+    }
+    let mut buf = [0_u8; MAX_MSG_SIZE];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    Ok((buf, bytes.len()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use links_core::unittest::setup;
+    use log::info;
+
+    #[test]
+    fn test_moldudp64_packet() {
+        setup::log::configure();
+        let session = moldudp64_session("SESSION1").unwrap();
+        assert_eq!(&session, b"SESSION1  ");
+        assert_eq!(moldudp64_session("SESSION_TOO_LONG").unwrap_err().kind(), ErrorKind::InvalidInput);
+
+        let packet = MoldUdp64Packet::new(&session, 7, &[&b"first"[..], b"", b"third"]).unwrap();
+        info!("packet: {:?}", packet);
+        assert_eq!(packet.session(), &session);
+        assert_eq!(packet.sequence_number(), 7);
+        assert_eq!(packet.message_count(), 3);
+        assert!(!packet.is_heartbeat() && !packet.is_end_of_session());
+        assert_eq!(packet.messages().collect::<Vec<_>>(), vec![Bytes::from_static(b"first"), Bytes::new(), Bytes::from_static(b"third")]);
+        assert_eq!(MoldUdp64Packet::from_frame(Bytes::copy_from_slice(packet.as_slice())).unwrap(), packet);
+        assert_eq!(MoldUdp64Packet::from_frame(Bytes::copy_from_slice(&packet.as_slice()[..packet.as_slice().len() - 1])).unwrap_err().kind(), ErrorKind::InvalidData);
+
+        let heartbeat = MoldUdp64Packet::heartbeat(&session, 10);
+        assert!(heartbeat.is_heartbeat());
+        assert_eq!(heartbeat.messages().count(), 0);
+        let end_of_session = MoldUdp64Packet::end_of_session(&session, 10);
+        assert!(end_of_session.is_end_of_session());
+        assert_eq!(end_of_session.messages().count(), 0);
+
+        let request = MoldUdp64Request { session, sequence_number: 7, message_count: 3 };
+        assert_eq!(MoldUdp64Request::from_bytes(&request.to_bytes()).unwrap(), request);
+    }
+
+    #[test]
+    fn test_moldudp64_framer() {
+        setup::log::configure();
+        let session = moldudp64_session("SESSION1").unwrap();
+        let packets = [
+            MoldUdp64Packet::new(&session, 1, &[&b"one"[..], b"two"]).unwrap(),
+            MoldUdp64Packet::heartbeat(&session, 3),
+            MoldUdp64Packet::new(&session, 3, &[&b"three"[..]]).unwrap(),
+            MoldUdp64Packet::end_of_session(&session, 4),
+        ];
+        let stream = packets.iter().flat_map(|packet| packet.as_slice().to_vec()).collect::<Vec<_>>();
+
+        // incomplete packet is not split regardless of where it is cut
+        let first = packets[0].as_slice();
+        for cut in 0..first.len() {
+            let mut bytes = BytesMut::from(&first[..cut]);
+            assert_eq!(MoldUdp64Framer::get_frame(&mut bytes), None, "cut: {}", cut);
+        }
+
+        let mut bytes = BytesMut::from(&stream[..]);
+        for packet in packets.iter() {
+            let frame = MoldUdp64CltMessenger::get_frame(&mut bytes).unwrap();
+            assert_eq!(&MoldUdp64CltMessenger::deserialize(&frame).unwrap(), packet);
+        }
+        assert!(bytes.is_empty());
+    }
+}
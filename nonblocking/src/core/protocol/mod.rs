@@ -1,10 +1,11 @@
 pub mod persistance;
+pub mod sequenced;
 pub mod state;
 
 use super::{ReSendNonBlocking, RecvNonBlocking, SendNonBlocking, SendStatus};
 use crate::prelude::{short_instance_type_name, ConnectionId, Messenger};
 use log::{log_enabled, warn};
-use std::{io::Error, time::Duration};
+use std::{io::Error, num::NonZeroUsize, time::Duration};
 
 /// Core protocol features that will works with any instantiation of [crate::prelude::Clt] and [crate::prelude::Svc] including
 /// [crate::prelude::CltRecver], [crate::prelude::CltRecverRef], [crate::prelude::CltSender], [crate::prelude::CltSenderRef]
@@ -56,10 +57,42 @@ pub trait ProtocolCore: Messenger + Sized {
     /// Called immediately after the message has been received
     #[inline(always)]
     fn on_recv<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::RecvT) {}
+
+    /// Called immediately after [ProtocolCore::on_recv] and before the message is delivered to [links_core::prelude::CallbackRecv::on_recv]. Returning `false` withholds
+    /// the message from the callback and from the caller of [crate::prelude::CltRecver], ex: because it arrived out of sequence, see [sequenced::SequencedSession]
+    #[inline(always)]
+    fn is_recv_ready<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::RecvT) -> bool {
+        true
+    }
+
+    /// Yields messages previously withheld by [ProtocolCore::is_recv_ready] which are now ready to be delivered, in the order they shall be delivered.
+    /// [crate::prelude::CltRecver] drains it prior to reading the next message from the connection
+    #[inline(always)]
+    fn pop_recv_ready(&self) -> Option<<Self as Messenger>::RecvT> {
+        None
+    }
+
+    /// Maximum time allowed to elapse between two consecutive messages received from the peer, [None] disables the check.
+    /// When exceeded, the [crate::prelude::PollHandler] servicing [crate::prelude::CltRecver] or [crate::prelude::CltRecverRef] will call [ProtocolCore::on_recv_timeout] and terminate the connection
+    #[inline(always)]
+    fn conf_recv_timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Called once [ProtocolCore::conf_recv_timeout] has elapsed since the last message was received and right before the connection is terminated
+    #[inline(always)]
+    fn on_recv_timeout<I: ConnectionId>(&self, who: &I, elapsed: Duration) {}
+
+    /// Maximum number of bytes the connection is allowed to queue when the peer is not consuming them fast enough, [None] disables the queue in which case
+    /// a partially written message will `busy wait` until the peer makes room for the rest of it. See [crate::prelude::FrameWriter::enable_outbound_queue] for details.
+    #[inline(always)]
+    fn conf_outbound_queue_high_water_mark(&self) -> Option<NonZeroUsize> {
+        None
+    }
 }
 
 /// Full set of protocol features that will only work with Ref instances of [crate::prelude::Clt] and [crate::prelude::Svc]
-/// which includes [crate::prelude::CltRecverRef], [crate::prelude::CltSenderRef]
+/// which includes [crate::prelude::CltRecverRef], [crate::prelude::CltSenderRef], or with a [crate::prelude::Clt] serviced as a whole by a [crate::prelude::PollHandler]
 ///
 /// # Important
 /// [Clone] implementation of structure implementing [Protocol] must provide a `CLEAN SLATE` state instance,
@@ -74,6 +107,17 @@ pub trait Protocol: ProtocolCore + Clone {
         Ok(())
     }
 
+    /// Called after [Protocol::send_reply] as well as after messages were withheld by [ProtocolCore::is_recv_ready], in which case [Protocol::send_reply] is not called.
+    /// The `sender` also implements [ReSendNonBlocking] to allow retransmitting messages without issuing send hooks, ex: answering peer resend requests
+    /// or requesting the peer to fill a gap, see [sequenced::SequencedSession].
+    #[inline(always)]
+    fn re_send_reply<S: SendNonBlocking<<Self as Messenger>::SendT> + ReSendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, sender: &mut S) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Interval at which [Protocol::send_heart_beat] is called, [None] disables heart beats. For a [crate::prelude::Clt] or [crate::prelude::CltRecverRef] serviced by a [crate::prelude::PollHandler]
+    /// the call is issued by the poll thread, for a [crate::prelude::CltRecver] serviced by one it is issued by the `paired` [crate::prelude::CltSender], see [crate::prelude::CltSender::send_due],
+    /// while for [crate::prelude::CltSenderRef] whose receiver is not serviced by one it is issued by [static@crate::connect::DEFAULT_HBEAT_HANDLER] thread
     #[inline(always)]
     fn conf_heart_beat_interval(&self) -> Option<Duration> {
         None
@@ -1,21 +1,78 @@
-use std::{fmt::Debug, slice::Iter};
+use std::{
+    fmt::Debug,
+    fs::{File, OpenOptions},
+    io::{Error, ErrorKind, Read, Write},
+    marker::PhantomData,
+    ops::Range,
+    path::{Path, PathBuf},
+    slice::Iter,
+};
+
+use bytes::BytesMut;
+use log::{log_enabled, warn};
 
 use super::state::ProtocolSessionState;
+use crate::prelude::{asserted_short_name, Messenger};
+
 pub trait ProtocolStorage: Debug {
     type Item;
-    fn store(&mut self, msg: Self::Item);
+    /// Appends a single item to the storage
+    fn store(&mut self, msg: Self::Item) -> Result<(), Error>;
+    /// Iterates over all items in the order they were stored
     fn iter(&self) -> Iter<'_, Self::Item>;
     fn is_empty(&self) -> bool;
     fn len(&self) -> usize;
 }
 
+/// Extends [ProtocolStorage] by assigning each stored item a sequence number, the first item ever stored is assigned `1`
+/// and every subsequent item is assigned the next number. This allows protocols which need to answer a resend request
+/// to look up an individual item in `O(1)` and replay a gap in `O(range)`.
+///
+/// # Note
+/// * [SequencedProtocolStorage::compact] removes items from the `head` of the storage while preserving sequence numbers of the retained items
+/// * [SequencedProtocolStorage::truncate] removes items from the `tail` of the storage and the next stored item will be assigned sequence number following the retained ones
+pub trait SequencedProtocolStorage: ProtocolStorage {
+    /// Sequence number of the first item retained by the storage, or if the storage is empty the sequence number that will be assigned to the next stored item
+    fn first_seq(&self) -> u64;
+
+    /// Sequence number of the last item stored or `first_seq() - 1` if the storage is empty, hence `0` if nothing has been stored
+    #[inline(always)]
+    fn last_seq(&self) -> u64 {
+        self.first_seq() + self.len() as u64 - 1
+    }
+
+    /// Returns the item with a given sequence number or [None] if it was never stored or removed by [Self::compact] or [Self::truncate]
+    #[inline(always)]
+    fn get(&self, seq: u64) -> Option<&Self::Item> {
+        let idx = seq.checked_sub(self.first_seq())?;
+        self.iter().as_slice().get(idx as usize)
+    }
+
+    /// Iterates over items with sequence numbers in the `range`, the part of the `range` which is not retained by the storage is ignored
+    #[inline(always)]
+    fn range(&self, range: Range<u64>) -> Iter<'_, Self::Item> {
+        let items = self.iter().as_slice();
+        let start = range.start.saturating_sub(self.first_seq()).min(items.len() as u64) as usize;
+        let end = range.end.saturating_sub(self.first_seq()).min(items.len() as u64) as usize;
+        items[start..end.max(start)].iter()
+    }
+
+    /// Removes all items with sequence numbers greater then `last_seq`, the next stored item will be assigned `last_seq + 1`
+    fn truncate(&mut self, last_seq: u64) -> Result<(), Error>;
+
+    /// Removes all items with sequence numbers less then `first_seq`, if `first_seq` is greater then [Self::last_seq] the storage will
+    /// become empty and the next stored item will be assigned `first_seq`
+    fn compact(&mut self, first_seq: u64) -> Result<(), Error>;
+}
+
 #[derive(Debug)]
 pub struct InMemoryMessageLog<T: Debug> {
     log: Vec<T>,
+    first_seq: u64,
 }
 impl<T: Debug> InMemoryMessageLog<T> {
     pub fn new() -> Self {
-        Self { log: Vec::new() }
+        Self { log: Vec::new(), first_seq: 1 }
     }
 }
 impl<T: Debug> Default for InMemoryMessageLog<T> {
@@ -25,8 +82,9 @@ impl<T: Debug> Default for InMemoryMessageLog<T> {
 }
 impl<T: Debug> ProtocolStorage for InMemoryMessageLog<T> {
     type Item = T;
-    fn store(&mut self, msg: T) {
+    fn store(&mut self, msg: T) -> Result<(), Error> {
         self.log.push(msg);
+        Ok(())
     }
     fn iter(&self) -> Iter<'_, T> {
         self.log.iter()
@@ -38,30 +96,263 @@ impl<T: Debug> ProtocolStorage for InMemoryMessageLog<T> {
         self.log.len()
     }
 }
+impl<T: Debug> SequencedProtocolStorage for InMemoryMessageLog<T> {
+    #[inline(always)]
+    fn first_seq(&self) -> u64 {
+        self.first_seq
+    }
+    fn truncate(&mut self, last_seq: u64) -> Result<(), Error> {
+        match last_seq.checked_sub(self.first_seq) {
+            Some(idx) => self.log.truncate(idx as usize + 1),
+            None => {
+                self.first_seq = next_seq(last_seq)?;
+                self.log.clear();
+            }
+        }
+        Ok(())
+    }
+    fn compact(&mut self, first_seq: u64) -> Result<(), Error> {
+        if first_seq <= self.first_seq {
+            return Ok(());
+        }
+        let count = ((first_seq - self.first_seq) as usize).min(self.log.len());
+        self.log.drain(..count);
+        self.first_seq = first_seq;
+        Ok(())
+    }
+}
 impl<T: Debug> From<InMemoryMessageLog<T>> for ProtocolSessionState<InMemoryMessageLog<T>> {
     fn from(value: InMemoryMessageLog<T>) -> Self {
         Self::new(value)
     }
 }
 
-// TODO complete this
-pub struct FileMessageLog<T> {
-    phantom: std::marker::PhantomData<T>,
+/// Sequence number which follows `last_seq`, fails instead of overflowing
+#[inline(always)]
+fn next_seq(last_seq: u64) -> Result<u64, Error> {
+    last_seq
+        .checked_add(1)
+        .ok_or_else(|| Error::new(ErrorKind::InvalidInput, format!("last_seq: {} is the max sequence number, next one can't be assigned", last_seq)))
+}
+
+const FILE_MAGIC: [u8; 6] = *b"LNKLOG";
+const FILE_VERSION: u16 = 1;
+const FILE_HEADER_LEN: usize = FILE_MAGIC.len() + std::mem::size_of::<u16>() + std::mem::size_of::<u64>();
+
+fn file_header(first_seq: u64) -> Vec<u8> {
+    let mut header = Vec::with_capacity(FILE_HEADER_LEN);
+    header.extend_from_slice(&FILE_MAGIC);
+    header.extend_from_slice(&FILE_VERSION.to_le_bytes());
+    header.extend_from_slice(&first_seq.to_le_bytes());
+    header
+}
+
+/// An append only, file backed implementation of [ProtocolStorage] & [SequencedProtocolStorage]. Every item is serialized using `S` [Messenger]
+/// and appended to the file, hence the file contains a header followed by exactly the same byte stream `S` would write to the wire.
+/// When the file is opened using [FileMessageLog::open] its content is split using `D` [crate::prelude::Framer] and deserialized
+/// using `D` [Messenger] to rebuild the in memory log and index of file offsets.
+///
+/// # Generic Parameters
+///  * `S` - [Messenger] whose [Messenger::SendT] is the type of the item being stored
+///  * `D` - [Messenger] whose [Messenger::RecvT] is the same type of the item being stored, typically this is the `peer` [Messenger]
+///  * `MAX_MSG_SIZE` - maximum size of a single serialized item
+///
+/// # Example
+/// * to log messages sent by `CltTestMessenger` use `FileMessageLog<CltTestMessenger, SvcTestMessenger, TEST_MSG_FRAME_SIZE>`
+/// * to log messages received by `CltTestMessenger` use `FileMessageLog<SvcTestMessenger, CltTestMessenger, TEST_MSG_FRAME_SIZE>`
+///
+/// # File Layout
+/// * `LNKLOG` magic followed by `u16` little endian layout version
+/// * `u64` little endian [SequencedProtocolStorage::first_seq]
+/// * serialized items
+///
+/// # Note
+/// * If the process terminates in the middle of [ProtocolStorage::store] the file might end with an incomplete frame, which
+///   will be truncated the next time the file is opened.
+/// * A file which does not start with the magic or has an unknown layout version fails to open with [ErrorKind::InvalidData].
+#[derive(Debug)]
+pub struct FileMessageLog<S: Messenger, D: Messenger<RecvT = S::SendT>, const MAX_MSG_SIZE: usize> {
+    path: PathBuf,
+    file: File,
+    file_len: u64,
+    first_seq: u64,
+    log: Vec<S::SendT>,
+    offsets: Vec<u64>, // file offset of each item in the log
+    phantom: PhantomData<D>,
+}
+impl<S: Messenger, D: Messenger<RecvT = S::SendT>, const MAX_MSG_SIZE: usize> FileMessageLog<S, D, MAX_MSG_SIZE> {
+    /// Opens an existing or creates a new file at a given `path` and loads all of its items into memory.
+    pub fn open<T: AsRef<Path>>(path: T) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = OpenOptions::new().create(true).read(true).append(true).open(&path)?;
+
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+        if buf.len() < FILE_HEADER_LEN && FILE_MAGIC.starts_with(&buf[..buf.len().min(FILE_MAGIC.len())]) {
+            // new file or the header was never completely written
+            file.set_len(0)?;
+            buf = file_header(1);
+            file.write_all(&buf)?;
+        }
+        if buf.len() < FILE_HEADER_LEN || buf[..FILE_MAGIC.len()] != FILE_MAGIC {
+            let msg = format!("{}::open path: {:?}, invalid header, expected magic: {:?}", asserted_short_name!("FileMessageLog", Self), path, String::from_utf8_lossy(&FILE_MAGIC));
+            return Err(Error::new(ErrorKind::InvalidData, msg));
+        }
+        let version = u16::from_le_bytes(buf[FILE_MAGIC.len()..FILE_MAGIC.len() + 2].try_into().expect("version is u16"));
+        if version != FILE_VERSION {
+            let msg = format!("{}::open path: {:?}, unsupported layout version: {}, expected: {}", asserted_short_name!("FileMessageLog", Self), path, version, FILE_VERSION);
+            return Err(Error::new(ErrorKind::InvalidData, msg));
+        }
+        let first_seq = u64::from_le_bytes(buf[FILE_MAGIC.len() + 2..FILE_HEADER_LEN].try_into().expect("first_seq is u64"));
+        let mut buf = BytesMut::from(&buf[FILE_HEADER_LEN..]);
+
+        let mut log = Vec::new();
+        let mut offsets = Vec::new();
+        let mut file_len = FILE_HEADER_LEN as u64;
+        while let Some(frame) = D::get_frame(&mut buf) {
+            let msg = D::deserialize(&frame).map_err(|e| {
+                let msg = format!("{}::open path: {:?}, failed to deserialize frame at offset: {}, caused by: [{}]", asserted_short_name!("FileMessageLog", Self), path, file_len, e);
+                Error::new(ErrorKind::InvalidData, msg)
+            })?;
+            log.push(msg);
+            offsets.push(file_len);
+            file_len += frame.len() as u64;
+        }
+        if !buf.is_empty() {
+            if log_enabled!(log::Level::Warn) {
+                warn!("{}::open path: {:?}, truncating incomplete frame of len: {} at offset: {}", asserted_short_name!("FileMessageLog", Self), path, buf.len(), file_len);
+            }
+            file.set_len(file_len)?;
+        }
+
+        Ok(Self {
+            path,
+            file,
+            file_len,
+            first_seq,
+            log,
+            offsets,
+            phantom: PhantomData,
+        })
+    }
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+    /// Flushes all written data to the disk, see [File::sync_data]
+    pub fn sync(&self) -> Result<(), Error> {
+        self.file.sync_data()
+    }
+    /// Writes a new header and retained items into a temporary file which then replaces the current file
+    fn rewrite(&mut self, first_seq: u64, log: Vec<S::SendT>) -> Result<(), Error> {
+        let tmp_path = self.path.with_extension("compact");
+        let mut tmp = OpenOptions::new().create(true).write(true).truncate(true).open(&tmp_path)?;
+        let mut buf = file_header(first_seq);
+        let mut offsets = Vec::with_capacity(log.len());
+        for msg in log.iter() {
+            let (bytes, size) = S::serialize::<MAX_MSG_SIZE>(msg)?;
+            offsets.push(buf.len() as u64);
+            buf.extend_from_slice(&bytes[..size]);
+        }
+        tmp.write_all(&buf)?;
+        tmp.sync_data()?;
+        drop(tmp);
+        std::fs::rename(&tmp_path, &self.path)?;
+
+        self.file = OpenOptions::new().read(true).append(true).open(&self.path)?;
+        self.file_len = buf.len() as u64;
+        self.first_seq = first_seq;
+        self.log = log;
+        self.offsets = offsets;
+        Ok(())
+    }
+}
+impl<S: Messenger, D: Messenger<RecvT = S::SendT>, const MAX_MSG_SIZE: usize> ProtocolStorage for FileMessageLog<S, D, MAX_MSG_SIZE> {
+    type Item = S::SendT;
+    /// Serializes and appends the item to the file before adding it to the in memory log. In the event of a failed write
+    /// the file will be truncated to its length prior to this call
+    fn store(&mut self, msg: Self::Item) -> Result<(), Error> {
+        let (bytes, size) = S::serialize::<MAX_MSG_SIZE>(&msg)?;
+        if let Err(e) = self.file.write_all(&bytes[..size]) {
+            self.file.set_len(self.file_len)?;
+            return Err(e);
+        }
+        self.offsets.push(self.file_len);
+        self.file_len += size as u64;
+        self.log.push(msg);
+        Ok(())
+    }
+    fn iter(&self) -> Iter<'_, Self::Item> {
+        self.log.iter()
+    }
+    fn is_empty(&self) -> bool {
+        self.log.is_empty()
+    }
+    fn len(&self) -> usize {
+        self.log.len()
+    }
+}
+impl<S: Messenger, D: Messenger<RecvT = S::SendT>, const MAX_MSG_SIZE: usize> SequencedProtocolStorage for FileMessageLog<S, D, MAX_MSG_SIZE> {
+    #[inline(always)]
+    fn first_seq(&self) -> u64 {
+        self.first_seq
+    }
+    /// Truncates the file at the offset of the first removed item, unless all items are removed in which case the file is rewritten
+    fn truncate(&mut self, last_seq: u64) -> Result<(), Error> {
+        match last_seq.checked_sub(self.first_seq) {
+            Some(idx) => {
+                let len = idx as usize + 1;
+                if len < self.log.len() {
+                    self.file_len = self.offsets[len];
+                    self.file.set_len(self.file_len)?;
+                    self.log.truncate(len);
+                    self.offsets.truncate(len);
+                }
+                Ok(())
+            }
+            None => self.rewrite(next_seq(last_seq)?, vec![]),
+        }
+    }
+    /// Rewrites the file with only the retained items
+    fn compact(&mut self, first_seq: u64) -> Result<(), Error> {
+        if first_seq <= self.first_seq {
+            return Ok(());
+        }
+        let count = ((first_seq - self.first_seq) as usize).min(self.log.len());
+        let mut log = std::mem::take(&mut self.log);
+        let retained = log.split_off(count);
+        self.rewrite(first_seq, retained)
+    }
+}
+impl<S: Messenger, D: Messenger<RecvT = S::SendT>, const MAX_MSG_SIZE: usize> From<FileMessageLog<S, D, MAX_MSG_SIZE>> for ProtocolSessionState<FileMessageLog<S, D, MAX_MSG_SIZE>> {
+    fn from(value: FileMessageLog<S, D, MAX_MSG_SIZE>) -> Self {
+        Self::new(value)
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use links_core::unittest::setup;
+    use links_core::unittest::setup::{
+        self,
+        messenger::{CltTestMessenger, SvcTestMessenger, TEST_MSG_FRAME_SIZE},
+        model::{CltTestMsg, CltTestMsgDebug},
+    };
     use log::info;
+    use std::{fs::OpenOptions, io::Write};
 
     use crate::prelude::*;
 
+    type CltSentFileLog = FileMessageLog<CltTestMessenger, SvcTestMessenger, TEST_MSG_FRAME_SIZE>;
+
+    fn msg(seq: u64) -> CltTestMsg {
+        CltTestMsgDebug::new(format!("Msg #{}", seq).as_bytes()).into()
+    }
+
     #[test]
     fn test_in_memory_log() {
         setup::log::configure();
         let mut log = InMemoryMessageLog::<usize>::default();
-        log.store(1);
-        log.store(2);
+        log.store(1).unwrap();
+        log.store(2).unwrap();
 
         info!("log: {:?}", log);
         assert_eq!(log.len(), 2);
@@ -70,4 +361,150 @@ mod test {
             info!("i: {:?}", i);
         }
     }
+
+    #[test]
+    fn test_in_memory_log_sequenced() {
+        setup::log::configure();
+        let mut log = InMemoryMessageLog::<u64>::default();
+        assert_eq!((log.first_seq(), log.last_seq()), (1, 0));
+        assert_eq!(log.get(1), None);
+        for seq in 1..=10 {
+            log.store(seq).unwrap();
+        }
+        assert_eq!((log.first_seq(), log.last_seq()), (1, 10));
+        assert_eq!(log.get(0), None);
+        assert_eq!(log.get(5), Some(&5));
+        assert_eq!(log.get(11), None);
+        assert_eq!(log.range(3..6).copied().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(log.range(8..20).copied().collect::<Vec<_>>(), vec![8, 9, 10]);
+
+        log.compact(4).unwrap();
+        info!("log: {:?}", log);
+        assert_eq!((log.first_seq(), log.last_seq()), (4, 10));
+        assert_eq!(log.get(3), None);
+        assert_eq!(log.get(4), Some(&4));
+        assert_eq!(log.range(1..6).copied().collect::<Vec<_>>(), vec![4, 5]);
+
+        log.truncate(7).unwrap();
+        assert_eq!((log.first_seq(), log.last_seq()), (4, 7));
+        log.store(8).unwrap();
+        assert_eq!(log.get(8), Some(&8));
+
+        log.compact(20).unwrap();
+        assert!(log.is_empty());
+        assert_eq!((log.first_seq(), log.last_seq()), (20, 19));
+        log.store(20).unwrap();
+        assert_eq!(log.get(20), Some(&20));
+    }
+
+    #[test]
+    fn test_file_log() {
+        setup::log::configure();
+        let path = std::env::temp_dir().join(format!("links_test_file_log_{}.log", rand::random::<u64>()));
+        const N: u64 = 10;
+
+        let state: ProtocolSessionState<_> = CltSentFileLog::open(&path).unwrap().into();
+        assert!(state.lock().is_empty());
+        for seq in 1..=N {
+            state.lock().store(msg(seq)).unwrap();
+        }
+        assert_eq!(state.lock().len(), N as usize);
+        drop(state);
+
+        // reopen and append a partial frame to emulate a crash during the write
+        let log = CltSentFileLog::open(&path).unwrap();
+        info!("log: {:?}", log);
+        assert_eq!(log.len(), N as usize);
+        for (seq, msg) in (1..).zip(log.iter()) {
+            assert_eq!(msg, &super::test::msg(seq));
+        }
+        drop(log);
+        OpenOptions::new().append(true).open(&path).unwrap().write_all(&[b'1'; TEST_MSG_FRAME_SIZE / 2]).unwrap();
+
+        // partial frame shall be truncated and next store shall be recovered
+        let mut log = CltSentFileLog::open(&path).unwrap();
+        assert_eq!(log.len(), N as usize);
+        log.store(msg(N + 1)).unwrap();
+        drop(log);
+
+        let log = CltSentFileLog::open(&path).unwrap();
+        assert_eq!(log.len(), N as usize + 1);
+        assert_eq!(log.iter().last().unwrap(), &msg(N + 1));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_file_log_sequenced() {
+        setup::log::configure();
+        let path = std::env::temp_dir().join(format!("links_test_file_log_sequenced_{}.log", rand::random::<u64>()));
+
+        let mut log = CltSentFileLog::open(&path).unwrap();
+        for seq in 1..=10 {
+            log.store(msg(seq)).unwrap();
+        }
+        assert_eq!(log.range(3..5).cloned().collect::<Vec<_>>(), vec![msg(3), msg(4)]);
+
+        log.compact(4).unwrap();
+        log.truncate(8).unwrap();
+        log.store(msg(9)).unwrap();
+        assert_eq!((log.first_seq(), log.last_seq()), (4, 9));
+        drop(log);
+
+        // compaction and truncation must survive reopen
+        let mut log = CltSentFileLog::open(&path).unwrap();
+        info!("log: {:?}", log);
+        assert_eq!((log.first_seq(), log.last_seq()), (4, 9));
+        assert_eq!(log.get(3), None);
+        assert_eq!(log.get(4), Some(&msg(4)));
+        assert_eq!(log.get(9), Some(&msg(9)));
+
+        log.truncate(1).unwrap();
+        assert!(log.is_empty());
+        assert_eq!((log.first_seq(), log.last_seq()), (2, 1));
+        drop(log);
+
+        let log = CltSentFileLog::open(&path).unwrap();
+        assert_eq!((log.first_seq(), log.last_seq()), (2, 1));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_file_log_header() {
+        setup::log::configure();
+        let path = std::env::temp_dir().join(format!("links_test_file_log_header_{}.log", rand::random::<u64>()));
+
+        // new file is created with a header
+        let mut log = CltSentFileLog::open(&path).unwrap();
+        for seq in 1..=3 {
+            log.store(msg(seq)).unwrap();
+        }
+        drop(log);
+        assert!(std::fs::read(&path).unwrap().starts_with(b"LNKLOG"));
+        let log = CltSentFileLog::open(&path).unwrap();
+        assert_eq!((log.first_seq(), log.last_seq()), (1, 3));
+        drop(log);
+
+        // file written without a header is rejected and left untouched instead of being misparsed
+        let mut file = OpenOptions::new().create(true).write(true).truncate(true).open(&path).unwrap();
+        let (bytes, size) = CltTestMessenger::serialize::<TEST_MSG_FRAME_SIZE>(&msg(1)).unwrap();
+        file.write_all(&bytes[..size]).unwrap();
+        drop(file);
+        let err = CltSentFileLog::open(&path).unwrap_err();
+        info!("err: {}", err);
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert_eq!(std::fs::read(&path).unwrap(), &bytes[..size]);
+        std::fs::write(&path, super::file_header(1)).unwrap();
+
+        // unknown layout version is rejected instead of being misparsed
+        let mut buf = std::fs::read(&path).unwrap();
+        buf[6..8].copy_from_slice(&u16::MAX.to_le_bytes());
+        std::fs::write(&path, buf).unwrap();
+        let err = CltSentFileLog::open(&path).unwrap_err();
+        info!("err: {}", err);
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -0,0 +1,559 @@
+use std::{collections::BTreeMap, fmt::Debug, io::Error, ops::Range};
+
+use log::{debug, log_enabled, warn};
+
+use super::{persistance::SequencedProtocolStorage, state::ProtocolSessionState};
+use crate::prelude::{asserted_short_name, ConnectionId, Messenger, ReSendNonBlocking, SendNonBlocking};
+
+/// Describes how messages of a [Messenger] expose their sequence numbers to the [SequencedSession] layer.
+///
+/// # Note
+/// * Sequence numbers are `1` based and follow the numbering of [SequencedProtocolStorage]
+/// * Messages that are not part of the sequenced stream, ex: heart beats, logins, resend requests, must yield [None] or `false`
+pub trait SequencedMessenger: Messenger {
+    /// Sequence number of the received message or [None] if the message is not sequenced
+    fn recv_seq(msg: &Self::RecvT) -> Option<u64>;
+
+    /// Range of sequence numbers the peer requests to be resent or [None] if the message is not a resend request
+    fn recv_resend_request(msg: &Self::RecvT) -> Option<Range<u64>>;
+
+    /// `true` if the sent message is part of the sequenced stream and shall be stored to answer future resend requests
+    fn is_send_sequenced(msg: &Self::SendT) -> bool;
+
+    /// Creates a message which requests the peer to resend a given range of sequence numbers
+    fn new_resend_request(range: Range<u64>) -> Self::SendT;
+
+    /// Sequence number expected after the received message carrying sequence number `seq`, ex: a gap fill which skips a range of sequence numbers.
+    /// Values lower then `seq + 1` are ignored
+    #[inline(always)]
+    fn recv_next_seq(msg: &Self::RecvT, seq: u64) -> u64 {
+        let _ = msg;
+        seq + 1
+    }
+
+    /// `false` if the received message shall be delivered even when it arrives ahead of a gap, ex: a login which completes a handshake,
+    /// in which case only its sequence number is queued until the gap is filled
+    #[inline(always)]
+    fn is_recv_withheld_on_gap(msg: &Self::RecvT) -> bool {
+        let _ = msg;
+        true
+    }
+}
+
+/// Outcome of processing a received message by [SequencedSession::on_recv]
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeqRecvStatus {
+    /// Message carries the next expected sequence number
+    InSequence(u64),
+    /// Message is not part of the sequenced stream
+    Unsequenced,
+    /// Message carries a sequence number which was already processed and shall be ignored
+    Duplicate(u64),
+    /// Message arrived ahead of the expected sequence number and was queued, `missing` is the range of the gap and
+    /// `request` is the part of the gap that was not yet requested from the peer
+    Gap { missing: Range<u64>, request: Option<Range<u64>> },
+    /// Message is a peer request to resend a range of sequence numbers
+    ResendRequest(Range<u64>),
+}
+
+/// A session layer component which tracks inbound sequence numbers, queues out of order messages and stores sequenced
+/// outbound messages so that the gaps in either direction can be recovered.
+///
+/// # Usage
+/// * Wrap it into [ProtocolSessionState] and keep it in the [crate::prelude::Protocol] instance
+/// * Call [SequencedSession::on_sent] from [crate::prelude::ProtocolCore::on_sent] to store sequenced outbound messages
+/// * Call [ProtocolSessionState::is_recv_ready] from [crate::prelude::ProtocolCore::is_recv_ready] to detect gaps and withhold out of order and duplicate messages
+/// * Call [ProtocolSessionState::pop_recv_ready] from [crate::prelude::ProtocolCore::pop_recv_ready] to deliver queued messages which became in sequence once a gap was filled
+/// * Call [ProtocolSessionState::re_send_reply] from [crate::prelude::Protocol::re_send_reply] to issue resend requests and answer peer resend requests
+///
+/// # Important
+/// * [crate::prelude::CltRecver] consults the session before [links_core::prelude::CallbackRecv::on_recv] is issued, hence the callback observes sequenced messages
+///   exactly once and in the order of their sequence numbers, while [crate::prelude::ProtocolCore::on_recv] observes them in the order of arrival
+/// * Peer resend requests are answered using [ReSendNonBlocking] hence [crate::prelude::ProtocolCore::on_sent] & [links_core::prelude::CallbackSend]
+///   hooks are only ever issued once per message, when it is originally sent.
+#[derive(Debug)]
+pub struct SequencedSession<M: SequencedMessenger, S: SequencedProtocolStorage<Item = M::SendT>> {
+    next_recv_seq: u64,
+    requested_until: u64, // exclusive end of the highest range requested from the peer
+    queue: BTreeMap<u64, Option<M::RecvT>>, // [None] if delivered ahead of the gap, see [SequencedMessenger::is_recv_withheld_on_gap]
+    ready: Vec<M::RecvT>,   // in reverse order of sequence numbers to allow pop
+    replies: Vec<SeqReply>, // pending until sent by [ProtocolSessionState::re_send_reply]
+    sent: S,
+}
+/// Replies accumulated by [SequencedSession::on_recv]
+#[derive(Debug)]
+enum SeqReply {
+    RequestResend(Range<u64>),
+    Resend(Range<u64>),
+}
+impl<M: SequencedMessenger, S: SequencedProtocolStorage<Item = M::SendT>> SequencedSession<M, S>
+where M::RecvT: Clone
+{
+    /// Creates a new session which expects the first inbound message to have sequence number `1` and uses `sent` to store outbound messages
+    pub fn new(sent: S) -> Self {
+        Self::with_next_recv_seq(sent, 1)
+    }
+    /// Creates a new session which expects the first inbound message to have sequence number `next_recv_seq`, typically used to recover a session
+    pub fn with_next_recv_seq(sent: S, next_recv_seq: u64) -> Self {
+        Self {
+            next_recv_seq,
+            requested_until: next_recv_seq,
+            queue: BTreeMap::new(),
+            ready: Vec::new(),
+            replies: Vec::new(),
+            sent,
+        }
+    }
+    /// Sequence number expected on the next inbound message
+    #[inline(always)]
+    pub fn next_recv_seq(&self) -> u64 {
+        self.next_recv_seq
+    }
+    /// Sequence number which will be assigned to the next sequenced outbound message
+    #[inline(always)]
+    pub fn next_send_seq(&self) -> u64 {
+        self.sent.last_seq() + 1
+    }
+    /// Storage of sequenced outbound messages
+    #[inline(always)]
+    pub fn sent(&self) -> &S {
+        &self.sent
+    }
+    #[inline(always)]
+    pub fn sent_mut(&mut self) -> &mut S {
+        &mut self.sent
+    }
+    /// Number of out of order messages waiting for a gap to be filled
+    #[inline(always)]
+    pub fn queued(&self) -> usize {
+        self.queue.len()
+    }
+    /// Stores the message if it is sequenced, see [SequencedMessenger::is_send_sequenced]
+    pub fn on_sent(&mut self, msg: &M::SendT) -> Result<(), Error>
+    where M::SendT: Clone {
+        if M::is_send_sequenced(msg) {
+            self.sent.store(msg.clone())?;
+        }
+        Ok(())
+    }
+    /// Advances the expected inbound sequence number or queues the message if it arrived ahead of a gap. Resend requests to and from
+    /// the peer are kept until sent by [ProtocolSessionState::re_send_reply]
+    pub fn on_recv(&mut self, msg: &M::RecvT) -> SeqRecvStatus {
+        use SeqRecvStatus::*;
+        if let Some(range) = M::recv_resend_request(msg) {
+            self.replies.push(SeqReply::Resend(range.clone()));
+            return ResendRequest(range);
+        }
+        let seq = match M::recv_seq(msg) {
+            Some(seq) => seq,
+            None => return Unsequenced,
+        };
+        if seq < self.next_recv_seq || self.queue.contains_key(&seq) {
+            return Duplicate(seq);
+        }
+        if seq > self.next_recv_seq {
+            self.queue.insert(seq, M::is_recv_withheld_on_gap(msg).then(|| msg.clone()));
+            let missing = self.next_recv_seq..*self.queue.keys().next().expect("queue is not empty");
+            let request = if seq > self.requested_until {
+                let request = self.requested_until.max(self.next_recv_seq)..seq;
+                self.requested_until = seq;
+                match self.replies.last_mut() {
+                    // extend a pending request instead of sending adjacent ones
+                    Some(SeqReply::RequestResend(pending)) if pending.end == request.start => pending.end = request.end,
+                    _ => self.replies.push(SeqReply::RequestResend(request.clone())),
+                }
+                Some(request)
+            } else {
+                None
+            };
+            return Gap { missing, request };
+        }
+
+        self.next_recv_seq = M::recv_next_seq(msg, seq).max(seq + 1);
+        self.release();
+        InSequence(seq)
+    }
+    /// Moves the expected inbound sequence number forward, ex: when the peer resets its sequence, queued messages below it are dropped while the ones
+    /// which became in sequence are released. Yields `false` and leaves the session unchanged if `next_recv_seq` is lower then [Self::next_recv_seq]
+    pub fn reset_next_recv_seq(&mut self, next_recv_seq: u64) -> bool {
+        if next_recv_seq < self.next_recv_seq {
+            return false;
+        }
+        self.next_recv_seq = next_recv_seq;
+        self.release();
+        true
+    }
+    /// Moves queued messages which became in sequence to the ready list and drops the ones skipped by [SequencedMessenger::recv_next_seq]
+    fn release(&mut self) {
+        let mut released = Vec::new();
+        while let Some(entry) = self.queue.first_entry() {
+            if *entry.key() > self.next_recv_seq {
+                break;
+            }
+            let (seq, msg) = entry.remove_entry();
+            if seq < self.next_recv_seq {
+                continue;
+            }
+            match msg {
+                Some(msg) => {
+                    self.next_recv_seq = M::recv_next_seq(&msg, seq).max(seq + 1);
+                    released.push(msg);
+                }
+                None => self.next_recv_seq = seq + 1,
+            }
+        }
+        // keep ready in reverse order so that pop_ready yields the lowest sequence number first
+        released.reverse();
+        released.append(&mut self.ready);
+        self.ready = released;
+        self.requested_until = self.requested_until.max(self.next_recv_seq);
+    }
+    /// Returns queued messages which became in sequence after a gap was filled, in the order of their sequence numbers
+    #[inline(always)]
+    pub fn pop_ready(&mut self) -> Option<M::RecvT> {
+        self.ready.pop()
+    }
+}
+
+impl<M: SequencedMessenger, S: SequencedProtocolStorage<Item = M::SendT>> ProtocolSessionState<SequencedSession<M, S>>
+where
+    M::RecvT: Clone,
+    M::SendT: Clone,
+{
+    /// Processes the received message using [SequencedSession::on_recv] and yields `false` if the message is a [SeqRecvStatus::Gap] or a [SeqRecvStatus::Duplicate]
+    /// and hence shall be withheld, unless the gap does not withhold it, see [SequencedMessenger::is_recv_withheld_on_gap]
+    pub fn is_recv_ready<I: ConnectionId>(&self, who: &I, msg: &M::RecvT) -> bool {
+        match self.lock().on_recv(msg) {
+            SeqRecvStatus::Gap { missing, request } => {
+                if log_enabled!(log::Level::Warn) {
+                    warn!("{} {} detected gap: {:?}, requesting: {:?}", who.con_id(), asserted_short_name!("SequencedSession", SequencedSession<M, S>), missing, request);
+                }
+                !M::is_recv_withheld_on_gap(msg)
+            }
+            SeqRecvStatus::Duplicate(seq) => {
+                if log_enabled!(log::Level::Debug) {
+                    debug!("{} {} ignoring duplicate seq: {}", who.con_id(), asserted_short_name!("SequencedSession", SequencedSession<M, S>), seq);
+                }
+                false
+            }
+            _ => true,
+        }
+    }
+    /// Same as [SequencedSession::pop_ready]
+    #[inline(always)]
+    pub fn pop_recv_ready(&self) -> Option<M::RecvT> {
+        self.lock().pop_ready()
+    }
+    /// Sends resend requests for gaps detected by [SequencedSession::on_recv] using [SendNonBlocking] and answers peer resend requests
+    /// with stored messages using [ReSendNonBlocking] so that no send hooks are issued. Sequence numbers received since the gap was detected
+    /// are not requested again and a request whose gap was filled entirely is dropped
+    ///
+    /// # Important
+    /// The session lock is released before sending, because sending will invoke [crate::prelude::ProtocolCore::on_sent] which
+    /// is expected to lock the same session to store the message, and the sender lock is acquired in the opposite order by other threads.
+    pub fn re_send_reply<C: SendNonBlocking<M::SendT> + ReSendNonBlocking<M::SendT> + ConnectionId>(&self, con: &mut C) -> Result<(), Error> {
+        let (requests, msgs) = {
+            let mut session = self.lock();
+            let (mut requests, mut msgs) = (vec![], vec![]);
+            for reply in std::mem::take(&mut session.replies) {
+                match reply {
+                    // part of the gap could have been filled since it was detected hence only the remainder is requested
+                    SeqReply::RequestResend(range) => {
+                        let range = range.start.max(session.next_recv_seq)..range.end;
+                        if !range.is_empty() {
+                            requests.push(range);
+                        }
+                    }
+                    SeqReply::Resend(range) => {
+                        let len = msgs.len();
+                        msgs.extend(session.sent().range(range.clone()).cloned());
+                        if log_enabled!(log::Level::Debug) {
+                            debug!("{} {} resending: {:?}, available: {}", con.con_id(), asserted_short_name!("SequencedSession", SequencedSession<M, S>), range, msgs.len() - len);
+                        }
+                    }
+                }
+            }
+            (requests, msgs)
+        };
+        for range in requests {
+            con.send_busywait(&mut M::new_resend_request(range))?;
+        }
+        for msg in msgs.iter() {
+            con.re_send_busywait(msg)?;
+        }
+        Ok(())
+    }
+}
+impl<M: SequencedMessenger, S: SequencedProtocolStorage<Item = M::SendT>> From<SequencedSession<M, S>> for ProtocolSessionState<SequencedSession<M, S>> {
+    fn from(value: SequencedSession<M, S>) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fmt::Display, io::Error, ops::Range, time::Duration};
+
+    use links_core::{
+        prelude::{ConId, ConnectionId},
+        unittest::setup::{
+            self,
+            messenger::CltTestMessenger,
+            model::{CltTestMsg, CltTestMsgDebug, SvcTestMsg, SvcTestMsgDebug},
+        },
+    };
+    use log::info;
+
+    use crate::prelude::*;
+
+    /// Encodes sequence numbers into the text of the debug messages, `"<seq>"` for sequenced and `"resend <start> <end>"` for resend requests
+    #[derive(Debug)]
+    struct CltTestSeqMessenger;
+    impl Framer for CltTestSeqMessenger {
+        fn get_frame_length(bytes: &bytes::BytesMut) -> Option<usize> {
+            CltTestMessenger::get_frame_length(bytes)
+        }
+    }
+    impl Messenger for CltTestSeqMessenger {
+        type RecvT = SvcTestMsg;
+        type SendT = CltTestMsg;
+        fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+            CltTestMessenger::deserialize(frame)
+        }
+        fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+            CltTestMessenger::serialize(msg)
+        }
+    }
+    fn parse_resend(text: &str) -> Option<Range<u64>> {
+        let mut parts = text.trim().strip_prefix("resend ")?.split(' ');
+        Some(parts.next()?.parse().ok()?..parts.next()?.parse().ok()?)
+    }
+    impl SequencedMessenger for CltTestSeqMessenger {
+        fn recv_seq(msg: &Self::RecvT) -> Option<u64> {
+            match msg {
+                SvcTestMsg::Dbg(msg) => msg.text.to_string().trim().parse().ok(),
+                _ => None,
+            }
+        }
+        fn recv_resend_request(msg: &Self::RecvT) -> Option<Range<u64>> {
+            match msg {
+                SvcTestMsg::Dbg(msg) => parse_resend(&msg.text.to_string()),
+                _ => None,
+            }
+        }
+        fn is_send_sequenced(msg: &Self::SendT) -> bool {
+            match msg {
+                CltTestMsg::Dbg(msg) => parse_resend(&msg.text.to_string()).is_none(),
+                _ => false,
+            }
+        }
+        fn new_resend_request(range: Range<u64>) -> Self::SendT {
+            CltTestMsgDebug::new(format!("resend {} {}", range.start, range.end).as_bytes()).into()
+        }
+    }
+
+    /// Records messages sent and resent to validate that resends bypass the send path
+    #[derive(Debug)]
+    struct TestCon {
+        con_id: ConId,
+        sent: Vec<CltTestMsg>,
+        re_sent: Vec<CltTestMsg>,
+    }
+    impl Display for TestCon {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "TestCon<{}>", self.con_id)
+        }
+    }
+    impl SendNonBlocking<CltTestMsg> for TestCon {
+        fn send(&mut self, msg: &mut CltTestMsg) -> Result<SendStatus, Error> {
+            self.sent.push(msg.clone());
+            Ok(SendStatus::Completed)
+        }
+    }
+    impl ReSendNonBlocking<CltTestMsg> for TestCon {
+        fn re_send(&mut self, msg: &CltTestMsg) -> Result<SendStatus, Error> {
+            self.re_sent.push(msg.clone());
+            Ok(SendStatus::Completed)
+        }
+    }
+    impl ConnectionId for TestCon {
+        fn con_id(&self) -> &ConId {
+            &self.con_id
+        }
+    }
+
+    fn svc_msg(text: &str) -> SvcTestMsg {
+        SvcTestMsgDebug::new(text.as_bytes()).into()
+    }
+    fn clt_msg(text: &str) -> CltTestMsg {
+        CltTestMsgDebug::new(text.as_bytes()).into()
+    }
+
+    #[test]
+    fn test_sequenced_session_recv() {
+        setup::log::configure();
+        let mut session = SequencedSession::<CltTestSeqMessenger, _>::new(InMemoryMessageLog::default());
+        use SeqRecvStatus::*;
+        assert_eq!(session.on_recv(&svc_msg("1")), InSequence(1));
+        assert_eq!(session.on_recv(&SvcTestMsg::HBeat(Default::default())), Unsequenced);
+        assert_eq!(session.on_recv(&svc_msg("1")), Duplicate(1));
+
+        assert_eq!(session.on_recv(&svc_msg("4")), Gap { missing: 2..4, request: Some(2..4) });
+        assert_eq!(session.on_recv(&svc_msg("4")), Duplicate(4));
+        assert_eq!(session.on_recv(&svc_msg("3")), Gap { missing: 2..3, request: None });
+        assert_eq!(session.on_recv(&svc_msg("6")), Gap { missing: 2..3, request: Some(4..6) });
+        assert_eq!(session.queued(), 3);
+        assert_eq!(session.pop_ready(), None);
+
+        assert_eq!(session.on_recv(&svc_msg("2")), InSequence(2));
+        assert_eq!(session.next_recv_seq(), 5);
+        assert_eq!(session.pop_ready(), Some(svc_msg("3")));
+        assert_eq!(session.pop_ready(), Some(svc_msg("4")));
+        assert_eq!(session.pop_ready(), None);
+        assert_eq!(session.queued(), 1);
+
+        assert_eq!(session.on_recv(&svc_msg("5")), InSequence(5));
+        assert_eq!(session.pop_ready(), Some(svc_msg("6")));
+        assert_eq!(session.next_recv_seq(), 7);
+
+        // reset drops queued messages it skips and releases the ones which became in sequence
+        assert_eq!(session.on_recv(&svc_msg("8")), Gap { missing: 7..8, request: Some(7..8) });
+        assert_eq!(session.on_recv(&svc_msg("10")), Gap { missing: 7..8, request: Some(8..10) });
+        assert!(!session.reset_next_recv_seq(6));
+        assert!(session.reset_next_recv_seq(10));
+        assert_eq!(session.pop_ready(), Some(svc_msg("10")));
+        assert_eq!(session.pop_ready(), None);
+        assert_eq!(session.next_recv_seq(), 11);
+        assert_eq!(session.queued(), 0);
+        info!("session: {:?}", session);
+    }
+
+    #[test]
+    fn test_sequenced_session_re_send_reply() {
+        setup::log::configure();
+        let session: ProtocolSessionState<_> = SequencedSession::<CltTestSeqMessenger, _>::new(InMemoryMessageLog::default()).into();
+        let mut con = TestCon {
+            con_id: ConId::clt(Some("unittest"), None, "127.0.0.1:1").unwrap(),
+            sent: vec![],
+            re_sent: vec![],
+        };
+
+        // only sequenced messages are stored
+        for seq in 1..=5 {
+            session.lock().on_sent(&clt_msg(&seq.to_string())).unwrap();
+        }
+        session.lock().on_sent(&CltTestSeqMessenger::new_resend_request(1..2)).unwrap();
+        assert_eq!(session.lock().next_send_seq(), 6);
+
+        // gap is withheld and triggers a single resend request
+        assert!(!session.is_recv_ready(&con, &svc_msg("3")));
+        assert!(!session.is_recv_ready(&con, &svc_msg("2")));
+        session.re_send_reply(&mut con).unwrap();
+        assert_eq!(con.sent, vec![CltTestSeqMessenger::new_resend_request(1..3)]);
+        assert!(con.re_sent.is_empty());
+        assert_eq!(session.pop_recv_ready(), None);
+
+        // filling the gap releases the withheld messages in order and duplicates are withheld
+        assert!(session.is_recv_ready(&con, &svc_msg("1")));
+        assert_eq!(session.pop_recv_ready(), Some(svc_msg("2")));
+        assert_eq!(session.pop_recv_ready(), Some(svc_msg("3")));
+        assert_eq!(session.pop_recv_ready(), None);
+        assert!(!session.is_recv_ready(&con, &svc_msg("2")));
+
+        // gap which is filled before the reply is sent is not requested
+        assert!(!session.is_recv_ready(&con, &svc_msg("5")));
+        assert!(session.is_recv_ready(&con, &svc_msg("4")));
+        assert_eq!(session.pop_recv_ready(), Some(svc_msg("5")));
+        session.re_send_reply(&mut con).unwrap();
+        assert_eq!(con.sent.len(), 1);
+
+        // peer resend request is delivered and answered via re_send only
+        assert!(session.is_recv_ready(&con, &svc_msg("resend 2 4")));
+        session.re_send_reply(&mut con).unwrap();
+        info!("con: {:?}", con);
+        assert_eq!(con.re_sent, vec![clt_msg("2"), clt_msg("3")]);
+        assert_eq!(con.sent.len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "unittest")]
+    #[cfg(target_family = "unix")]
+    fn test_sequenced_session_clt_recv_in_order() {
+        use crate::unittest::setup::protocol::SvcTestProtocolManual;
+        use links_core::unittest::setup::framer::TEST_MSG_FRAME_SIZE;
+
+        /// Withholds out of order messages from [CallbackRecv] and requests the peer to fill the gap
+        #[derive(Debug, Clone)]
+        struct CltTestSeqProtocol(ProtocolSessionState<SequencedSession<CltTestSeqMessenger, InMemoryMessageLog<CltTestMsg>>>);
+        impl Framer for CltTestSeqProtocol {
+            fn get_frame_length(bytes: &bytes::BytesMut) -> Option<usize> {
+                CltTestSeqMessenger::get_frame_length(bytes)
+            }
+        }
+        impl Messenger for CltTestSeqProtocol {
+            type RecvT = SvcTestMsg;
+            type SendT = CltTestMsg;
+            fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+                CltTestSeqMessenger::deserialize(frame)
+            }
+            fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+                CltTestSeqMessenger::serialize(msg)
+            }
+        }
+        impl ProtocolCore for CltTestSeqProtocol {
+            fn is_recv_ready<I: ConnectionId>(&self, who: &I, msg: &Self::RecvT) -> bool {
+                self.0.is_recv_ready(who, msg)
+            }
+            fn pop_recv_ready(&self) -> Option<Self::RecvT> {
+                self.0.pop_recv_ready()
+            }
+        }
+        impl Protocol for CltTestSeqProtocol {
+            fn re_send_reply<S: SendNonBlocking<Self::SendT> + ReSendNonBlocking<Self::SendT> + ConnectionId>(&self, sender: &mut S) -> Result<(), Error> {
+                self.0.re_send_reply(sender)
+            }
+        }
+
+        setup::log::configure();
+        let (clt_transport, svc_transport) = mio::net::UnixStream::pair().unwrap();
+        let counter = CounterCallback::new_ref();
+        let protocol = CltTestSeqProtocol(SequencedSession::new(InMemoryMessageLog::default()).into());
+        let mut clt = Clt::<_, _, TEST_MSG_FRAME_SIZE, _>::from_transport(clt_transport, ConId::clt_uds(Some("unittest"), "pair"), counter.clone(), protocol).unwrap();
+        let mut svc = Clt::<_, _, TEST_MSG_FRAME_SIZE, _>::from_transport(svc_transport, ConId::svc_uds(Some("unittest"), "pair"), LoggerCallback::new_ref(), SvcTestProtocolManual).unwrap();
+
+        // out of order and duplicate messages are withheld from the callback
+        for seq in ["2", "3", "1", "1", "4"] {
+            svc.send_busywait(&mut svc_msg(seq)).unwrap();
+        }
+        for seq in ["1", "2", "3", "4"] {
+            let msg = clt.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap().unwrap_completed_some();
+            assert_eq!(msg, svc_msg(seq));
+        }
+        assert_eq!(counter.recv_count(), 4);
+
+        // the gap was filled before replies were sent hence it is not requested, while a new gap is requested as soon as it is withheld
+        svc.send_busywait(&mut svc_msg("6")).unwrap();
+        assert!(matches!(clt.recv_busywait_timeout(Duration::from_millis(100)).unwrap(), RecvStatus::WouldBlock));
+        let msg = svc.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap().unwrap_completed_some();
+        info!("msg: {:?}", msg);
+        assert_eq!(msg, CltTestSeqMessenger::new_resend_request(5..6));
+        assert!(matches!(svc.recv().unwrap(), RecvStatus::WouldBlock));
+
+        // filling the gap releases the withheld message
+        svc.send_busywait(&mut svc_msg("5")).unwrap();
+        for seq in ["5", "6"] {
+            let msg = clt.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap().unwrap_completed_some();
+            assert_eq!(msg, svc_msg(seq));
+        }
+        assert_eq!(counter.recv_count(), 6);
+
+        // owned recver only flags the request as due since the owned sender is the only writer of the connection
+        let (mut clt_recver, mut clt_sender) = clt.into_split();
+        svc.send_busywait(&mut svc_msg("8")).unwrap();
+        assert!(matches!(clt_recver.recv_busywait_timeout(Duration::from_millis(100)).unwrap(), RecvStatus::WouldBlock));
+        assert!(matches!(svc.recv().unwrap(), RecvStatus::WouldBlock));
+        clt_sender.send_due().unwrap();
+        let msg = svc.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap().unwrap_completed_some();
+        assert_eq!(msg, CltTestSeqMessenger::new_resend_request(7..8));
+    }
+}
@@ -0,0 +1,307 @@
+//! This module contains [SocketOptions] which allows tuning of tcp sockets established by [crate::prelude::Clt::connect_with] and
+//! accepted by [crate::prelude::Svc::bind_with], using [socket2], as well as reading back the effective values of an established socket for diagnostics.
+//!
+//! # Note
+//! Only options which are explicitly set are applied, everything else is left at the os default.
+
+use socket2::{Domain, Protocol, SockRef, Socket, Type};
+use std::{
+    fmt::Display,
+    io::{Error, ErrorKind},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+/// Tcp keepalive probe settings, see [SocketOptions::with_keepalive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TcpKeepalive {
+    /// Time the connection needs to remain idle before the first probe is sent
+    pub idle: Duration,
+    /// Time between individual probes
+    pub interval: Duration,
+    /// Number of unacknowledged probes before the connection is dropped
+    pub retries: u32,
+}
+
+/// Options applied to a tcp socket once it is established, use [SocketOptions::effective] to read back the values the os actually applied.
+///
+/// # Example
+/// ```
+/// use links_nonblocking::prelude::*;
+/// use std::time::Duration;
+///
+/// let options = SocketOptions::default()
+///     .with_nodelay(true)
+///     .with_recv_buffer_size(1 << 20)
+///     .with_keepalive(TcpKeepalive { idle: Duration::from_secs(10), interval: Duration::from_secs(1), retries: 3 });
+/// assert_eq!(options.nodelay, Some(true));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SocketOptions {
+    /// `TCP_NODELAY`
+    pub nodelay: Option<bool>,
+    /// `SO_RCVBUF` in bytes, note the os may adjust the value, ex: linux doubles it
+    pub recv_buffer_size: Option<usize>,
+    /// `SO_SNDBUF` in bytes, note the os may adjust the value, ex: linux doubles it
+    pub send_buffer_size: Option<usize>,
+    /// `SO_KEEPALIVE` along with the probe settings
+    pub keepalive: Option<TcpKeepalive>,
+    /// `SO_BUSY_POLL`, linux only, the value is applied with microsecond precision
+    pub busy_poll: Option<Duration>,
+    /// `IP_TOS` for ipv4 or `IPV6_TCLASS` for ipv6 sockets
+    pub tos: Option<u32>,
+    /// Local address the initiator socket binds to prior to connecting, port `0` lets the os pick the port. Ignored for accepted sockets.
+    pub local_addr: Option<SocketAddr>,
+}
+impl SocketOptions {
+    pub fn with_nodelay(self, nodelay: bool) -> Self {
+        Self { nodelay: Some(nodelay), ..self }
+    }
+    pub fn with_recv_buffer_size(self, size: usize) -> Self {
+        Self { recv_buffer_size: Some(size), ..self }
+    }
+    pub fn with_send_buffer_size(self, size: usize) -> Self {
+        Self { send_buffer_size: Some(size), ..self }
+    }
+    pub fn with_keepalive(self, keepalive: TcpKeepalive) -> Self {
+        Self { keepalive: Some(keepalive), ..self }
+    }
+    pub fn with_busy_poll(self, busy_poll: Duration) -> Self {
+        Self { busy_poll: Some(busy_poll), ..self }
+    }
+    pub fn with_tos(self, tos: u32) -> Self {
+        Self { tos: Some(tos), ..self }
+    }
+    pub fn with_local_addr(self, local_addr: SocketAddr) -> Self {
+        Self { local_addr: Some(local_addr), ..self }
+    }
+
+    /// Establishes a `blocking` [TcpStream] to `addr`, binding to [SocketOptions::local_addr] if set. All other options are applied prior to connecting
+    /// so that [SocketOptions::recv_buffer_size] & [SocketOptions::send_buffer_size] are taken into account during the handshake, ex: tcp window scaling
+    pub(crate) fn connect(&self, addr: &str) -> Result<TcpStream, Error> {
+        let mut last_err = Error::new(ErrorKind::InvalidInput, format!("could not resolve to any addresses: {}", addr));
+        for peer_addr in addr.to_socket_addrs()?.filter(|peer_addr| self.local_addr.map_or(true, |local_addr| peer_addr.is_ipv4() == local_addr.is_ipv4())) {
+            match self.socket(&peer_addr).and_then(|socket| socket.connect(&peer_addr.into()).map(|()| socket)) {
+                Ok(socket) => return Ok(socket.into()),
+                Err(e) => last_err = e,
+            }
+        }
+        Err(last_err)
+    }
+
+    /// Same as [SocketOptions::connect] but initiates a `non blocking` connect to `peer_addr`, the connection is established once the stream becomes writable
+    pub(crate) fn connect_nonblocking(&self, peer_addr: &SocketAddr) -> Result<mio::net::TcpStream, Error> {
+        let socket = self.socket(peer_addr)?;
+        socket.set_nonblocking(true)?;
+        match socket.connect(&(*peer_addr).into()) {
+            Ok(()) => {}
+            #[cfg(target_family = "unix")]
+            Err(e) if e.raw_os_error() == Some(libc::EINPROGRESS) => {}
+            Err(e) if e.kind() == ErrorKind::WouldBlock => {}
+            Err(e) => return Err(e),
+        }
+        Ok(mio::net::TcpStream::from_std(socket.into()))
+    }
+
+    /// Binds a [TcpListener] to `addr` and applies [SocketOptions::recv_buffer_size] & [SocketOptions::send_buffer_size] prior to listening
+    /// so that they are inherited by every accepted connection, the rest of the options are ignored
+    pub fn bind(&self, addr: &str) -> Result<TcpListener, Error> {
+        let mut last_err = Error::new(ErrorKind::InvalidInput, format!("could not resolve to any addresses: {}", addr));
+        for local_addr in addr.to_socket_addrs()? {
+            match self.listener(&local_addr) {
+                Ok(listener) => return Ok(listener),
+                Err(e) => last_err = e,
+            }
+        }
+        Err(last_err)
+    }
+
+    /// Creates a `blocking` socket ready to connect to `peer_addr`, binding it to [SocketOptions::local_addr] if set
+    fn socket(&self, peer_addr: &SocketAddr) -> Result<Socket, Error> {
+        let socket = Socket::new(Domain::for_address(*peer_addr), Type::STREAM, Some(Protocol::TCP))?;
+        self.apply_to(&SockRef::from(&socket), peer_addr.is_ipv4())?;
+        if let Some(local_addr) = self.local_addr {
+            socket.bind(&local_addr.into())?;
+        }
+        Ok(socket)
+    }
+    fn listener(&self, local_addr: &SocketAddr) -> Result<TcpListener, Error> {
+        let socket = Socket::new(Domain::for_address(*local_addr), Type::STREAM, Some(Protocol::TCP))?;
+        // same as std::net::TcpListener::bind
+        #[cfg(target_family = "unix")]
+        socket.set_reuse_address(true)?;
+        if let Some(size) = self.recv_buffer_size {
+            socket.set_recv_buffer_size(size)?;
+        }
+        if let Some(size) = self.send_buffer_size {
+            socket.set_send_buffer_size(size)?;
+        }
+        socket.bind(&(*local_addr).into())?;
+        socket.listen(128)?;
+        Ok(socket.into())
+    }
+
+    /// Applies all of the options which are set to the established `socket`, except for [SocketOptions::local_addr]
+    ///
+    /// # Errors
+    /// * [ErrorKind::Unsupported] - if an option is not supported by the platform
+    #[cfg(target_family = "unix")]
+    pub fn apply(&self, socket: &impl std::os::fd::AsRawFd) -> Result<(), Error> {
+        // SAFETY: the fd is owned by the socket and remains open for the duration of the borrow
+        let fd = unsafe { std::os::fd::BorrowedFd::borrow_raw(socket.as_raw_fd()) };
+        let socket = SockRef::from(&fd);
+        let is_ipv4 = socket.local_addr()?.is_ipv4();
+        self.apply_to(&socket, is_ipv4)
+    }
+    #[cfg(target_family = "unix")]
+    fn apply_to(&self, socket: &SockRef, is_ipv4: bool) -> Result<(), Error> {
+        if let Some(nodelay) = self.nodelay {
+            socket.set_nodelay(nodelay)?;
+        }
+        if let Some(size) = self.recv_buffer_size {
+            socket.set_recv_buffer_size(size)?;
+        }
+        if let Some(size) = self.send_buffer_size {
+            socket.set_send_buffer_size(size)?;
+        }
+        if let Some(keepalive) = self.keepalive {
+            sys::set_keepalive(socket, &keepalive)?;
+        }
+        if let Some(busy_poll) = self.busy_poll {
+            sys::set_busy_poll(socket, busy_poll)?;
+        }
+        if let Some(tos) = self.tos {
+            match is_ipv4 {
+                true => socket.set_tos(tos)?,
+                false => socket.set_tclass_v6(tos)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads back the values currently in effect on the `socket`, options which are not supported by the platform are reported as [None]
+    #[cfg(target_family = "unix")]
+    pub fn effective(socket: &impl std::os::fd::AsRawFd) -> Result<Self, Error> {
+        // SAFETY: the fd is owned by the socket and remains open for the duration of the borrow
+        let fd = unsafe { std::os::fd::BorrowedFd::borrow_raw(socket.as_raw_fd()) };
+        let socket = SockRef::from(&fd);
+        let local_addr = socket.local_addr()?.as_socket().ok_or_else(|| Error::new(ErrorKind::InvalidInput, "not an ip socket"))?;
+        Ok(Self {
+            nodelay: Some(socket.nodelay()?),
+            recv_buffer_size: Some(socket.recv_buffer_size()?),
+            send_buffer_size: Some(socket.send_buffer_size()?),
+            keepalive: sys::keepalive(&socket)?,
+            busy_poll: sys::busy_poll(&socket).ok(),
+            tos: Some(match local_addr.is_ipv4() {
+                true => socket.tos()?,
+                false => socket.tclass_v6()?,
+            }),
+            local_addr: Some(local_addr),
+        })
+    }
+}
+impl Display for SocketOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn fmt_opt<T: std::fmt::Debug>(value: &Option<T>) -> String {
+            value.as_ref().map_or_else(|| "default".to_owned(), |value| format!("{:?}", value))
+        }
+        write!(
+            f,
+            "SocketOptions {{ nodelay: {}, recv_buffer_size: {}, send_buffer_size: {}, keepalive: {}, busy_poll: {}, tos: {}, local_addr: {} }}",
+            fmt_opt(&self.nodelay),
+            fmt_opt(&self.recv_buffer_size),
+            fmt_opt(&self.send_buffer_size),
+            fmt_opt(&self.keepalive),
+            fmt_opt(&self.busy_poll),
+            fmt_opt(&self.tos),
+            fmt_opt(&self.local_addr),
+        )
+    }
+}
+
+#[cfg(not(target_family = "unix"))]
+impl SocketOptions {
+    pub fn apply(&self, _socket: &impl std::os::windows::io::AsRawSocket) -> Result<(), Error> {
+        self.apply_to(&(), true)
+    }
+    fn apply_to<S>(&self, _socket: &S, _is_ipv4: bool) -> Result<(), Error> {
+        match self
+            == &(Self {
+                local_addr: self.local_addr,
+                ..Self::default()
+            }) {
+            true => Ok(()),
+            false => Err(Error::new(ErrorKind::Unsupported, "SocketOptions are only supported on unix")),
+        }
+    }
+    pub fn effective(_socket: &impl std::os::windows::io::AsRawSocket) -> Result<Self, Error> {
+        Err(Error::new(ErrorKind::Unsupported, "SocketOptions are only supported on unix"))
+    }
+}
+
+#[cfg(target_family = "unix")]
+mod sys {
+    use super::TcpKeepalive;
+    use socket2::SockRef;
+    use std::{
+        io::{Error, ErrorKind},
+        time::Duration,
+    };
+
+    #[cfg(any(target_os = "linux", target_os = "android", target_vendor = "apple"))]
+    pub(super) fn set_keepalive(socket: &SockRef, keepalive: &TcpKeepalive) -> Result<(), Error> {
+        let params = socket2::TcpKeepalive::new().with_time(keepalive.idle).with_interval(keepalive.interval).with_retries(keepalive.retries);
+        socket.set_tcp_keepalive(&params)
+    }
+    #[cfg(any(target_os = "linux", target_os = "android", target_vendor = "apple"))]
+    pub(super) fn keepalive(socket: &SockRef) -> Result<Option<TcpKeepalive>, Error> {
+        if !socket.keepalive()? {
+            return Ok(None);
+        }
+        Ok(Some(TcpKeepalive {
+            idle: socket.keepalive_time()?,
+            interval: socket.keepalive_interval()?,
+            retries: socket.keepalive_retries()?,
+        }))
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android", target_vendor = "apple")))]
+    pub(super) fn set_keepalive(_socket: &SockRef, _keepalive: &TcpKeepalive) -> Result<(), Error> {
+        Err(Error::new(ErrorKind::Unsupported, "tcp keepalive probe settings are not supported on this platform"))
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android", target_vendor = "apple")))]
+    pub(super) fn keepalive(_socket: &SockRef) -> Result<Option<TcpKeepalive>, Error> {
+        Ok(None)
+    }
+
+    // socket2 does not expose SO_BUSY_POLL
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(super) fn set_busy_poll(socket: &SockRef, busy_poll: Duration) -> Result<(), Error> {
+        use std::os::fd::AsRawFd;
+        let value = libc::c_int::try_from(busy_poll.as_micros()).map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+        let len = std::mem::size_of::<libc::c_int>() as libc::socklen_t;
+        // SAFETY: value outlives the call and len matches its size
+        match unsafe { libc::setsockopt(socket.as_raw_fd(), libc::SOL_SOCKET, libc::SO_BUSY_POLL, &value as *const libc::c_int as *const libc::c_void, len) } {
+            -1 => Err(Error::last_os_error()),
+            _ => Ok(()),
+        }
+    }
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    pub(super) fn busy_poll(socket: &SockRef) -> Result<Duration, Error> {
+        use std::os::fd::AsRawFd;
+        let mut value: libc::c_int = 0;
+        let mut len = std::mem::size_of::<libc::c_int>() as libc::socklen_t;
+        // SAFETY: value outlives the call and len matches its size
+        match unsafe { libc::getsockopt(socket.as_raw_fd(), libc::SOL_SOCKET, libc::SO_BUSY_POLL, &mut value as *mut libc::c_int as *mut libc::c_void, &mut len) } {
+            -1 => Err(Error::last_os_error()),
+            _ => Ok(Duration::from_micros(value as u64)),
+        }
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub(super) fn set_busy_poll(_socket: &SockRef, _busy_poll: Duration) -> Result<(), Error> {
+        Err(Error::new(ErrorKind::Unsupported, "SO_BUSY_POLL is only supported on linux"))
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    pub(super) fn busy_poll(_socket: &SockRef) -> Result<Duration, Error> {
+        Err(Error::new(ErrorKind::Unsupported, "SO_BUSY_POLL is only supported on linux"))
+    }
+}
@@ -0,0 +1,325 @@
+//! This module contains [TlsSession] which wraps a [rustls::Connection] so that it can be shared by a `paired` [crate::prelude::FrameReader]
+//! and [crate::prelude::FrameWriter] while preserving their non blocking semantics.
+//!
+//! # Note
+//! The handshake is driven to completion by busy waiting on a non blocking [crate::prelude::Transport], bounded by a timeout, before it is split,
+//! see [crate::prelude::Clt::connect_tls] and [crate::prelude::Svc::bind_tls]. Once established all reads and writes are non blocking.
+
+use crate::prelude::SendStatus;
+use log::{debug, log_enabled};
+use rustls::Connection;
+use std::{
+    fmt::Debug,
+    io::{Error, ErrorKind, Read, Write},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Maximum time [crate::prelude::SvcAcceptor] will wait for a client to complete the tls handshake after its connection was accepted
+pub const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(1);
+
+const EOF: usize = 0;
+
+/// A reference counted [rustls::Connection] shared by `paired` [crate::prelude::FrameReader] and [crate::prelude::FrameWriter]
+///
+/// # Important
+/// The lock is only held for the duration of a single non blocking read or write call on the underlying socket, which allows the `pair`
+/// to be used from separate threads without one starving the other.
+#[derive(Clone)]
+pub struct TlsSession {
+    connection: Arc<spin::Mutex<Connection>>,
+}
+impl TlsSession {
+    /// Drives the handshake to completion on a `non blocking` stream, busy waiting while it would block, and returns an established [TlsSession]
+    ///
+    /// # Errors
+    /// * [ErrorKind::TimedOut] - if the handshake did not complete within `timeout`
+    /// * [ErrorKind::InvalidData] - if the peer failed certificate verification or violated the protocol
+    pub fn handshake<S: Read + Write>(connection: impl Into<Connection>, stream: &mut S, timeout: Duration) -> Result<Self, Error> {
+        let mut connection = connection.into();
+        let start = Instant::now();
+        while connection.is_handshaking() || connection.wants_write() {
+            if start.elapsed() > timeout {
+                return Err(Error::new(ErrorKind::TimedOut, format!("tls handshake timeout: {:?}", timeout)));
+            }
+            match connection.complete_io(stream) {
+                Ok(_) => {}
+                Err(e) if e.kind() == ErrorKind::WouldBlock => std::hint::spin_loop(),
+                Err(e) => return Err(e),
+            }
+        }
+        if log_enabled!(log::Level::Debug) {
+            debug!("tls handshake completed in {:?}, protocol: {:?}, cipher: {:?}", start.elapsed(), connection.protocol_version(), connection.negotiated_cipher_suite());
+        }
+        Ok(Self {
+            connection: Arc::new(spin::Mutex::new(connection)),
+        })
+    }
+
+    /// Reads decrypted bytes into `buf` following [Read::read] conventions of a non blocking socket, specifically
+    /// * [Ok(0)] - peer closed the connection, with or without sending `close_notify`
+    /// * [ErrorKind::WouldBlock] - no complete tls record is available yet
+    pub(crate) fn read<S: Read + Write>(&self, stream: &mut S, buf: &mut [u8]) -> Result<usize, Error> {
+        let mut connection = self.connection.lock();
+        loop {
+            match connection.reader().read(buf) {
+                Ok(len) => return Ok(len),
+                Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(EOF),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {}
+                Err(e) => return Err(e),
+            }
+            connection.read_tls(stream)?;
+            let res = connection.process_new_packets();
+            // deliver alerts and post handshake messages, if the socket is busy [Self::write] will deliver them later
+            while connection.wants_write() {
+                match connection.write_tls(stream) {
+                    Ok(EOF) => break,
+                    Ok(_) => continue,
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                    Err(e) => return Err(e),
+                }
+            }
+            if let Err(e) = res {
+                return Err(Error::new(ErrorKind::InvalidData, e));
+            }
+        }
+    }
+
+    /// Encrypts and writes the `entire` frame or `no` bytes at all, following [crate::prelude::FrameWriter::write_frame] conventions
+    ///
+    /// # Important
+    /// If the socket is busy delivering previously queued tls records the frame is not accepted and [SendStatus::WouldBlock] is returned,
+    /// otherwise the frame is accepted and this call will `busy wait` until all of its records are written to the socket.
+    pub(crate) fn write<S: Read + Write>(&self, stream: &mut S, bytes: &[u8]) -> Result<SendStatus, Error> {
+        {
+            let mut connection = self.connection.lock();
+            while connection.wants_write() {
+                match connection.write_tls(stream) {
+                    Ok(EOF) => return Err(Error::new(ErrorKind::ConnectionReset, "tls write_tls EOF")),
+                    Ok(_) => continue,
+                    Err(e) if e.kind() == ErrorKind::WouldBlock => return Ok(SendStatus::WouldBlock),
+                    Err(e) => return Err(e),
+                }
+            }
+            connection.writer().write_all(bytes)?;
+        }
+        // release the lock between attempts so that the paired reader can make progress while the socket is busy
+        loop {
+            let mut connection = self.connection.lock();
+            if !connection.wants_write() {
+                return Ok(SendStatus::Completed);
+            }
+            match connection.write_tls(stream) {
+                Ok(EOF) => return Err(Error::new(ErrorKind::ConnectionReset, "tls write_tls EOF")),
+                Ok(_) => continue,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Queues a `close_notify` alert and makes a single attempt to deliver it
+    pub(crate) fn close<S: Read + Write>(&self, stream: &mut S) {
+        let mut connection = self.connection.lock();
+        connection.send_close_notify();
+        let _ = connection.write_tls(stream);
+    }
+}
+impl Debug for TlsSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let connection = self.connection.lock();
+        f.debug_struct("TlsSession")
+            .field("protocol", &connection.protocol_version())
+            .field("cipher", &connection.negotiated_cipher_suite().map(|suite| suite.suite()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::prelude::*;
+    use crate::unittest::setup::protocol::{CltTestProtocolManual, SvcTestProtocolManual};
+    use links_core::unittest::setup::{
+        self,
+        framer::TEST_MSG_FRAME_SIZE,
+        model::{CltTestMsg, CltTestMsgDebug, SvcTestMsg, SvcTestMsgDebug},
+    };
+    use log::{info, LevelFilter};
+    use rustls::{
+        pki_types::{PrivateKeyDer, PrivatePkcs8KeyDer, ServerName},
+        ClientConfig, RootCertStore, ServerConfig,
+    };
+    use std::{
+        io::{ErrorKind, Read},
+        num::NonZeroUsize,
+        sync::Arc,
+        thread::Builder,
+        time::{Duration, Instant},
+    };
+
+    /// Generates a self signed certificate for `localhost` and returns a [ServerConfig] presenting it along with a [ClientConfig] trusting it
+    fn self_signed_configs() -> (Arc<ServerConfig>, Arc<ClientConfig>) {
+        let certified = rcgen::generate_simple_self_signed(vec!["localhost".to_owned()]).unwrap();
+        let key = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(certified.key_pair.serialize_der()));
+        let svc_config = ServerConfig::builder().with_no_client_auth().with_single_cert(vec![certified.cert.der().clone()], key).unwrap();
+
+        let mut roots = RootCertStore::empty();
+        roots.add(certified.cert.der().clone()).unwrap();
+        let clt_config = ClientConfig::builder().with_root_certificates(roots).with_no_client_auth();
+        (Arc::new(svc_config), Arc::new(clt_config))
+    }
+
+    #[test]
+    fn test_tls_clt_svc_connected() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        const N: usize = 1_000;
+        let (svc_config, clt_config) = self_signed_configs();
+
+        let mut svc = Svc::<_, _, TEST_MSG_FRAME_SIZE>::bind_tls(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), SvcTestProtocolManual, Some("unittest"), svc_config).unwrap();
+        info!("svc: {}", svc);
+
+        // handshake blocks until the peer responds hence clt must run on a separate thread from svc acceptor
+        let clt_jh = Builder::new()
+            .name("Clt-Thread".to_owned())
+            .spawn(move || {
+                let server_name = ServerName::try_from("localhost").unwrap();
+                let mut clt = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect_tls(
+                    addr,
+                    setup::net::default_connect_timeout(),
+                    setup::net::default_connect_retry_after(),
+                    LoggerCallback::new_ref(),
+                    CltTestProtocolManual,
+                    Some("unittest"),
+                    clt_config,
+                    server_name,
+                )
+                .unwrap();
+                info!("clt: {}", clt);
+                let mut clt_msg_inp = CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Client Msg"));
+                for _ in 0..N {
+                    clt.send_busywait(&mut clt_msg_inp).unwrap();
+                    let clt_msg_out = clt.recv_busywait().unwrap().unwrap();
+                    assert_eq!(clt_msg_out, SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"Hello Frm Server Msg")));
+                }
+                clt
+            })
+            .unwrap();
+
+        svc.accept_into_pool_busywait().unwrap();
+        assert!(svc.all_connected());
+
+        let mut svc_msg_inp = SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"Hello Frm Server Msg"));
+        for _ in 0..N {
+            let svc_msg_out = svc.recv_busywait().unwrap().unwrap();
+            assert_eq!(svc_msg_out, CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Client Msg")));
+            svc.send_busywait(&mut svc_msg_inp).unwrap();
+        }
+
+        // clt drop sends close_notify which svc must report as a graceful disconnect
+        let clt = clt_jh.join().unwrap();
+        drop(clt);
+        let status = svc.recv_busywait_timeout(setup::net::default_connect_timeout()).unwrap();
+        info!("status: {:?}", status);
+        assert!(status.is_completed_none());
+    }
+
+    #[test]
+    fn test_tls_clt_untrusted_svc_certificate() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let (svc_config, _) = self_signed_configs();
+        let (_, clt_config) = self_signed_configs(); // trusts a different self signed certificate
+
+        let mut svc = Svc::<_, _, TEST_MSG_FRAME_SIZE>::bind_tls(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), SvcTestProtocolManual, Some("unittest"), svc_config)
+            .unwrap()
+            .into_sender_with_spawned_recver();
+        info!("svc: {}", svc);
+
+        let res = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect_tls(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            LoggerCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest"),
+            clt_config,
+            ServerName::try_from("localhost").unwrap(),
+        );
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(!svc.is_next_connected());
+    }
+
+    #[test]
+    fn test_tls_svc_spawned_handshake_not_blocking_poll_handler() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let (svc_config, clt_config) = self_signed_configs();
+        let tls_handshake_timeout = Duration::from_secs(2);
+
+        let max_connections = NonZeroUsize::new(2).unwrap();
+        let acceptor = SvcAcceptor::<_, _, TEST_MSG_FRAME_SIZE>::new_tls(
+            ConId::svc(Some("unittest"), addr, None).unwrap(),
+            std::net::TcpListener::bind(addr).unwrap(),
+            LoggerCallback::new_ref(),
+            SvcTestProtocolManual,
+            max_connections,
+            svc_config,
+        )
+        .with_tls_handshake_timeout(tls_handshake_timeout);
+        let mut svc = Svc::from_acceptor(acceptor, max_connections).into_sender_with_spawned_recver();
+        info!("svc: {}", svc);
+
+        // peer which never starts a handshake is accepted first and must not stall the poll thread from accepting the next connection
+        let mut stalled = std::net::TcpStream::connect(addr).unwrap();
+
+        let start = Instant::now();
+        let mut clt = Clt::<_, _, TEST_MSG_FRAME_SIZE>::connect_tls(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            LoggerCallback::new_ref(),
+            CltTestProtocolManual,
+            Some("unittest"),
+            clt_config,
+            ServerName::try_from("localhost").unwrap(),
+        )
+        .unwrap();
+        let elapsed = start.elapsed();
+        info!("clt: {}, elapsed: {:?}", clt, elapsed);
+        assert!(elapsed < tls_handshake_timeout / 2);
+
+        clt.send_busywait(&mut CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Client Msg"))).unwrap();
+        assert!(svc.is_next_connected_busywait_timeout(setup::net::default_connect_timeout()));
+
+        // stalled peer is disconnected once the configured handshake timeout elapses
+        stalled.set_read_timeout(Some(tls_handshake_timeout * 2)).unwrap();
+        let res = stalled.read(&mut [0_u8; 1]);
+        info!("stalled res: {:?}, elapsed: {:?}", res, start.elapsed());
+        assert!(matches!(res, Ok(0)) || matches!(res, Err(ref e) if e.kind() == ErrorKind::ConnectionReset));
+        assert!(start.elapsed() < tls_handshake_timeout * 2);
+    }
+
+    #[test]
+    fn test_tls_clt_connector() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let (svc_config, clt_config) = self_signed_configs();
+
+        let mut svc = Svc::<_, _, TEST_MSG_FRAME_SIZE>::bind_tls(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), SvcTestProtocolManual, Some("unittest"), svc_config)
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let (connector, pending) = CltConnector::<_, _, TEST_MSG_FRAME_SIZE>::new(addr, setup::net::default_connect_timeout(), LoggerCallback::new_ref(), CltTestProtocolManual, Some("unittest")).unwrap();
+        let connector = connector.with_tls(clt_config, ServerName::try_from("localhost").unwrap());
+        DEFAULT_POLL_HANDLER.add_connector(connector.into()).unwrap();
+        let mut clt = pending.take_busywait_timeout(setup::net::default_connect_timeout()).unwrap();
+        info!("clt: {}", clt);
+
+        clt.send_busywait(&mut CltTestMsg::Dbg(CltTestMsgDebug::new(b"Hello Frm Client Msg"))).unwrap();
+        assert!(svc.is_next_connected_busywait_timeout(setup::net::default_connect_timeout()));
+        svc.send_busywait(&mut SvcTestMsg::Dbg(SvcTestMsgDebug::new(b"Hello Frm Server Msg"))).unwrap();
+    }
+}
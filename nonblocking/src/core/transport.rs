@@ -0,0 +1,216 @@
+//! This module contains the [Transport] trait which allows [crate::prelude::FrameReader], [crate::prelude::FrameWriter] and everything built on top of them
+//! to operate over any non blocking connected byte stream. It is implemented for [mio::net::TcpStream], which is the default, and on unix for [mio::net::UnixStream]
+//! which avoids the tcp loopback overhead for links between processes on the same host.
+//!
+//! # Note
+//! [AcceptTransport] extends [Transport] with a listening socket so that [crate::prelude::SvcAcceptor] can accept connections of that [Transport],
+//! while [ConnectTransport] extends it with a non blocking connect so that [crate::prelude::CltConnector] can initiate connections of that [Transport].
+
+use crate::prelude::{ConId, SocketOptions};
+use mio::event::Source;
+use std::{
+    fmt::Debug,
+    io::{Error, ErrorKind, Read, Write},
+    net::Shutdown,
+};
+
+/// A non blocking connected byte stream which can be registered with a [mio::Poll] and used by [crate::prelude::FrameReader] and [crate::prelude::FrameWriter]
+///
+/// # Important
+/// [Transport::try_clone] must yield a handle to the same underlying connection, as the `paired` [crate::prelude::FrameReader] and [crate::prelude::FrameWriter]
+/// each own one of the handles
+pub trait Transport: Read + Write + Source + Debug + Send + Sized + 'static {
+    /// Type of the local and peer addresses of the connection, ex: [std::net::SocketAddr]
+    type Addr: Debug;
+    /// Shuts down the read, write, or both halves of the connection
+    fn shutdown(&self, how: Shutdown) -> Result<(), Error>;
+    /// Creates a new handle to the same underlying connection
+    fn try_clone(&self) -> Result<Self, Error>;
+    fn local_addr(&self) -> Result<Self::Addr, Error>;
+    fn peer_addr(&self) -> Result<Self::Addr, Error>;
+    /// Applies the `options` to the connection, the default implementation only accepts [SocketOptions::default] and returns [ErrorKind::Unsupported] otherwise
+    fn apply_socket_options(&self, options: &SocketOptions) -> Result<(), Error> {
+        match options == &SocketOptions::default() {
+            true => Ok(()),
+            false => Err(Error::new(ErrorKind::Unsupported, format!("{} does not support socket options: {}", std::any::type_name::<Self>(), options))),
+        }
+    }
+    /// Reads back the [SocketOptions] in effect on the connection, the default implementation returns [ErrorKind::Unsupported]
+    fn socket_options(&self) -> Result<SocketOptions, Error> {
+        Err(Error::new(ErrorKind::Unsupported, format!("{} does not support socket options", std::any::type_name::<Self>())))
+    }
+}
+
+/// A [Transport] whose connections can be accepted from a non blocking [AcceptTransport::Listener] by [crate::prelude::SvcAcceptor]
+pub trait AcceptTransport: Transport {
+    type Listener: Source + Debug + Send + 'static;
+    /// Accepts a pending connection and derives its [ConId] from the acceptor's `con_id`, shall return [std::io::ErrorKind::WouldBlock] if there is none pending
+    fn accept(listener: &Self::Listener, con_id: &ConId) -> Result<(Self, ConId), Error>;
+}
+
+/// A [Transport] whose connections can be initiated without blocking by [crate::prelude::CltConnector]
+pub trait ConnectTransport: Transport {
+    /// Initiates a non blocking connect to the peer identified by `con_id`, the connection is established once the returned instance signals it is writable
+    /// and [ConnectTransport::take_error] yields no error
+    fn connect(con_id: &ConId, options: &SocketOptions) -> Result<Self, Error>;
+    /// Takes the pending error of the connection, this is how a failed non blocking connect is reported
+    fn take_error(&self) -> Result<Option<Error>, Error>;
+    /// Derives the [ConId] of the established connection from the initiator's `con_id`
+    fn connected(&self, con_id: &ConId) -> Result<ConId, Error>;
+}
+
+impl Transport for mio::net::TcpStream {
+    type Addr = std::net::SocketAddr;
+    #[inline(always)]
+    fn shutdown(&self, how: Shutdown) -> Result<(), Error> {
+        mio::net::TcpStream::shutdown(self, how)
+    }
+    /// [mio::net::TcpStream] does not provide `try_clone` hence the underlying socket is duplicated and wrapped into a new instance
+    fn try_clone(&self) -> Result<Self, Error> {
+        #[cfg(target_family = "unix")]
+        let stream = {
+            use std::os::fd::{AsRawFd, BorrowedFd};
+            // SAFETY: the fd is owned by self and remains open for the duration of the borrow
+            let fd = unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }.try_clone_to_owned()?;
+            std::net::TcpStream::from(fd)
+        };
+        #[cfg(target_family = "windows")]
+        let stream = {
+            use std::os::windows::io::{AsRawSocket, BorrowedSocket};
+            // SAFETY: the socket is owned by self and remains open for the duration of the borrow
+            let socket = unsafe { BorrowedSocket::borrow_raw(self.as_raw_socket()) }.try_clone_to_owned()?;
+            std::net::TcpStream::from(socket)
+        };
+        Ok(mio::net::TcpStream::from_std(stream))
+    }
+    #[inline(always)]
+    fn local_addr(&self) -> Result<Self::Addr, Error> {
+        mio::net::TcpStream::local_addr(self)
+    }
+    #[inline(always)]
+    fn peer_addr(&self) -> Result<Self::Addr, Error> {
+        mio::net::TcpStream::peer_addr(self)
+    }
+    #[inline(always)]
+    fn apply_socket_options(&self, options: &SocketOptions) -> Result<(), Error> {
+        options.apply(self)
+    }
+    #[inline(always)]
+    fn socket_options(&self) -> Result<SocketOptions, Error> {
+        SocketOptions::effective(self)
+    }
+}
+impl AcceptTransport for mio::net::TcpStream {
+    type Listener = mio::net::TcpListener;
+    fn accept(listener: &Self::Listener, con_id: &ConId) -> Result<(Self, ConId), Error> {
+        let (stream, addr) = listener.accept()?;
+        let mut con_id = con_id.clone();
+        con_id.set_peer(addr);
+        Ok((stream, con_id))
+    }
+}
+impl ConnectTransport for mio::net::TcpStream {
+    fn connect(con_id: &ConId, options: &SocketOptions) -> Result<Self, Error> {
+        match con_id.get_peer() {
+            Some(peer_addr) => options.connect_nonblocking(&peer_addr),
+            None => Err(Error::new(ErrorKind::InvalidInput, format!("{} has no peer address to connect to", con_id))),
+        }
+    }
+    #[inline(always)]
+    fn take_error(&self) -> Result<Option<Error>, Error> {
+        mio::net::TcpStream::take_error(self)
+    }
+    fn connected(&self, con_id: &ConId) -> Result<ConId, Error> {
+        let mut con_id = con_id.clone();
+        con_id.set_local(self.local_addr()?);
+        con_id.set_peer(self.peer_addr()?);
+        Ok(con_id)
+    }
+}
+
+#[cfg(target_family = "unix")]
+impl Transport for mio::net::UnixStream {
+    type Addr = mio::net::SocketAddr;
+    #[inline(always)]
+    fn shutdown(&self, how: Shutdown) -> Result<(), Error> {
+        mio::net::UnixStream::shutdown(self, how)
+    }
+    fn try_clone(&self) -> Result<Self, Error> {
+        use std::os::fd::{AsRawFd, BorrowedFd};
+        // SAFETY: the fd is owned by self and remains open for the duration of the borrow
+        let fd = unsafe { BorrowedFd::borrow_raw(self.as_raw_fd()) }.try_clone_to_owned()?;
+        Ok(mio::net::UnixStream::from_std(std::os::unix::net::UnixStream::from(fd)))
+    }
+    #[inline(always)]
+    fn local_addr(&self) -> Result<Self::Addr, Error> {
+        mio::net::UnixStream::local_addr(self)
+    }
+    #[inline(always)]
+    fn peer_addr(&self) -> Result<Self::Addr, Error> {
+        mio::net::UnixStream::peer_addr(self)
+    }
+}
+#[cfg(target_family = "unix")]
+impl AcceptTransport for mio::net::UnixStream {
+    type Listener = mio::net::UnixListener;
+    /// Unix domain socket peers are typically unnamed hence the acceptor's `con_id` is used as is
+    fn accept(listener: &Self::Listener, con_id: &ConId) -> Result<(Self, ConId), Error> {
+        let (stream, _) = listener.accept()?;
+        Ok((stream, con_id.clone()))
+    }
+}
+#[cfg(target_family = "unix")]
+impl ConnectTransport for mio::net::UnixStream {
+    /// Unix domain sockets do not support [SocketOptions], hence only [SocketOptions::default] is accepted
+    fn connect(con_id: &ConId, options: &SocketOptions) -> Result<Self, Error> {
+        if options != &SocketOptions::default() {
+            return Err(Error::new(ErrorKind::Unsupported, format!("{} does not support socket options: {}", con_id, options)));
+        }
+        match con_id.get_path() {
+            Some(path) => mio::net::UnixStream::connect(path),
+            None => Err(Error::new(ErrorKind::InvalidInput, format!("{} has no path to connect to", con_id))),
+        }
+    }
+    #[inline(always)]
+    fn take_error(&self) -> Result<Option<Error>, Error> {
+        mio::net::UnixStream::take_error(self)
+    }
+    /// Unix domain socket peers are identified by the path hence the initiator's `con_id` is used as is
+    fn connected(&self, con_id: &ConId) -> Result<ConId, Error> {
+        Ok(con_id.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use links_core::unittest::setup;
+
+    #[test]
+    fn test_tcp_try_clone_shares_connection() {
+        setup::log::configure();
+        let addr = setup::net::rand_avail_addr_port();
+        let listener = mio::net::TcpListener::bind(addr.parse().unwrap()).unwrap();
+        let clt = std::net::TcpStream::connect(addr).unwrap();
+        let (svc, con_id) = loop {
+            match <mio::net::TcpStream as AcceptTransport>::accept(&listener, &ConId::svc(Some("unittest"), addr, None).unwrap()) {
+                Ok(accepted) => break accepted,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => continue,
+                Err(e) => panic!("{}", e),
+            }
+        };
+        assert_eq!(con_id.get_peer(), Some(clt.local_addr().unwrap()));
+
+        let mut clone = Transport::try_clone(&svc).unwrap();
+        assert_eq!(Transport::peer_addr(&clone).unwrap(), Transport::peer_addr(&svc).unwrap());
+        clone.write_all(b"hello").unwrap();
+
+        let mut buf = [0_u8; 5];
+        let mut clt = clt;
+        clt.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"hello");
+
+        Transport::shutdown(&svc, Shutdown::Both).unwrap();
+        assert_eq!(clt.read(&mut buf).unwrap(), 0);
+    }
+}
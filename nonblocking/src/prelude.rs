@@ -1,20 +1,44 @@
 pub use crate::core::{
-    framer::{into_split_framer, FrameReader, FrameWriter},
-    messenger::{into_split_messenger, MessageRecver, MessageSender},
+    datagram::{DatagramRecver, DatagramSender},
+    framer::{into_split_framer, into_split_framer_transport, FrameReader, FrameWriter},
+    messenger::{into_split_messenger, into_split_messenger_transport, MessageRecver, MessageSender},
+    moldudp64::{
+        moldudp64_session, MoldUdp64CltMessenger, MoldUdp64Framer, MoldUdp64Packet, MoldUdp64Request, MoldUdp64Session, MoldUdp64SvcMessenger, MOLDUDP64_END_OF_SESSION, MOLDUDP64_HEADER_SIZE, MOLDUDP64_MAX_PACKET_SIZE, MOLDUDP64_SESSION_SIZE,
+    },
     protocol::{
-        persistance::{FileMessageLog, InMemoryMessageLog, ProtocolStorage},
+        persistance::{FileMessageLog, InMemoryMessageLog, ProtocolStorage, SequencedProtocolStorage},
+        sequenced::{SeqRecvStatus, SequencedMessenger, SequencedSession},
         state::{ProtocolConnectionState, ProtocolSessionState},
         Protocol, ProtocolCore,
     },
-    AcceptStatus, PollAble, PollAccept, PollEventStatus, PollRead, PoolAcceptStatus, PoolSvcAcceptorOfCltNonBlocking, ReSendNonBlocking, RecvNonBlocking, RecvStatus, SendNonBlocking, SendNonBlockingNonMut, SendStatus, SvcAcceptorOfCltNonBlocking,
+    socket::{SocketOptions, TcpKeepalive},
+    transport::{AcceptTransport, ConnectTransport, Transport},
+    AcceptStatus, PollAble, PollAccept, PollAccepted, PollConnect, PollEventStatus, PollRead, PollWrite, PoolAcceptStatus, PoolSvcAcceptorOfCltNonBlocking, ReSendNonBlocking, RecvNonBlocking, RecvStatus, SendBatchError, SendNonBlocking, SendNonBlockingNonMut, SendStatus,
+    SvcAcceptorOfCltNonBlocking,
 };
 
 pub use crate::connect::{
-    clt::{Clt, CltRecver, CltRecverRef, CltSender, CltSenderRef},
-    poll::{PollHandler, PollHandlerDynamic, PollHandlerStatic, SpawnedPollHandler, SpawnedPollHandlerDynamic, SpawnedPollHandlerStatic},
+    clt::{Clt, CltConnector, CltRecver, CltRecverRef, CltSender, CltSenderRef, PendingCltSenderRef},
+    moldudp64::{MoldUdp64Publisher, MoldUdp64Recver, MOLDUDP64_REQUEST_RETRY_AFTER},
+    poll::{
+        PlacementPolicy, PollHandler, PollHandlerDynamic, PollHandlerGroup, PollHandlerGroupDynamic, PollHandlerGroupStatic, PollHandlerOptions, PollHandlerShutdownHandle, PollHandlerShutdownHandleDynamic, PollHandlerStatic, PollHandlerWatchdog,
+        PollHandlerWatchdogCallback, ServiceableKind, ServiceableStats, SpawnedPollHandler, SpawnedPollHandlerDynamic, SpawnedPollHandlerStatic, DEFAULT_POLL_HANDLER_BUDGET,
+    },
     pool::{CltRecversPool, CltSendersPool, CltsPool, TransmittingSvcAcceptor, TransmittingSvcAcceptorRef},
+    reconnect::{ReconnectPolicy, ReconnectingClt},
+    runtime::Runtime,
     svc::{Svc, SvcAcceptor, SvcRecver, SvcRecverRef, SvcSender, SvcSenderRef, SVC_MAX_CONNECTIONS_2_POOL_SIZE_FACTOR},
-    DEFAULT_HBEAT_HANDLER, DEFAULT_POLL_HANDLER,
+    udp::{UdpRecver, UdpSender},
+    DEFAULT_HBEAT_HANDLER, DEFAULT_POLL_HANDLER, DEFAULT_RECONNECT_HANDLER, DEFAULT_WATCHDOG_HANDLER,
+};
+
+#[cfg(target_family = "unix")]
+pub use crate::core::messenger::into_split_messenger_uds;
+
+#[cfg(feature = "tls")]
+pub use crate::core::{
+    messenger::into_split_messenger_tls,
+    tls::{TlsSession, TLS_HANDSHAKE_TIMEOUT},
 };
 
 pub use links_core::prelude::*;
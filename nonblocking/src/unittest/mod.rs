@@ -14,9 +14,10 @@ pub mod setup {
         use log::info;
         use std::{
             io::{Error, ErrorKind},
+            num::NonZeroUsize,
             time::Duration,
         };
-        const HBEAT_INTERVAL: Duration = Duration::from_millis(1000);
+        pub const HBEAT_INTERVAL: Duration = Duration::from_millis(1000);
 
         #[derive(Debug, Clone, Default)]
         pub struct SvcTestProtocolManual;
@@ -112,6 +113,46 @@ pub mod setup {
             }
         }
 
+        /// Same as [SvcTestProtocolManual] but terminates connections which are silent for longer then `recv_timeout`
+        #[derive(Debug, Clone)]
+        pub struct SvcTestProtocolRecvTimeout {
+            recv_timeout: Duration,
+        }
+        impl SvcTestProtocolRecvTimeout {
+            pub fn new(recv_timeout: Duration) -> Self {
+                Self { recv_timeout }
+            }
+        }
+        impl Framer for SvcTestProtocolRecvTimeout {
+            fn get_frame_length(bytes: &bytes::BytesMut) -> Option<usize> {
+                SvcTestMessenger::get_frame_length(bytes)
+            }
+        }
+        impl Messenger for SvcTestProtocolRecvTimeout {
+            type RecvT = <SvcTestMessenger as Messenger>::RecvT;
+            type SendT = <SvcTestMessenger as Messenger>::SendT;
+            #[inline]
+            fn deserialize(frame: &[u8]) -> Result<Self::RecvT, std::io::Error> {
+                SvcTestMessenger::deserialize(frame)
+            }
+            #[inline]
+            fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), std::io::Error> {
+                SvcTestMessenger::serialize(msg)
+            }
+        }
+        impl ProtocolCore for SvcTestProtocolRecvTimeout {
+            fn is_connected(&self) -> bool {
+                true
+            }
+            fn conf_recv_timeout(&self) -> Option<Duration> {
+                Some(self.recv_timeout)
+            }
+            fn on_recv_timeout<I: ConnectionId>(&self, who: &I, elapsed: Duration) {
+                info!("on_recv_timeout: {}, elapsed: {:?}", who.con_id(), elapsed);
+            }
+        }
+        impl Protocol for SvcTestProtocolRecvTimeout {}
+
         #[derive(Debug, Clone, Default)]
         pub struct CltTestProtocolManual;
         impl Framer for CltTestProtocolManual {
@@ -138,6 +179,43 @@ pub mod setup {
         }
         impl Protocol for CltTestProtocolManual {}
 
+        /// Same as [CltTestProtocolManual] but enables the outbound queue with a given `high_water_mark`
+        #[derive(Debug, Clone)]
+        pub struct CltTestProtocolOutboundQueue {
+            high_water_mark: NonZeroUsize,
+        }
+        impl CltTestProtocolOutboundQueue {
+            pub fn new(high_water_mark: NonZeroUsize) -> Self {
+                Self { high_water_mark }
+            }
+        }
+        impl Framer for CltTestProtocolOutboundQueue {
+            fn get_frame_length(bytes: &bytes::BytesMut) -> Option<usize> {
+                CltTestMessenger::get_frame_length(bytes)
+            }
+        }
+        impl Messenger for CltTestProtocolOutboundQueue {
+            type RecvT = <CltTestMessenger as Messenger>::RecvT;
+            type SendT = <CltTestMessenger as Messenger>::SendT;
+            #[inline]
+            fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+                CltTestMessenger::deserialize(frame)
+            }
+            #[inline]
+            fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+                CltTestMessenger::serialize(msg)
+            }
+        }
+        impl ProtocolCore for CltTestProtocolOutboundQueue {
+            fn is_connected(&self) -> bool {
+                true
+            }
+            fn conf_outbound_queue_high_water_mark(&self) -> Option<NonZeroUsize> {
+                Some(self.high_water_mark)
+            }
+        }
+        impl Protocol for CltTestProtocolOutboundQueue {}
+
         /// Provides an [ProtocolCore::on_connect] implementation]
         #[derive(Debug, Clone, Default)]
         pub struct CltTestProtocolAuthAndHbeat;
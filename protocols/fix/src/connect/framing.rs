@@ -0,0 +1,158 @@
+use std::io::{Error, ErrorKind};
+
+use bytes::BytesMut;
+
+use crate::prelude::*;
+
+pub const SOH: u8 = 0x01;
+/// Length of the `10=xxx|` trailer
+pub const TRAILER_LEN: usize = 7;
+/// Longest `BeginString` value accepted, ex: `FIXT.1.1` or `FIX.4.4`
+const MAX_BEGIN_STRING_LEN: usize = 16;
+/// Longest `BodyLength` value accepted
+const MAX_BODY_LENGTH_DIGITS: usize = 7;
+
+/// Locates `8=FIX.x.y|9=len|...|10=xxx|` frame boundaries using the `BodyLength` field, where `|` is the `SOH` delimiter.
+///
+/// # Note
+/// [Framer] is not able to report errors, hence when the header is malformed the entire buffer is returned as a single frame
+/// which will then be rejected by [FixFramer::validate] during [FixMessenger::deserialize]
+#[derive(Debug)]
+pub struct FixFramer;
+impl FixFramer {
+    /// Parses `8=...|9=...|` header and returns the header length, including both delimiters, and the value of `BodyLength`,
+    /// [None] if more bytes are needed to complete the header or [Error] if the header is malformed
+    pub fn header(bytes: &[u8]) -> Result<Option<(usize, usize)>, Error> {
+        let (begin_string_end, _) = match Self::field(bytes, 0, b"8=", MAX_BEGIN_STRING_LEN)? {
+            Some(field) => field,
+            None => return Ok(None),
+        };
+        let (body_length_end, body_length) = match Self::field(bytes, begin_string_end + 1, b"9=", MAX_BODY_LENGTH_DIGITS)? {
+            Some(field) => field,
+            None => return Ok(None),
+        };
+        let body_length = std::str::from_utf8(body_length)
+            .ok()
+            .and_then(|len| len.parse::<usize>().ok())
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("Invalid BodyLength: {:?}", String::from_utf8_lossy(body_length))))?;
+        Ok(Some((body_length_end + 1, body_length)))
+    }
+    /// Locates `prefix` followed by a value of at most `max_len` bytes and a `SOH` starting at `start`, returns the index of the `SOH` and the value
+    fn field<'a>(bytes: &'a [u8], start: usize, prefix: &[u8], max_len: usize) -> Result<Option<(usize, &'a [u8])>, Error> {
+        let available = &bytes[start.min(bytes.len())..];
+        let cmp_len = available.len().min(prefix.len());
+        if available[..cmp_len] != prefix[..cmp_len] {
+            return Err(Error::new(ErrorKind::InvalidData, format!("Expected {:?} at idx: {}", String::from_utf8_lossy(prefix), start)));
+        }
+        let value_start = start + prefix.len();
+        let value_end = (value_start + max_len + 1).min(bytes.len());
+        if value_start > value_end {
+            return Ok(None);
+        }
+        match bytes[value_start..value_end].iter().position(|b| *b == SOH) {
+            Some(0) => Err(Error::new(ErrorKind::InvalidData, format!("Empty {:?} value", String::from_utf8_lossy(prefix)))),
+            Some(len) => Ok(Some((value_start + len, &bytes[value_start..value_start + len]))),
+            None if value_end - value_start > max_len => Err(Error::new(ErrorKind::InvalidData, format!("{:?} value exceeds {} bytes", String::from_utf8_lossy(prefix), max_len))),
+            None => Ok(None),
+        }
+    }
+    /// Sum of all bytes modulo 256
+    #[inline(always)]
+    pub fn checksum(bytes: &[u8]) -> u8 {
+        bytes.iter().fold(0_u8, |sum, b| sum.wrapping_add(*b))
+    }
+    /// Validates `BodyLength` & `CheckSum` of a complete frame and returns the `BeginString` value and the body, which includes the trailing `SOH` of its last field
+    pub fn validate(frame: &[u8]) -> Result<(&str, &[u8]), Error> {
+        let (header_len, body_length) = Self::header(frame)?.ok_or_else(|| Error::new(ErrorKind::InvalidData, "Incomplete header"))?;
+        let body_end = header_len + body_length;
+        if frame.len() != body_end + TRAILER_LEN || !frame[body_end..].starts_with(b"10=") || frame[frame.len() - 1] != SOH {
+            return Err(Error::new(ErrorKind::InvalidData, format!("BodyLength: {} does not match frame length: {}", body_length, frame.len())));
+        }
+        let expected = std::str::from_utf8(&frame[body_end + 3..frame.len() - 1])
+            .ok()
+            .and_then(|cks| cks.parse::<u8>().ok())
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("Invalid CheckSum: {:?}", String::from_utf8_lossy(&frame[body_end..]))))?;
+        let actual = Self::checksum(&frame[..body_end]);
+        if expected != actual {
+            return Err(Error::new(ErrorKind::InvalidData, format!("CheckSum mismatch expected: {:03}, actual: {:03}", expected, actual)));
+        }
+        let begin_string = std::str::from_utf8(&frame[2..frame[..header_len].iter().position(|b| *b == SOH).expect("header validated")]).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        Ok((begin_string, &frame[header_len..body_end]))
+    }
+}
+impl Framer for FixFramer {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        match Self::header(bytes) {
+            Ok(Some((header_len, body_length))) => {
+                let frame_length = header_len + body_length + TRAILER_LEN;
+                if bytes.len() < frame_length {
+                    None
+                } else {
+                    Some(frame_length)
+                }
+            }
+            Ok(None) => None,
+            Err(_) => Some(bytes.len()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bytes::BytesMut;
+    use links_core::unittest::setup;
+    use log::info;
+
+    fn soh(text: &str) -> Vec<u8> {
+        text.replace('|', "\x01").into_bytes()
+    }
+
+    #[test]
+    fn test_fix_framer() {
+        setup::log::configure();
+        let frame = soh("8=FIX.4.2|9=65|35=A|34=177|49=SERVER|52=20090107-18:15:16|56=CLIENT|98=0|108=30|10=062|");
+        let mut bytes = BytesMut::new();
+
+        // incomplete frame is not split regardless of where it is cut
+        for cut in 0..frame.len() {
+            bytes.clear();
+            bytes.extend_from_slice(&frame[..cut]);
+            assert_eq!(FixFramer::get_frame(&mut bytes), None, "cut: {}", cut);
+        }
+
+        // two frames back to back
+        bytes.clear();
+        bytes.extend_from_slice(&frame);
+        bytes.extend_from_slice(&frame);
+        for _ in 0..2 {
+            let out = FixFramer::get_frame(&mut bytes).unwrap();
+            info!("frame: {:?}", String::from_utf8_lossy(&out));
+            assert_eq!(out, frame);
+            let (begin_string, body) = FixFramer::validate(&out).unwrap();
+            assert_eq!(begin_string, "FIX.4.2");
+            assert_eq!(body.len(), 65);
+        }
+        assert!(bytes.is_empty());
+
+        // malformed header is returned as is to be rejected by validate
+        bytes.extend_from_slice(&soh("9=5|8=FIX.4.2|"));
+        let out = FixFramer::get_frame(&mut bytes).unwrap();
+        assert_eq!(FixFramer::validate(&out).unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_fix_framer_validate() {
+        setup::log::configure();
+        let checksum = soh("8=FIX.4.2|9=65|35=A|34=177|49=SERVER|52=20090107-18:15:16|56=CLIENT|98=0|108=30|10=063|");
+        let res = FixFramer::validate(&checksum);
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidData);
+
+        let body_length = soh("8=FIX.4.2|9=64|35=A|34=177|49=SERVER|52=20090107-18:15:16|56=CLIENT|98=0|108=30|10=062|");
+        let res = FixFramer::validate(&body_length);
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,111 @@
+use std::io::{Error, ErrorKind, Write};
+
+use bytes::BytesMut;
+
+use crate::{
+    connect::framing::SOH,
+    prelude::{tags::*, *},
+};
+
+/// [Messenger] for either side of a FIX session, sends and receives [FixMsg]
+///
+/// # Note
+/// Serialization computes [BODY_LENGTH] & [CHECK_SUM] while deserialization validates them using [FixFramer::validate]
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixMessenger;
+impl Framer for FixMessenger {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        FixFramer::get_frame_length(bytes)
+    }
+}
+impl Messenger for FixMessenger {
+    type SendT = FixMsg;
+    type RecvT = FixMsg;
+    fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+        fn digits(mut n: usize) -> usize {
+            let mut count = 1;
+            while n >= 10 {
+                n /= 10;
+                count += 1;
+            }
+            count
+        }
+        let body_length = msg.iter().map(|(tag, value)| digits(tag as usize) + value.len() + 2).sum::<usize>();
+
+        let mut buf = [0_u8; MAX_MSG_SIZE];
+        let mut cursor = &mut buf[..];
+        let res = (|| {
+            write!(cursor, "{}={}\x01{}={}\x01", BEGIN_STRING, msg.begin_string(), BODY_LENGTH, body_length)?;
+            for (tag, value) in msg.iter() {
+                write!(cursor, "{}={}\x01", tag, value)?;
+            }
+            Ok::<_, Error>(())
+        })();
+        if let Err(e) = res {
+            return Err(Error::new(ErrorKind::Other, format!("Failed to serialize msg: {}, buffer size: {}, error: {}", msg, MAX_MSG_SIZE, e)));
+        }
+        let len = MAX_MSG_SIZE - cursor.len();
+        let checksum = FixFramer::checksum(&buf[..len]);
+        let mut cursor = &mut buf[len..];
+        if let Err(e) = write!(cursor, "{}={:03}\x01", CHECK_SUM, checksum) {
+            return Err(Error::new(ErrorKind::Other, format!("Failed to serialize msg: {}, buffer size: {}, error: {}", msg, MAX_MSG_SIZE, e)));
+        }
+        let len = MAX_MSG_SIZE - cursor.len();
+        Ok((buf, len))
+    }
+    fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+        let (begin_string, body) = FixFramer::validate(frame)?;
+        let mut fields = Vec::with_capacity(16);
+        for field in body.split(|b| *b == SOH).take_while(|field| !field.is_empty()) {
+            let field = std::str::from_utf8(field).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            let (tag, value) = field
+                .split_once('=')
+                .and_then(|(tag, value)| Some((tag.parse::<u32>().ok()?, value)))
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, format!("Invalid field: {:?}", field)))?;
+            fields.push((tag, value.to_owned()));
+        }
+        match fields.first() {
+            Some((MSG_TYPE, _)) => Ok(FixMsg::from_fields(begin_string.to_owned(), fields)),
+            _ => Err(Error::new(ErrorKind::InvalidData, format!("Expected MsgType as the first field of the body, frame: {:?}", String::from_utf8_lossy(frame)))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use links_core::unittest::setup;
+    use log::info;
+
+    #[test]
+    fn test_fix_messenger() {
+        setup::log::configure();
+        let mut msg_inp = FixMsg::with_begin_string("FIX.4.2", msg_type::LOGON);
+        msg_inp.push(MSG_SEQ_NUM, 177);
+        msg_inp.push(SENDER_COMP_ID, "SERVER");
+        msg_inp.push(SENDING_TIME, "20090107-18:15:16");
+        msg_inp.push(TARGET_COMP_ID, "CLIENT");
+        msg_inp.push(ENCRYPT_METHOD, 0);
+        msg_inp.push(HEART_BT_INT, 30);
+
+        let (buf, len) = FixMessenger::serialize::<128>(&msg_inp).unwrap();
+        let frame = &buf[..len];
+        info!("frame: {:?}", String::from_utf8_lossy(frame));
+        assert_eq!(frame, b"8=FIX.4.2\x019=65\x0135=A\x0134=177\x0149=SERVER\x0152=20090107-18:15:16\x0156=CLIENT\x0198=0\x01108=30\x0110=062\x01");
+
+        let msg_out = FixMessenger::deserialize(frame).unwrap();
+        info!("msg_out: {}", msg_out);
+        assert_eq!(msg_inp, msg_out);
+
+        // buffer too small to fit the message
+        let res = FixMessenger::serialize::<16>(&msg_inp);
+        info!("res: {:?}", res);
+        assert!(res.is_err());
+
+        // body must start with MsgType
+        let res = FixMessenger::deserialize(b"8=FIX.4.2\x019=7\x0134=177\x0110=017\x01");
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod framing;
+pub mod messenger;
+pub mod protocol;
+
+use crate::prelude::{Clt, FixSessionProtocol, Svc};
+
+pub type FixClt<C, const MAX_MSG_SIZE: usize> = Clt<FixSessionProtocol, C, MAX_MSG_SIZE>;
+pub type FixSvc<C, const MAX_MSG_SIZE: usize> = Svc<FixSessionProtocol, C, MAX_MSG_SIZE>;
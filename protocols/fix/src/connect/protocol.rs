@@ -0,0 +1,556 @@
+use std::{
+    collections::BTreeMap,
+    io::{Error, ErrorKind},
+    ops::Range,
+    time::Duration,
+};
+
+use bytes::BytesMut;
+use chrono::Utc;
+use log::{debug, warn};
+
+use crate::prelude::{tags::*, *};
+
+/// FIX session protocol which will
+/// * exchange [msg_type::LOGON] during [ProtocolCore::on_connect], the initiator sends it first while the acceptor validates
+///   `SenderCompID`/`TargetCompID` and answers with its own or rejects with [msg_type::LOGOUT]
+/// * stamp `BeginString`, `SenderCompID`, `TargetCompID`, `MsgSeqNum` & `SendingTime` on every message sent
+/// * track inbound `MsgSeqNum` using [SequencedSession], honor [msg_type::SEQUENCE_RESET] and request a resend using [msg_type::RESEND_REQUEST] when a gap is detected
+/// * answer [msg_type::RESEND_REQUEST] by resending application messages with `PossDupFlag=Y` and replacing session messages with a gap fill
+/// * answer [msg_type::TEST_REQUEST] with [msg_type::HEARTBEAT] and [msg_type::LOGOUT] with [msg_type::LOGOUT]
+/// * send [msg_type::HEARTBEAT] at the `HeartBtInt` interval, configured by the initiator and requested by the initiator's logon on the acceptor
+///
+/// # Note
+/// Inbound sequence number and the log of messages sent, which determines the outbound sequence number, are kept in [ProtocolSessionState]
+/// hence a reconnect using the same instance resumes the session, while all connections accepted by the same [crate::prelude::Svc] belong to the same session.
+/// Messages ahead of a gap are withheld until it is filled while duplicates are dropped, with the exception of [msg_type::LOGON] which completes the handshake.
+#[derive(Debug, Clone)]
+pub struct FixSessionProtocol {
+    begin_string: String,
+    sender_comp_id: String,
+    target_comp_id: String,
+    is_initiator: bool,
+    io_timeout: Duration,
+    hbeat_interval: ProtocolConnectionState<Option<Duration>>,
+    session: ProtocolSessionState<SequencedSession<Self, InMemoryMessageLog<FixMsg>>>,
+    unsent: ProtocolSessionState<BTreeMap<u64, bool>>, // `MsgSeqNum` reserved by [ProtocolCore::on_send] but not yet sent, `true` while the send attempt is in progress
+    resend_pending: ProtocolConnectionState<Vec<FixMsg>>, // peer resend requests answered by [Protocol::re_send_reply]
+    logout_sent: ProtocolConnectionState<bool>,
+    is_connected: ProtocolConnectionState<bool>,
+}
+impl FixSessionProtocol {
+    /// Side of the session which connects and sends [msg_type::LOGON] first
+    ///
+    /// # Arguments
+    /// * `io_timeout` - how long to wait for the logon to complete
+    /// * `hbeat_interval` - requested as `HeartBtInt` rounded to seconds
+    pub fn initiator(begin_string: &str, sender_comp_id: &str, target_comp_id: &str, io_timeout: Duration, hbeat_interval: Duration) -> Self {
+        Self::new(begin_string, sender_comp_id, target_comp_id, true, io_timeout, Some(hbeat_interval))
+    }
+    /// Side of the session which accepts connections and waits for [msg_type::LOGON]
+    ///
+    /// # Arguments
+    /// * `io_timeout` - how long to wait for the logon once the connection is accepted
+    pub fn acceptor(begin_string: &str, sender_comp_id: &str, target_comp_id: &str, io_timeout: Duration) -> Self {
+        Self::new(begin_string, sender_comp_id, target_comp_id, false, io_timeout, None)
+    }
+    fn new(begin_string: &str, sender_comp_id: &str, target_comp_id: &str, is_initiator: bool, io_timeout: Duration, hbeat_interval: Option<Duration>) -> Self {
+        Self {
+            begin_string: begin_string.to_owned(),
+            sender_comp_id: sender_comp_id.to_owned(),
+            target_comp_id: target_comp_id.to_owned(),
+            is_initiator,
+            io_timeout,
+            hbeat_interval: ProtocolConnectionState::new(hbeat_interval),
+            session: SequencedSession::new(InMemoryMessageLog::new()).into(),
+            unsent: ProtocolSessionState::new(BTreeMap::new()),
+            resend_pending: ProtocolConnectionState::new(vec![]),
+            logout_sent: ProtocolConnectionState::new(false),
+            is_connected: ProtocolConnectionState::new(false),
+        }
+    }
+    /// `MsgSeqNum` expected on the next inbound message
+    pub fn next_recv_seq(&self) -> u64 {
+        self.session.lock().next_recv_seq()
+    }
+    /// `MsgSeqNum` which will be assigned to the next outbound message
+    pub fn next_send_seq(&self) -> u64 {
+        self.session.lock().next_send_seq()
+    }
+    /// Session shared by all connections, its [SequencedSession::sent] log is indexed by `MsgSeqNum` and a message is stored as soon as its `MsgSeqNum` is reserved by [ProtocolCore::on_send]
+    pub fn session(&self) -> &ProtocolSessionState<SequencedSession<Self, InMemoryMessageLog<FixMsg>>> {
+        &self.session
+    }
+    fn stamp(&self, msg: &mut FixMsg, seq: u64) {
+        msg.set_begin_string(&self.begin_string);
+        msg.set_header(SENDING_TIME, Utc::now().format("%Y%m%d-%H:%M:%S%.3f"));
+        msg.set_header(MSG_SEQ_NUM, seq);
+        msg.set_header(TARGET_COMP_ID, &self.target_comp_id);
+        msg.set_header(SENDER_COMP_ID, &self.sender_comp_id);
+    }
+    fn send<C: SendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, con: &mut C, msg: &mut FixMsg) -> Result<(), Error> {
+        match con.send_busywait_timeout(msg, self.io_timeout)? {
+            SendStatus::Completed => Ok(()),
+            SendStatus::WouldBlock => Err(Error::new(ErrorKind::TimedOut, format!("{} timeout: {:?} sending msg: {}", con.con_id(), self.io_timeout, msg))),
+        }
+    }
+    fn reject<C: SendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, con: &mut C, kind: ErrorKind, reason: &str) -> Result<(), Error> {
+        self.send(con, &mut FixMsg::logout(Some(reason)))?;
+        Err(Error::new(kind, format!("{} {}", con.con_id(), reason)))
+    }
+    /// Resends application messages in the requested range with `PossDupFlag=Y` and replaces session messages with a gap fill
+    fn resend<C: ReSendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, con: &mut C, msg: &FixMsg) -> Result<(), Error> {
+        let begin = msg.get_u64(BEGIN_SEQ_NO).unwrap_or(1).max(1);
+        let end = match msg.get_u64(END_SEQ_NO) {
+            Some(0) | None => u64::MAX,
+            Some(end) => end,
+        };
+        // release the lock before sending, see [ProtocolSessionState::re_send_reply]
+        let (msgs, next_send_seq) = {
+            let session = self.session.lock();
+            (session.sent().range(begin..end.saturating_add(1)).cloned().collect::<Vec<_>>(), session.next_send_seq())
+        };
+        debug!("{} resending range: {}..={}, available: {}", con.con_id(), begin, end, msgs.len());
+
+        let mut gap_fill_from = None;
+        for orig in msgs.iter() {
+            let seq = orig.msg_seq_num().unwrap_or_default();
+            if orig.is_admin() {
+                gap_fill_from.get_or_insert(seq);
+                continue;
+            }
+            if let Some(from) = gap_fill_from.take() {
+                self.resend_gap_fill(con, from, seq)?;
+            }
+            let mut msg = orig.clone();
+            msg.set_header(POSS_DUP_FLAG, "Y");
+            msg.set_header(ORIG_SENDING_TIME, orig.get(SENDING_TIME).unwrap_or_default());
+            msg.set_header(SENDING_TIME, Utc::now().format("%Y%m%d-%H:%M:%S%.3f"));
+            con.re_send_busywait(&msg)?;
+        }
+        if let Some(from) = gap_fill_from {
+            self.resend_gap_fill(con, from, next_send_seq.min(end.saturating_add(1)))?;
+        }
+        Ok(())
+    }
+    /// Releases `MsgSeqNum` reserved by a send attempt which did not go through, reservations at the end of the log which are not being sent are rolled back
+    /// so that the peer does not observe a gap, while the rest are kept until retried or replayed by a resend
+    fn release<I: ConnectionId>(&self, who: &I, msg: &FixMsg) {
+        let seq = match msg.msg_seq_num() {
+            Some(seq) => seq,
+            None => return,
+        };
+        let mut session = self.session.lock();
+        let mut unsent = self.unsent.lock();
+        if let Some(is_in_flight) = unsent.get_mut(&seq) {
+            *is_in_flight = false;
+        }
+        while let Some((&last, &false)) = unsent.last_key_value() {
+            if last != session.sent().last_seq() {
+                break;
+            }
+            if let Err(e) = session.sent_mut().truncate(last - 1) {
+                warn!("{} failed to roll back MsgSeqNum: {}, error: {}", who.con_id(), last, e);
+                break;
+            }
+            unsent.remove(&last);
+        }
+    }
+    fn resend_gap_fill<C: ReSendNonBlocking<<Self as Messenger>::SendT>>(&self, con: &mut C, from: u64, new_seq_no: u64) -> Result<(), Error> {
+        let mut msg = FixMsg::sequence_reset(new_seq_no, true);
+        self.stamp(&mut msg, from);
+        msg.set_header(POSS_DUP_FLAG, "Y");
+        con.re_send_busywait(&msg)
+    }
+}
+impl Framer for FixSessionProtocol {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        FixFramer::get_frame_length(bytes)
+    }
+}
+impl Messenger for FixSessionProtocol {
+    type SendT = <FixMessenger as Messenger>::SendT;
+    type RecvT = <FixMessenger as Messenger>::RecvT;
+    #[inline(always)]
+    fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+        FixMessenger::serialize(msg)
+    }
+    #[inline(always)]
+    fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+        FixMessenger::deserialize(frame)
+    }
+}
+impl SequencedMessenger for FixSessionProtocol {
+    /// [None] for [msg_type::SEQUENCE_RESET] in reset mode, which applies regardless of its `MsgSeqNum`, see [ProtocolCore::on_recv]
+    #[inline(always)]
+    fn recv_seq(msg: &Self::RecvT) -> Option<u64> {
+        match msg.msg_type() {
+            msg_type::SEQUENCE_RESET if msg.get(GAP_FILL_FLAG) != Some("Y") => None,
+            _ => msg.msg_seq_num(),
+        }
+    }
+    /// Always [None] because peer [msg_type::RESEND_REQUEST] is answered by [Protocol::re_send_reply] with gap fills in place of session messages
+    #[inline(always)]
+    fn recv_resend_request(_msg: &Self::RecvT) -> Option<Range<u64>> {
+        None
+    }
+    /// Every message is stored by [ProtocolCore::on_send] as soon as its `MsgSeqNum` is reserved
+    #[inline(always)]
+    fn is_send_sequenced(_msg: &Self::SendT) -> bool {
+        true
+    }
+    #[inline(always)]
+    fn new_resend_request(range: Range<u64>) -> Self::SendT {
+        FixMsg::resend_request(range.start, range.end - 1)
+    }
+    /// `NewSeqNo` of a [msg_type::SEQUENCE_RESET] gap fill
+    #[inline(always)]
+    fn recv_next_seq(msg: &Self::RecvT, seq: u64) -> u64 {
+        match msg.msg_type() {
+            msg_type::SEQUENCE_RESET => msg.get_u64(NEW_SEQ_NO).unwrap_or_default(),
+            _ => seq + 1,
+        }
+    }
+    #[inline(always)]
+    fn is_recv_withheld_on_gap(msg: &Self::RecvT) -> bool {
+        msg.msg_type() != msg_type::LOGON
+    }
+}
+impl ProtocolCore for FixSessionProtocol {
+    fn on_connect<C: SendNonBlocking<<Self as Messenger>::SendT> + ReSendNonBlocking<<Self as Messenger>::SendT> + RecvNonBlocking<<Self as Messenger>::RecvT> + ConnectionId>(&self, con: &mut C) -> Result<(), Error> {
+        if self.is_initiator {
+            let heart_bt_int = self.hbeat_interval.lock().unwrap_or_default().as_secs();
+            self.send(con, &mut FixMsg::logon(heart_bt_int))?;
+        }
+        let logon = match con.recv_busywait_timeout(self.io_timeout)? {
+            RecvStatus::Completed(Some(msg)) if msg.msg_type() == msg_type::LOGON => msg,
+            RecvStatus::Completed(Some(msg)) if msg.msg_type() == msg_type::LOGOUT => return Err(Error::new(ErrorKind::PermissionDenied, format!("{} Logon rejected msg: {}", con.con_id(), msg))),
+            RecvStatus::Completed(msg) => return Err(Error::new(ErrorKind::InvalidData, format!("{} Expected Logon instead got msg: {:?}", con.con_id(), msg))),
+            RecvStatus::WouldBlock => return Err(Error::new(ErrorKind::TimedOut, format!("{} timeout: {:?} waiting for Logon", con.con_id(), self.io_timeout))),
+        };
+        debug!("{} logon: {}", con.con_id(), logon);
+
+        if !self.is_initiator {
+            if logon.begin_string() != self.begin_string {
+                return self.reject(con, ErrorKind::InvalidData, &format!("BeginString: {} not supported", logon.begin_string()));
+            }
+            if logon.get(SENDER_COMP_ID) != Some(&self.target_comp_id) || logon.get(TARGET_COMP_ID) != Some(&self.sender_comp_id) {
+                return self.reject(con, ErrorKind::PermissionDenied, "SenderCompID/TargetCompID mismatch");
+            }
+            let heart_bt_int = logon.get_u64(HEART_BT_INT).unwrap_or_default();
+            self.hbeat_interval.set(match heart_bt_int {
+                0 => None,
+                secs => Some(Duration::from_secs(secs)),
+            });
+            self.send(con, &mut FixMsg::logon(heart_bt_int))?;
+        }
+        self.is_connected.set(true);
+        // a gap detected by the logon is requested only once the logon exchange is complete
+        self.re_send_reply(con)
+    }
+    fn on_disconnect<C: SendNonBlocking<<Self as Messenger>::SendT> + ReSendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, con: &mut C) -> Result<(), Error> {
+        if self.is_connected() && !*self.logout_sent.lock() {
+            self.send(con, &mut FixMsg::logout(None))?;
+        }
+        self.is_connected.set(false);
+        Ok(())
+    }
+    #[inline(always)]
+    fn is_connected(&self) -> bool {
+        *self.is_connected.lock()
+    }
+    /// Reserves `MsgSeqNum` by stamping and storing the message under the same lock, so that messages which are stamped before any of them
+    /// is sent, ex: [SendNonBlocking::send_batch], or sent concurrently are never assigned the same `MsgSeqNum`.
+    /// A message which carries `MsgSeqNum` reserved by an attempt that resulted in [ProtocolCore::on_wouldblock] or [ProtocolCore::on_error] keeps it when retried
+    #[inline(always)]
+    fn on_send<I: ConnectionId>(&self, who: &I, msg: &mut <Self as Messenger>::SendT) {
+        let mut session = self.session.lock();
+        let mut unsent = self.unsent.lock();
+        if let Some(is_in_flight @ false) = msg.msg_seq_num().and_then(|seq| unsent.get_mut(&seq)) {
+            *is_in_flight = true;
+            return;
+        }
+        let seq = session.next_send_seq();
+        self.stamp(msg, seq);
+        match session.sent_mut().store(msg.clone()) {
+            Ok(()) => {
+                unsent.insert(seq, true);
+            }
+            Err(e) => warn!("{} failed to store msg: {}, error: {}", who.con_id(), msg, e),
+        }
+    }
+    /// Rolls back `MsgSeqNum` reserved by [ProtocolCore::on_send] unless a message with a higher one was reserved since
+    #[inline(always)]
+    fn on_wouldblock<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::SendT) {
+        self.release(who, msg);
+    }
+    /// Same as [ProtocolCore::on_wouldblock]
+    #[inline(always)]
+    fn on_error<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::SendT, _e: &Error) {
+        self.release(who, msg);
+    }
+    #[inline(always)]
+    fn on_sent<I: ConnectionId>(&self, _who: &I, msg: &<Self as Messenger>::SendT) {
+        if let Some(seq) = msg.msg_seq_num() {
+            self.unsent.lock().remove(&seq);
+        }
+        if msg.msg_type() == msg_type::LOGOUT {
+            self.logout_sent.set(true);
+        }
+    }
+    /// Handles messages in the order of arrival, while the gap fill is applied by [SequencedSession] once it is in sequence
+    fn on_recv<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::RecvT) {
+        if msg.msg_seq_num().is_none() {
+            return warn!("{} received msg without MsgSeqNum: {}", who.con_id(), msg);
+        }
+        match msg.msg_type() {
+            msg_type::SEQUENCE_RESET if msg.get(GAP_FILL_FLAG) != Some("Y") => {
+                let new_seq_no = msg.get_u64(NEW_SEQ_NO).unwrap_or_default();
+                let mut session = self.session.lock();
+                if !session.reset_next_recv_seq(new_seq_no) {
+                    warn!("{} ignoring Sequence Reset to lower MsgSeqNum: {}, expected: {}", who.con_id(), new_seq_no, session.next_recv_seq());
+                }
+            }
+            msg_type::LOGOUT => self.is_connected.set(false),
+            msg_type::RESEND_REQUEST => self.resend_pending.lock().push(msg.clone()),
+            _ => {}
+        }
+    }
+    /// Withholds messages ahead of a gap and duplicates, see [SequencedSession], while [msg_type::LOGON] is always delivered to complete the handshake
+    #[inline(always)]
+    fn is_recv_ready<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::RecvT) -> bool {
+        self.session.is_recv_ready(who, msg) || msg.msg_type() == msg_type::LOGON
+    }
+    #[inline(always)]
+    fn pop_recv_ready(&self) -> Option<<Self as Messenger>::RecvT> {
+        self.session.pop_recv_ready()
+    }
+}
+impl Protocol for FixSessionProtocol {
+    fn send_reply<S: SendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, msg: &<Self as Messenger>::RecvT, sender: &mut S) -> Result<(), Error> {
+        match msg.msg_type() {
+            msg_type::TEST_REQUEST => self.send(sender, &mut FixMsg::heartbeat(msg.get(TEST_REQ_ID)))?,
+            msg_type::LOGOUT if !*self.logout_sent.lock() => self.send(sender, &mut FixMsg::logout(None))?,
+            _ => {}
+        }
+        Ok(())
+    }
+    /// Requests the peer to resend gaps detected by [SequencedSession] and answers peer [msg_type::RESEND_REQUEST] messages using [ReSendNonBlocking],
+    /// both are deferred until the logon exchange is complete
+    fn re_send_reply<S: SendNonBlocking<<Self as Messenger>::SendT> + ReSendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, sender: &mut S) -> Result<(), Error> {
+        if !self.is_connected() {
+            return Ok(());
+        }
+        self.session.re_send_reply(sender)?;
+        let pending = std::mem::take(&mut *self.resend_pending.lock());
+        for msg in pending.iter() {
+            self.resend(sender, msg)?;
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn conf_heart_beat_interval(&self) -> Option<Duration> {
+        *self.hbeat_interval.lock()
+    }
+    #[inline(always)]
+    fn send_heart_beat<S: SendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, sender: &mut S) -> Result<SendStatus, Error> {
+        sender.send(&mut FixMsg::heartbeat(None))
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::{
+        prelude::*,
+        unittest::setup::{
+            connection::{FixCltTest, FixSvcTest},
+            protocol::{clt_protocol, svc_protocol, CLT_COMP_ID},
+        },
+    };
+    use links_core::unittest::setup;
+    use log::{info, LevelFilter};
+    use std::{io::ErrorKind, num::NonZeroUsize, time::Duration};
+
+    fn recv<R: RecvNonBlocking<FixMsg>>(clt: &mut R) -> FixMsg {
+        match clt.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap() {
+            RecvStatus::Completed(Some(msg)) => msg,
+            status => panic!("Expected FixMsg instead got: {:?}", status),
+        }
+    }
+
+    #[test]
+    fn test_fix_clt_svc_logon_accepted() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let mut svc = FixSvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), svc_protocol(), Some("fix/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let protocol = clt_protocol(CLT_COMP_ID);
+        let mut clt = FixCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), protocol.clone(), Some("fix/clt")).unwrap();
+        info!("clt: {}", clt);
+        assert!(clt.is_connected());
+        assert!(svc.is_next_connected_busywait_timeout(setup::net::default_find_timeout()));
+        assert_eq!(protocol.next_send_seq(), 2);
+        assert_eq!(protocol.next_recv_seq(), 2);
+
+        // test request is answered with a heart beat carrying the same TestReqID
+        // while skipping regular heart beats scheduled by svc
+        clt.send_busywait(&mut FixMsg::test_request("ping")).unwrap();
+        let msg = loop {
+            let msg = recv(&mut clt);
+            info!("msg: {}", msg);
+            assert_eq!(msg.msg_type(), msg_type::HEARTBEAT);
+            assert_eq!(msg.get(tags::SENDER_COMP_ID), Some("VENUE"));
+            if msg.get(tags::TEST_REQ_ID).is_some() {
+                break msg;
+            }
+        };
+        assert_eq!(msg.get(tags::TEST_REQ_ID), Some("ping"));
+        assert_eq!(protocol.next_recv_seq(), msg.msg_seq_num().unwrap() + 1);
+    }
+
+    #[test]
+    fn test_fix_clt_svc_logon_rejected() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let _svc = FixSvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), svc_protocol(), Some("fix/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let res = FixCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), clt_protocol("UNKNOWN"), Some("fix/clt"));
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::PermissionDenied);
+    }
+
+    #[test]
+    fn test_fix_clt_send_batch_reserves_msg_seq_num() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let _svc = FixSvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), svc_protocol(), Some("fix/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let protocol = clt_protocol(CLT_COMP_ID);
+        let mut clt = FixCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), protocol.clone(), Some("fix/clt")).unwrap();
+        assert_eq!(protocol.next_send_seq(), 2);
+
+        // every message of the batch is stamped before any of them is sent
+        let mut msgs = [FixMsg::heartbeat(None), FixMsg::heartbeat(None), FixMsg::heartbeat(None)];
+        assert_eq!(clt.send_batch(&mut msgs).unwrap(), msgs.len());
+        for (seq, msg) in (2..).zip(msgs.iter()) {
+            info!("msg: {}", msg);
+            assert_eq!(msg.msg_seq_num(), Some(seq));
+            assert_eq!(protocol.session().lock().sent().get(seq).and_then(|msg| msg.msg_seq_num()), Some(seq));
+        }
+        assert_eq!(protocol.next_send_seq(), 5);
+    }
+
+    #[test]
+    fn test_fix_clt_msg_seq_num_released_on_wouldblock() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let _svc = FixSvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), svc_protocol(), Some("fix/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let protocol = clt_protocol(CLT_COMP_ID);
+        let mut clt = FixCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), protocol.clone(), Some("fix/clt")).unwrap();
+        assert_eq!(protocol.next_send_seq(), 2);
+
+        // attempts which would block are rolled back in reverse order of reservation, ex: the tail of a batch
+        let (mut msg1, mut msg2) = (FixMsg::heartbeat(None), FixMsg::heartbeat(None));
+        protocol.on_send(&clt, &mut msg1);
+        protocol.on_send(&clt, &mut msg2);
+        assert_eq!((msg1.msg_seq_num(), msg2.msg_seq_num()), (Some(2), Some(3)));
+        protocol.on_wouldblock(&clt, &msg1);
+        assert_eq!(protocol.next_send_seq(), 4);
+        protocol.on_wouldblock(&clt, &msg2);
+        assert_eq!(protocol.next_send_seq(), 2);
+
+        // attempt which would block while a higher MsgSeqNum is being sent keeps its MsgSeqNum when retried
+        let (mut retried, mut other) = (FixMsg::heartbeat(None), FixMsg::heartbeat(None));
+        protocol.on_send(&clt, &mut retried);
+        protocol.on_send(&clt, &mut other);
+        protocol.on_wouldblock(&clt, &retried);
+        protocol.on_sent(&clt, &other);
+        assert_eq!(protocol.next_send_seq(), 4);
+        clt.send_busywait(&mut retried).unwrap();
+        info!("retried: {}", retried);
+        assert_eq!(retried.msg_seq_num(), Some(2));
+        assert_eq!(protocol.next_send_seq(), 4);
+    }
+
+    #[test]
+    fn test_fix_clt_withholds_msgs_ahead_of_gap() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let svc_protocol = svc_protocol();
+        let mut svc = FixSvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), svc_protocol.clone(), Some("fix/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let protocol = clt_protocol(CLT_COMP_ID);
+        let mut clt = FixCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), protocol.clone(), Some("fix/clt")).unwrap();
+        assert!(svc.is_next_connected_busywait_timeout(setup::net::default_find_timeout()));
+
+        // svc stores a message which never reaches clt, hence the one sent next is ahead of a gap
+        let mut lost = FixMsg::new("8").with(tags::TEXT, "1");
+        svc_protocol.on_send(&clt, &mut lost);
+        svc_protocol.on_sent(&clt, &lost);
+        svc.send_busywait(&mut FixMsg::new("8").with(tags::TEXT, "2")).unwrap();
+
+        // message ahead of the gap is withheld until the resent one fills it and is delivered exactly once
+        let mut received = vec![];
+        while let RecvStatus::Completed(Some(msg)) = clt.recv_busywait_timeout(Duration::from_millis(100)).unwrap() {
+            info!("msg: {}", msg);
+            if msg.msg_type() == "8" {
+                received.push((msg.get(tags::TEXT).unwrap().to_owned(), msg.is_poss_dup()));
+            }
+        }
+        assert_eq!(received, [("1".to_owned(), true), ("2".to_owned(), false)]);
+        assert_eq!(protocol.next_recv_seq(), svc_protocol.next_send_seq());
+    }
+
+    #[test]
+    fn test_fix_clt_svc_resend_on_gap() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let svc_protocol = svc_protocol();
+        let mut svc = FixSvcTest::bind(addr, NonZeroUsize::new(2).unwrap(), LoggerCallback::new_ref(), svc_protocol.clone(), Some("fix/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let protocol = clt_protocol(CLT_COMP_ID);
+        let clt = FixCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), protocol.clone(), Some("fix/clt")).unwrap();
+        assert!(svc.is_next_connected_busywait_timeout(setup::net::default_find_timeout()));
+
+        // messages sent by svc are never received by clt
+        for id in ["1", "2"] {
+            svc.send_busywait(&mut FixMsg::new("8").with(tags::TEXT, id)).unwrap();
+        }
+        drop(clt);
+        assert_eq!(protocol.next_recv_seq(), 2);
+
+        // reconnect detects the gap on logon, requests resend and receives application messages as PossDup while session messages are gap filled
+        let mut clt = FixCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), protocol.clone(), Some("fix/clt")).unwrap();
+        let mut resent = vec![];
+        while protocol.next_recv_seq() < svc_protocol.next_send_seq() {
+            let msg = recv(&mut clt);
+            info!("msg: {}", msg);
+            match msg.msg_type() {
+                "8" => {
+                    assert!(msg.is_poss_dup());
+                    assert!(msg.get(tags::ORIG_SENDING_TIME).is_some());
+                    resent.push(msg.get(tags::TEXT).unwrap().to_owned());
+                }
+                msg_type::SEQUENCE_RESET => assert_eq!(msg.get(tags::GAP_FILL_FLAG), Some("Y")),
+                _ => {}
+            }
+        }
+        assert_eq!(resent, ["1", "2"]);
+        assert_eq!(protocol.next_recv_seq(), svc_protocol.next_send_seq());
+    }
+}
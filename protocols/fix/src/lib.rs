@@ -0,0 +1,8 @@
+//! FIX 4.x tag=value session layer implemented on top of `links_nonblocking` [links_nonblocking::prelude::Protocol] traits
+
+pub mod connect;
+pub mod model;
+pub mod prelude;
+
+#[cfg(feature = "unittest")]
+pub mod unittest;
@@ -0,0 +1,3 @@
+pub mod msg;
+pub mod msg_type;
+pub mod tags;
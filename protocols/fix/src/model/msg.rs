@@ -0,0 +1,159 @@
+use std::fmt::{self, Display};
+
+use crate::prelude::{msg_type, tags::*};
+
+/// Default [BEGIN_STRING] used by [FixMsg::new], it is replaced by [crate::prelude::FixSessionProtocol] with the configured value when sent
+pub const DEFAULT_BEGIN_STRING: &str = "FIX.4.4";
+
+/// A FIX message represented as a list of `tag=value` fields in the order they appear on the wire.
+///
+/// # Note
+/// * [BEGIN_STRING] is kept separately while [BODY_LENGTH] & [CHECK_SUM] are computed by [crate::prelude::FixMessenger] during serialization
+/// * [MSG_TYPE] is always the first field
+/// * Repeating groups are supported by [FixMsg::push] since the same tag can appear more then once, [FixMsg::get] returns the first occurrence
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixMsg {
+    begin_string: String,
+    fields: Vec<(u32, String)>,
+}
+impl FixMsg {
+    pub fn new(msg_type: &str) -> Self {
+        Self::with_begin_string(DEFAULT_BEGIN_STRING, msg_type)
+    }
+    pub fn with_begin_string(begin_string: &str, msg_type: &str) -> Self {
+        Self {
+            begin_string: begin_string.to_owned(),
+            fields: vec![(MSG_TYPE, msg_type.to_owned())],
+        }
+    }
+    /// Used by [crate::prelude::FixMessenger::deserialize], `fields` must start with [MSG_TYPE]
+    pub(crate) fn from_fields(begin_string: String, fields: Vec<(u32, String)>) -> Self {
+        debug_assert!(matches!(fields.first(), Some((MSG_TYPE, _))));
+        Self { begin_string, fields }
+    }
+
+    pub fn begin_string(&self) -> &str {
+        &self.begin_string
+    }
+    pub fn set_begin_string(&mut self, begin_string: &str) {
+        if self.begin_string != begin_string {
+            self.begin_string = begin_string.to_owned();
+        }
+    }
+    pub fn msg_type(&self) -> &str {
+        &self.fields[0].1
+    }
+    /// Value of the first occurrence of the `tag`
+    pub fn get(&self, tag: u32) -> Option<&str> {
+        self.fields.iter().find(|(t, _)| *t == tag).map(|(_, v)| v.as_str())
+    }
+    pub fn get_u64(&self, tag: u32) -> Option<u64> {
+        self.get(tag)?.parse().ok()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> {
+        self.fields.iter().map(|(t, v)| (*t, v.as_str()))
+    }
+    /// Appends the field even if the `tag` is already present
+    pub fn push<V: ToString>(&mut self, tag: u32, value: V) {
+        self.fields.push((tag, value.to_string()));
+    }
+    /// Replaces the value of the first occurrence of the `tag` or appends the field if the `tag` is not present
+    pub fn set<V: ToString>(&mut self, tag: u32, value: V) {
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.fields.push((tag, value.to_string())),
+        }
+    }
+    /// Same as [Self::set] but a missing `tag` is inserted immediately after [MSG_TYPE] to keep it part of the standard header
+    pub fn set_header<V: ToString>(&mut self, tag: u32, value: V) {
+        match self.fields.iter_mut().find(|(t, _)| *t == tag) {
+            Some((_, v)) => *v = value.to_string(),
+            None => self.fields.insert(1, (tag, value.to_string())),
+        }
+    }
+    pub fn with<V: ToString>(mut self, tag: u32, value: V) -> Self {
+        self.push(tag, value);
+        self
+    }
+    /// Removes all occurrences of the `tag` and returns the value of the first one
+    pub fn remove(&mut self, tag: u32) -> Option<String> {
+        let idx = self.fields.iter().position(|(t, _)| *t == tag)?;
+        let (_, value) = self.fields.remove(idx);
+        self.fields.retain(|(t, _)| *t != tag);
+        Some(value)
+    }
+
+    pub fn msg_seq_num(&self) -> Option<u64> {
+        self.get_u64(MSG_SEQ_NUM)
+    }
+    pub fn is_poss_dup(&self) -> bool {
+        self.get(POSS_DUP_FLAG) == Some("Y")
+    }
+    /// `true` if the message is a session level message, see [msg_type::is_admin]
+    pub fn is_admin(&self) -> bool {
+        msg_type::is_admin(self.msg_type())
+    }
+}
+#[rustfmt::skip]
+impl FixMsg {
+    pub fn heartbeat(test_req_id: Option<&str>) -> Self {
+        let msg = Self::new(msg_type::HEARTBEAT);
+        match test_req_id { Some(id) => msg.with(TEST_REQ_ID, id), None => msg }
+    }
+    pub fn test_request(test_req_id: &str) -> Self { Self::new(msg_type::TEST_REQUEST).with(TEST_REQ_ID, test_req_id) }
+    /// `heart_bt_int` is in seconds, `0` means heart beats are not requested
+    pub fn logon(heart_bt_int: u64) -> Self { Self::new(msg_type::LOGON).with(ENCRYPT_METHOD, 0).with(HEART_BT_INT, heart_bt_int) }
+    pub fn logout(text: Option<&str>) -> Self {
+        let msg = Self::new(msg_type::LOGOUT);
+        match text { Some(text) => msg.with(TEXT, text), None => msg }
+    }
+    /// `end_seq_no` of `0` requests all messages following `begin_seq_no`
+    pub fn resend_request(begin_seq_no: u64, end_seq_no: u64) -> Self { Self::new(msg_type::RESEND_REQUEST).with(BEGIN_SEQ_NO, begin_seq_no).with(END_SEQ_NO, end_seq_no) }
+    pub fn sequence_reset(new_seq_no: u64, gap_fill: bool) -> Self {
+        let msg = Self::new(msg_type::SEQUENCE_RESET);
+        let msg = if gap_fill { msg.with(GAP_FILL_FLAG, "Y") } else { msg };
+        msg.with(NEW_SEQ_NO, new_seq_no)
+    }
+}
+impl Display for FixMsg {
+    /// Uses `|` in place of `SOH` delimiter and omits [BODY_LENGTH] & [CHECK_SUM]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}|", BEGIN_STRING, self.begin_string)?;
+        for (tag, value) in self.fields.iter() {
+            write!(f, "{}={}|", tag, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use links_core::unittest::setup;
+    use log::info;
+
+    #[test]
+    fn test_fix_msg_fields() {
+        setup::log::configure();
+        let mut msg = FixMsg::new("D").with(tags::TEXT, "first").with(tags::TEXT, "second");
+        msg.set_header(tags::MSG_SEQ_NUM, 5);
+        msg.set(tags::SENDER_COMP_ID, "CLT");
+        info!("msg: {}", msg);
+        assert_eq!(msg.to_string(), "8=FIX.4.4|35=D|34=5|58=first|58=second|49=CLT|");
+        assert_eq!(msg.msg_type(), "D");
+        assert_eq!(msg.msg_seq_num(), Some(5));
+        assert_eq!(msg.get(tags::TEXT), Some("first"));
+        assert!(!msg.is_admin());
+        assert!(!msg.is_poss_dup());
+
+        msg.set_header(tags::MSG_SEQ_NUM, 6);
+        assert_eq!(msg.msg_seq_num(), Some(6));
+        assert_eq!(msg.remove(tags::TEXT), Some("first".to_owned()));
+        assert_eq!(msg.get(tags::TEXT), None);
+
+        let msg = FixMsg::sequence_reset(10, true);
+        info!("msg: {}", msg);
+        assert!(msg.is_admin());
+        assert_eq!(msg.get_u64(tags::NEW_SEQ_NO), Some(10));
+    }
+}
@@ -0,0 +1,14 @@
+//! Values of [crate::prelude::tags::MSG_TYPE] for session level messages
+
+pub const HEARTBEAT: &str = "0";
+pub const TEST_REQUEST: &str = "1";
+pub const RESEND_REQUEST: &str = "2";
+pub const REJECT: &str = "3";
+pub const SEQUENCE_RESET: &str = "4";
+pub const LOGOUT: &str = "5";
+pub const LOGON: &str = "A";
+
+/// `true` for session level messages, which are replaced by a gap fill [SEQUENCE_RESET] when the peer requests them to be resent
+pub fn is_admin(msg_type: &str) -> bool {
+    matches!(msg_type, HEARTBEAT | TEST_REQUEST | RESEND_REQUEST | REJECT | SEQUENCE_RESET | LOGOUT | LOGON)
+}
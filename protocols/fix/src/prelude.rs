@@ -0,0 +1,5 @@
+pub use crate::model::{msg::FixMsg, msg_type, tags};
+
+pub use crate::connect::{framing::FixFramer, messenger::FixMessenger, protocol::FixSessionProtocol, FixClt, FixSvc};
+
+pub use links_nonblocking::prelude::*;
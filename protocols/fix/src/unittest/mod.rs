@@ -0,0 +1,26 @@
+pub mod setup {
+    pub mod protocol {
+        use crate::prelude::*;
+        use std::time::Duration;
+
+        pub const BEGIN_STRING: &str = "FIX.4.4";
+        pub const CLT_COMP_ID: &str = "BROKER";
+        pub const SVC_COMP_ID: &str = "VENUE";
+        pub const IO_TIMEOUT: Duration = Duration::from_millis(500);
+        pub const HBEAT_INTERVAL: Duration = Duration::from_secs(1);
+
+        pub fn clt_protocol(sender_comp_id: &str) -> FixSessionProtocol {
+            FixSessionProtocol::initiator(BEGIN_STRING, sender_comp_id, SVC_COMP_ID, IO_TIMEOUT, HBEAT_INTERVAL)
+        }
+        pub fn svc_protocol() -> FixSessionProtocol {
+            FixSessionProtocol::acceptor(BEGIN_STRING, SVC_COMP_ID, CLT_COMP_ID, IO_TIMEOUT)
+        }
+    }
+    pub mod connection {
+        use crate::prelude::*;
+
+        pub const FIX_TEST_MAX_MSG_SIZE: usize = 1024;
+        pub type FixCltTest<C> = FixClt<C, FIX_TEST_MAX_MSG_SIZE>;
+        pub type FixSvcTest<C> = FixSvc<C, FIX_TEST_MAX_MSG_SIZE>;
+    }
+}
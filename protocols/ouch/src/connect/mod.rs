@@ -0,0 +1,7 @@
+pub mod protocol;
+
+use crate::prelude::{Clt, OuchCltAdminProtocol, OuchSvcAdminProtocol, OuchSvcAutoProtocol, Svc};
+
+pub type OuchClt<C, const MAX_MSG_SIZE: usize> = Clt<OuchCltAdminProtocol, C, MAX_MSG_SIZE>;
+pub type OuchSvc<C, const MAX_MSG_SIZE: usize> = Svc<OuchSvcAdminProtocol, C, MAX_MSG_SIZE>;
+pub type OuchSvcAuto<C, const MAX_MSG_SIZE: usize> = Svc<OuchSvcAutoProtocol, C, MAX_MSG_SIZE>;
@@ -0,0 +1,4 @@
+use crate::prelude::*;
+
+/// Broker side OUCH protocol, SoupBinTCP session management is provided by [SBCltAdminProtocol]
+pub type OuchCltAdminProtocol = SBCltAdminProtocol<OuchCltPld, OuchSvcPld>;
@@ -0,0 +1,86 @@
+pub mod clt;
+pub mod svc;
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::{
+        prelude::*,
+        unittest::setup::{
+            connection::{OuchCltTest, OuchSvcAutoTest},
+            protocol::{clt_protocol, svc_protocol_auto},
+        },
+    };
+    use links_core::unittest::setup;
+    use log::{info, LevelFilter};
+    use std::num::NonZeroUsize;
+
+    fn recv_reply<R: RecvNonBlocking<OuchSvcMsg>>(clt: &mut R) -> OuchSvcPld {
+        loop {
+            match clt.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap() {
+                RecvStatus::Completed(Some(SBSvcMsg::S(SPayload { body, .. }))) => return body,
+                RecvStatus::Completed(Some(_)) => continue,
+                status => panic!("Expected SBSvcMsg::S instead got: {:?}", status),
+            }
+        }
+    }
+
+    #[test]
+    fn test_ouch_clt_svc_auto_reply() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let protocol = svc_protocol_auto();
+        let mut svc = OuchSvcAutoTest::bind(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), protocol.clone(), Some("ouch/venue"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let mut clt = OuchCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), clt_protocol(), Some("ouch/broker")).unwrap();
+        assert!(svc.is_next_connected_busywait_timeout(setup::net::default_find_timeout()));
+
+        // enter
+        let enter = EnterOrder::default();
+        clt.send_busywait(&mut enter.clone().into()).unwrap();
+        let reply = recv_reply(&mut clt);
+        info!("reply: {:?}", reply);
+        assert!(matches!(reply, OuchSvcPld::Accepted(OrderAccepted { user_ref_number, .. }) if user_ref_number == enter.user_ref_number));
+        assert!(protocol.orders().lock().get(&enter.user_ref_number).is_some());
+
+        // replace
+        let mut replace = ReplaceOrder::from(&enter);
+        replace.user_ref_number = UserRefNumber::new(enter.user_ref_number.value() + 1);
+        replace.quantity = Quantity::new(200);
+        clt.send_busywait(&mut replace.clone().into()).unwrap();
+        let reply = recv_reply(&mut clt);
+        info!("reply: {:?}", reply);
+        assert!(matches!(reply, OuchSvcPld::Replaced(_)));
+        assert!(protocol.orders().lock().get(&enter.user_ref_number).is_none());
+        assert_eq!(protocol.orders().lock().get(&replace.user_ref_number).unwrap().quantity, Quantity::new(200));
+
+        // modify
+        let modify = ModifyOrder::new(replace.user_ref_number, Side::sell(), Quantity::new(150));
+        clt.send_busywait(&mut modify.into()).unwrap();
+        let reply = recv_reply(&mut clt);
+        info!("reply: {:?}", reply);
+        assert!(matches!(reply, OuchSvcPld::Modified(OrderModified { side, quantity, .. }) if side == Side::sell() && quantity == Quantity::new(150)));
+
+        // account query
+        clt.send_busywait(&mut AccountQueryRequest::default().into()).unwrap();
+        let reply = recv_reply(&mut clt);
+        info!("reply: {:?}", reply);
+        assert!(matches!(reply, OuchSvcPld::AccQryRes(AccountQueryResponse { next_user_ref_number, .. }) if next_user_ref_number == UserRefNumber::new(replace.user_ref_number.value() + 1)));
+
+        // cancel
+        let cancel = CancelOrder::new(replace.user_ref_number, Quantity::new(0));
+        clt.send_busywait(&mut cancel.clone().into()).unwrap();
+        let reply = recv_reply(&mut clt);
+        info!("reply: {:?}", reply);
+        assert!(matches!(reply, OuchSvcPld::Canceled(_)));
+        assert!(protocol.orders().lock().is_empty());
+
+        // cancel of an order which is no longer live is rejected
+        clt.send_busywait(&mut cancel.into()).unwrap();
+        let reply = recv_reply(&mut clt);
+        info!("reply: {:?}", reply);
+        assert!(matches!(reply, OuchSvcPld::CanReject(CancelReject { user_ref_number, .. }) if user_ref_number == replace.user_ref_number));
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    collections::HashMap,
+    io::{Error, ErrorKind},
+    time::Duration,
+};
+
+use bytes::BytesMut;
+use log::warn;
+
+use crate::prelude::*;
+
+/// Venue side OUCH protocol, SoupBinTCP session management is provided by [SBSvcAdminProtocol]
+pub type OuchSvcAdminProtocol = SBSvcAdminProtocol<OuchCltPld, OuchSvcPld>;
+
+/// Orders acknowledged by [OuchSvcAutoProtocol] which have not yet been canceled or replaced
+#[derive(Debug, Default)]
+pub struct LiveOrders {
+    orders: HashMap<UserRefNumber, EnterOrder>,
+    last_user_ref_number: u32,
+}
+impl LiveOrders {
+    pub fn get(&self, user_ref_number: &UserRefNumber) -> Option<&EnterOrder> {
+        self.orders.get(user_ref_number)
+    }
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+    /// Next [UserRefNumber] the client is expected to use, reported in [AccountQueryResponse]
+    pub fn next_user_ref_number(&self) -> UserRefNumber {
+        UserRefNumber::new(self.last_user_ref_number + 1)
+    }
+    fn insert(&mut self, order: EnterOrder) {
+        self.last_user_ref_number = self.last_user_ref_number.max(order.user_ref_number.value());
+        self.orders.insert(order.user_ref_number, order);
+    }
+    /// Returns a reply to the client's message and updates the book accordingly, [None] if the message does not warrant a reply
+    fn reply(&mut self, msg: &OuchCltPld) -> Option<OuchSvcMsg> {
+        match msg {
+            OuchCltPld::Enter(ord) => {
+                self.insert(ord.clone());
+                Some(OrderAccepted::from(ord).into())
+            }
+            OuchCltPld::Replace(rep) => match self.orders.remove(&rep.orig_user_ref_number) {
+                Some(mut ord) => {
+                    let reply = OrderReplaced::from((&ord, rep)).into();
+                    ord.user_ref_number = rep.user_ref_number;
+                    ord.quantity = rep.quantity;
+                    ord.price = rep.price;
+                    ord.time_in_force = rep.time_in_force;
+                    ord.display = rep.display;
+                    ord.int_mkt_sweep_eligibility = rep.int_mkt_sweep_eligibility;
+                    ord.clt_order_id = rep.clt_order_id;
+                    ord.appendages = rep.appendages;
+                    self.insert(ord);
+                    Some(reply)
+                }
+                None => Some(OrderRejected::from((rep, RejectReason::replace_not_allowed())).into()),
+            },
+            OuchCltPld::Cancel(can) => match self.orders.get_mut(&can.user_ref_number) {
+                Some(ord) => {
+                    let reply = OrderCanceled::from((&*ord, can)).into();
+                    // quantity is the new intended order size, hence zero means cancel the entire order
+                    ord.quantity = can.quantity;
+                    if can.quantity.value() == 0 {
+                        self.orders.remove(&can.user_ref_number);
+                    }
+                    Some(reply)
+                }
+                None => Some(CancelReject::from(can).into()),
+            },
+            OuchCltPld::Modify(modify) => match self.orders.get_mut(&modify.user_ref_number) {
+                Some(ord) => {
+                    ord.side = modify.side;
+                    ord.quantity = modify.quantity;
+                    Some(OrderModified::from((&*ord, modify.side)).into())
+                }
+                None => {
+                    warn!("Ignoring Modify Order for unknown user_ref_number: {:?}", modify.user_ref_number);
+                    None
+                }
+            },
+            OuchCltPld::AccQry(_) => Some(
+                AccountQueryResponse {
+                    next_user_ref_number: self.next_user_ref_number(),
+                    ..Default::default()
+                }
+                .into(),
+            ),
+        }
+    }
+}
+
+/// Venue side OUCH protocol which delegates SoupBinTCP session management to [OuchSvcAdminProtocol] and uses
+/// [Protocol::send_reply] to automatically acknowledge every message received, which is sufficient to simulate a venue locally
+/// * [EnterOrder] - [OrderAccepted]
+/// * [ReplaceOrder] - [OrderReplaced] or [OrderRejected] when the original order is not live
+/// * [CancelOrder] - [OrderCanceled] or [CancelReject] when the order is not live
+/// * [ModifyOrder] - [OrderModified], ignored when the order is not live
+/// * [AccountQueryRequest] - [AccountQueryResponse]
+///
+/// # Note
+/// [LiveOrders] are shared by all connections accepted by the same [crate::prelude::Svc], just like the [SBSvcMsg::S] log used to replay the session
+#[derive(Debug, Clone)]
+pub struct OuchSvcAutoProtocol {
+    admin: OuchSvcAdminProtocol,
+    io_timeout: Duration,
+    orders: ProtocolSessionState<LiveOrders>,
+}
+impl OuchSvcAutoProtocol {
+    /// # Arguments
+    /// * `io_timeout` - how long to wait for the login request once the connection is accepted and for each automated reply to be sent
+    pub fn new(username: UserName, password: Password, session_id: SessionId, io_timeout: Duration) -> Self {
+        Self {
+            admin: OuchSvcAdminProtocol::new(username, password, session_id, io_timeout),
+            io_timeout,
+            orders: ProtocolSessionState::new(LiveOrders::default()),
+        }
+    }
+    pub fn admin(&self) -> &OuchSvcAdminProtocol {
+        &self.admin
+    }
+    pub fn orders(&self) -> &ProtocolSessionState<LiveOrders> {
+        &self.orders
+    }
+}
+impl Framer for OuchSvcAutoProtocol {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        OuchSvcAdminProtocol::get_frame_length(bytes)
+    }
+}
+impl Messenger for OuchSvcAutoProtocol {
+    type SendT = <OuchSvcAdminProtocol as Messenger>::SendT;
+    type RecvT = <OuchSvcAdminProtocol as Messenger>::RecvT;
+    #[inline(always)]
+    fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+        OuchSvcAdminProtocol::serialize(msg)
+    }
+    #[inline(always)]
+    fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+        OuchSvcAdminProtocol::deserialize(frame)
+    }
+}
+impl ProtocolCore for OuchSvcAutoProtocol {
+    #[inline(always)]
+    fn on_connect<C: SendNonBlocking<<Self as Messenger>::SendT> + ReSendNonBlocking<<Self as Messenger>::SendT> + RecvNonBlocking<<Self as Messenger>::RecvT> + ConnectionId>(&self, con: &mut C) -> Result<(), Error> {
+        self.admin.on_connect(con)
+    }
+    #[inline(always)]
+    fn on_disconnect<C: SendNonBlocking<<Self as Messenger>::SendT> + ReSendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, con: &mut C) -> Result<(), Error> {
+        self.admin.on_disconnect(con)
+    }
+    #[inline(always)]
+    fn is_connected(&self) -> bool {
+        self.admin.is_connected()
+    }
+    #[inline(always)]
+    fn on_send<I: ConnectionId>(&self, who: &I, msg: &mut <Self as Messenger>::SendT) {
+        self.admin.on_send(who, msg)
+    }
+    #[inline(always)]
+    fn on_wouldblock<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::SendT) {
+        self.admin.on_wouldblock(who, msg)
+    }
+    #[inline(always)]
+    fn on_error<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::SendT, e: &Error) {
+        self.admin.on_error(who, msg, e)
+    }
+    #[inline(always)]
+    fn on_sent<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::SendT) {
+        self.admin.on_sent(who, msg)
+    }
+    #[inline(always)]
+    fn on_recv<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::RecvT) {
+        self.admin.on_recv(who, msg)
+    }
+}
+impl Protocol for OuchSvcAutoProtocol {
+    fn send_reply<S: SendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, msg: &<Self as Messenger>::RecvT, sender: &mut S) -> Result<(), Error> {
+        let payload = match msg {
+            SBCltMsg::U(UPayload { body, .. }) => body,
+            SBCltMsg::S(SPayload { body, .. }) => body,
+            _ => return self.admin.send_reply(msg, sender),
+        };
+        let reply = self.orders.lock().reply(payload);
+        if let Some(mut reply) = reply {
+            if let SendStatus::WouldBlock = sender.send_busywait_timeout(&mut reply, self.io_timeout)? {
+                return Err(Error::new(ErrorKind::TimedOut, format!("{} timeout: {:?} sending reply: {:?}", sender.con_id(), self.io_timeout, reply)));
+            }
+        }
+        Ok(())
+    }
+    #[inline(always)]
+    fn conf_heart_beat_interval(&self) -> Option<Duration> {
+        self.admin.conf_heart_beat_interval()
+    }
+    #[inline(always)]
+    fn send_heart_beat<S: SendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, sender: &mut S) -> Result<SendStatus, Error> {
+        self.admin.send_heart_beat(sender)
+    }
+}
@@ -0,0 +1,8 @@
+//! OUCH 5.0 order entry protocol carried as the payload of `links_soupbintcp` session layer
+
+pub mod connect;
+pub mod model;
+pub mod prelude;
+
+#[cfg(feature = "unittest")]
+pub mod unittest;
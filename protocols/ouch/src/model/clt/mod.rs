@@ -0,0 +1,5 @@
+pub mod account_query_req;
+pub mod cancel_order;
+pub mod enter_order;
+pub mod modify_order;
+pub mod replace_order;
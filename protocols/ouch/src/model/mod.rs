@@ -0,0 +1,6 @@
+pub mod appendages;
+pub mod types;
+
+pub mod clt;
+pub mod ouch;
+pub mod svc;
@@ -0,0 +1,14 @@
+pub mod account_query_res;
+pub mod broken_trade;
+pub mod cancel_pending;
+pub mod cancel_reject;
+pub mod order_accepted;
+pub mod order_aiq_canceled;
+pub mod order_canceled;
+pub mod order_executed;
+pub mod order_modified;
+pub mod order_rejected;
+pub mod order_replaced;
+pub mod order_restated;
+pub mod priority_update;
+pub mod system_event;
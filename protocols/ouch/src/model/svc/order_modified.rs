@@ -0,0 +1,58 @@
+use crate::prelude::*;
+use byteserde_derive::{ByteDeserializeSlice, ByteSerializeStack, ByteSerializedLenOf};
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteDeserializeSlice, ByteSerializedLenOf, PartialEq, Clone, Debug)]
+#[byteserde(endian = "be")]
+pub struct OrderModified {
+    packet_type: PacketTypeOrderModified,
+
+    pub timestamp: Timestamp, // Venue assigned
+
+    pub user_ref_number: UserRefNumber,
+    pub side: Side,
+    pub quantity: Quantity,
+    
+}
+
+impl<T> From<(&T, Side)> for OrderModified
+where T: CancelableOrder
+{
+    fn from(value: (&T, Side)) -> Self {
+        let (ord, side) = value;
+        Self {
+            packet_type: PacketTypeOrderModified::default(),
+
+            timestamp: Timestamp::default(), // Venue assigned
+
+            user_ref_number: ord.user_ref_number(),
+            side,
+            quantity: ord.quantity(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use byteserde::prelude::*;
+    use links_core::unittest::setup;
+    use log::info;
+
+    #[test]
+    fn test_msg() {
+        setup::log::configure();
+
+        let enter_order = EnterOrder::default();
+        let msg_inp = OrderModified::from((&enter_order, Side::buy()));
+
+        let ser: ByteSerializerStack<128> = to_serializer_stack(&msg_inp).unwrap();
+        info!("ser: {:#x}", ser);
+
+        let msg_out: OrderModified = from_serializer_stack(&ser).unwrap();
+
+        info!("msg_inp: {:?}", msg_inp);
+        info!("msg_out: {:?}", msg_out);
+        assert_eq!(msg_out, msg_inp);
+    }
+}
@@ -0,0 +1,36 @@
+// field types
+pub use crate::model::types::*;
+
+// clt messages
+pub use crate::model::clt::{
+    account_query_req::AccountQueryRequest,
+    cancel_order::{CancelOrder, CancelableOrder},
+    enter_order::EnterOrder,
+    modify_order::ModifyOrder,
+    replace_order::ReplaceOrder,
+};
+
+// svc messages
+pub use crate::model::svc::{
+    account_query_res::AccountQueryResponse, broken_trade::BrokenTrade, cancel_pending::CancelPending, cancel_reject::CancelReject, order_accepted::OrderAccepted, order_aiq_canceled::OrderAiqCanceled, order_canceled::OrderCanceled,
+    order_executed::OrderExecuted, order_modified::OrderModified, order_rejected::OrderRejected, order_replaced::OrderReplaced, order_restated::OrderRestated, priority_update::PriorityUpdate, system_event::SystemEvent,
+};
+
+// payload, message enums & frame sizes
+pub use crate::model::ouch::{OuchCltMsg, OuchCltPld, OuchMsg, OuchSvcMsg, OuchSvcPld, MAX_FRAME_SIZE_OUCH_CLT_MSG, MAX_FRAME_SIZE_OUCH_CLT_PLD, MAX_FRAME_SIZE_OUCH_SVC_MSG, MAX_FRAME_SIZE_OUCH_SVC_PLD};
+
+// connect
+pub use crate::connect::{
+    protocol::{
+        clt::OuchCltAdminProtocol,
+        svc::{OuchSvcAdminProtocol, OuchSvcAutoProtocol},
+    },
+    OuchClt, OuchSvc, OuchSvcAuto,
+};
+
+// SoupBinTCP session layer, only select items are re-exported since some of its field types, ex: RejectReason, clash with OUCH
+pub use links_soupbintcp::prelude::{
+    Password, SBCltAdminProtocol, SBCltMsg, SBMsg, SBSvcAdminProtocol, SBSvcMsg, SPayload, SPayloadHeader, SequenceNumber, SessionId, SoupBinPayload, UPayload, UPayloadHeader, UserName, MAX_FRAME_SIZE_SOUPBIN_EXC_PAYLOAD_DEBUG,
+};
+
+pub use links_nonblocking::prelude::*;
@@ -0,0 +1,26 @@
+pub mod setup {
+    pub mod protocol {
+        use crate::prelude::*;
+        use std::time::Duration;
+
+        pub const USERNAME: &[u8] = b"abcdef";
+        pub const PASSWORD: &[u8] = b"1234567890";
+        pub const SESSION_ID: &[u8] = b"session #1";
+        pub const IO_TIMEOUT: Duration = Duration::from_millis(500);
+        pub const HBEAT_INTERVAL: Duration = Duration::from_millis(250);
+
+        pub fn clt_protocol() -> OuchCltAdminProtocol {
+            OuchCltAdminProtocol::new(USERNAME.into(), PASSWORD.into(), SESSION_ID.into(), 0, IO_TIMEOUT, HBEAT_INTERVAL)
+        }
+        pub fn svc_protocol_auto() -> OuchSvcAutoProtocol {
+            OuchSvcAutoProtocol::new(USERNAME.into(), PASSWORD.into(), SESSION_ID.into(), IO_TIMEOUT)
+        }
+    }
+    pub mod connection {
+        use crate::prelude::*;
+
+        pub const OUCH_TEST_MAX_MSG_SIZE: usize = MAX_FRAME_SIZE_OUCH_SVC_MSG;
+        pub type OuchCltTest<C> = OuchClt<C, OUCH_TEST_MAX_MSG_SIZE>;
+        pub type OuchSvcAutoTest<C> = OuchSvcAuto<C, OUCH_TEST_MAX_MSG_SIZE>;
+    }
+}
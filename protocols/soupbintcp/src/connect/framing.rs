@@ -0,0 +1,37 @@
+use crate::prelude::PacketLengthU16Framer;
+
+/// SoupBinTCP frames start with a big endian `u16` packet length which excludes the length field itself
+pub type SoupBinFramer = PacketLengthU16Framer<0, true, true>;
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::{prelude::*, unittest::setup::model::*};
+    use bytes::BytesMut;
+    use byteserde::prelude::*;
+    use links_core::unittest::setup;
+    use log::info;
+
+    #[test]
+    fn test_soup_bin_framer() {
+        setup::log::configure();
+        let msgs = clt_msgs_default::<SamplePayload>();
+        let mut ser = ByteSerializerStack::<1024>::default();
+        for msg in msgs.iter() {
+            ser.serialize(msg).unwrap();
+        }
+        let mut bytes = BytesMut::from(ser.as_slice());
+        // incomplete frame must not be returned
+        let mut partial = BytesMut::from(&ser.as_slice()[..1]);
+        assert_eq!(SoupBinFramer::get_frame(&mut partial), None);
+
+        for msg_inp in msgs.iter() {
+            let frame = SoupBinFramer::get_frame(&mut bytes).unwrap();
+            info!("frame: {:?}", frame);
+            assert_eq!(frame.len(), msg_inp.byte_len());
+            let msg_out: SBCltMsg<SamplePayload> = from_slice(&frame).unwrap();
+            assert_eq!(msg_inp, &msg_out);
+        }
+        assert!(bytes.is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    io::{Error, ErrorKind},
+    marker::PhantomData,
+};
+
+use bytes::BytesMut;
+use byteserde::prelude::{from_slice, to_bytes_stack};
+
+use crate::prelude::*;
+
+/// [Messenger] for the client side of a SoupBinTCP session, sends [SBCltMsg] and receives [SBSvcMsg]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SBCltMessenger<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> {
+    phantom: PhantomData<(CltPayload, SvcPayload)>,
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Framer for SBCltMessenger<CltPayload, SvcPayload> {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        SoupBinFramer::get_frame_length(bytes)
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Messenger for SBCltMessenger<CltPayload, SvcPayload> {
+    type SendT = SBCltMsg<CltPayload>;
+    type RecvT = SBSvcMsg<SvcPayload>;
+    #[inline(always)]
+    fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+        match to_bytes_stack::<MAX_MSG_SIZE, Self::SendT>(msg) {
+            Ok(res) => Ok(res),
+            Err(e) => Err(Error::new(ErrorKind::Other, e.message)),
+        }
+    }
+    #[inline(always)]
+    fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+        match from_slice::<Self::RecvT>(frame) {
+            Ok(res) => Ok(res),
+            Err(e) => Err(Error::new(ErrorKind::Other, e.message)),
+        }
+    }
+}
+
+/// [Messenger] for the server side of a SoupBinTCP session, sends [SBSvcMsg] and receives [SBCltMsg]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SBSvcMessenger<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> {
+    phantom: PhantomData<(CltPayload, SvcPayload)>,
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Framer for SBSvcMessenger<CltPayload, SvcPayload> {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        SoupBinFramer::get_frame_length(bytes)
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Messenger for SBSvcMessenger<CltPayload, SvcPayload> {
+    type SendT = SBSvcMsg<SvcPayload>;
+    type RecvT = SBCltMsg<CltPayload>;
+    #[inline(always)]
+    fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+        match to_bytes_stack::<MAX_MSG_SIZE, Self::SendT>(msg) {
+            Ok(res) => Ok(res),
+            Err(e) => Err(Error::new(ErrorKind::Other, e.message)),
+        }
+    }
+    #[inline(always)]
+    fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+        match from_slice::<Self::RecvT>(frame) {
+            Ok(res) => Ok(res),
+            Err(e) => Err(Error::new(ErrorKind::Other, e.message)),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::{prelude::*, unittest::setup::model::*};
+    use links_core::unittest::setup;
+    use log::info;
+
+    const MAX_MSG_SIZE: usize = 128;
+    type CltMessenger = SBCltMessenger<SamplePayload, SamplePayload>;
+    type SvcMessenger = SBSvcMessenger<SamplePayload, SamplePayload>;
+
+    #[test]
+    fn test_soup_bin_messenger() {
+        setup::log::configure();
+        for msg_inp in clt_msgs_default::<SamplePayload>() {
+            let (buf, len) = CltMessenger::serialize::<MAX_MSG_SIZE>(&msg_inp).unwrap();
+            let msg_out = SvcMessenger::deserialize(&buf[..len]).unwrap();
+            info!("clt msg_out: {:?}", msg_out);
+            assert_eq!(msg_inp, msg_out);
+        }
+        for msg_inp in svc_msgs_default::<SamplePayload>() {
+            let (buf, len) = SvcMessenger::serialize::<MAX_MSG_SIZE>(&msg_inp).unwrap();
+            let msg_out = CltMessenger::deserialize(&buf[..len]).unwrap();
+            info!("svc msg_out: {:?}", msg_out);
+            assert_eq!(msg_inp, msg_out);
+        }
+        // buffer too small to fit the message
+        let res = CltMessenger::serialize::<2>(&SBCltMsg::login(b"dummy".as_slice().into(), b"dummy".as_slice().into(), Default::default(), Default::default(), Default::default()));
+        info!("res: {:?}", res);
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,8 @@
+pub mod framing;
+pub mod messenger;
+pub mod protocol;
+
+use crate::prelude::{Clt, SBCltAdminProtocol, SBSvcAdminProtocol, Svc};
+
+pub type SBClt<CltPayload, SvcPayload, C, const MAX_MSG_SIZE: usize> = Clt<SBCltAdminProtocol<CltPayload, SvcPayload>, C, MAX_MSG_SIZE>;
+pub type SBSvc<CltPayload, SvcPayload, C, const MAX_MSG_SIZE: usize> = Svc<SBSvcAdminProtocol<CltPayload, SvcPayload>, C, MAX_MSG_SIZE>;
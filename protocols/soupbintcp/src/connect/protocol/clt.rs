@@ -0,0 +1,123 @@
+use std::{
+    io::{Error, ErrorKind},
+    marker::PhantomData,
+    time::Duration,
+};
+
+use bytes::BytesMut;
+use log::{debug, warn};
+
+use crate::prelude::*;
+
+/// Client side SoupBinTCP session protocol which will
+/// * log in during [ProtocolCore::on_connect] requesting the next expected sequence number
+/// * track the sequence number of [SBSvcMsg::S] messages received so that a reconnect using the same instance resumes where the previous connection left off
+/// * log out during [ProtocolCore::on_disconnect]
+/// * send [SBCltMsg::HBeat] at the configured interval
+#[derive(Debug, Clone)]
+pub struct SBCltAdminProtocol<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> {
+    username: UserName,
+    password: Password,
+    session_id: SessionId,
+    io_timeout: Duration,
+    hbeat_interval: Duration,
+    next_recv_seq: ProtocolSessionState<u64>,
+    is_connected: ProtocolConnectionState<bool>,
+    phantom: PhantomData<(CltPayload, SvcPayload)>,
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> SBCltAdminProtocol<CltPayload, SvcPayload> {
+    /// # Arguments
+    /// * `session_id` - blank [SessionId] requests the currently active session
+    /// * `sequence_number` - next sequence number expected by the client, `0` requests to start with the next message generated by the server
+    /// * `io_timeout` - how long to wait for the login to complete
+    /// * `hbeat_interval` - interval at which [SBCltMsg::HBeat] will be sent, also requested as the server's heartbeat interval
+    pub fn new(username: UserName, password: Password, session_id: SessionId, sequence_number: u64, io_timeout: Duration, hbeat_interval: Duration) -> Self {
+        Self {
+            username,
+            password,
+            session_id,
+            io_timeout,
+            hbeat_interval,
+            next_recv_seq: ProtocolSessionState::new(sequence_number),
+            is_connected: ProtocolConnectionState::new(false),
+            phantom: PhantomData,
+        }
+    }
+    /// Sequence number which will be requested on the next login, updated by [SBSvcMsg::LoginAcc] and every [SBSvcMsg::S] received
+    pub fn next_recv_seq(&self) -> u64 {
+        *self.next_recv_seq.lock()
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Framer for SBCltAdminProtocol<CltPayload, SvcPayload> {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        SoupBinFramer::get_frame_length(bytes)
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Messenger for SBCltAdminProtocol<CltPayload, SvcPayload> {
+    type SendT = <SBCltMessenger<CltPayload, SvcPayload> as Messenger>::SendT;
+    type RecvT = <SBCltMessenger<CltPayload, SvcPayload> as Messenger>::RecvT;
+    #[inline(always)]
+    fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+        SBCltMessenger::<CltPayload, SvcPayload>::serialize(msg)
+    }
+    #[inline(always)]
+    fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+        SBCltMessenger::<CltPayload, SvcPayload>::deserialize(frame)
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> ProtocolCore for SBCltAdminProtocol<CltPayload, SvcPayload> {
+    fn on_connect<C: SendNonBlocking<<Self as Messenger>::SendT> + ReSendNonBlocking<<Self as Messenger>::SendT> + RecvNonBlocking<<Self as Messenger>::RecvT> + ConnectionId>(&self, con: &mut C) -> Result<(), Error> {
+        let hbeat_timeout_ms = u16::try_from(self.hbeat_interval.as_millis()).unwrap_or(u16::MAX);
+        let mut msg = SBCltMsg::login(self.username, self.password, self.session_id, self.next_recv_seq().into(), hbeat_timeout_ms.into());
+        if let SendStatus::WouldBlock = con.send_busywait_timeout(&mut msg, self.io_timeout)? {
+            return Err(Error::new(ErrorKind::TimedOut, format!("{} timeout: {:?} sending Login Request", con.con_id(), self.io_timeout)));
+        }
+        match con.recv_busywait_timeout(self.io_timeout)? {
+            RecvStatus::Completed(Some(SBSvcMsg::LoginAcc(acc))) => {
+                debug!("{} login accepted: {}", con.con_id(), acc);
+                self.next_recv_seq.set(acc.sequence_number.into());
+                self.is_connected.set(true);
+                Ok(())
+            }
+            RecvStatus::Completed(Some(SBSvcMsg::LoginRej(rej))) => Err(Error::new(ErrorKind::PermissionDenied, format!("{} login rejected: {}", con.con_id(), rej))),
+            RecvStatus::Completed(msg) => Err(Error::new(ErrorKind::InvalidData, format!("{} Expected Login Accepted instead got msg: {:?}", con.con_id(), msg))),
+            RecvStatus::WouldBlock => Err(Error::new(ErrorKind::TimedOut, format!("{} timeout: {:?} waiting for Login Accepted", con.con_id(), self.io_timeout))),
+        }
+    }
+    fn on_disconnect<C: SendNonBlocking<<Self as Messenger>::SendT> + ReSendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, con: &mut C) -> Result<(), Error> {
+        if !self.is_connected() {
+            return Ok(());
+        }
+        self.is_connected.set(false);
+        match con.send_busywait_timeout(&mut SBCltMsg::logout(), self.io_timeout)? {
+            SendStatus::Completed => Ok(()),
+            SendStatus::WouldBlock => Err(Error::new(ErrorKind::TimedOut, format!("{} timeout: {:?} sending Logout Request", con.con_id(), self.io_timeout))),
+        }
+    }
+    #[inline(always)]
+    fn is_connected(&self) -> bool {
+        *self.is_connected.lock()
+    }
+    #[inline(always)]
+    fn on_recv<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::RecvT) {
+        match msg {
+            SBSvcMsg::S(_) => *self.next_recv_seq.lock() += 1,
+            SBSvcMsg::End(_) => {
+                warn!("{} received End of Session", who.con_id());
+                self.is_connected.set(false);
+            }
+            _ => {}
+        }
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Protocol for SBCltAdminProtocol<CltPayload, SvcPayload> {
+    #[inline(always)]
+    fn conf_heart_beat_interval(&self) -> Option<Duration> {
+        Some(self.hbeat_interval)
+    }
+    #[inline(always)]
+    fn send_heart_beat<S: SendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, sender: &mut S) -> Result<SendStatus, Error> {
+        sender.send(&mut SBCltMsg::hbeat())
+    }
+}
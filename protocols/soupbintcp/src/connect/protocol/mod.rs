@@ -0,0 +1,136 @@
+pub mod clt;
+pub mod svc;
+
+#[cfg(test)]
+#[cfg(feature = "unittest")]
+mod test {
+    use crate::{
+        prelude::*,
+        unittest::setup::{
+            connection::{SBCltTest, SBSvcTest},
+            protocol::{clt_protocol, svc_protocol, PASSWORD},
+        },
+    };
+    use links_core::unittest::setup;
+    use log::{info, LevelFilter};
+    use std::{io::ErrorKind, num::NonZeroUsize, time::Duration};
+
+    fn recv_sdata<R: RecvNonBlocking<SBSvcMsg<SamplePayload>>>(clt: &mut R, count: usize) -> Vec<SamplePayload> {
+        let mut out = vec![];
+        while out.len() < count {
+            match clt.recv_busywait_timeout(setup::net::default_find_timeout()).unwrap() {
+                RecvStatus::Completed(Some(SBSvcMsg::S(SPayload { body, .. }))) => out.push(body),
+                RecvStatus::Completed(Some(_)) => continue,
+                status => panic!("Expected SBSvcMsg::S instead got: {:?}", status),
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_sb_clt_svc_login_accepted() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let mut svc = SBSvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), svc_protocol(), Some("sb/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let clt = SBCltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            LoggerCallback::new_ref(),
+            clt_protocol(PASSWORD, 0),
+            Some("sb/clt"),
+        )
+        .unwrap();
+        info!("clt: {}", clt);
+        assert!(clt.is_connected());
+        assert!(svc.is_next_connected_busywait_timeout(setup::net::default_find_timeout()));
+    }
+
+    #[test]
+    fn test_sb_clt_svc_login_rejected() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let _svc = SBSvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), LoggerCallback::new_ref(), svc_protocol(), Some("sb/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let res = SBCltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            LoggerCallback::new_ref(),
+            clt_protocol(b"bad pass", 0),
+            Some("sb/clt"),
+        );
+        info!("res: {:?}", res);
+        assert_eq!(res.unwrap_err().kind(), ErrorKind::PermissionDenied);
+    }
+
+    #[test]
+    fn test_sb_clt_svc_hbeat() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let svc_counter = CounterCallback::new_ref();
+        let clt_counter = CounterCallback::new_ref();
+        let mut svc = SBSvcTest::bind(addr, NonZeroUsize::new(1).unwrap(), svc_counter.clone(), svc_protocol(), Some("sb/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        let _clt = SBCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), clt_counter.clone(), clt_protocol(PASSWORD, 0), Some("sb/clt"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+        assert!(svc.is_next_connected_busywait_timeout(setup::net::default_find_timeout()));
+
+        // nothing but heart beats are exchanged after login
+        let timeout = Duration::from_secs(2);
+        clt_counter.assert_recv_count_busywait_timeout(4, timeout);
+        svc_counter.assert_recv_count_busywait_timeout(4, timeout);
+        info!("clt_counter: {}, svc_counter: {}", clt_counter, svc_counter);
+    }
+
+    #[test]
+    fn test_sb_clt_svc_replay_on_login() {
+        setup::log::configure_level(LevelFilter::Info);
+        let addr = setup::net::rand_avail_addr_port();
+        let mut svc = SBSvcTest::bind(addr, NonZeroUsize::new(2).unwrap(), LoggerCallback::new_ref(), svc_protocol(), Some("sb/svc"))
+            .unwrap()
+            .into_sender_with_spawned_recver_ref();
+
+        let protocol = clt_protocol(PASSWORD, 1);
+        let mut clt = SBCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), protocol.clone(), Some("sb/clt")).unwrap();
+        assert!(svc.is_next_connected_busywait_timeout(setup::net::default_find_timeout()));
+
+        let msgs_inp = (1..=3)
+            .map(|i| SamplePayload {
+                context1: format!("seq #{}", i).as_bytes().into(),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        for msg in msgs_inp.iter() {
+            svc.send_busywait(&mut SBSvcMsg::sdata(msg.clone())).unwrap();
+        }
+        assert_eq!(recv_sdata(&mut clt, 3), msgs_inp);
+        assert_eq!(protocol.next_recv_seq(), 4);
+        drop(clt);
+
+        // explicit sequence number replays the tail of the session
+        let mut clt = SBCltTest::connect(
+            addr,
+            setup::net::default_connect_timeout(),
+            setup::net::default_connect_retry_after(),
+            LoggerCallback::new_ref(),
+            clt_protocol(PASSWORD, 2),
+            Some("sb/clt"),
+        )
+        .unwrap();
+        assert_eq!(recv_sdata(&mut clt, 2), msgs_inp[1..]);
+        drop(clt);
+
+        // reconnect using the same protocol instance resumes after the last message received
+        let clt = SBCltTest::connect(addr, setup::net::default_connect_timeout(), setup::net::default_connect_retry_after(), LoggerCallback::new_ref(), protocol.clone(), Some("sb/clt")).unwrap();
+        info!("clt: {}", clt);
+        assert_eq!(protocol.next_recv_seq(), 4);
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    io::{Error, ErrorKind},
+    marker::PhantomData,
+    time::Duration,
+};
+
+use bytes::BytesMut;
+use log::{debug, warn};
+
+use crate::prelude::*;
+
+/// Server side SoupBinTCP session protocol which will
+/// * validate [SBCltMsg::Login] during [ProtocolCore::on_connect] and reply with [SBSvcMsg::LoginAcc] or [SBSvcMsg::LoginRej]
+/// * store every [SBSvcMsg::S] sent in a log shared by all connections and replay it starting with the sequence number requested by the login
+/// * send [SBSvcMsg::HBeat] at the interval requested by the login
+///
+/// # Note
+/// Sequence number of a [SBSvcMsg::S] is its position in the shared log, hence all connections accepted by the same [crate::prelude::Svc] belong to the same session
+#[derive(Debug, Clone)]
+pub struct SBSvcAdminProtocol<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> {
+    username: UserName,
+    password: Password,
+    session_id: SessionId,
+    io_timeout: Duration,
+    hbeat_interval: ProtocolConnectionState<Option<Duration>>,
+    is_connected: ProtocolConnectionState<bool>,
+    sent: ProtocolSessionState<InMemoryMessageLog<SBSvcMsg<SvcPayload>>>,
+    phantom: PhantomData<CltPayload>,
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> SBSvcAdminProtocol<CltPayload, SvcPayload> {
+    /// # Arguments
+    /// * `io_timeout` - how long to wait for the login request once the connection is accepted
+    pub fn new(username: UserName, password: Password, session_id: SessionId, io_timeout: Duration) -> Self {
+        Self {
+            username,
+            password,
+            session_id,
+            io_timeout,
+            hbeat_interval: ProtocolConnectionState::new(None),
+            is_connected: ProtocolConnectionState::new(false),
+            sent: InMemoryMessageLog::new().into(),
+            phantom: PhantomData,
+        }
+    }
+    /// Log of [SBSvcMsg::S] messages sent across all connections
+    pub fn sent(&self) -> &ProtocolSessionState<InMemoryMessageLog<SBSvcMsg<SvcPayload>>> {
+        &self.sent
+    }
+    fn reject<C: SendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, con: &mut C, mut msg: SBSvcMsg<SvcPayload>, kind: ErrorKind, reason: &str) -> Result<(), Error> {
+        con.send_busywait_timeout(&mut msg, self.io_timeout)?;
+        Err(Error::new(kind, format!("{} {}, msg: {:?}", con.con_id(), reason, msg)))
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Framer for SBSvcAdminProtocol<CltPayload, SvcPayload> {
+    #[inline(always)]
+    fn get_frame_length(bytes: &BytesMut) -> Option<usize> {
+        SoupBinFramer::get_frame_length(bytes)
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Messenger for SBSvcAdminProtocol<CltPayload, SvcPayload> {
+    type SendT = <SBSvcMessenger<CltPayload, SvcPayload> as Messenger>::SendT;
+    type RecvT = <SBSvcMessenger<CltPayload, SvcPayload> as Messenger>::RecvT;
+    #[inline(always)]
+    fn serialize<const MAX_MSG_SIZE: usize>(msg: &Self::SendT) -> Result<([u8; MAX_MSG_SIZE], usize), Error> {
+        SBSvcMessenger::<CltPayload, SvcPayload>::serialize(msg)
+    }
+    #[inline(always)]
+    fn deserialize(frame: &[u8]) -> Result<Self::RecvT, Error> {
+        SBSvcMessenger::<CltPayload, SvcPayload>::deserialize(frame)
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> ProtocolCore for SBSvcAdminProtocol<CltPayload, SvcPayload> {
+    fn on_connect<C: SendNonBlocking<<Self as Messenger>::SendT> + ReSendNonBlocking<<Self as Messenger>::SendT> + RecvNonBlocking<<Self as Messenger>::RecvT> + ConnectionId>(&self, con: &mut C) -> Result<(), Error> {
+        let login = match con.recv_busywait_timeout(self.io_timeout)? {
+            RecvStatus::Completed(Some(SBCltMsg::Login(login))) => login,
+            RecvStatus::Completed(msg) => return Err(Error::new(ErrorKind::InvalidData, format!("{} Expected Login Request instead got msg: {:?}", con.con_id(), msg))),
+            RecvStatus::WouldBlock => return Err(Error::new(ErrorKind::TimedOut, format!("{} timeout: {:?} waiting for Login Request", con.con_id(), self.io_timeout))),
+        };
+        debug!("{} login request: {}", con.con_id(), login);
+        if login.username != self.username || login.password != self.password {
+            return self.reject(con, SBSvcMsg::login_rej_not_auth(), ErrorKind::PermissionDenied, "username/password mismatch");
+        }
+        if !login.session_id.is_blank() && login.session_id != self.session_id {
+            return self.reject(con, SBSvcMsg::login_rej_ses_not_avail(), ErrorKind::NotFound, "session not available");
+        }
+
+        let (first_seq, last_seq) = {
+            let sent = self.sent.lock();
+            (sent.first_seq(), sent.last_seq())
+        };
+        let next_seq = match u64::from(login.sequence_number) {
+            0 => last_seq + 1,
+            requested => requested.clamp(first_seq, last_seq + 1),
+        };
+        self.hbeat_interval.set(match u64::from(login.hbeat_timeout_ms) {
+            0 => None,
+            ms => Some(Duration::from_millis(ms)),
+        });
+
+        let mut msg = SBSvcMsg::login_acc(self.session_id, next_seq.into());
+        if let SendStatus::WouldBlock = con.send_busywait_timeout(&mut msg, self.io_timeout)? {
+            return Err(Error::new(ErrorKind::TimedOut, format!("{} timeout: {:?} sending Login Accepted", con.con_id(), self.io_timeout)));
+        }
+        // copy the replay range so that the lock is not held while sending, other connections store into the same log
+        let replay = self.sent.lock().range(next_seq..last_seq + 1).cloned().collect::<Vec<_>>();
+        for msg in replay.iter() {
+            con.re_send_busywait(msg)?;
+        }
+        debug!("{} replayed {} sequenced messages starting with seq: {}", con.con_id(), replay.len(), next_seq);
+        self.is_connected.set(true);
+        Ok(())
+    }
+    #[inline(always)]
+    fn is_connected(&self) -> bool {
+        *self.is_connected.lock()
+    }
+    #[inline(always)]
+    fn on_sent<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::SendT) {
+        if let SBSvcMsg::S(_) = msg {
+            if let Err(e) = self.sent.lock().store(msg.clone()) {
+                warn!("{} failed to store msg: {:?}, error: {}", who.con_id(), msg, e);
+            }
+        }
+    }
+    #[inline(always)]
+    fn on_recv<I: ConnectionId>(&self, who: &I, msg: &<Self as Messenger>::RecvT) {
+        if let SBCltMsg::Logout(_) = msg {
+            debug!("{} received Logout Request", who.con_id());
+            self.is_connected.set(false);
+        }
+    }
+}
+impl<CltPayload: SoupBinPayload, SvcPayload: SoupBinPayload> Protocol for SBSvcAdminProtocol<CltPayload, SvcPayload> {
+    #[inline(always)]
+    fn conf_heart_beat_interval(&self) -> Option<Duration> {
+        *self.hbeat_interval.lock()
+    }
+    #[inline(always)]
+    fn send_heart_beat<S: SendNonBlocking<<Self as Messenger>::SendT> + ConnectionId>(&self, sender: &mut S) -> Result<SendStatus, Error> {
+        sender.send(&mut SBSvcMsg::hbeat())
+    }
+}
@@ -0,0 +1,3 @@
+pub mod heartbeat;
+pub mod login_request;
+pub mod logout_request;
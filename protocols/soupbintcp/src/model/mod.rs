@@ -0,0 +1,9 @@
+pub mod debug;
+pub mod payload;
+pub mod sequenced_data;
+pub mod soup_bin;
+pub mod types;
+pub mod unsequenced_data;
+
+pub mod clt;
+pub mod svc;
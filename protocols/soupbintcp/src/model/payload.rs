@@ -0,0 +1,44 @@
+use byteserde::prelude::*;
+use byteserde_derive::{ByteDeserializeSlice, ByteSerializeStack, ByteSerializedLenOf, ByteSerializedSizeOf};
+use byteserde_types::string_ascii_fixed;
+use std::fmt::Debug;
+
+/// Bounds every SoupBinTCP payload must satisfy to be carried by [crate::prelude::SPayload] & [crate::prelude::UPayload]
+pub trait SoupBinPayload: ByteSerializeStack + ByteDeserializeSlice<Self> + ByteSerializedLenOf + PartialEq + Clone + Debug + Send + Sync + 'static {}
+impl<T> SoupBinPayload for T where T: ByteSerializeStack + ByteDeserializeSlice<T> + ByteSerializedLenOf + PartialEq + Clone + Debug + Send + Sync + 'static {}
+
+#[rustfmt::skip]
+string_ascii_fixed!(Context1, 10, b' ', true, #[derive(ByteSerializeStack, ByteDeserializeSlice, ByteSerializedSizeOf, ByteSerializedLenOf, PartialEq, Clone, Copy)]);
+#[rustfmt::skip]
+string_ascii_fixed!(Context2, 10, b' ', true, #[derive(ByteSerializeStack, ByteDeserializeSlice, ByteSerializedSizeOf, ByteSerializedLenOf, PartialEq, Clone, Copy)]);
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteDeserializeSlice, ByteSerializedLenOf, PartialEq, Clone, Debug)]
+pub struct SamplePayload {
+    pub context1: Context1,
+    pub context2: Context2,
+}
+
+impl Default for SamplePayload {
+    fn default() -> Self {
+        Self {
+            context1: b"10 char load".as_slice().into(),
+            context2: b"hello world".as_slice().into(),
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteDeserializeSlice, ByteSerializedLenOf, PartialEq, Clone, Debug, Default)]
+pub struct Nil;
+
+#[rustfmt::skip]
+#[derive(ByteSerializeStack, ByteDeserializeSlice, ByteSerializedLenOf, PartialEq, Clone, Debug, Default)]
+pub struct VecPayload {
+    pub payload: Vec<u8>,
+}
+impl VecPayload {
+    pub fn new(payload: &[u8]) -> Self {
+        Self { payload: payload.to_vec() }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod end_of_session;
+pub mod heartbeat;
+pub mod login_accepted;
+pub mod login_rejected;
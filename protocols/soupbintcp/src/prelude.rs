@@ -0,0 +1,19 @@
+pub use crate::model::{
+    clt::{heartbeat::*, login_request::*, logout_request::*},
+    debug::*,
+    payload::*,
+    sequenced_data::*,
+    soup_bin::*,
+    svc::{end_of_session::*, heartbeat::*, login_accepted::*, login_rejected::*},
+    types::*,
+    unsequenced_data::*,
+};
+
+pub use crate::connect::{
+    framing::SoupBinFramer,
+    messenger::{SBCltMessenger, SBSvcMessenger},
+    protocol::{clt::SBCltAdminProtocol, svc::SBSvcAdminProtocol},
+    SBClt, SBSvc,
+};
+
+pub use links_nonblocking::prelude::*;
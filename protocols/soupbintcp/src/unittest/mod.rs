@@ -0,0 +1,54 @@
+pub mod setup {
+    pub mod model {
+        use crate::prelude::*;
+
+        #[rustfmt::skip]
+        pub fn svc_msgs_default<SvcPayload: SoupBinPayload + Default>() -> Vec<SBSvcMsg<SvcPayload>> {
+            vec![
+                SBSvcMsg::HBeat(SvcHeartbeat::default()),
+                SBSvcMsg::Dbg(DebugMsg::default()),
+                SBSvcMsg::LoginAcc(LoginAccepted::default()),
+                SBSvcMsg::LoginRej(LoginRejected::not_authorized()),
+                SBSvcMsg::End(EndOfSession::default()),
+                SBSvcMsg::S(SPayload::new(SvcPayload::default())),
+                SBSvcMsg::U(UPayload::new(SvcPayload::default())),
+            ]
+        }
+
+        #[rustfmt::skip]
+        pub fn clt_msgs_default<CltPayload: SoupBinPayload + Default>() -> Vec<SBCltMsg<CltPayload>> {
+            vec![
+                SBCltMsg::HBeat(CltHeartbeat::default()),
+                SBCltMsg::Dbg(DebugMsg::default()),
+                SBCltMsg::Login(LoginRequest::default()),
+                SBCltMsg::Logout(LogoutRequest::default()),
+                SBCltMsg::S(SPayload::new(CltPayload::default())),
+                SBCltMsg::U(UPayload::new(CltPayload::default())),
+            ]
+        }
+    }
+    pub mod protocol {
+        use crate::prelude::*;
+        use std::time::Duration;
+
+        pub const USERNAME: &[u8] = b"abcdef";
+        pub const PASSWORD: &[u8] = b"1234567890";
+        pub const SESSION_ID: &[u8] = b"session #1";
+        pub const IO_TIMEOUT: Duration = Duration::from_millis(500);
+        pub const HBEAT_INTERVAL: Duration = Duration::from_millis(250);
+
+        pub fn clt_protocol(password: &[u8], sequence_number: u64) -> SBCltAdminProtocol<SamplePayload, SamplePayload> {
+            SBCltAdminProtocol::new(USERNAME.into(), password.into(), SESSION_ID.into(), sequence_number, IO_TIMEOUT, HBEAT_INTERVAL)
+        }
+        pub fn svc_protocol() -> SBSvcAdminProtocol<SamplePayload, SamplePayload> {
+            SBSvcAdminProtocol::new(USERNAME.into(), PASSWORD.into(), SESSION_ID.into(), IO_TIMEOUT)
+        }
+    }
+    pub mod connection {
+        use crate::prelude::*;
+
+        pub const SB_TEST_MAX_MSG_SIZE: usize = 128;
+        pub type SBCltTest<C> = SBClt<SamplePayload, SamplePayload, C, SB_TEST_MAX_MSG_SIZE>;
+        pub type SBSvcTest<C> = SBSvc<SamplePayload, SamplePayload, C, SB_TEST_MAX_MSG_SIZE>;
+    }
+}